@@ -1,6 +1,6 @@
 use fitbit_sdk::client::FitbitClient;
 use fitbit_sdk::types::activity::{ActivityClient, ActivityError, Resource};
-use std::env;
+use fitbit_sdk::types::date::FitbitDate;
 use tracing::{error, info};
 
 #[tokio::main]
@@ -18,7 +18,7 @@ async fn main() -> Result<(), ActivityError> {
     let client = FitbitClient::new::<ActivityError>()?;
 
     // Get today's activity summary
-    match client.get_activity_summary("-", "today").await {
+    match client.get_activity_summary("-", FitbitDate::Today).await {
         Ok(summary) => {
             info!("Activity Summary for Today:");
             info!("  Steps: {}", summary.steps);
@@ -48,10 +48,10 @@ async fn main() -> Result<(), ActivityError> {
 
     // Get steps time series for the last 7 days
     info!("\nSteps for Last 7 Days:");
-    match client.get_activity_time_series("-", Resource::Steps, "today", "7d").await {
+    match client.get_activity_time_series("-", Resource::Steps, FitbitDate::Today, "7d").await {
         Ok(time_series) => {
             for data_point in time_series {
-                info!("  {}: {} steps", data_point.datetime, data_point.value);
+                info!("  {}: {} steps", data_point.date_time, data_point.value);
             }
         }
         Err(e) => {
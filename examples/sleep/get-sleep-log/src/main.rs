@@ -1,6 +1,6 @@
 use fitbit_sdk::client::FitbitClient;
+use fitbit_sdk::types::date::FitbitDate;
 use fitbit_sdk::types::sleep::{SleepClient, SleepError};
-use std::env;
 use tracing::{error, info};
 
 #[tokio::main]
@@ -18,7 +18,7 @@ async fn main() -> Result<(), SleepError> {
     let client = FitbitClient::new::<SleepError>()?;
 
     // Get last night's sleep data
-    match client.get_sleep_logs("-", "yesterday").await {
+    match client.get_sleep_logs("-", FitbitDate::Yesterday).await {
         Ok(sleep_logs) => {
             info!("Sleep Summary:");
             info!("  Total Sleep Records: {}", sleep_logs.summary.total_sleep_records);
@@ -62,8 +62,11 @@ async fn main() -> Result<(), SleepError> {
     // Get sleep goal
     info!("\nSleep Goal:");
     match client.get_sleep_goal("-").await {
-        Ok(goal) => {
-            info!("  Target sleep: {} minutes", goal.goal);
+        Ok(response) => {
+            info!("  Target sleep: {} minutes", response.goal.min_duration);
+            if let Some(typical) = response.consistency.and_then(|c| c.typical_duration) {
+                info!("  Typical sleep: {} minutes", typical);
+            }
         }
         Err(e) => {
             error!("Error getting sleep goal: {}", e);
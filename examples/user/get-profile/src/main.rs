@@ -1,6 +1,5 @@
 use fitbit_sdk::client::FitbitClient;
 use fitbit_sdk::types::user::{UserClient, UserError};
-use std::env;
 use tracing::{error, info};
 
 #[tokio::main]
@@ -21,16 +20,26 @@ async fn main() -> Result<(), UserError> {
         Ok(profile) => {
             info!("User Profile Information:");
             info!("  Display Name: {}", profile.display_name);
-            info!("  Full Name: {}", profile.full_name);
-            info!("  Date of Birth: {}", profile.date_of_birth);
-            info!("  Gender: {:?}", profile.gender);
-            info!("  Height: {}", profile.height);
+            if let Some(full_name) = profile.full_name {
+                info!("  Full Name: {}", full_name);
+            }
+            if let Some(date_of_birth) = profile.date_of_birth {
+                info!("  Date of Birth: {}", date_of_birth);
+            }
+            if let Some(gender) = profile.gender {
+                info!("  Gender: {:?}", gender);
+            }
+            if let Some(height) = profile.height {
+                info!("  Height: {}", height);
+            }
             if let Some(weight) = profile.weight {
                 info!("  Weight: {}", weight);
             }
             info!("  Height Unit: {:?}", profile.height_unit);
             info!("  Weight Unit: {:?}", profile.weight_unit);
-            info!("  Average Daily Steps: {}", profile.average_daily_steps);
+            if let Some(steps) = profile.average_daily_steps {
+                info!("  Average Daily Steps: {}", steps);
+            }
         }
         Err(e) => {
             error!("Error getting profile: {}", e);
@@ -0,0 +1,172 @@
+//! Recorded Responses
+//!
+//! This module contains the recorded Fitbit API response of every endpoint
+//! implemented by `fitbit-sdk`.
+//!
+//! Dated endpoints are recorded for `DATE`, date ranges for `START_DATE` to
+//! `DATE` and periods for `7d` ending on `DATE`. Intraday endpoints are
+//! recorded at the `1min` detail level, and intraday windows for `08:00` to
+//! `08:05`. Endpoints taking a log or activity ID are recorded for the IDs
+//! below.
+//!
+//! Responses for `MINIMAL_SCOPE_USER_ID` are recorded with a token granting
+//! only the scope of the endpoint, for a user sharing no personal information.
+
+/// Date the dated fixtures are recorded for
+pub const DATE: &str = "2025-01-15";
+
+/// First date of the date range fixtures, ending on `DATE`
+pub const START_DATE: &str = "2025-01-13";
+
+/// Log ID of the recorded activity log
+pub const ACTIVITY_LOG_ID: i64 = 45678901234;
+
+/// Activity type ID of the recorded activity type and favorite
+pub const ACTIVITY_ID: i64 = 90009;
+
+/// Log ID of the recorded sleep log
+pub const SLEEP_LOG_ID: i64 = 36543210987;
+
+/// Log ID of the recorded weight and body fat logs
+pub const BODY_LOG_ID: i64 = 1736928000000;
+
+/// Log ID of the recorded water log
+pub const WATER_LOG_ID: i64 = 10987654321;
+
+/// Log ID of the recorded food log
+pub const FOOD_LOG_ID: i64 = 20987654321;
+
+/// Subscription ID of the recorded subscription
+pub const SUBSCRIPTION_ID: &str = "320";
+
+/// Encoded ID of the user recorded with a minimal-scope token
+pub const MINIMAL_SCOPE_USER_ID: &str = "XYZ789";
+
+/// Recorded response of a single endpoint
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Fixture {
+    /// HTTP method of the request
+    pub method: &'static str,
+    /// Path of the request, including the API version
+    pub path: &'static str,
+    /// HTTP status of the response
+    pub status: u16,
+    /// Body of the response (empty for `204 No Content`)
+    pub body: &'static str,
+}
+
+macro_rules! fixture {
+    ($method:literal, $path:literal, $status:literal) => {
+        Fixture {
+            method: $method,
+            path: $path,
+            status: $status,
+            body: "",
+        }
+    };
+    ($method:literal, $path:literal, $status:literal, $file:literal) => {
+        Fixture {
+            method: $method,
+            path: $path,
+            status: $status,
+            body: include_str!(concat!("../fixtures/", $file)),
+        }
+    };
+}
+
+/// Recorded responses of every implemented endpoint
+pub const FIXTURES: &[Fixture] = &[
+    // User
+    fixture!("GET", "/1/user/-/profile.json", 200, "user/profile.json"),
+    fixture!("POST", "/1/user/-/profile.json", 200, "user/profile.json"),
+    fixture!("GET", "/1/user/-/badges.json", 200, "user/badges.json"),
+    fixture!("GET", "/1/user/XYZ789/profile.json", 200, "user/profile_minimal_scope.json"),
+    // Activity
+    fixture!("GET", "/1/user/-/activities/date/2025-01-15.json", 200, "activity/daily.json"),
+    fixture!("GET", "/1/user/XYZ789/activities/date/2025-01-15.json", 200, "activity/daily_minimal_scope.json"),
+    fixture!("GET", "/1/user/-/activities/steps/date/2025-01-15/7d.json", 200, "activity/steps_7d.json"),
+    fixture!("GET", "/1/user/-/activities/steps/date/2025-01-13/2025-01-15.json", 200, "activity/steps_range.json"),
+    fixture!("GET", "/1/user/-/activities/elevation/date/2025-01-15/7d.json", 200, "activity/elevation_7d.json"),
+    fixture!("GET", "/1/user/-/activities/steps/date/2025-01-15/1d/1min.json", 200, "activity/steps_intraday.json"),
+    fixture!("GET", "/1/user/-/activities/steps/date/2025-01-15/1d/1min/time/08:00/08:05.json", 200, "activity/steps_intraday.json"),
+    fixture!("GET", "/1/user/-/activities.json", 200, "activity/lifetime.json"),
+    fixture!("POST", "/1/user/-/activities.json", 201, "activity/log_activity.json"),
+    fixture!("DELETE", "/1/user/-/activities/45678901234.json", 204),
+    fixture!("GET", "/1/user/-/activities/list.json", 200, "activity/list.json"),
+    fixture!("GET", "/1/user/-/activities/45678901234.tcx", 200, "activity/activity.tcx"),
+    fixture!("GET", "/1/user/-/activities/goals/daily.json", 200, "activity/goals_daily.json"),
+    fixture!("POST", "/1/user/-/activities/goals/daily.json", 201, "activity/goals_daily.json"),
+    fixture!("GET", "/1/user/-/activities/goals/weekly.json", 200, "activity/goals_weekly.json"),
+    fixture!("GET", "/1/user/-/activities/favorite.json", 200, "activity/favorite.json"),
+    fixture!("POST", "/1/user/-/activities/favorite/90009.json", 201),
+    fixture!("DELETE", "/1/user/-/activities/favorite/90009.json", 204),
+    fixture!("GET", "/1/user/-/activities/frequent.json", 200, "activity/frequent.json"),
+    fixture!("GET", "/1/user/-/activities/recent.json", 200, "activity/recent.json"),
+    fixture!("GET", "/1/activities.json", 200, "activity/activity_types.json"),
+    fixture!("GET", "/1/activities/90009.json", 200, "activity/activity_type.json"),
+    // Sleep
+    fixture!("GET", "/1.2/user/-/sleep/date/2025-01-15.json", 200, "sleep/sleep_log.json"),
+    fixture!("GET", "/1.2/user/-/sleep/goal.json", 200, "sleep/goal.json"),
+    fixture!("POST", "/1.2/user/-/sleep/goal.json", 201, "sleep/goal.json"),
+    fixture!("POST", "/1.2/user/-/sleep.json", 201, "sleep/log_sleep.json"),
+    fixture!("DELETE", "/1.2/user/-/sleep/36543210987.json", 204),
+    fixture!("GET", "/1.2/user/-/sleep/list.json", 200, "sleep/list.json"),
+    // Body
+    fixture!("GET", "/1/user/-/body/log/weight/date/2025-01-15.json", 200, "body/weight_log.json"),
+    fixture!("GET", "/1/user/-/body/log/weight/date/2025-01-13/2025-01-15.json", 200, "body/weight_log_range.json"),
+    fixture!("GET", "/1/user/-/body/log/weight/date/2025-01-15/7d.json", 200, "body/weight_log_range.json"),
+    fixture!("GET", "/1/user/-/body/log/fat/date/2025-01-15.json", 200, "body/fat_log.json"),
+    fixture!("GET", "/1/user/-/body/log/fat/date/2025-01-13/2025-01-15.json", 200, "body/fat_log_range.json"),
+    fixture!("GET", "/1/user/-/body/log/fat/date/2025-01-15/7d.json", 200, "body/fat_log_range.json"),
+    fixture!("GET", "/1/user/-/body/log/weight/goal.json", 200, "body/weight_goal.json"),
+    fixture!("GET", "/1/user/-/body/log/fat/goal.json", 200, "body/fat_goal.json"),
+    fixture!("POST", "/1/user/-/body/log/weight.json", 201, "body/log_weight.json"),
+    fixture!("DELETE", "/1/user/-/body/log/weight/1736928000000.json", 204),
+    fixture!("POST", "/1/user/-/body/log/fat.json", 201, "body/log_fat.json"),
+    fixture!("DELETE", "/1/user/-/body/log/fat/1736928000000.json", 204),
+    fixture!("GET", "/1/user/-/body/weight/date/2025-01-15/7d.json", 200, "body/weight_7d.json"),
+    fixture!("GET", "/1/user/-/body/bmi/date/2025-01-15/1d.json", 200, "body/bmi_1d.json"),
+    // Nutrition
+    fixture!("GET", "/1/user/-/foods/log/water/date/2025-01-15.json", 200, "nutrition/water_log.json"),
+    fixture!("GET", "/1/user/-/foods/log/date/2025-01-15.json", 200, "nutrition/food_log.json"),
+    fixture!("POST", "/1/user/-/foods/log/water.json", 201, "nutrition/log_water.json"),
+    fixture!("DELETE", "/1/user/-/foods/log/water/10987654321.json", 204),
+    fixture!("GET", "/1/user/-/foods/log/water/goal.json", 200, "nutrition/water_goal.json"),
+    fixture!("POST", "/1/user/-/foods/log/water/goal.json", 201, "nutrition/water_goal.json"),
+    fixture!("POST", "/1/user/-/foods/log.json", 201, "nutrition/log_food.json"),
+    fixture!("POST", "/1/user/-/foods/log/20987654321.json", 200, "nutrition/log_food.json"),
+    fixture!("DELETE", "/1/user/-/foods/log/20987654321.json", 204),
+    fixture!("GET", "/1/user/-/foods/log/caloriesIn/date/2025-01-15/7d.json", 200, "nutrition/calories_in_7d.json"),
+    // Heart Rate
+    fixture!("GET", "/1/user/-/activities/heart/date/2025-01-15/1d.json", 200, "heart_rate/heart_1d.json"),
+    fixture!("GET", "/1/user/-/activities/heart/date/2025-01-13/2025-01-15.json", 200, "heart_rate/heart_range.json"),
+    fixture!("GET", "/1/user/-/activities/heart/date/2025-01-15/1d/1min.json", 200, "heart_rate/heart_intraday.json"),
+    fixture!("GET", "/1/user/-/activities/heart/date/2025-01-15/1d/1min/time/08:00/08:05.json", 200, "heart_rate/heart_intraday.json"),
+    // Heart Rate Variability
+    fixture!("GET", "/1/user/-/hrv/date/2025-01-15.json", 200, "hrv/summary.json"),
+    fixture!("GET", "/1/user/-/hrv/date/2025-01-13/2025-01-15.json", 200, "hrv/summary_range.json"),
+    fixture!("GET", "/1/user/-/hrv/date/2025-01-15/all.json", 200, "hrv/intraday.json"),
+    fixture!("GET", "/1/user/-/hrv/date/2025-01-13/2025-01-15/all.json", 200, "hrv/intraday_range.json"),
+    // Breathing Rate
+    fixture!("GET", "/1/user/-/br/date/2025-01-15.json", 200, "breathing_rate/summary.json"),
+    fixture!("GET", "/1/user/-/br/date/2025-01-13/2025-01-15.json", 200, "breathing_rate/summary_range.json"),
+    fixture!("GET", "/1/user/-/br/date/2025-01-15/all.json", 200, "breathing_rate/intraday.json"),
+    fixture!("GET", "/1/user/-/br/date/2025-01-13/2025-01-15/all.json", 200, "breathing_rate/intraday_range.json"),
+    // Temperature
+    fixture!("GET", "/1/user/-/temp/skin/date/2025-01-15.json", 200, "temperature/skin.json"),
+    fixture!("GET", "/1/user/-/temp/skin/date/2025-01-13/2025-01-15.json", 200, "temperature/skin_range.json"),
+    fixture!("GET", "/1/user/-/temp/core/date/2025-01-15.json", 200, "temperature/core.json"),
+    fixture!("GET", "/1/user/-/temp/core/date/2025-01-13/2025-01-15.json", 200, "temperature/core_range.json"),
+    // Cardio Fitness Score
+    fixture!("GET", "/1/user/-/cardioscore/date/2025-01-15.json", 200, "cardio_fitness/score.json"),
+    fixture!("GET", "/1/user/-/cardioscore/date/2025-01-13/2025-01-15.json", 200, "cardio_fitness/score_range.json"),
+    // Active Zone Minutes
+    fixture!("GET", "/1/user/-/activities/active-zone-minutes/date/2025-01-15/1d.json", 200, "azm/time_series.json"),
+    fixture!("GET", "/1/user/-/activities/active-zone-minutes/date/2025-01-13/2025-01-15.json", 200, "azm/time_series_range.json"),
+    fixture!("GET", "/1/user/-/activities/active-zone-minutes/date/2025-01-15/1d/1min.json", 200, "azm/intraday.json"),
+    fixture!("GET", "/1/user/-/activities/active-zone-minutes/date/2025-01-15/1d/1min/time/08:00/08:05.json", 200, "azm/intraday.json"),
+    // Subscriptions
+    fixture!("GET", "/1/user/-/apiSubscriptions.json", 200, "subscriptions/list.json"),
+    fixture!("POST", "/1/user/-/activities/apiSubscriptions/320.json", 201, "subscriptions/subscription.json"),
+    fixture!("DELETE", "/1/user/-/activities/apiSubscriptions/320.json", 204),
+];
@@ -0,0 +1,101 @@
+//! Fitbit SDK Test Harness
+//!
+//! This crate contains recorded Fitbit API responses for every endpoint
+//! implemented by `fitbit-sdk`, and a `FixtureServer` serving them over HTTP so
+//! that code built on `FitbitClient` can be tested against realistic payloads.
+//!
+//! # Examples
+//!
+//! ```
+//! use fitbit_sdk::types::user::{UserClient, UserError};
+//! use fitbit_sdk_test::FixtureServer;
+//! use tokio;
+//!
+//! #[tokio::main]
+//! async fn main() -> Result<(), UserError> {
+//!     let server = FixtureServer::start().await;
+//!     let client = server.client::<UserError>()?;
+//!
+//!     let badges = client.get_badges("-").await?;
+//!     assert_eq!(badges[0].short_name, "Classics");
+//!
+//!     Ok(())
+//! }
+//! ```
+pub mod fixtures;
+
+pub use fixtures::{FIXTURES, Fixture};
+
+use fitbit_sdk::client::FitbitClient;
+use fitbit_sdk::types::error::ClientBuildError;
+use fitbit_sdk::types::profile::ClientProfile;
+use std::error::Error as StdError;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+/// HTTP server serving the recorded Fitbit API responses
+///
+/// Every fixture in `FIXTURES` is mounted on a local server; requests without
+/// a fixture receive a `404 Not Found`. Mount additional responses on
+/// `mock_server` to override or extend the recorded ones.
+pub struct FixtureServer {
+    server: MockServer,
+}
+
+impl FixtureServer {
+    /// Access token the clients created by `client` send
+    pub const ACCESS_TOKEN: &'static str = "fixture-access-token";
+
+    /// Starts a server serving every fixture in `FIXTURES`
+    pub async fn start() -> Self {
+        Self::with_fixtures(FIXTURES).await
+    }
+
+    /// Starts a server serving only `fixtures`
+    pub async fn with_fixtures(fixtures: &[Fixture]) -> Self {
+        let server = MockServer::start().await;
+        for fixture in fixtures {
+            let response = if fixture.body.is_empty() {
+                ResponseTemplate::new(fixture.status)
+            } else {
+                let content_type = if fixture.path.ends_with(".tcx") {
+                    "application/vnd.garmin.tcx+xml"
+                } else {
+                    "application/json"
+                };
+                ResponseTemplate::new(fixture.status).set_body_raw(fixture.body, content_type)
+            };
+            Mock::given(method(fixture.method))
+                .and(path(fixture.path))
+                .respond_with(response)
+                .mount(&server)
+                .await;
+        }
+        Self { server }
+    }
+
+    /// Returns the base URL of the server, without the API version
+    pub fn uri(&self) -> String {
+        self.server.uri()
+    }
+
+    /// Returns the underlying mock server
+    pub fn mock_server(&self) -> &MockServer {
+        &self.server
+    }
+
+    /// Creates a client sending its requests to this server
+    ///
+    /// The client checks that every request path is a known endpoint of the
+    /// Fitbit API.
+    pub fn client<E>(&self) -> Result<FitbitClient, E>
+    where
+        E: StdError + From<ClientBuildError>,
+    {
+        FitbitClient::builder()
+            .with_access_token(Self::ACCESS_TOKEN)
+            .with_profile(ClientProfile::Mock(self.uri()))
+            .build()
+            .map_err(E::from)
+    }
+}
@@ -0,0 +1,404 @@
+use fitbit_sdk::types::activity::{
+    ActivityClient, ActivityError, ActivityGoalsParams, ElevationUnit, GoalPeriod,
+    LogActivityParams, Resource,
+};
+use fitbit_sdk::types::date::FitbitDate;
+use fitbit_sdk::types::datetime::ApiField;
+use fitbit_sdk::types::error::FitbitError;
+use fitbit_sdk::types::intraday::DetailLevel;
+use fitbit_sdk::types::pagination::{ListParams, SortOrder};
+use fitbit_sdk_test::FixtureServer;
+use fitbit_sdk_test::fixtures::{
+    ACTIVITY_ID, ACTIVITY_LOG_ID, DATE, MINIMAL_SCOPE_USER_ID, START_DATE,
+};
+use futures_util::TryStreamExt;
+use time::OffsetDateTime;
+use time::macros::{date, datetime, time};
+use std::time::Duration;
+use wiremock::matchers::{body_string_contains, method, path};
+use wiremock::{Mock, ResponseTemplate};
+
+fn date() -> FitbitDate {
+    DATE.parse().unwrap()
+}
+
+#[tokio::test]
+async fn get_activity_summary() {
+    let server = FixtureServer::start().await;
+    let client = server.client::<ActivityError>().unwrap();
+
+    let summary = client.get_activity_summary("-", date()).await.unwrap();
+    assert_eq!(summary.steps, 10234);
+    assert_eq!(summary.calories, 2650);
+    assert_eq!(summary.activity_calories, Some(1120));
+    assert_eq!(summary.calories_bmr, Some(1650));
+    assert_eq!(summary.marginal_calories, Some(720));
+    assert_eq!(summary.heart_rate_zones.len(), 4);
+    assert_eq!(summary.use_estimation, Some(true));
+}
+
+#[tokio::test]
+async fn get_activity_daily() {
+    let server = FixtureServer::start().await;
+    let client = server.client::<ActivityError>().unwrap();
+
+    let daily = client.get_activity_daily("-", date()).await.unwrap();
+    assert_eq!(daily.activities[0].log_id, ACTIVITY_LOG_ID);
+    assert_eq!(daily.goals.unwrap().steps, Some(10000));
+}
+
+#[tokio::test]
+async fn get_activity_daily_without_heartrate_scope() {
+    let server = FixtureServer::start().await;
+    let client = server.client::<ActivityError>().unwrap();
+
+    let daily = client.get_activity_daily(MINIMAL_SCOPE_USER_ID, date()).await.unwrap();
+    assert!(daily.activities.is_empty());
+    assert!(daily.goals.is_none());
+    assert_eq!(daily.summary.steps, 10234);
+    assert_eq!(daily.summary.resting_heart_rate, None);
+    assert!(daily.summary.heart_rate_zones.is_empty());
+}
+
+#[tokio::test]
+async fn get_activity_summary_range() {
+    let server = FixtureServer::start().await;
+    for (day, steps, resting_heart_rate) in [("2025-01-13", 8000, 60), ("2025-01-14", 12500, 61)] {
+        let body = serde_json::json!({
+            "summary": {
+                "steps": steps,
+                "distances": [{"activity": "total", "distance": 6.0}],
+                "caloriesOut": 2400,
+                "sedentaryMinutes": 700,
+                "lightlyActiveMinutes": 200,
+                "fairlyActiveMinutes": 20,
+                "veryActiveMinutes": 15,
+                "restingHeartRate": resting_heart_rate
+            }
+        });
+        Mock::given(method("GET"))
+            .and(path(format!("/1/user/-/activities/date/{}.json", day)))
+            .respond_with(ResponseTemplate::new(200).set_body_json(body))
+            .mount(server.mock_server())
+            .await;
+    }
+    let client = server.client::<ActivityError>().unwrap();
+
+    let range = client
+        .get_activity_summary_range("-", date!(2025 - 01 - 13), date!(2025 - 01 - 15))
+        .await
+        .unwrap();
+    assert_eq!(range.days.len(), 3);
+    assert_eq!(range.days[2].date.to_string(), DATE);
+    assert_eq!(range.totals.steps, 8000 + 12500 + 10234);
+    assert_eq!(range.totals.calories, 2400 + 2400 + 2650);
+    assert_eq!(range.averages.resting_heart_rate, Some(61.0));
+    assert_eq!(range.best_day.unwrap().date, date!(2025 - 01 - 14));
+
+    let empty = client
+        .get_activity_summary_range("-", date!(2025 - 01 - 15), date!(2025 - 01 - 13))
+        .await
+        .unwrap();
+    assert!(empty.days.is_empty());
+    assert!(empty.best_day.is_none());
+}
+
+#[tokio::test]
+async fn get_activity_time_series() {
+    let server = FixtureServer::start().await;
+    let client = server.client::<ActivityError>().unwrap();
+
+    let series = client
+        .get_activity_time_series("-", Resource::Steps, date(), "7d")
+        .await
+        .unwrap();
+    assert_eq!(series.len(), 7);
+    assert_eq!(series[6].date_time.to_api_string(), DATE);
+    assert_eq!(series[6].value, 10234.0);
+}
+
+#[tokio::test]
+async fn get_activity_time_series_range() {
+    let server = FixtureServer::start().await;
+    let client = server.client::<ActivityError>().unwrap();
+
+    let start: FitbitDate = START_DATE.parse().unwrap();
+    let series = client
+        .get_activity_time_series_range("-", Resource::Steps, start, date())
+        .await
+        .unwrap();
+    assert_eq!(series.len(), 3);
+    assert_eq!(series[0].date_time.to_api_string(), START_DATE);
+    assert_eq!(series[2].value, 10234.0);
+}
+
+#[tokio::test]
+async fn get_elevation_time_series() {
+    let server = FixtureServer::start().await;
+    let client = server.client::<ActivityError>().unwrap();
+
+    let elevation = client.get_elevation_time_series("-", date(), "7d", false).await.unwrap();
+    assert_eq!(elevation.len(), 7);
+    assert_eq!(elevation[6].date.to_string(), DATE);
+    assert_eq!(elevation[6].value, 30.48);
+    assert_eq!(elevation[6].unit, ElevationUnit::Meters);
+}
+
+#[tokio::test]
+async fn get_tracker_elevation_in_us_units() {
+    let server = FixtureServer::with_fixtures(&[]).await;
+    let body = r#"{"activities-tracker-elevation":[{"dateTime":"2025-01-15","value":"100"}]}"#;
+    Mock::given(method("GET"))
+        .and(path("/1/user/-/activities/tracker/elevation/date/2025-01-15/1d.json"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("Content-Language", "en_US")
+                .set_body_raw(body, "application/json"),
+        )
+        .mount(server.mock_server())
+        .await;
+    let client = server.client::<ActivityError>().unwrap();
+
+    let elevation = client.get_elevation_time_series("-", date(), "1d", true).await.unwrap();
+    assert_eq!(elevation[0].unit, ElevationUnit::Feet);
+    assert_eq!(elevation[0].to_meters(), 30.48);
+}
+
+#[tokio::test]
+async fn get_activity_intraday() {
+    let server = FixtureServer::start().await;
+    let client = server.client::<ActivityError>().unwrap();
+
+    let series = client
+        .get_activity_intraday("-", Resource::Steps, date(), DetailLevel::OneMinute)
+        .await
+        .unwrap();
+    assert_eq!(series.summary[0].value, 10234.0);
+    assert_eq!(series.intraday.dataset_interval, 1);
+    assert_eq!(series.intraday.dataset[0].time.to_api_string(), "08:00:00");
+
+    let window = client
+        .get_activity_intraday_window(
+            "-",
+            Resource::Steps,
+            date(),
+            DetailLevel::OneMinute,
+            time!(08:00),
+            time!(08:05),
+        )
+        .await
+        .unwrap();
+    assert_eq!(window.intraday.dataset.len(), 6);
+}
+
+#[tokio::test]
+async fn stream_activity_intraday() {
+    let server = FixtureServer::start().await;
+    let client = server.client::<ActivityError>().unwrap();
+
+    let series = client
+        .get_activity_intraday("-", Resource::Steps, date(), DetailLevel::OneMinute)
+        .await
+        .unwrap();
+    let points: Vec<_> = client
+        .stream_activity_intraday("-", Resource::Steps, date(), DetailLevel::OneMinute)
+        .try_collect()
+        .await
+        .unwrap();
+    assert_eq!(points, series.intraday.dataset);
+}
+
+#[tokio::test]
+async fn get_lifetime_stats() {
+    let server = FixtureServer::start().await;
+    let client = server.client::<ActivityError>().unwrap();
+
+    let stats = client.get_lifetime_stats("-").await.unwrap();
+    assert_eq!(stats.lifetime.total.steps, 10345678);
+    assert_eq!(stats.lifetime.tracker.steps, 10212345);
+    assert_eq!(stats.lifetime.total.calories_out, Some(-1));
+    assert_eq!(stats.best.total.steps.value, 31245);
+    assert_eq!(stats.best.tracker.steps.value, 30988);
+    assert_eq!(stats.best.tracker.floors.unwrap().date.to_api_string(), "2024-09-14");
+}
+
+#[tokio::test]
+async fn get_lifetime_stats_detailed() {
+    let server = FixtureServer::start().await;
+    let client = server.client::<ActivityError>().unwrap();
+
+    let stats = client.get_lifetime_stats_detailed("-").await.unwrap();
+    assert_eq!(stats.total.steps, 10345678);
+    assert_eq!(stats.tracker.steps, 10212345);
+    assert_eq!(stats.manual_steps(), 133333);
+    assert_eq!(stats.total.calories_out, None);
+    assert_eq!(stats.tracker.floors, Some(12034));
+    assert_eq!(stats.total.best_steps.value, 31245);
+    assert_eq!(stats.tracker.best_steps.value, 30988);
+    assert_eq!(stats.tracker.best_distance.value, 23.9);
+}
+
+#[tokio::test]
+async fn log_and_delete_activity() {
+    let server = FixtureServer::start().await;
+    let client = server.client::<ActivityError>().unwrap();
+
+    let params = LogActivityParams::for_activity(ACTIVITY_ID, DATE, "08:00", 1800000);
+    let log = client.log_activity("-", &params).await.unwrap();
+    assert_eq!(log.log_id, ACTIVITY_LOG_ID);
+    assert_eq!(log.steps, Some(5120));
+
+    client.delete_activity_log("-", ACTIVITY_LOG_ID).await.unwrap();
+}
+
+#[tokio::test]
+async fn quick_log_activity() {
+    let server = FixtureServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/1/user/-/activities.json"))
+        .and(body_string_contains(format!("activityId={}", ACTIVITY_ID)))
+        .and(body_string_contains("startTime=08%3A00"))
+        .and(body_string_contains("durationMillis=1800000"))
+        .and(body_string_contains(format!("date={}", DATE)))
+        .respond_with(ResponseTemplate::new(201).set_body_raw(
+            include_str!("../fixtures/activity/log_activity.json"),
+            "application/json",
+        ))
+        .with_priority(1)
+        .expect(1)
+        .mount(server.mock_server())
+        .await;
+    let client = server.client::<ActivityError>().unwrap();
+
+    let log = client
+        .quick_log_activity(ACTIVITY_ID, date!(2025 - 01 - 15), time!(08:00:30), Duration::from_secs(30 * 60))
+        .await
+        .unwrap();
+    assert_eq!(log.log_id, ACTIVITY_LOG_ID);
+}
+
+#[tokio::test]
+async fn get_activity_log_list() {
+    let server = FixtureServer::start().await;
+    let client = server.client::<ActivityError>().unwrap();
+
+    let params = ListParams::before("2025-01-16").with_limit(20);
+    let page = client.get_activity_log_list("-", &params).await.unwrap();
+    assert_eq!(page.pagination.limit, 20);
+    let activity = &page.activities[0];
+    assert_eq!(activity.log_id, ACTIVITY_LOG_ID);
+    assert_eq!(activity.activity_type_id, ACTIVITY_ID);
+    assert_eq!(
+        activity.start_time.convert::<OffsetDateTime>(),
+        Ok(datetime!(2025-01-15 08:00:00 -08:00))
+    );
+    assert_eq!(activity.heart_rate_zones.len(), 4);
+    assert_eq!(activity.elevation_gain, Some(12.19));
+    assert_eq!(activity.swim_lengths, None);
+    assert_eq!(activity.activity_level.len(), 4);
+    assert_eq!(activity.activity_level[3].name, "very");
+    assert_eq!(activity.activity_level[3].minutes, 23);
+
+    let swim = &page.activities[1];
+    assert_eq!(swim.activity_name, "Swim");
+    assert_eq!(swim.swim_lengths, Some(40));
+    assert_eq!(swim.pool_length, Some(25.0));
+    assert_eq!(swim.pool_length_unit.as_deref(), Some("Meter"));
+    assert_eq!(swim.pace, Some(1800.0));
+    assert_eq!(swim.speed, Some(2.0));
+    assert_eq!(swim.elevation_gain, None);
+
+    let activities = client.get_all_activity_log_list("-", &params).await.unwrap();
+    assert_eq!(activities, page.activities);
+}
+
+#[tokio::test]
+async fn get_activity_log_list_rejects_invalid_params() {
+    let server = FixtureServer::with_fixtures(&[]).await;
+    let client = server.client::<ActivityError>().unwrap();
+
+    let too_many = ListParams::after("2025-01-01").with_limit(101);
+    let error = client.get_activity_log_list("-", &too_many).await.unwrap_err();
+    assert!(matches!(error, ActivityError(FitbitError::RequestFailed(_))));
+
+    let mut both_dates = ListParams::after("2025-01-01");
+    both_dates.before_date = Some("2025-01-16".to_string());
+    assert!(client.get_all_activity_log_list("-", &both_dates).await.is_err());
+
+    let mut wrong_sort = ListParams::before("2025-01-16");
+    wrong_sort.sort = SortOrder::Asc;
+    assert!(client.get_activity_log_list("-", &wrong_sort).await.is_err());
+
+    assert!(server.mock_server().received_requests().await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn get_activity_tcx() {
+    let server = FixtureServer::start().await;
+    let client = server.client::<ActivityError>().unwrap();
+
+    let tcx = client.get_activity_tcx("-", ACTIVITY_LOG_ID).await.unwrap();
+    assert!(tcx.contains("<TrainingCenterDatabase"));
+
+    let tcx = client.get_activity_tcx_parsed("-", ACTIVITY_LOG_ID).await.unwrap();
+    let activity = &tcx.activities.activity[0];
+    assert_eq!(activity.sport, "Running");
+    assert_eq!(activity.laps[0].calories, Some(312));
+    assert_eq!(activity.laps[0].track.as_ref().unwrap().trackpoints.len(), 2);
+}
+
+#[tokio::test]
+async fn get_and_update_activity_goals() {
+    let server = FixtureServer::start().await;
+    let client = server.client::<ActivityError>().unwrap();
+
+    let daily = client.get_activity_goals("-", GoalPeriod::Daily).await.unwrap();
+    assert_eq!(daily.steps, Some(10000));
+    assert_eq!(daily.active_zone_minutes, Some(22));
+
+    let weekly = client.get_activity_goals("-", GoalPeriod::Weekly).await.unwrap();
+    assert_eq!(weekly.steps, Some(70000));
+    assert_eq!(weekly.calories_out, None);
+
+    let params = ActivityGoalsParams::new().with_steps(10000);
+    let updated = client
+        .update_activity_goals("-", GoalPeriod::Daily, &params)
+        .await
+        .unwrap();
+    assert_eq!(updated, daily);
+}
+
+#[tokio::test]
+async fn get_favorite_frequent_and_recent_activities() {
+    let server = FixtureServer::start().await;
+    let client = server.client::<ActivityError>().unwrap();
+
+    let favorites = client.get_favorite_activities("-").await.unwrap();
+    assert_eq!(favorites[0].activity_id, ACTIVITY_ID);
+
+    let frequent = client.get_frequent_activities("-").await.unwrap();
+    assert_eq!(frequent.len(), 2);
+    assert_eq!(frequent[0].distance, 0.0);
+
+    let recent = client.get_recent_activities("-").await.unwrap();
+    assert_eq!(recent[0].name, "Run");
+
+    client.add_favorite_activity("-", ACTIVITY_ID).await.unwrap();
+    client.delete_favorite_activity("-", ACTIVITY_ID).await.unwrap();
+}
+
+#[tokio::test]
+async fn browse_activity_types() {
+    let server = FixtureServer::start().await;
+    let client = server.client::<ActivityError>().unwrap();
+
+    let categories = client.browse_activity_types().await.unwrap();
+    assert_eq!(categories.len(), 2);
+    let run = categories[0].find_activity("run").unwrap();
+    assert_eq!(run.id, ACTIVITY_ID);
+    assert!(categories[1].find_activity("Mountain biking").is_some());
+
+    let activity = client.get_activity_type(ACTIVITY_ID).await.unwrap();
+    assert_eq!(&activity, run);
+    assert_eq!(activity.activity_levels[0].min_speed_mph, Some(4.8));
+}
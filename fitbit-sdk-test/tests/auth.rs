@@ -0,0 +1,143 @@
+use fitbit_sdk::auth::OAuth2Client;
+use fitbit_sdk::token_store::FileTokenStore;
+use fitbit_sdk::types::auth::{Scope, TokenResponse, TokenStore};
+use reqwest::Url;
+use wiremock::matchers::{body_string_contains, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+const TOKEN: &str = r#"{
+    "access_token": "new-access-token",
+    "expires_in": 28800,
+    "refresh_token": "new-refresh-token",
+    "scope": "activity sleep",
+    "token_type": "Bearer",
+    "user_id": "ABC123"
+}"#;
+
+/// Returns a local port nothing listens on
+fn free_port() -> u16 {
+    std::net::TcpListener::bind("127.0.0.1:0")
+        .unwrap()
+        .local_addr()
+        .unwrap()
+        .port()
+}
+
+/// Creates an OAuth client redirecting to `port` and exchanging codes with `server`
+fn oauth_client(server: &MockServer, port: u16) -> OAuth2Client {
+    OAuth2Client::builder()
+        .with_client_id("client-id")
+        .with_redirect_uri(format!("http://localhost:{}/callback", port))
+        .with_token_url(format!("{}/oauth2/token", server.uri()))
+        .build()
+        .unwrap()
+}
+
+/// Follows the redirect Fitbit would send after the user answered the authorization page
+fn redirect(port: u16, authorize_url: &str, query: &str) {
+    let authorize_url = Url::parse(authorize_url).unwrap();
+    let state = authorize_url
+        .query_pairs()
+        .find(|(key, _)| key == "state")
+        .map(|(_, value)| value.into_owned())
+        .unwrap();
+    let url = format!("http://127.0.0.1:{}/callback?{}&state={}", port, query, state);
+    tokio::spawn(async move {
+        // The browser may ask for other pages first
+        let _ = reqwest::get(format!("http://127.0.0.1:{}/favicon.ico", port)).await;
+        let _ = reqwest::get(url).await;
+    });
+}
+
+#[tokio::test]
+async fn local_auth_flow_exchanges_redirect_code() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/oauth2/token"))
+        .and(body_string_contains("code=redirect-code"))
+        .and(body_string_contains("code_verifier="))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(TOKEN, "application/json"))
+        .expect(1)
+        .mount(&server)
+        .await;
+    let port = free_port();
+    let oauth = oauth_client(&server, port);
+
+    let token = oauth
+        .run_local_auth_flow_with(&[Scope::Activity, Scope::Sleep], |url| {
+            assert!(url.contains("scope=activity+sleep"));
+            assert!(url.contains("code_challenge="));
+            redirect(port, url, "code=redirect-code");
+            Ok(())
+        })
+        .await
+        .unwrap();
+    assert_eq!(token.access_token, "new-access-token");
+}
+
+#[tokio::test]
+async fn local_auth_flow_reports_denied_authorization() {
+    let server = MockServer::start().await;
+    let port = free_port();
+    let oauth = oauth_client(&server, port);
+
+    let error = oauth
+        .run_local_auth_flow_with(&[Scope::Activity], |url| {
+            redirect(port, url, "error=access_denied");
+            Ok(())
+        })
+        .await
+        .unwrap_err();
+    assert!(error.to_string().contains("access_denied"));
+}
+
+#[tokio::test]
+async fn local_auth_flow_requires_local_redirect_uri() {
+    let oauth = OAuth2Client::builder()
+        .with_client_id("client-id")
+        .with_redirect_uri("https://example.com/callback")
+        .build()
+        .unwrap();
+
+    let result = oauth.run_local_auth_flow_with(&[Scope::Activity], |_| Ok(())).await;
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn file_token_store_round_trips_token() {
+    let dir = std::env::temp_dir().join(format!("fitbit-sdk-test-{}", std::process::id()));
+    let store = FileTokenStore::new(dir.join("tokens").join("token.json"));
+    assert_eq!(store.load().await.unwrap(), None);
+
+    let token: TokenResponse = serde_json::from_str(TOKEN).unwrap();
+    store.save(&token).await.unwrap();
+    assert_eq!(store.load().await.unwrap(), Some(token));
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let mode = std::fs::metadata(store.path()).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o600);
+    }
+    std::fs::remove_dir_all(dir).unwrap();
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn file_token_store_replaces_stale_temporary_file() {
+    use std::os::unix::fs::PermissionsExt;
+
+    let dir = std::env::temp_dir().join(format!("fitbit-sdk-test-stale-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let temporary = dir.join("token.json.tmp");
+    std::fs::write(&temporary, "stale").unwrap();
+    std::fs::set_permissions(&temporary, std::fs::Permissions::from_mode(0o644)).unwrap();
+    let store = FileTokenStore::new(dir.join("token.json"));
+
+    let token: TokenResponse = serde_json::from_str(TOKEN).unwrap();
+    store.save(&token).await.unwrap();
+    assert_eq!(store.load().await.unwrap(), Some(token));
+    let mode = std::fs::metadata(store.path()).unwrap().permissions().mode();
+    assert_eq!(mode & 0o777, 0o600);
+    std::fs::remove_dir_all(dir).unwrap();
+}
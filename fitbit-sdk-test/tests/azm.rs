@@ -0,0 +1,36 @@
+use fitbit_sdk::types::azm::{AzmClient, AzmError};
+use fitbit_sdk::types::intraday::DetailLevel;
+use fitbit_sdk_test::FixtureServer;
+use fitbit_sdk_test::fixtures::{DATE, START_DATE};
+
+#[tokio::test]
+async fn get_azm_time_series() {
+    let server = FixtureServer::start().await;
+    let client = server.client::<AzmError>().unwrap();
+
+    let series = client.get_azm_time_series("-", DATE, "1d").await.unwrap();
+    assert_eq!(series[0].value.active_zone_minutes, 102);
+    assert_eq!(series[0].value.peak_active_zone_minutes, 8);
+
+    let range = client.get_azm_time_series_range("-", START_DATE, DATE).await.unwrap();
+    assert_eq!(range.len(), 3);
+    assert_eq!(range[0].value.cardio_active_zone_minutes, 0);
+}
+
+#[tokio::test]
+async fn get_azm_intraday() {
+    let server = FixtureServer::start().await;
+    let client = server.client::<AzmError>().unwrap();
+
+    let intraday = client
+        .get_azm_intraday("-", DATE, DetailLevel::OneMinute)
+        .await
+        .unwrap();
+    assert_eq!(intraday[0].minutes.len(), 3);
+
+    let window = client
+        .get_azm_intraday_window("-", DATE, DetailLevel::OneMinute, "08:00", "08:05")
+        .await
+        .unwrap();
+    assert_eq!(window, intraday);
+}
@@ -0,0 +1,160 @@
+use fitbit_sdk::types::body::{
+    BmiCategory, BodyClient, BodyError, BodyResource, LogBodyFatParams, LogWeightParams,
+    WeightGoalType,
+};
+use fitbit_sdk::types::date::FitbitDate;
+use fitbit_sdk_test::FixtureServer;
+use fitbit_sdk_test::fixtures::{BODY_LOG_ID, DATE, START_DATE};
+use wiremock::matchers::{body_string_contains, header, method, path};
+use wiremock::{Mock, ResponseTemplate};
+
+fn date(date: &str) -> FitbitDate {
+    date.parse().unwrap()
+}
+
+#[tokio::test]
+async fn get_body_weight() {
+    let server = FixtureServer::start().await;
+    let client = server.client::<BodyError>().unwrap();
+
+    let weights = client.get_body_weight("-", date(DATE)).await.unwrap();
+    assert_eq!(weights[0].weight, 72.4);
+    assert_eq!(weights[0].source.as_deref(), Some("Aria"));
+
+    let range = client
+        .get_body_weight_range("-", date(START_DATE), date(DATE))
+        .await
+        .unwrap();
+    assert_eq!(range.len(), 3);
+
+    let period = client.get_body_weight_period("-", date(DATE), "7d").await.unwrap();
+    assert_eq!(period, range);
+}
+
+#[tokio::test]
+async fn get_body_fat() {
+    let server = FixtureServer::start().await;
+    let client = server.client::<BodyError>().unwrap();
+
+    let fats = client.get_body_fat("-", date(DATE)).await.unwrap();
+    assert_eq!(fats[0].fat, 18.4);
+
+    let range = client
+        .get_body_fat_range("-", date(START_DATE), date(DATE))
+        .await
+        .unwrap();
+    assert_eq!(range.len(), 2);
+
+    let period = client.get_body_fat_period("-", date(DATE), "7d").await.unwrap();
+    assert_eq!(period, range);
+}
+
+#[tokio::test]
+async fn get_weight_goal() {
+    let server = FixtureServer::start().await;
+    let client = server.client::<BodyError>().unwrap();
+
+    let goal = client.get_weight_goal("-").await.unwrap();
+    assert_eq!(goal.goal_type, Some(WeightGoalType::Lose));
+    assert_eq!(goal.start_date.to_string(), "2024-11-01");
+    assert_eq!(goal.start_weight, 76.2);
+    assert_eq!(goal.weight, 70.0);
+    assert!(goal.is_reached(70.04));
+    assert!(!goal.is_reached(72.4));
+}
+
+#[tokio::test]
+async fn get_fat_goal() {
+    let server = FixtureServer::start().await;
+    let client = server.client::<BodyError>().unwrap();
+
+    let goal = client.get_fat_goal("-").await.unwrap();
+    assert_eq!(goal.fat, 22.0);
+}
+
+#[tokio::test]
+async fn log_and_delete_body_weight() {
+    let server = FixtureServer::start().await;
+    let client = server.client::<BodyError>().unwrap();
+
+    let params = LogWeightParams::new(72.4, DATE).with_time("07:12:45");
+    let weight = client.log_body_weight("-", &params).await.unwrap();
+    assert_eq!(weight.log_id, BODY_LOG_ID);
+
+    client.delete_body_weight_log("-", BODY_LOG_ID).await.unwrap();
+}
+
+#[tokio::test]
+async fn log_body_weight_sends_form_body() {
+    let server = FixtureServer::with_fixtures(&[]).await;
+    Mock::given(method("POST"))
+        .and(path("/1/user/-/body/log/weight.json"))
+        .and(header("Content-Type", "application/x-www-form-urlencoded"))
+        .and(body_string_contains("weight=72.4"))
+        .and(body_string_contains("date=2025-01-15"))
+        .and(body_string_contains("time=07%3A12%3A45"))
+        .respond_with(ResponseTemplate::new(201).set_body_raw(
+            include_str!("../fixtures/body/log_weight.json"),
+            "application/json",
+        ))
+        .expect(1)
+        .mount(server.mock_server())
+        .await;
+    let client = server.client::<BodyError>().unwrap();
+
+    let params = LogWeightParams::new(72.4, DATE).with_time("07:12:45");
+    client.log_body_weight("-", &params).await.unwrap();
+}
+
+#[tokio::test]
+async fn log_and_delete_body_fat() {
+    let server = FixtureServer::start().await;
+    let client = server.client::<BodyError>().unwrap();
+
+    let params = LogBodyFatParams::new(18.4, DATE).with_time("07:12:45");
+    let fat = client.log_body_fat("-", &params).await.unwrap();
+    assert_eq!(fat.log_id, BODY_LOG_ID);
+
+    client.delete_body_fat_log("-", BODY_LOG_ID).await.unwrap();
+}
+
+#[tokio::test]
+async fn get_body_time_series() {
+    let server = FixtureServer::start().await;
+    let client = server.client::<BodyError>().unwrap();
+
+    let series = client
+        .get_body_time_series("-", BodyResource::Weight, date(DATE), "7d")
+        .await
+        .unwrap();
+    assert_eq!(series.len(), 7);
+    assert_eq!(series[6].value, 72.4);
+}
+
+#[tokio::test]
+async fn get_bmi() {
+    let server = FixtureServer::start().await;
+    let client = server.client::<BodyError>().unwrap();
+
+    let bmi = client.get_bmi("-", date(DATE)).await.unwrap().unwrap();
+    assert_eq!(bmi.date.to_string(), DATE);
+    assert_eq!(bmi.value, 23.57);
+    assert_eq!(bmi.category(), BmiCategory::Normal);
+
+    let weight = client.get_body_weight("-", date(DATE)).await.unwrap();
+    assert_eq!(weight[0].bmi, Some(bmi.value));
+}
+
+#[tokio::test]
+async fn get_bmi_without_weight_logs() {
+    let server = FixtureServer::with_fixtures(&[]).await;
+    let body = r#"{"body-bmi":[{"dateTime":"2025-01-15","value":"0"}]}"#;
+    Mock::given(method("GET"))
+        .and(path("/1/user/-/body/bmi/date/2025-01-15/1d.json"))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(body, "application/json"))
+        .mount(server.mock_server())
+        .await;
+    let client = server.client::<BodyError>().unwrap();
+
+    assert_eq!(client.get_bmi("-", date(DATE)).await.unwrap(), None);
+}
@@ -0,0 +1,35 @@
+use fitbit_sdk::types::breathing_rate::{BreathingRateClient, BreathingRateError};
+use fitbit_sdk_test::FixtureServer;
+use fitbit_sdk_test::fixtures::{DATE, START_DATE};
+
+#[tokio::test]
+async fn get_breathing_rate_summary() {
+    let server = FixtureServer::start().await;
+    let client = server.client::<BreathingRateError>().unwrap();
+
+    let summary = client.get_breathing_rate_summary("-", DATE).await.unwrap();
+    assert_eq!(summary[0].value.breathing_rate, 15.8);
+
+    let range = client
+        .get_breathing_rate_summary_range("-", START_DATE, DATE)
+        .await
+        .unwrap();
+    assert_eq!(range.len(), 3);
+}
+
+#[tokio::test]
+async fn get_breathing_rate_intraday() {
+    let server = FixtureServer::start().await;
+    let client = server.client::<BreathingRateError>().unwrap();
+
+    let intraday = client.get_breathing_rate_intraday("-", DATE).await.unwrap();
+    let stages = &intraday[0].value;
+    assert_eq!(stages.deep_sleep_summary.as_ref().unwrap().breathing_rate, 14.8);
+    assert_eq!(stages.rem_sleep_summary.as_ref().unwrap().breathing_rate, -1.0);
+
+    let range = client
+        .get_breathing_rate_intraday_range("-", START_DATE, DATE)
+        .await
+        .unwrap();
+    assert_eq!(range.len(), 2);
+}
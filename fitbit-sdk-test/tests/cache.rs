@@ -0,0 +1,116 @@
+use fitbit_sdk::cache::InMemoryCache;
+use fitbit_sdk::client::FitbitClient;
+use fitbit_sdk::types::nutrition::NutritionClient;
+use fitbit_sdk::types::user::UserClient;
+use fitbit_sdk_test::FixtureServer;
+use wiremock::matchers::{header, method, path};
+use wiremock::{Mock, ResponseTemplate};
+
+const BADGES: &str = include_str!("../fixtures/user/badges.json");
+
+/// Creates a client with an in-memory cache sending its requests to `server`
+fn cached_client(server: &FixtureServer) -> FitbitClient {
+    FitbitClient::builder()
+        .with_access_token(FixtureServer::ACCESS_TOKEN)
+        .with_api_base_url(server.uri())
+        .with_cache(InMemoryCache::new(10))
+        .build()
+        .unwrap()
+}
+
+/// Returns the number of requests received for `method` and `path`
+async fn request_count(server: &FixtureServer, method: &str, path: &str) -> usize {
+    server
+        .mock_server()
+        .received_requests()
+        .await
+        .unwrap()
+        .iter()
+        .filter(|request| request.method.as_str() == method && request.url.path() == path)
+        .count()
+}
+
+#[tokio::test]
+async fn repeated_requests_are_served_from_cache() {
+    let server = FixtureServer::start().await;
+    let client = cached_client(&server);
+
+    let first = client.get_badges("-").await.unwrap();
+    let second = client.get_badges("-").await.unwrap();
+    assert_eq!(first, second);
+    assert_eq!(request_count(&server, "GET", "/1/user/-/badges.json").await, 1);
+}
+
+#[tokio::test]
+async fn stale_responses_are_revalidated() {
+    let server = FixtureServer::with_fixtures(&[]).await;
+    Mock::given(method("GET"))
+        .and(path("/1/user/-/badges.json"))
+        .and(header("If-None-Match", r#""v1""#))
+        .respond_with(ResponseTemplate::new(304).insert_header("ETag", r#""v1""#))
+        .with_priority(1)
+        .expect(1)
+        .mount(server.mock_server())
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/1/user/-/badges.json"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("ETag", r#""v1""#)
+                .insert_header("Cache-Control", "private, max-age=0")
+                .set_body_raw(BADGES, "application/json"),
+        )
+        .expect(1)
+        .mount(server.mock_server())
+        .await;
+    let client = cached_client(&server);
+
+    let first = client.get_badges("-").await.unwrap();
+    let second = client.get_badges("-").await.unwrap();
+    assert_eq!(first, second);
+}
+
+#[tokio::test]
+async fn no_store_responses_are_not_cached() {
+    let server = FixtureServer::with_fixtures(&[]).await;
+    Mock::given(method("GET"))
+        .and(path("/1/user/-/badges.json"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("Cache-Control", "no-store")
+                .set_body_raw(BADGES, "application/json"),
+        )
+        .expect(2)
+        .mount(server.mock_server())
+        .await;
+    let client = cached_client(&server);
+
+    client.get_badges("-").await.unwrap();
+    client.get_badges("-").await.unwrap();
+}
+
+#[tokio::test]
+async fn writes_clear_the_cache() {
+    let server = FixtureServer::start().await;
+    let client = cached_client(&server);
+    let goal_path = "/1/user/-/foods/log/water/goal.json";
+
+    client.get_water_goal("-").await.unwrap();
+    client.get_water_goal("-").await.unwrap();
+    assert_eq!(request_count(&server, "GET", goal_path).await, 1);
+
+    client.update_water_goal("-", 2000.0).await.unwrap();
+    client.get_water_goal("-").await.unwrap();
+    assert_eq!(request_count(&server, "GET", goal_path).await, 2);
+}
+
+#[tokio::test]
+async fn responses_are_cached_per_user() {
+    let server = FixtureServer::start().await;
+    let client = cached_client(&server);
+
+    client.as_user("first-token").get_badges("-").await.unwrap();
+    client.as_user("second-token").get_badges("-").await.unwrap();
+    client.as_user("first-token").get_badges("-").await.unwrap();
+    assert_eq!(request_count(&server, "GET", "/1/user/-/badges.json").await, 2);
+}
@@ -0,0 +1,19 @@
+use fitbit_sdk::types::cardio_fitness::{CardioFitnessClient, CardioFitnessError};
+use fitbit_sdk_test::FixtureServer;
+use fitbit_sdk_test::fixtures::{DATE, START_DATE};
+
+#[tokio::test]
+async fn get_cardio_fitness_score() {
+    let server = FixtureServer::start().await;
+    let client = server.client::<CardioFitnessError>().unwrap();
+
+    let scores = client.get_cardio_fitness_score("-", DATE).await.unwrap();
+    assert_eq!(scores[0].value.vo2_max.to_string(), "44-48");
+
+    let range = client
+        .get_cardio_fitness_score_range("-", START_DATE, DATE)
+        .await
+        .unwrap();
+    assert_eq!(range.len(), 3);
+    assert_eq!(range[2].value.vo2_max.to_string(), "46");
+}
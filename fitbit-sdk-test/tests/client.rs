@@ -0,0 +1,683 @@
+use fitbit_sdk::auth::OAuth2Client;
+use fitbit_sdk::client::{ApiVersion, FitbitClient};
+use fitbit_sdk::prelude::{FitbitApi, FitbitDate};
+use fitbit_sdk::types::auth::Scope;
+use fitbit_sdk::types::error::{ClientBuildError, FitbitError};
+use fitbit_sdk::types::heart_rate::{HeartRateClient, HeartRateError};
+use fitbit_sdk::types::intraday::DetailLevel;
+use fitbit_sdk::types::metrics::{MetricsRecorder, RequestMetrics};
+use fitbit_sdk::types::rate_limit::{RateLimit, RateLimitMode};
+use fitbit_sdk::types::request::Body;
+use fitbit_sdk::types::sleep::{SleepClient, SleepError};
+use fitbit_sdk::types::user::{UserClient, UserError};
+use fitbit_sdk_test::FixtureServer;
+use fitbit_sdk_test::fixtures::{DATE, SLEEP_LOG_ID};
+use flate2::Compression;
+use flate2::write::GzEncoder;
+use futures_util::TryStreamExt;
+use reqwest::Method;
+use std::io::Write;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use wiremock::matchers::{body_string_contains, header, header_regex, method, path};
+use wiremock::{Mock, ResponseTemplate};
+
+#[tokio::test]
+async fn requests_time_out() {
+    let server = FixtureServer::with_fixtures(&[]).await;
+    Mock::given(method("GET"))
+        .and(path("/1/user/-/badges.json"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_raw(r#"{"badges": []}"#, "application/json")
+                .set_delay(Duration::from_secs(5)),
+        )
+        .mount(server.mock_server())
+        .await;
+    let client = FitbitClient::builder()
+        .with_access_token(FixtureServer::ACCESS_TOKEN)
+        .with_api_base_url(server.uri())
+        .with_timeout(Duration::from_millis(100))
+        .with_connect_timeout(Duration::from_secs(1))
+        .with_pool_max_idle_per_host(1)
+        .build()
+        .unwrap();
+
+    assert!(client.get_badges("-").await.is_err());
+}
+
+#[tokio::test]
+async fn requests_go_through_proxy() {
+    // The fixture server acts as an HTTP proxy for an unreachable API host
+    let server = FixtureServer::start().await;
+    let client = FitbitClient::builder()
+        .with_access_token(FixtureServer::ACCESS_TOKEN)
+        .with_api_base_url("http://api.fitbit.invalid")
+        .with_proxy(server.uri())
+        .build()
+        .unwrap();
+
+    let badges = client.get_badges("-").await.unwrap();
+    assert!(!badges.is_empty());
+}
+
+#[test]
+fn invalid_proxy_url_is_rejected() {
+    let result = FitbitClient::builder()
+        .with_access_token(FixtureServer::ACCESS_TOKEN)
+        .with_proxy("not a url")
+        .build();
+    assert!(matches!(
+        result,
+        Err(ClientBuildError::InvalidProxyUrl { .. })
+    ));
+}
+
+#[test]
+fn invalid_base_url_is_rejected() {
+    let result = FitbitClient::builder()
+        .with_access_token(FixtureServer::ACCESS_TOKEN)
+        .with_api_base_url("api.fitbit.com")
+        .build();
+    assert!(matches!(
+        result,
+        Err(ClientBuildError::InvalidBaseUrl { .. })
+    ));
+
+    // Build errors convert into the error type of every domain
+    let error = HeartRateError::from(result.unwrap_err());
+    assert!(matches!(
+        error,
+        HeartRateError(FitbitError::Build(ClientBuildError::InvalidBaseUrl { .. }))
+    ));
+}
+
+#[tokio::test]
+async fn base_url_with_api_version_is_accepted() {
+    let server = FixtureServer::start().await;
+    for base_url in [format!("{}/1", server.uri()), format!("{}/1.2/", server.uri())] {
+        let client = FitbitClient::builder()
+            .with_access_token(FixtureServer::ACCESS_TOKEN)
+            .with_api_base_url(base_url)
+            .build()
+            .unwrap();
+        assert_eq!(client.get_api_base_url(), server.uri());
+        assert!(!client.get_badges("-").await.unwrap().is_empty());
+        assert!(client.get_sleep_goal("-").await.is_ok());
+    }
+}
+
+#[tokio::test]
+async fn large_responses_are_received_compressed() {
+    let server = FixtureServer::with_fixtures(&[]).await;
+    let dataset: Vec<_> = (0..1440)
+        .map(|minute| {
+            serde_json::json!({
+                "time": format!("{:02}:{:02}:00", minute / 60, minute % 60),
+                "value": 60 + minute % 40,
+            })
+        })
+        .collect();
+    let body = serde_json::json!({
+        "activities-heart": [{
+            "dateTime": DATE,
+            "value": {"customHeartRateZones": [], "heartRateZones": [], "restingHeartRate": 62}
+        }],
+        "activities-heart-intraday": {
+            "dataset": dataset,
+            "datasetInterval": 1,
+            "datasetType": "minute"
+        }
+    });
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+    encoder.write_all(body.to_string().as_bytes()).unwrap();
+    let compressed = encoder.finish().unwrap();
+    assert!(compressed.len() < body.to_string().len() / 4);
+
+    // Only requests accepting gzip get a response
+    Mock::given(method("GET"))
+        .and(path("/1/user/-/activities/heart/date/2025-01-15/1d/1min.json"))
+        .and(header_regex("accept-encoding", "gzip"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("Content-Encoding", "gzip")
+                .set_body_raw(compressed, "application/json"),
+        )
+        .mount(server.mock_server())
+        .await;
+    let client = server.client::<HeartRateError>().unwrap();
+
+    let series = client
+        .get_heart_rate_intraday("-", DATE, DetailLevel::OneMinute)
+        .await
+        .unwrap();
+    assert_eq!(series.intraday.dataset.len(), 1440);
+    assert_eq!(series.summary[0].value.resting_heart_rate, Some(62));
+
+    let points: Vec<_> = client
+        .stream_heart_rate_intraday("-", DATE, DetailLevel::OneMinute)
+        .try_collect()
+        .await
+        .unwrap();
+    assert_eq!(points, series.intraday.dataset);
+
+    let requests = server.mock_server().received_requests().await.unwrap();
+    let accept_encoding = requests[0].headers["accept-encoding"].to_str().unwrap();
+    for encoding in ["gzip", "br", "deflate"] {
+        assert!(accept_encoding.contains(encoding), "{}", accept_encoding);
+    }
+}
+
+#[tokio::test]
+async fn compression_can_be_disabled() {
+    let server = FixtureServer::start().await;
+    let client = FitbitClient::builder()
+        .with_access_token(FixtureServer::ACCESS_TOKEN)
+        .with_api_base_url(server.uri())
+        .with_compression(false)
+        .build()
+        .unwrap();
+
+    client.get_badges("-").await.unwrap();
+    let requests = server.mock_server().received_requests().await.unwrap();
+    assert!(requests[0].headers.get("accept-encoding").is_none());
+}
+
+/// Recorder keeping every request in memory
+#[derive(Default)]
+struct MemoryRecorder(Mutex<Vec<RequestMetrics>>);
+
+impl MetricsRecorder for MemoryRecorder {
+    fn record(&self, metrics: &RequestMetrics) {
+        self.0.lock().unwrap().push(metrics.clone());
+    }
+}
+
+#[tokio::test]
+async fn requests_are_recorded() {
+    let server = FixtureServer::start().await;
+    let recorder = Arc::new(MemoryRecorder::default());
+    let client = FitbitClient::builder()
+        .with_access_token(FixtureServer::ACCESS_TOKEN)
+        .with_api_base_url(server.uri())
+        .with_metrics_recorder(recorder.clone())
+        .build()
+        .unwrap();
+
+    client.get_badges("-").await.unwrap();
+    assert!(client.get_badges("ABC123").await.is_err());
+    client
+        .get_heart_rate_intraday_window("-", DATE, DetailLevel::OneMinute, "08:00", "08:05")
+        .await
+        .unwrap();
+    client.delete_sleep_log("-", SLEEP_LOG_ID).await.unwrap();
+
+    let metrics = recorder.0.lock().unwrap();
+    assert_eq!(metrics.len(), 4);
+    assert_eq!(metrics[0].method.as_str(), "GET");
+    assert_eq!(metrics[0].api_version, ApiVersion::V1);
+    assert_eq!(metrics[0].endpoint, "/user/-/badges.json");
+    assert_eq!(metrics[0].status.map(|status| status.as_u16()), Some(200));
+    assert!(metrics[0].is_success());
+    // User IDs, dates, times and log IDs are replaced by placeholders
+    assert_eq!(metrics[1].endpoint, "/user/{user}/badges.json");
+    assert!(!metrics[1].is_success());
+    assert_eq!(
+        metrics[2].endpoint,
+        "/user/-/activities/heart/date/{date}/1d/1min/time/{time}/{time}.json"
+    );
+    assert_eq!(metrics[3].method.as_str(), "DELETE");
+    assert_eq!(metrics[3].api_version, ApiVersion::V1_2);
+    assert_eq!(metrics[3].endpoint, "/user/-/sleep/{id}.json");
+}
+
+/// Collects the logs written by a `tracing` subscriber
+#[derive(Clone, Default)]
+struct LogBuffer(Arc<Mutex<Vec<u8>>>);
+
+impl std::io::Write for LogBuffer {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.lock().unwrap().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn wire_logging_redacts_credentials() {
+    let server = FixtureServer::with_fixtures(&[]).await;
+    Mock::given(method("POST"))
+        .and(path("/1/user/-/apps/link.json"))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(
+            r#"{"linked": true, "access_token": "response-secret"}"#,
+            "application/json",
+        ))
+        .mount(server.mock_server())
+        .await;
+    let logs = LogBuffer::default();
+    let writer = logs.clone();
+    let subscriber = tracing_subscriber::fmt()
+        .with_max_level(tracing::Level::DEBUG)
+        .with_ansi(false)
+        .with_writer(move || writer.clone())
+        .finish();
+    let _guard = tracing::subscriber::set_default(subscriber);
+
+    let client = FitbitClient::builder()
+        .with_access_token(FixtureServer::ACCESS_TOKEN)
+        .with_api_base_url(server.uri())
+        .with_wire_logging(true)
+        .build()
+        .unwrap();
+    let form = [("name", "my-app"), ("refresh_token", "request-secret")];
+    let _: serde_json::Value = client
+        .request::<_, (), _>(Method::POST, "/user/-/apps/link.json", None, Some(Body::Form(&form)))
+        .await
+        .unwrap();
+
+    let logs = String::from_utf8(logs.0.lock().unwrap().clone()).unwrap();
+    assert!(logs.contains("fitbit_sdk::wire"), "{}", logs);
+    assert!(logs.contains("name=my-app&refresh_token=[REDACTED]"), "{}", logs);
+    assert!(logs.contains("linked"), "{}", logs);
+    assert!(!logs.contains(FixtureServer::ACCESS_TOKEN), "{}", logs);
+    assert!(!logs.contains("request-secret"), "{}", logs);
+    assert!(!logs.contains("response-secret"), "{}", logs);
+}
+
+/// Creates a client rejecting requests over `requests` per hour
+fn rate_limited_client(server: &FixtureServer, requests: u32) -> FitbitClient {
+    FitbitClient::builder()
+        .with_access_token(FixtureServer::ACCESS_TOKEN)
+        .with_api_base_url(server.uri())
+        .with_rate_limit(RateLimit::per_hour(requests).with_mode(RateLimitMode::Reject))
+        .build()
+        .unwrap()
+}
+
+#[tokio::test]
+async fn requests_over_rate_limit_are_rejected() {
+    let server = FixtureServer::start().await;
+    let client = rate_limited_client(&server, 2);
+
+    client.get_badges("-").await.unwrap();
+    client.clone().get_badges("-").await.unwrap();
+    assert!(client.get_badges("-").await.is_err());
+    assert_eq!(server.mock_server().received_requests().await.unwrap().len(), 2);
+}
+
+#[tokio::test]
+async fn rate_limiter_follows_remaining_quota() {
+    let server = FixtureServer::with_fixtures(&[]).await;
+    Mock::given(method("GET"))
+        .and(path("/1/user/-/badges.json"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("Fitbit-Rate-Limit-Limit", "150")
+                .insert_header("Fitbit-Rate-Limit-Remaining", "0")
+                .insert_header("Fitbit-Rate-Limit-Reset", "1800")
+                .set_body_raw(r#"{"badges": []}"#, "application/json"),
+        )
+        .expect(1)
+        .mount(server.mock_server())
+        .await;
+    let client = rate_limited_client(&server, 150);
+
+    client.get_badges("-").await.unwrap();
+    assert!(client.get_badges("-").await.is_err());
+}
+
+#[tokio::test]
+async fn rate_limiter_waits_for_window_reset() {
+    let server = FixtureServer::with_fixtures(&[]).await;
+    Mock::given(method("GET"))
+        .and(path("/1/user/-/badges.json"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("Fitbit-Rate-Limit-Limit", "150")
+                .insert_header("Fitbit-Rate-Limit-Remaining", "0")
+                .insert_header("Fitbit-Rate-Limit-Reset", "1")
+                .set_body_raw(r#"{"badges": []}"#, "application/json"),
+        )
+        .expect(2)
+        .mount(server.mock_server())
+        .await;
+    // Refills a token every 10ms, far sooner than the API window resets
+    let client = FitbitClient::builder()
+        .with_access_token(FixtureServer::ACCESS_TOKEN)
+        .with_api_base_url(server.uri())
+        .with_rate_limit(
+            RateLimit::new(100, Duration::from_secs(1)).with_mode(RateLimitMode::Reject),
+        )
+        .build()
+        .unwrap();
+
+    client.get_badges("-").await.unwrap();
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    assert!(client.get_badges("-").await.is_err());
+    tokio::time::sleep(Duration::from_millis(1000)).await;
+    client.get_badges("-").await.unwrap();
+}
+
+#[tokio::test]
+async fn rate_limiter_waits_after_too_many_requests() {
+    let server = FixtureServer::with_fixtures(&[]).await;
+    Mock::given(method("GET"))
+        .and(path("/1/user/-/badges.json"))
+        .respond_with(ResponseTemplate::new(429).insert_header("Retry-After", "1"))
+        .up_to_n_times(1)
+        .expect(1)
+        .mount(server.mock_server())
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/1/user/-/badges.json"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_raw(r#"{"badges": []}"#, "application/json"),
+        )
+        .expect(1)
+        .mount(server.mock_server())
+        .await;
+    let client = FitbitClient::builder()
+        .with_access_token(FixtureServer::ACCESS_TOKEN)
+        .with_api_base_url(server.uri())
+        .with_rate_limit(RateLimit::default())
+        .build()
+        .unwrap();
+
+    assert!(client.get_badges("-").await.is_err());
+    let started = std::time::Instant::now();
+    client.get_badges("-").await.unwrap();
+    assert!(started.elapsed() >= Duration::from_millis(900), "{:?}", started.elapsed());
+}
+
+#[tokio::test]
+async fn zero_rate_limit_rejects_every_request() {
+    let server = FixtureServer::start().await;
+    for mode in [RateLimitMode::Reject, RateLimitMode::Wait] {
+        let client = FitbitClient::builder()
+            .with_access_token(FixtureServer::ACCESS_TOKEN)
+            .with_api_base_url(server.uri())
+            .with_rate_limit(RateLimit::per_hour(0).with_mode(mode))
+            .build()
+            .unwrap();
+        assert!(client.get_badges("-").await.is_err());
+    }
+    assert!(server.mock_server().received_requests().await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn clones_share_refreshed_access_token() {
+    let server = FixtureServer::with_fixtures(&[]).await;
+    Mock::given(method("GET"))
+        .and(path("/1/user/-/badges.json"))
+        .and(header("Authorization", "Bearer expired-token"))
+        .respond_with(ResponseTemplate::new(401).set_body_raw(
+            r#"{"errors": [{"errorType": "expired_token", "message": "Access token expired"}]}"#,
+            "application/json",
+        ))
+        .expect(1)
+        .mount(server.mock_server())
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/oauth2/token"))
+        .and(body_string_contains("refresh_token=refresh-token"))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(
+            r#"{"access_token": "new-token", "expires_in": 28800, "refresh_token": "new-refresh-token",
+                "scope": "activity", "token_type": "Bearer", "user_id": "ABC123"}"#,
+            "application/json",
+        ))
+        .expect(1)
+        .mount(server.mock_server())
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/1/user/-/badges.json"))
+        .and(header("Authorization", "Bearer new-token"))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(r#"{"badges": []}"#, "application/json"))
+        .expect(2)
+        .mount(server.mock_server())
+        .await;
+    let oauth = OAuth2Client::builder()
+        .with_client_id("client-id")
+        .with_token_url(format!("{}/oauth2/token", server.uri()))
+        .build()
+        .unwrap();
+    let client = FitbitClient::builder()
+        .with_access_token("expired-token")
+        .with_refresh_token("refresh-token")
+        .with_oauth_client(oauth)
+        .with_api_base_url(server.uri())
+        .build()
+        .unwrap();
+
+    // The token refreshed by a clone is used by the original without
+    // refreshing again, which would fail with the used refresh token
+    let clone = client.clone();
+    tokio::spawn(async move { clone.get_badges("-").await })
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(client.get_access_token().await.unwrap(), "new-token");
+    client.get_badges("-").await.unwrap();
+}
+
+#[tokio::test]
+async fn last_response_meta_reports_headers() {
+    let server = FixtureServer::with_fixtures(&[]).await;
+    Mock::given(method("GET"))
+        .and(path("/1/user/-/badges.json"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("Content-Language", "en_GB")
+                .insert_header("X-Request-Id", "8e2f1c")
+                .insert_header("Fitbit-Rate-Limit-Limit", "150")
+                .insert_header("Fitbit-Rate-Limit-Remaining", "149")
+                .insert_header("Fitbit-Rate-Limit-Reset", "1800")
+                .set_body_raw(r#"{"badges": []}"#, "application/json"),
+        )
+        .mount(server.mock_server())
+        .await;
+    let client = server.client::<UserError>().unwrap();
+    assert!(client.last_response_meta().is_none());
+
+    client.get_badges("-").await.unwrap();
+    let meta = client.last_response_meta().unwrap();
+    assert_eq!(meta.status, 200);
+    assert_eq!(meta.content_language.as_deref(), Some("en_GB"));
+    assert_eq!(meta.request_id.as_deref(), Some("8e2f1c"));
+    assert_eq!(meta.rate_limit.unwrap().remaining, 149);
+
+    // Error responses are reported too
+    assert!(client.get_badges("other").await.is_err());
+    assert_eq!(client.last_response_meta().unwrap().status, 404);
+}
+
+#[tokio::test]
+async fn as_user_sends_requests_with_user_token() {
+    let server = FixtureServer::with_fixtures(&[]).await;
+    Mock::given(method("GET"))
+        .and(path("/1/user/-/badges.json"))
+        .and(header("Authorization", "Bearer user-token"))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(r#"{"badges": []}"#, "application/json"))
+        .expect(1)
+        .mount(server.mock_server())
+        .await;
+    let client = server.client::<UserError>().unwrap();
+
+    let user = client.as_user("user-token");
+    assert!(user.get_badges("-").await.unwrap().is_empty());
+    assert_eq!(user.get_access_token().await.unwrap(), "user-token");
+    assert_eq!(client.get_access_token().await.unwrap(), FixtureServer::ACCESS_TOKEN);
+}
+
+#[tokio::test]
+async fn empty_responses_are_accepted_without_body() {
+    let server = FixtureServer::with_fixtures(&[]).await;
+    Mock::given(method("DELETE"))
+        .and(path("/1.2/user/-/sleep/36543210987.json"))
+        .respond_with(ResponseTemplate::new(200))
+        .mount(server.mock_server())
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/1/user/-/badges.json"))
+        .respond_with(ResponseTemplate::new(204))
+        .mount(server.mock_server())
+        .await;
+
+    let sleep = server.client::<SleepError>().unwrap();
+    sleep.delete_sleep_log("-", SLEEP_LOG_ID).await.unwrap();
+
+    let user = server.client::<UserError>().unwrap();
+    let error = user.get_badges("-").await.unwrap_err();
+    assert!(error.to_string().contains("Expected a response body"), "{}", error);
+}
+
+#[tokio::test]
+async fn binary_responses_are_returned_unparsed() {
+    let server = FixtureServer::with_fixtures(&[]).await;
+    let png: &[u8] = &[0x89, b'P', b'N', b'G', 0x0d, 0x0a, 0x1a, 0x0a, 0xff, 0x00];
+    Mock::given(method("GET"))
+        .and(path("/1/user/-/avatar.png"))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(png, "image/png"))
+        .mount(server.mock_server())
+        .await;
+    // Not a Fitbit API endpoint, so the client must not validate paths
+    let client = FitbitClient::builder()
+        .with_access_token(FixtureServer::ACCESS_TOKEN)
+        .with_api_base_url(server.uri())
+        .build()
+        .unwrap();
+
+    let body = client
+        .get_bytes::<UserError>(ApiVersion::V1, "/user/-/avatar.png")
+        .await
+        .unwrap();
+    assert_eq!(&body[..], png);
+
+    // The PNG signature is not valid UTF-8
+    assert!(client.get_text::<UserError>(ApiVersion::V1, "/user/-/avatar.png").await.is_err());
+}
+
+#[tokio::test]
+async fn request_reaches_unimplemented_endpoints() {
+    let server = FixtureServer::with_fixtures(&[]).await;
+    Mock::given(method("GET"))
+        .and(path("/1/user/-/devices.json"))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(
+            r#"[{"id": "123", "battery": "High"}]"#,
+            "application/json",
+        ))
+        .mount(server.mock_server())
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/1/user/-/devices/tracker/123/alarms.json"))
+        .and(header("Content-Type", "application/x-www-form-urlencoded"))
+        .and(body_string_contains("enabled=true"))
+        .respond_with(ResponseTemplate::new(201).set_body_raw(r#"{"trackerAlarm": {}}"#, "application/json"))
+        .expect(1)
+        .mount(server.mock_server())
+        .await;
+    // Reaches the mock server with unknown paths, so it gets to answer 404
+    let client = FitbitClient::builder()
+        .with_access_token(FixtureServer::ACCESS_TOKEN)
+        .with_api_base_url(server.uri())
+        .build()
+        .unwrap();
+
+    let devices: serde_json::Value = client
+        .request::<_, (), ()>(Method::GET, "/user/-/devices.json", None, None)
+        .await
+        .unwrap();
+    assert_eq!(devices[0]["battery"], "High");
+
+    let alarm = [("time", "07:15-08:00"), ("enabled", "true")];
+    client
+        .request::<serde_json::Value, (), _>(
+            Method::POST,
+            "/user/-/devices/tracker/123/alarms.json",
+            None,
+            Some(Body::Form(&alarm)),
+        )
+        .await
+        .unwrap();
+
+    let error = client
+        .request::<serde_json::Value, (), ()>(Method::GET, "/user/-/unknown.json", None, None)
+        .await
+        .unwrap_err();
+    assert!(matches!(error, FitbitError::ApiError(_)));
+}
+
+#[tokio::test]
+async fn mock_profile_rejects_unknown_endpoints() {
+    let server = FixtureServer::start().await;
+    let client = server.client::<FitbitError>().unwrap();
+
+    let profile: serde_json::Value = client
+        .request::<_, (), ()>(Method::GET, "/user/-/profile.json", None, None)
+        .await
+        .unwrap();
+    assert!(profile["user"].is_object());
+
+    let error = client
+        .request::<serde_json::Value, (), ()>(Method::GET, "/user/-/profle.json", None, None)
+        .await
+        .unwrap_err();
+    assert!(matches!(
+        error,
+        FitbitError::UnknownEndpoint { ref path, .. } if path == "/user/-/profle.json"
+    ));
+    let sent = server.mock_server().received_requests().await.unwrap();
+    assert!(sent.iter().all(|request| request.url.path() != "/1/user/-/profle.json"));
+}
+
+/// Reads the minutes asleep, reporting errors as `FitbitError`
+async fn minutes_asleep(client: &FitbitClient) -> Result<i32, FitbitError> {
+    let date: FitbitDate = DATE.parse()?;
+    Ok(client.get_sleep_logs("-", date).await?.summary.total_minutes_asleep)
+}
+
+#[tokio::test]
+async fn domain_errors_convert_into_fitbit_error() {
+    let server = FixtureServer::with_fixtures(&[]).await;
+    let body = r#"{"errors":[{"errorType":"insufficient_scope","message":"This application does not have permission to read sleep data."}],"success":false}"#;
+    Mock::given(method("GET"))
+        .and(path(format!("/1.2/user/-/sleep/date/{}.json", DATE)))
+        .respond_with(ResponseTemplate::new(403).set_body_raw(body, "application/json"))
+        .mount(server.mock_server())
+        .await;
+    let client = server.client::<FitbitError>().unwrap();
+
+    // The scope named by the sleep domain survives the conversion
+    let error = minutes_asleep(&client).await.unwrap_err();
+    assert!(matches!(error, FitbitError::InsufficientScope(ref scopes) if scopes == &[Scope::Sleep]));
+
+    let error = client
+        .request::<serde_json::Value, (), ()>(Method::GET, "/user/-/profle.json", None, None)
+        .await
+        .unwrap_err();
+    // The unknown endpoint survives the conversion to a domain error and back
+    let error = SleepError::from(error);
+    assert!(matches!(error, SleepError(FitbitError::UnknownEndpoint { ref path, .. }) if path == "/user/-/profle.json"));
+    let error = FitbitError::from(error);
+    assert!(matches!(error, FitbitError::UnknownEndpoint { ref method, .. } if method == Method::GET));
+}
+
+/// Reads several domains through the `FitbitApi` bound
+async fn badges_and_minutes_asleep(api: &impl FitbitApi) -> (usize, i32) {
+    let date: FitbitDate = fitbit_sdk_test::fixtures::DATE.parse().unwrap();
+    let badges = api.get_badges("-").await.unwrap();
+    let sleep = api.get_sleep_logs("-", date).await.unwrap();
+    (badges.len(), sleep.summary.total_minutes_asleep)
+}
+
+#[tokio::test]
+async fn prelude_gives_access_to_every_domain() {
+    let server = FixtureServer::start().await;
+    let client: FitbitClient = server.client::<FitbitError>().unwrap();
+
+    let (badges, minutes_asleep) = badges_and_minutes_asleep(&client).await;
+    assert!(badges > 0);
+    assert_eq!(minutes_asleep, 405);
+}
@@ -0,0 +1,79 @@
+use fitbit_sdk::types::activity::{ActivityClient, ActivityError, Resource};
+use fitbit_sdk::types::body::{BodyClient, BodyError};
+use fitbit_sdk::types::date::FitbitDate;
+use fitbit_sdk::types::datetime::ApiField;
+use fitbit_sdk::types::heart_rate::{HeartRateClient, HeartRateError};
+use fitbit_sdk::types::intraday::DetailLevel;
+use fitbit_sdk::types::sleep::{SleepClient, SleepError};
+use fitbit_sdk::types::temperature::{TemperatureClient, TemperatureError};
+use fitbit_sdk_test::FixtureServer;
+use fitbit_sdk_test::fixtures::DATE;
+use time::macros::{date, datetime, time};
+use time::{Date, PrimitiveDateTime, Time};
+
+// These tests pass with and without the `typed-time` feature, e.g.
+// `cargo test -p fitbit-sdk-test --features fitbit-sdk/typed-time --test datetime`
+
+fn date() -> FitbitDate {
+    DATE.parse().unwrap()
+}
+
+#[tokio::test]
+async fn sleep_date_times_are_parsed() {
+    let server = FixtureServer::start().await;
+    let client = server.client::<SleepError>().unwrap();
+
+    let log = client.get_sleep_logs("-", date()).await.unwrap();
+    let entry = &log.sleep[0];
+    assert_eq!(
+        entry.start_time.convert::<PrimitiveDateTime>(),
+        Ok(datetime!(2025-01-14 23:15:30))
+    );
+    let levels = entry.levels.as_ref().unwrap();
+    assert_eq!(levels.data[0].datetime.to_api_string(), "2025-01-14T23:15:30.000");
+}
+
+#[tokio::test]
+async fn time_series_dates_are_parsed() {
+    let server = FixtureServer::start().await;
+    let client = server.client::<HeartRateError>().unwrap();
+
+    let series = client.get_heart_rate_time_series("-", DATE, "1d").await.unwrap();
+    assert_eq!(series[0].date_time.convert::<Date>(), Ok(date!(2025 - 01 - 15)));
+    let resting = series[0].resting_heart_rate().unwrap();
+    assert_eq!(resting.date.to_api_string(), DATE);
+}
+
+#[tokio::test]
+async fn intraday_and_log_times_are_parsed() {
+    let server = FixtureServer::start().await;
+    let client = server.client::<ActivityError>().unwrap();
+
+    let steps = client
+        .get_activity_intraday("-", Resource::Steps, date(), DetailLevel::OneMinute)
+        .await
+        .unwrap();
+    assert_eq!(steps.intraday.dataset[0].time.convert::<Time>(), Ok(time!(08:00)));
+
+    let daily = client.get_activity_daily("-", date()).await.unwrap();
+    let activity = &daily.activities[0];
+    assert_eq!(activity.start_time.convert::<Time>(), Ok(time!(08:00)));
+    let start_date = activity.start_date.as_ref().unwrap();
+    assert_eq!(start_date.convert::<Date>(), Ok(date!(2025 - 01 - 15)));
+
+    let body = server.client::<BodyError>().unwrap();
+    let weights = body.get_body_weight("-", date()).await.unwrap();
+    assert_eq!(weights[0].time.convert::<Time>(), Ok(time!(07:12:45)));
+}
+
+#[tokio::test]
+async fn date_times_without_milliseconds_are_parsed() {
+    let server = FixtureServer::start().await;
+    let client = server.client::<TemperatureError>().unwrap();
+
+    let core = client.get_core_temperature("-", DATE).await.unwrap();
+    assert_eq!(
+        core[0].date_time.convert::<PrimitiveDateTime>(),
+        Ok(datetime!(2025-01-15 07:30:00))
+    );
+}
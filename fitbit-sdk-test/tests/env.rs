@@ -0,0 +1,74 @@
+//! Configuration from environment variables
+//!
+//! The variables are process-wide, so this file holds a single test to keep
+//! it from racing with others.
+
+use fitbit_sdk::client::{FitbitClient, FitbitClientBuilder};
+use fitbit_sdk::types::error::ClientBuildError;
+use fitbit_sdk::types::user::{UserClient, UserError};
+use fitbit_sdk_test::FixtureServer;
+use wiremock::matchers::{header, method, path};
+use wiremock::{Mock, ResponseTemplate};
+
+#[tokio::test]
+async fn client_is_configured_from_environment() {
+    let server = FixtureServer::with_fixtures(&[]).await;
+    Mock::given(method("GET"))
+        .and(path("/1/user/-/badges.json"))
+        .and(header("Authorization", "Bearer env-access-token"))
+        .and(header("Accept-Locale", "ja_JP"))
+        .and(header("Accept-Language", "en_GB"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_raw(r#"{"badges": []}"#, "application/json"),
+        )
+        .expect(1)
+        .mount(server.mock_server())
+        .await;
+
+    // SAFETY: no other test of this binary reads or writes the environment
+    unsafe {
+        std::env::set_var("FITBIT_ACCESS_TOKEN", "env-access-token");
+        std::env::set_var("FITBIT_API_BASE_URL", server.uri());
+        std::env::set_var("FITBIT_LOCALE", "ja_JP");
+        std::env::set_var("FITBIT_UNIT_SYSTEM", "uk");
+        std::env::set_var("FITBIT_TIMEOUT_SECS", "10");
+    }
+    let client = FitbitClient::new::<UserError>().unwrap();
+    assert_eq!(client.get_api_base_url(), server.uri());
+    assert!(client.get_badges("-").await.unwrap().is_empty());
+
+    // Builder settings override the environment
+    let client = FitbitClientBuilder::from_env()
+        .unwrap()
+        .with_api_base_url("http://localhost:1")
+        .build()
+        .unwrap();
+    assert_eq!(client.get_api_base_url(), "http://localhost:1");
+
+    unsafe { std::env::set_var("FITBIT_TIMEOUT_SECS", "ten") };
+    let result = FitbitClientBuilder::from_env();
+    assert!(matches!(
+        result,
+        Err(ClientBuildError::InvalidEnvVar { ref name, .. }) if name == "FITBIT_TIMEOUT_SECS"
+    ));
+
+    unsafe {
+        std::env::remove_var("FITBIT_TIMEOUT_SECS");
+        std::env::set_var("FITBIT_UNIT_SYSTEM", "imperial");
+    }
+    assert!(matches!(
+        FitbitClientBuilder::from_env(),
+        Err(ClientBuildError::InvalidEnvVar { ref name, .. }) if name == "FITBIT_UNIT_SYSTEM"
+    ));
+
+    // A blank access token counts as missing
+    unsafe { std::env::set_var("FITBIT_ACCESS_TOKEN", " \t") };
+    assert!(matches!(
+        FitbitClient::builder().build(),
+        Err(ClientBuildError::MissingAccessToken)
+    ));
+    assert!(matches!(
+        FitbitClient::builder().with_access_token("").build(),
+        Err(ClientBuildError::MissingAccessToken)
+    ));
+}
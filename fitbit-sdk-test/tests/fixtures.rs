@@ -0,0 +1,27 @@
+use fitbit_sdk_test::FIXTURES;
+use std::collections::HashSet;
+
+#[test]
+fn fixtures_are_unique() {
+    let mut seen = HashSet::new();
+    for fixture in FIXTURES {
+        assert!(
+            seen.insert((fixture.method, fixture.path)),
+            "Duplicate fixture for {} {}",
+            fixture.method,
+            fixture.path
+        );
+    }
+}
+
+#[test]
+fn json_fixtures_are_valid() {
+    for fixture in FIXTURES {
+        if fixture.body.is_empty() || fixture.path.ends_with(".tcx") {
+            continue;
+        }
+        if let Err(e) = serde_json::from_str::<serde_json::Value>(fixture.body) {
+            panic!("Invalid JSON for {} {}: {}", fixture.method, fixture.path, e);
+        }
+    }
+}
@@ -0,0 +1,281 @@
+use fitbit_sdk::analysis::heart::intraday_time_in_zones;
+use fitbit_sdk::types::heart_rate::{
+    HeartRateClient, HeartRateError, IntradayHeartRateSeries, ZoneSelection,
+};
+use fitbit_sdk::types::intraday::DetailLevel;
+use fitbit_sdk_test::FixtureServer;
+use fitbit_sdk_test::fixtures::{DATE, START_DATE};
+use futures_util::TryStreamExt;
+use time::Duration;
+use time::macros::date;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, ResponseTemplate};
+
+#[tokio::test]
+async fn get_heart_rate_time_series() {
+    let server = FixtureServer::start().await;
+    let client = server.client::<HeartRateError>().unwrap();
+
+    let series = client.get_heart_rate_time_series("-", DATE, "1d").await.unwrap();
+    assert_eq!(series[0].value.resting_heart_rate, Some(62));
+    assert_eq!(series[0].value.heart_rate_zones.len(), 4);
+
+    let range = client
+        .get_heart_rate_time_series_range("-", START_DATE, DATE)
+        .await
+        .unwrap();
+    assert_eq!(range.len(), 3);
+    assert_eq!(range[1].value.resting_heart_rate, None);
+}
+
+#[tokio::test]
+async fn get_resting_heart_rate() {
+    let server = FixtureServer::start().await;
+    let client = server.client::<HeartRateError>().unwrap();
+
+    let resting = client.get_resting_heart_rate("-", DATE, "1d").await.unwrap();
+    assert_eq!(resting.len(), 1);
+    assert_eq!(resting[0].value, 62);
+}
+
+#[tokio::test]
+async fn get_custom_heart_rate_zones() {
+    let server = FixtureServer::start().await;
+    let client = server.client::<HeartRateError>().unwrap();
+
+    let zones = client
+        .get_custom_heart_rate_zones("-", DATE)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(zones.zones.len(), 3);
+    assert_eq!(zones.zone_for(119).unwrap().name, "Below");
+    assert_eq!(zones.zone_for(120).unwrap().name, "Custom Zone");
+    assert_eq!(zones.zone_for(180).unwrap().name, "Above");
+    assert_eq!(zones.zone_for(221), None);
+
+    let range = client
+        .get_heart_rate_time_series_range("-", START_DATE, DATE)
+        .await
+        .unwrap();
+    assert_eq!(range[0].value.custom_zones(), None);
+    let default_zones = range[0].value.default_zones();
+    assert_eq!(default_zones.zone_for(140).unwrap().name, "Cardio");
+}
+
+#[tokio::test]
+async fn get_heart_rate_intraday() {
+    let server = FixtureServer::start().await;
+    let client = server.client::<HeartRateError>().unwrap();
+
+    let series = client
+        .get_heart_rate_intraday("-", DATE, DetailLevel::OneMinute)
+        .await
+        .unwrap();
+    assert_eq!(series.intraday.dataset_type, "minute");
+    assert_eq!(series.intraday.dataset[4].value, 152);
+
+    let window = client
+        .get_heart_rate_intraday_window("-", DATE, DetailLevel::OneMinute, "08:00", "08:05")
+        .await
+        .unwrap();
+    assert_eq!(window, series);
+}
+
+#[tokio::test]
+async fn intraday_time_in_zones_per_day() {
+    let server = FixtureServer::start().await;
+    let client = server.client::<HeartRateError>().unwrap();
+
+    let day = client
+        .get_heart_rate_intraday("-", DATE, DetailLevel::OneMinute)
+        .await
+        .unwrap();
+    // 1-second samples of the previous day, with a gap of 5 minutes
+    let previous_day: IntradayHeartRateSeries = serde_json::from_value(serde_json::json!({
+        "activities-heart": [{
+            "dateTime": "2025-01-14",
+            "value": {"customHeartRateZones": [], "heartRateZones": day.summary[0].value.heart_rate_zones}
+        }],
+        "activities-heart-intraday": {
+            "dataset": [
+                {"time": "07:00:00", "value": 136},
+                {"time": "07:00:30", "value": 135},
+                {"time": "07:05:30", "value": 97},
+                {"time": "23:59:59", "value": 25}
+            ],
+            "datasetInterval": 1,
+            "datasetType": "second"
+        }
+    }))
+    .unwrap();
+    let days = [day, previous_day];
+
+    let time = intraday_time_in_zones(&days, &ZoneSelection::Default, Duration::seconds(15));
+    assert_eq!(time.days.len(), 2);
+    assert_eq!(time.days[0].date.to_string(), "2025-01-14");
+    let zones = &time.days[0].zones;
+    assert_eq!(zones[2].name, "Cardio");
+    assert_eq!(zones[2].minutes, 0.25);
+    assert_eq!(zones[1].name, "Fat Burn");
+    assert_eq!(zones[1].minutes, 0.5);
+    assert_eq!(time.days[0].outside_minutes, 1.0 / 60.0);
+    let zones = &time.days[1].zones;
+    assert_eq!((zones[1].minutes, zones[2].minutes), (2.0, 4.0));
+    assert_eq!(time.totals[1].minutes, 2.5);
+    assert_eq!(time.totals[2].minutes, 4.25);
+
+    // The fixture user has no custom zones
+    let time = intraday_time_in_zones(&days[..1], &ZoneSelection::Custom, Duration::minutes(1));
+    assert!(time.totals.is_empty());
+    assert_eq!(time.outside_minutes, 6.0);
+
+    let custom = client
+        .get_custom_heart_rate_zones("-", DATE)
+        .await
+        .unwrap()
+        .unwrap();
+    let time = intraday_time_in_zones(&days[..1], &ZoneSelection::Fixed(custom), Duration::minutes(1));
+    let minutes: Vec<_> = time.totals.iter().map(|zone| zone.minutes).collect();
+    assert_eq!(minutes, [1.0, 3.0, 2.0]);
+}
+
+#[tokio::test]
+async fn get_heart_rate_time_in_zones() {
+    let server = FixtureServer::start().await;
+    let body = serde_json::json!({
+        "activities-heart": [{
+            "dateTime": "2025-01-14",
+            "value": {"customHeartRateZones": [], "heartRateZones": []}
+        }],
+        "activities-heart-intraday": {
+            "dataset": [{"time": "07:00:00", "value": 120}],
+            "datasetInterval": 1,
+            "datasetType": "minute"
+        }
+    });
+    Mock::given(method("GET"))
+        .and(path("/1/user/-/activities/heart/date/2025-01-14/1d/1min.json"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(body))
+        .mount(server.mock_server())
+        .await;
+    let client = server.client::<HeartRateError>().unwrap();
+
+    let time = client
+        .get_heart_rate_time_in_zones(
+            "-",
+            date!(2025 - 01 - 14),
+            date!(2025 - 01 - 15),
+            DetailLevel::OneMinute,
+            &ZoneSelection::Default,
+            Duration::minutes(1),
+        )
+        .await
+        .unwrap();
+    assert_eq!(time.days.len(), 2);
+    // The previous day has no zones, so its sample is outside every zone
+    assert!(time.days[0].zones.is_empty());
+    assert_eq!(time.days[0].outside_minutes, 1.0);
+    let day = client
+        .get_heart_rate_intraday("-", DATE, DetailLevel::OneMinute)
+        .await
+        .unwrap();
+    let expected = intraday_time_in_zones(&[day], &ZoneSelection::Default, Duration::minutes(1));
+    assert_eq!(time.days[1], expected.days[0]);
+    assert_eq!(time.totals, expected.totals);
+    assert_eq!(time.outside_minutes, expected.outside_minutes + 1.0);
+    assert!(time.totals.iter().all(|zone| zone.calories_out.is_none()));
+
+    let empty = client
+        .get_heart_rate_time_in_zones(
+            "-",
+            date!(2025 - 01 - 15),
+            date!(2025 - 01 - 14),
+            DetailLevel::OneMinute,
+            &ZoneSelection::Default,
+            Duration::minutes(1),
+        )
+        .await
+        .unwrap();
+    assert!(empty.days.is_empty());
+}
+
+#[tokio::test]
+async fn stream_heart_rate_intraday() {
+    let server = FixtureServer::start().await;
+    let client = server.client::<HeartRateError>().unwrap();
+
+    let series = client
+        .get_heart_rate_intraday("-", DATE, DetailLevel::OneMinute)
+        .await
+        .unwrap();
+    let points: Vec<_> = client
+        .stream_heart_rate_intraday("-", DATE, DetailLevel::OneMinute)
+        .try_collect()
+        .await
+        .unwrap();
+    assert_eq!(points, series.intraday.dataset);
+
+    let missing = client
+        .stream_heart_rate_intraday("-", "2024-01-01", DetailLevel::OneMinute)
+        .try_collect::<Vec<_>>()
+        .await;
+    assert!(missing.is_err());
+}
+
+#[tokio::test]
+async fn stream_heart_rate_intraday_full_day() {
+    let server = FixtureServer::with_fixtures(&[]).await;
+    let dataset: Vec<_> = (0..86_400)
+        .map(|second| {
+            serde_json::json!({
+                "time": format!("{:02}:{:02}:{:02}", second / 3600, second / 60 % 60, second % 60),
+                "value": 60 + second % 100,
+            })
+        })
+        .collect();
+    let body = serde_json::json!({
+        "activities-heart": [{
+            "dateTime": DATE,
+            "value": {"customHeartRateZones": [], "heartRateZones": [], "note": "\"dataset\": ["}
+        }],
+        "activities-heart-intraday": {
+            "dataset": dataset,
+            "datasetInterval": 1,
+            "datasetType": "second"
+        }
+    });
+    Mock::given(method("GET"))
+        .and(path("/1/user/-/activities/heart/date/2025-01-15/1d/1sec.json"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(body))
+        .mount(server.mock_server())
+        .await;
+    let client = server.client::<HeartRateError>().unwrap();
+
+    let mut points = client.stream_heart_rate_intraday("-", DATE, DetailLevel::OneSecond);
+    let mut count = 0;
+    while let Some(point) = points.try_next().await.unwrap() {
+        assert_eq!(point.value, 60 + count % 100);
+        count += 1;
+    }
+    assert_eq!(count, 86_400);
+}
+
+#[tokio::test]
+async fn stream_heart_rate_intraday_rejects_truncated_body() {
+    let server = FixtureServer::with_fixtures(&[]).await;
+    Mock::given(method("GET"))
+        .and(path("/1/user/-/activities/heart/date/2025-01-15/1d/1min.json"))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(
+            r#"{"activities-heart-intraday": {"dataset": [{"time": "00:00:00", "value": 61}, {"ti"#,
+            "application/json",
+        ))
+        .mount(server.mock_server())
+        .await;
+    let client = server.client::<HeartRateError>().unwrap();
+
+    let mut points = client.stream_heart_rate_intraday("-", DATE, DetailLevel::OneMinute);
+    assert_eq!(points.try_next().await.unwrap().unwrap().value, 61);
+    let error = points.try_next().await.unwrap_err();
+    assert!(error.to_string().contains("ended inside the intraday dataset"));
+}
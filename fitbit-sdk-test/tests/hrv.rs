@@ -0,0 +1,28 @@
+use fitbit_sdk::types::hrv::{HrvClient, HrvError};
+use fitbit_sdk_test::FixtureServer;
+use fitbit_sdk_test::fixtures::{DATE, START_DATE};
+
+#[tokio::test]
+async fn get_hrv_summary() {
+    let server = FixtureServer::start().await;
+    let client = server.client::<HrvError>().unwrap();
+
+    let summary = client.get_hrv_summary("-", DATE).await.unwrap();
+    assert_eq!(summary[0].value.daily_rmssd, 34.938);
+
+    let range = client.get_hrv_summary_range("-", START_DATE, DATE).await.unwrap();
+    assert_eq!(range.len(), 3);
+}
+
+#[tokio::test]
+async fn get_hrv_intraday() {
+    let server = FixtureServer::start().await;
+    let client = server.client::<HrvError>().unwrap();
+
+    let intraday = client.get_hrv_intraday("-", DATE).await.unwrap();
+    assert_eq!(intraday[0].minutes.len(), 2);
+    assert_eq!(intraday[0].minutes[0].value.coverage, 0.935);
+
+    let range = client.get_hrv_intraday_range("-", START_DATE, DATE).await.unwrap();
+    assert_eq!(range.len(), 2);
+}
@@ -0,0 +1,94 @@
+use fitbit_sdk::types::date::FitbitDate;
+use fitbit_sdk::types::nutrition::{
+    EditFoodLogParams, LogFoodParams, LogWaterParams, MealType, NutritionClient, NutritionError,
+    NutritionResource,
+};
+use fitbit_sdk_test::FixtureServer;
+use fitbit_sdk_test::fixtures::{DATE, FOOD_LOG_ID, WATER_LOG_ID};
+
+fn date() -> FitbitDate {
+    DATE.parse().unwrap()
+}
+
+#[tokio::test]
+async fn get_water_logs() {
+    let server = FixtureServer::start().await;
+    let client = server.client::<NutritionError>().unwrap();
+
+    let log = client.get_water_logs("-", date()).await.unwrap();
+    assert_eq!(log.summary.water, 1300.0);
+    assert_eq!(log.water.len(), 3);
+    assert_eq!(log.water[0].log_id, WATER_LOG_ID);
+    assert_eq!(log.water[0].time, None);
+}
+
+#[tokio::test]
+async fn get_food_logs() {
+    let server = FixtureServer::start().await;
+    let client = server.client::<NutritionError>().unwrap();
+
+    let log = client.get_food_logs("-", date()).await.unwrap();
+    assert_eq!(log.summary.calories, 95);
+    let food = &log.foods[0];
+    assert_eq!(food.log_id, FOOD_LOG_ID);
+    assert_eq!(food.logged_food.meal_type(), Some(MealType::Breakfast));
+    assert_eq!(food.logged_food.unit.plural, "servings");
+    assert_eq!(food.logged_food.units, vec![304, 226, 180]);
+    assert_eq!(log.goals.as_ref().unwrap().calories, Some(2200));
+}
+
+#[tokio::test]
+async fn log_and_delete_water() {
+    let server = FixtureServer::start().await;
+    let client = server.client::<NutritionError>().unwrap();
+
+    let params = LogWaterParams::new(500.0, DATE);
+    let water = client.log_water("-", &params).await.unwrap();
+    assert_eq!(water.log_id, WATER_LOG_ID);
+    assert_eq!(water.amount, 500.0);
+    assert_eq!(water.time, None);
+
+    client.delete_water_log("-", WATER_LOG_ID).await.unwrap();
+}
+
+#[tokio::test]
+async fn get_and_update_water_goal() {
+    let server = FixtureServer::start().await;
+    let client = server.client::<NutritionError>().unwrap();
+
+    let goal = client.get_water_goal("-").await.unwrap();
+    assert_eq!(goal.goal, 2000.0);
+
+    let updated = client.update_water_goal("-", 2000.0).await.unwrap();
+    assert_eq!(updated, goal);
+}
+
+#[tokio::test]
+async fn log_edit_and_delete_food() {
+    let server = FixtureServer::start().await;
+    let client = server.client::<NutritionError>().unwrap();
+
+    let params = LogFoodParams::for_food(81171, MealType::Breakfast, 304, 1.0, DATE);
+    let food = client.log_food("-", &params).await.unwrap();
+    assert_eq!(food.log_id, FOOD_LOG_ID);
+    assert_eq!(food.nutritional_values.calories, 95);
+
+    let params = EditFoodLogParams::new(MealType::Breakfast, 304, 1.0);
+    let edited = client.edit_food_log("-", FOOD_LOG_ID, &params).await.unwrap();
+    assert_eq!(edited, food);
+
+    client.delete_food_log("-", FOOD_LOG_ID).await.unwrap();
+}
+
+#[tokio::test]
+async fn get_nutrition_time_series() {
+    let server = FixtureServer::start().await;
+    let client = server.client::<NutritionError>().unwrap();
+
+    let series = client
+        .get_nutrition_time_series("-", NutritionResource::CaloriesIn, date(), "7d")
+        .await
+        .unwrap();
+    assert_eq!(series.len(), 7);
+    assert_eq!(series[0].value, 1850.0);
+}
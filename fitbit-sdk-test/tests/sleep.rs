@@ -0,0 +1,205 @@
+use fitbit_sdk::types::auth::Scope;
+use fitbit_sdk::types::date::FitbitDate;
+use fitbit_sdk::types::datetime::ApiField;
+use fitbit_sdk::types::error::FitbitError;
+use fitbit_sdk::analysis::sleep::stage_efficiency;
+use fitbit_sdk::types::sleep::{
+    LogSleepParams, SleepClient, SleepError, SleepLevel, SleepListParams,
+};
+use fitbit_sdk_test::FixtureServer;
+use fitbit_sdk_test::fixtures::{DATE, SLEEP_LOG_ID};
+use futures_util::{StreamExt, TryStreamExt};
+use time::macros::{datetime, time};
+use time::{OffsetDateTime, Time};
+use wiremock::matchers::{method, path, query_param};
+use wiremock::{Mock, ResponseTemplate};
+
+#[tokio::test]
+async fn get_sleep_logs() {
+    let server = FixtureServer::start().await;
+    let client = server.client::<SleepError>().unwrap();
+
+    let date: FitbitDate = DATE.parse().unwrap();
+    let log = client.get_sleep_logs("-", date).await.unwrap();
+    assert_eq!(log.summary.total_minutes_asleep, 405);
+    let stages = log.summary.stages.as_ref().unwrap();
+    assert_eq!(stages.deep, 104);
+    assert_eq!(stages.wake, 57);
+    assert_eq!(stages.asleep_minutes(), log.summary.total_minutes_asleep);
+    let entry = &log.sleep[0];
+    assert_eq!(entry.log_id, SLEEP_LOG_ID);
+    assert_eq!(entry.type_, "stages");
+    assert_eq!(entry.minutes_awake, Some(57));
+    let levels = entry.levels.as_ref().unwrap();
+    assert_eq!(levels.summary.deep.as_ref().unwrap().minutes, 104);
+    assert_eq!(levels.summary.deep.as_ref().unwrap().thirty_day_avg_minutes, Some(69));
+    assert_eq!(levels.data.len(), 6);
+    assert_eq!(levels.data[2].level, SleepLevel::Deep);
+    let short_data = levels.short_data.as_ref().unwrap();
+    assert_eq!(short_data.len(), 1);
+    assert_eq!(short_data[0].level, SleepLevel::Wake);
+    assert_eq!(short_data[0].seconds, 60);
+}
+
+#[tokio::test]
+async fn get_classic_sleep_logs() {
+    let server = FixtureServer::with_fixtures(&[]).await;
+    Mock::given(method("GET"))
+        .and(path(format!("/1.2/user/-/sleep/date/{}.json", DATE)))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(
+            include_str!("../fixtures/sleep/sleep_log_classic.json"),
+            "application/json",
+        ))
+        .mount(server.mock_server())
+        .await;
+    let client = server.client::<SleepError>().unwrap();
+
+    let date: FitbitDate = DATE.parse().unwrap();
+    let log = client.get_sleep_logs("-", date).await.unwrap();
+    assert!(log.summary.stages.is_none());
+    let entry = &log.sleep[0];
+    assert_eq!(entry.type_, "classic");
+    assert!(!entry.is_main_sleep);
+    let levels = entry.levels.as_ref().unwrap();
+    assert_eq!(levels.summary.asleep.as_ref().unwrap().minutes, 84);
+    assert_eq!(levels.summary.restless.as_ref().unwrap().count, 1);
+    assert_eq!(levels.summary.awake.as_ref().unwrap().minutes, 2);
+    assert!(levels.summary.deep.is_none());
+    assert!(levels.short_data.is_none());
+    let kinds: Vec<_> = levels.data.iter().map(|point| point.level.clone()).collect();
+    assert_eq!(
+        kinds,
+        [SleepLevel::Asleep, SleepLevel::Restless, SleepLevel::Awake, SleepLevel::Asleep]
+    );
+
+    let stages = stage_efficiency(levels).unwrap();
+    assert_eq!(stages.wake, 120.0 * 100.0 / 5400.0);
+}
+
+#[tokio::test]
+async fn get_sleep_goal() {
+    let server = FixtureServer::start().await;
+    let client = server.client::<SleepError>().unwrap();
+
+    let response = client.get_sleep_goal("-").await.unwrap();
+    assert_eq!(response.goal.min_duration, 480);
+    // Compared as `time` values to pass with and without the `typed-time` feature
+    assert_eq!(
+        response.goal.updated_on.convert::<OffsetDateTime>(),
+        Ok(datetime!(2025-01-10 08:14:15.111 UTC))
+    );
+    assert_eq!(response.goal.bedtime.unwrap().convert::<Time>(), Ok(time!(22:30)));
+    assert_eq!(response.goal.wakeup_time.unwrap().convert::<Time>(), Ok(time!(06:45)));
+    let consistency = response.consistency.unwrap();
+    assert_eq!(consistency.flow_id, 2);
+    assert_eq!(consistency.recommended_sleep_goal, Some(480));
+    assert_eq!(consistency.typical_duration, Some(450));
+    assert_eq!(consistency.typical_wakeup_time.unwrap().convert::<Time>(), Ok(time!(06:45)));
+}
+
+#[tokio::test]
+async fn update_sleep_goal() {
+    let server = FixtureServer::start().await;
+    let client = server.client::<SleepError>().unwrap();
+
+    let update = client.update_sleep_goal("-", 480).await.unwrap();
+    assert_eq!(update.goal.min_duration, 480);
+    assert_eq!(update.consistency.unwrap().recommended_sleep_goal, Some(480));
+}
+
+#[tokio::test]
+async fn log_and_delete_sleep() {
+    let server = FixtureServer::start().await;
+    let client = server.client::<SleepError>().unwrap();
+
+    let params = LogSleepParams::new("23:00", 28800000, "2025-01-14");
+    let entry = client.log_sleep("-", &params).await.unwrap();
+    assert_eq!(entry.type_, "classic");
+    assert_eq!(entry.minutes_asleep, 480);
+
+    client.delete_sleep_log("-", SLEEP_LOG_ID).await.unwrap();
+}
+
+#[tokio::test]
+async fn get_sleep_log_list() {
+    let server = FixtureServer::start().await;
+    let client = server.client::<SleepError>().unwrap();
+
+    let params = SleepListParams::before("2025-01-16").with_limit(10);
+    let page = client.get_sleep_log_list("-", &params).await.unwrap();
+    assert_eq!(page.sleep.len(), 1);
+    assert_eq!(page.pagination.limit, 10);
+
+    let entries = client.get_all_sleep_log_list("-", &params).await.unwrap();
+    assert_eq!(entries, page.sleep);
+}
+
+/// Mounts the recorded sleep list as the page at `offset`, linking to the page at `next_offset`
+async fn mount_sleep_list_page(server: &FixtureServer, offset: u32, next_offset: Option<u32>) {
+    let next = next_offset
+        .map(|next| {
+            format!(
+                "{}/1.2/user/-/sleep/list.json?beforeDate=2025-01-16&sort=desc&limit=1&offset={}",
+                server.uri(),
+                next
+            )
+        })
+        .unwrap_or_default();
+    let body = include_str!("../fixtures/sleep/list.json")
+        .replace(r#""next": """#, &format!(r#""next": "{}""#, next));
+    Mock::given(method("GET"))
+        .and(path("/1.2/user/-/sleep/list.json"))
+        .and(query_param("offset", offset.to_string()))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(body, "application/json"))
+        .mount(server.mock_server())
+        .await;
+}
+
+#[tokio::test]
+async fn stream_sleep_log_list_follows_next_links() {
+    let server = FixtureServer::with_fixtures(&[]).await;
+    mount_sleep_list_page(&server, 0, Some(1)).await;
+    mount_sleep_list_page(&server, 1, Some(2)).await;
+    mount_sleep_list_page(&server, 2, None).await;
+    let client = server.client::<SleepError>().unwrap();
+
+    let params = SleepListParams::before("2025-01-16").with_limit(1);
+    let entries: Vec<_> = client
+        .stream_sleep_log_list("-", &params)
+        .try_collect()
+        .await
+        .unwrap();
+    assert_eq!(entries.len(), 3);
+    assert!(entries.iter().all(|entry| entry.log_id == SLEEP_LOG_ID));
+}
+
+#[tokio::test]
+async fn stream_sleep_log_list_stops_after_max_pages() {
+    let server = FixtureServer::with_fixtures(&[]).await;
+    mount_sleep_list_page(&server, 0, Some(0)).await;
+    let client = server.client::<SleepError>().unwrap();
+
+    let params = SleepListParams::before("2025-01-16").with_limit(1).with_max_pages(3);
+    let results: Vec<_> = client.stream_sleep_log_list("-", &params).collect().await;
+    assert_eq!(results.len(), 4);
+    assert!(results[..3].iter().all(Result::is_ok));
+    assert!(results[3].is_err());
+
+    assert!(client.get_all_sleep_log_list("-", &params).await.is_err());
+}
+
+#[tokio::test]
+async fn insufficient_scope_names_missing_scope() {
+    let server = FixtureServer::with_fixtures(&[]).await;
+    let body = r#"{"errors":[{"errorType":"insufficient_scope","message":"This application does not have permission to read sleep data. Visit https://dev.fitbit.com/docs/oauth2 for more information on the Fitbit Web API authorization process."}],"success":false}"#;
+    Mock::given(method("GET"))
+        .and(path(format!("/1.2/user/-/sleep/date/{}.json", DATE)))
+        .respond_with(ResponseTemplate::new(403).set_body_raw(body, "application/json"))
+        .mount(server.mock_server())
+        .await;
+    let client = server.client::<SleepError>().unwrap();
+
+    let date: FitbitDate = DATE.parse().unwrap();
+    let error = client.get_sleep_logs("-", date).await.unwrap_err();
+    assert!(matches!(error, SleepError(FitbitError::InsufficientScope(scopes)) if scopes == vec![Scope::Sleep]));
+}
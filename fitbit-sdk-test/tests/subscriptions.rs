@@ -0,0 +1,52 @@
+use fitbit_sdk::types::subscriptions::{CollectionType, SubscriptionClient, SubscriptionError};
+use fitbit_sdk::webhook::parse_notifications;
+use fitbit_sdk_test::FixtureServer;
+use fitbit_sdk_test::fixtures::SUBSCRIPTION_ID;
+
+#[tokio::test]
+async fn list_subscriptions() {
+    let server = FixtureServer::start().await;
+    let client = server.client::<SubscriptionError>().unwrap();
+
+    let subscriptions = client.list_subscriptions("-", None).await.unwrap();
+    assert_eq!(subscriptions.len(), 2);
+    assert_eq!(subscriptions[1].collection_type, "sleep");
+}
+
+#[tokio::test]
+async fn create_and_delete_subscription() {
+    let server = FixtureServer::start().await;
+    let client = server.client::<SubscriptionError>().unwrap();
+
+    let subscription = client
+        .create_subscription("-", Some(CollectionType::Activities), SUBSCRIPTION_ID, None)
+        .await
+        .unwrap();
+    assert_eq!(subscription.subscription_id, SUBSCRIPTION_ID);
+
+    client
+        .delete_subscription("-", Some(CollectionType::Activities), SUBSCRIPTION_ID, None)
+        .await
+        .unwrap();
+}
+
+#[test]
+fn parse_notification_collection_types() {
+    let body = br#"[
+        {"collectionType":"activities","date":"2025-01-01","ownerId":"ABC123","ownerType":"user","subscriptionId":"1"},
+        {"collectionType":"deleteUser","ownerId":"ABC123","ownerType":"user","subscriptionId":"1"},
+        {"collectionType":"newCollection","ownerId":"ABC123","ownerType":"user","subscriptionId":"1"}
+    ]"#;
+
+    let notifications = parse_notifications(body).unwrap();
+    let collections: Vec<_> = notifications.iter().map(|n| n.collection_type.clone()).collect();
+    assert_eq!(
+        collections,
+        [
+            CollectionType::Activities,
+            CollectionType::DeleteUser,
+            CollectionType::Other("newCollection".to_string()),
+        ]
+    );
+    assert_eq!(collections[2].as_str(), "newCollection");
+}
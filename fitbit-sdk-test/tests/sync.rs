@@ -0,0 +1,132 @@
+use async_trait::async_trait;
+use fitbit_sdk::sync::JsonlSink;
+use fitbit_sdk::types::activity::Resource;
+use fitbit_sdk::types::sync::{
+    SyncCheckpoint, SyncClient, SyncData, SyncError, SyncParams, SyncRecord, SyncSink,
+};
+use fitbit_sdk_test::FixtureServer;
+use fitbit_sdk_test::fixtures::{DATE, SLEEP_LOG_ID, START_DATE};
+use time::Date;
+use time::macros::format_description;
+
+fn parse_date(date: &str) -> Date {
+    Date::parse(date, format_description!("[year]-[month]-[day]")).unwrap()
+}
+
+/// Params syncing the recorded date range, from `START_DATE` to `DATE`
+fn params(checkpoint: SyncCheckpoint) -> SyncParams {
+    SyncParams::new(checkpoint, parse_date(START_DATE))
+        .with_end_date(parse_date(DATE))
+        .with_activity_resources([Resource::Steps])
+}
+
+#[derive(Default)]
+struct MemorySink {
+    records: Vec<SyncRecord>,
+    finished: bool,
+}
+
+#[async_trait]
+impl SyncSink for MemorySink {
+    async fn write(&mut self, record: SyncRecord) -> Result<(), SyncError> {
+        self.records.push(record);
+        Ok(())
+    }
+
+    async fn finish(&mut self) -> Result<(), SyncError> {
+        self.finished = true;
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn sync_pulls_every_data_type() {
+    let server = FixtureServer::start().await;
+    let client = server.client::<SyncError>().unwrap();
+
+    let mut sink = MemorySink::default();
+    let summary = client
+        .sync("-", &params(SyncCheckpoint::default()), &mut sink)
+        .await
+        .unwrap();
+    assert_eq!(summary.checkpoint, SyncCheckpoint::through(parse_date(DATE)));
+    assert_eq!(summary.records, sink.records.len());
+    assert!(sink.finished);
+
+    let count = |matches: fn(&SyncRecord) -> bool| {
+        sink.records.iter().filter(|record| matches(record)).count()
+    };
+    assert_eq!(count(|r| matches!(r, SyncRecord::Activity { .. })), 3);
+    assert_eq!(count(|r| matches!(r, SyncRecord::Weight(_))), 3);
+    assert_eq!(count(|r| matches!(r, SyncRecord::BodyFat(_))), 2);
+    assert_eq!(count(|r| matches!(r, SyncRecord::HeartRate(_))), 3);
+    assert_eq!(count(|r| matches!(r, SyncRecord::Sleep(_))), 1);
+    assert!(matches!(
+        sink.records.iter().find(|r| matches!(r, SyncRecord::Sleep(_))),
+        Some(SyncRecord::Sleep(entry)) if entry.log_id == SLEEP_LOG_ID
+    ));
+}
+
+#[tokio::test]
+async fn sync_only_pulls_selected_data() {
+    let server = FixtureServer::start().await;
+    let client = server.client::<SyncError>().unwrap();
+
+    let mut sink = MemorySink::default();
+    let params = params(SyncCheckpoint::default()).with_data([SyncData::Body]);
+    let summary = client.sync("-", &params, &mut sink).await.unwrap();
+    assert_eq!(summary.records, 5);
+    assert!(
+        sink.records
+            .iter()
+            .all(|r| matches!(r, SyncRecord::Weight(_) | SyncRecord::BodyFat(_)))
+    );
+}
+
+#[tokio::test]
+async fn sync_is_a_no_op_when_up_to_date() {
+    let server = FixtureServer::with_fixtures(&[]).await;
+    let client = server.client::<SyncError>().unwrap();
+
+    let checkpoint = SyncCheckpoint::through(parse_date(DATE));
+    let mut sink = MemorySink::default();
+    let summary = client.sync("-", &params(checkpoint), &mut sink).await.unwrap();
+    assert_eq!(summary.checkpoint, checkpoint);
+    assert_eq!(summary.records, 0);
+    assert!(server.mock_server().received_requests().await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn sync_does_not_advance_on_error() {
+    let server = FixtureServer::with_fixtures(&[]).await;
+    let client = server.client::<SyncError>().unwrap();
+
+    let mut sink = MemorySink::default();
+    let result = client.sync("-", &params(SyncCheckpoint::default()), &mut sink).await;
+    assert!(result.is_err());
+    assert!(!sink.finished);
+}
+
+#[tokio::test]
+async fn jsonl_sink_appends_tagged_records() {
+    let server = FixtureServer::start().await;
+    let client = server.client::<SyncError>().unwrap();
+
+    let path = std::env::temp_dir()
+        .join(format!("fitbit-sdk-sync-{}.jsonl", std::process::id()));
+    let _ = std::fs::remove_file(&path);
+    let mut sink = JsonlSink::create(&path).await.unwrap();
+    let params = params(SyncCheckpoint::default()).with_data([SyncData::Activity]);
+    client.sync("-", &params, &mut sink).await.unwrap();
+
+    let contents = std::fs::read_to_string(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    let lines: Vec<serde_json::Value> = contents
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+    assert_eq!(lines.len(), 3);
+    assert_eq!(lines[0]["type"], "activity");
+    assert_eq!(lines[0]["resource"], "steps");
+    assert_eq!(lines[0]["point"]["dateTime"], START_DATE);
+}
@@ -0,0 +1,28 @@
+use fitbit_sdk::types::temperature::{TemperatureClient, TemperatureError};
+use fitbit_sdk_test::FixtureServer;
+use fitbit_sdk_test::fixtures::{DATE, START_DATE};
+
+#[tokio::test]
+async fn get_skin_temperature() {
+    let server = FixtureServer::start().await;
+    let client = server.client::<TemperatureError>().unwrap();
+
+    let skin = client.get_skin_temperature("-", DATE).await.unwrap();
+    assert_eq!(skin[0].value.nightly_relative, -0.3);
+    assert_eq!(skin[0].log_type, "dedicated_temp_sensor");
+
+    let range = client.get_skin_temperature_range("-", START_DATE, DATE).await.unwrap();
+    assert_eq!(range.len(), 3);
+}
+
+#[tokio::test]
+async fn get_core_temperature() {
+    let server = FixtureServer::start().await;
+    let client = server.client::<TemperatureError>().unwrap();
+
+    let core = client.get_core_temperature("-", DATE).await.unwrap();
+    assert_eq!(core[0].value, 36.8);
+
+    let range = client.get_core_temperature_range("-", START_DATE, DATE).await.unwrap();
+    assert_eq!(range.len(), 2);
+}
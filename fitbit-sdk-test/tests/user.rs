@@ -0,0 +1,162 @@
+use fitbit_sdk::types::auth::Scope;
+use fitbit_sdk::types::error::FitbitError;
+use fitbit_sdk::types::user::{
+    BadgeCategory, ClockTimeFormat, SleepTracking, StartDayOfWeek, UpdateProfileParams,
+    UserClient, UserError,
+};
+use fitbit_sdk_test::FixtureServer;
+use fitbit_sdk_test::fixtures::MINIMAL_SCOPE_USER_ID;
+use wiremock::matchers::{body_string_contains, header, method, path};
+use wiremock::{Mock, ResponseTemplate};
+
+#[tokio::test]
+async fn get_profile() {
+    let server = FixtureServer::start().await;
+    let client = server.client::<UserError>().unwrap();
+
+    let profile = client.get_profile("-").await.unwrap();
+    assert_eq!(profile.display_name, "Alex");
+    assert_eq!(profile.full_name.as_deref(), Some("Alex Morgan"));
+    assert_eq!(profile.height, Some(176.5));
+    assert_eq!(profile.weight, Some(72.4));
+    assert_eq!(profile.average_daily_steps, Some(9312));
+    assert_eq!(profile.timezone.as_deref(), Some("Europe/London"));
+    assert_eq!(profile.offset_from_utc_millis, Some(0));
+    assert_eq!(profile.member_since.unwrap().to_string(), "2019-03-02");
+    assert_eq!(profile.stride_length_walking, Some(73.2));
+    assert_eq!(profile.sleep_tracking, Some(SleepTracking::Normal));
+    assert_eq!(profile.clock_time_display_format, Some(ClockTimeFormat::TwentyFourHour));
+    assert_eq!(profile.start_day_of_week, Some(StartDayOfWeek::Monday));
+    assert!(profile.extra.is_empty());
+}
+
+const PROFILE_WITH_UNKNOWN_FIELD: &str = r#"{"user":{"displayName":"Alex","heightUnit":"METRIC",
+    "weightUnit":"METRIC","avatar":"","avatar150":"","avatar640":"","visibleUser":true}}"#;
+
+#[tokio::test]
+#[cfg(not(feature = "strict-serde"))]
+async fn get_profile_keeps_unknown_fields() {
+    let server = FixtureServer::with_fixtures(&[]).await;
+    Mock::given(method("GET"))
+        .and(path("/1/user/-/profile.json"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_raw(PROFILE_WITH_UNKNOWN_FIELD, "application/json"),
+        )
+        .mount(server.mock_server())
+        .await;
+    let client = server.client::<UserError>().unwrap();
+
+    let profile = client.get_profile("-").await.unwrap();
+    assert_eq!(profile.extra.len(), 1);
+    assert_eq!(profile.extra["visibleUser"], serde_json::Value::Bool(true));
+}
+
+#[tokio::test]
+#[cfg(feature = "strict-serde")]
+async fn get_profile_rejects_unknown_fields() {
+    let server = FixtureServer::with_fixtures(&[]).await;
+    Mock::given(method("GET"))
+        .and(path("/1/user/-/profile.json"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_raw(PROFILE_WITH_UNKNOWN_FIELD, "application/json"),
+        )
+        .mount(server.mock_server())
+        .await;
+    let client = server.client::<UserError>().unwrap();
+
+    let error = client.get_profile("-").await.unwrap_err();
+    assert!(error.to_string().contains("unknown fields: visibleUser"), "{}", error);
+}
+
+#[tokio::test]
+async fn get_profile_without_personal_information() {
+    let server = FixtureServer::start().await;
+    let client = server.client::<UserError>().unwrap();
+
+    let profile = client.get_profile(MINIMAL_SCOPE_USER_ID).await.unwrap();
+    assert_eq!(profile.display_name, "Sam");
+    assert_eq!(profile.full_name, None);
+    assert_eq!(profile.date_of_birth, None);
+    assert_eq!(profile.gender, None);
+    assert_eq!(profile.height, None);
+    assert_eq!(profile.weight, None);
+    assert_eq!(profile.average_daily_steps, None);
+}
+
+#[tokio::test]
+async fn update_profile() {
+    let server = FixtureServer::start().await;
+    let client = server.client::<UserError>().unwrap();
+
+    let params = UpdateProfileParams::new().with_display_name("Alex");
+    let profile = client.update_profile(&params).await.unwrap();
+    assert_eq!(profile.display_name, "Alex");
+}
+
+#[tokio::test]
+async fn update_profile_sends_form_body() {
+    let server = FixtureServer::with_fixtures(&[]).await;
+    Mock::given(method("POST"))
+        .and(path("/1/user/-/profile.json"))
+        .and(header("Content-Type", "application/x-www-form-urlencoded"))
+        .and(body_string_contains("fullname=Alex+Morgan"))
+        .and(body_string_contains("height=176.5"))
+        .and(body_string_contains("strideLengthWalking=73.2"))
+        .and(body_string_contains("timezone=Europe%2FLondon"))
+        .and(body_string_contains("localeLang=en"))
+        .and(body_string_contains("localeCountry=GB"))
+        .and(body_string_contains("startDayOfWeek=MONDAY"))
+        .and(body_string_contains("clockTimeDisplayFormat=24hour"))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(
+            include_str!("../fixtures/user/profile.json"),
+            "application/json",
+        ))
+        .expect(1)
+        .mount(server.mock_server())
+        .await;
+    let client = server.client::<UserError>().unwrap();
+
+    let params = UpdateProfileParams::new()
+        .with_full_name("Alex Morgan")
+        .with_height(176.5)
+        .with_stride_length_walking(73.2)
+        .with_timezone("Europe/London")
+        .with_locale("en_GB")
+        .with_start_day_of_week(StartDayOfWeek::Monday)
+        .with_clock_time_display_format(ClockTimeFormat::TwentyFourHour);
+    let profile = client.update_profile(&params).await.unwrap();
+    assert_eq!(profile.timezone.as_deref(), Some("Europe/London"));
+}
+
+#[tokio::test]
+async fn get_badges() {
+    let server = FixtureServer::start().await;
+    let client = server.client::<UserError>().unwrap();
+
+    let badges = client.get_badges("-").await.unwrap();
+    assert_eq!(badges.len(), 2);
+    assert_eq!(badges[0].category, BadgeCategory::DailySteps);
+    assert_eq!(badges[0].gradient_start_color.to_string(), "#007273");
+    assert_eq!(badges[0].short_description.as_deref(), Some("25,000 steps"));
+    assert!(badges[0].image_300px.as_deref().unwrap().contains("/300px/"));
+    assert!(badges[1].share_image_640px.is_some());
+    assert!(badges[1].extra.is_empty());
+    assert_eq!(badges[1].category, BadgeCategory::LifetimeDistance);
+    assert_eq!(badges[1].unit.as_deref(), Some("KILOMETERS"));
+}
+
+#[tokio::test]
+async fn insufficient_scope_falls_back_to_endpoint_scope() {
+    let server = FixtureServer::with_fixtures(&[]).await;
+    let body = r#"{"errors":[{"errorType":"insufficient_scope","message":"Missing scope"}],"success":false}"#;
+    Mock::given(method("GET"))
+        .and(path("/1/user/-/badges.json"))
+        .respond_with(ResponseTemplate::new(403).set_body_raw(body, "application/json"))
+        .mount(server.mock_server())
+        .await;
+    let client = server.client::<UserError>().unwrap();
+
+    let error = client.get_badges("-").await.unwrap_err();
+    assert_eq!(error.to_string(), "Insufficient scope, the request requires: profile");
+    assert!(matches!(error, UserError(FitbitError::InsufficientScope(scopes)) if scopes == vec![Scope::Profile]));
+}
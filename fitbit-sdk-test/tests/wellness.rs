@@ -0,0 +1,41 @@
+use fitbit_sdk::types::cardio_fitness::Vo2Max;
+use fitbit_sdk::types::wellness::{WellnessClient, WellnessError};
+use fitbit_sdk_test::FixtureServer;
+use time::macros::date;
+
+#[tokio::test]
+async fn get_recovery_report() {
+    let server = FixtureServer::start().await;
+    let client = server.client::<WellnessError>().unwrap();
+
+    let report = client
+        .get_recovery_report("-", date!(2025 - 01 - 13), date!(2025 - 01 - 15))
+        .await
+        .unwrap();
+    assert_eq!(report.len(), 3);
+
+    let first = &report[0];
+    assert_eq!(first.date, date!(2025 - 01 - 13));
+    assert_eq!(first.hrv.as_ref().unwrap().daily_rmssd, 36.1);
+    assert_eq!(first.resting_heart_rate, Some(63));
+    assert_eq!(first.breathing_rate, Some(16.2));
+    assert_eq!(first.skin_temperature, Some(0.1));
+    assert_eq!(first.vo2_max, Some(Vo2Max::Range(44.0, 48.0)));
+
+    // No resting heart rate was calculated on the second day
+    assert_eq!(report[1].resting_heart_rate, None);
+    assert_eq!(report[1].breathing_rate, Some(15.6));
+    assert_eq!(report[2].vo2_max, Some(Vo2Max::Single(46.0)));
+}
+
+#[tokio::test]
+async fn get_recovery_report_is_empty_for_reversed_range() {
+    let server = FixtureServer::with_fixtures(&[]).await;
+    let client = server.client::<WellnessError>().unwrap();
+
+    let report = client
+        .get_recovery_report("-", date!(2025 - 01 - 15), date!(2025 - 01 - 13))
+        .await
+        .unwrap();
+    assert!(report.is_empty());
+}
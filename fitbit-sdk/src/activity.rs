@@ -3,12 +3,106 @@
 //! This module contains the implementations for the Fitbit Activity API endpoints.
 //! It provides functionality for retrieving activity data and statistics.
 
-use crate::client::FitbitClient;
+use crate::client::{ApiVersion, FitbitClient};
 use crate::types::activity::{
-    ActivityClient, ActivityError, ActivitySummary, ActivitySummaryResponse, ActivityTimeSeries,
-    ActivityLifetimeStats, LifetimeStatsResponse, Resource,
+    ActivityCatalogResponse, ActivityCategory, ActivityClient, ActivityError, ActivityGoals,
+    ActivityGoalsParams, ActivityGoalsResponse, ActivityLog, ActivityLogEntry, ActivityLogList,
+    ActivitySummary,
+    ActivitySummaryResponse, ActivityTimeSeries, DailyActivityResponse, DetailedLifetimeStats, ActivityType, ActivityTypeResponse,
+    ActivityLifetimeStats, ActivityRangeSummary, FavoriteActivity, FrequentActivity, GoalPeriod, IntradayActivitySeries,
+    LogActivityParams, LogActivityResponse, Resource, Elevation, ElevationUnit, TrackerResource,
 };
+use crate::types::date::FitbitDate;
+use crate::types::error::FitbitError;
+use crate::types::intraday::{DetailLevel, IntradayDataPoint, intraday_path_suffix};
+use crate::types::pagination::ListParams;
+#[cfg(feature = "tcx")]
+use crate::types::tcx::Tcx;
+use crate::types::request::Body;
+use crate::types::user::UserId;
 use async_trait::async_trait;
+use futures_util::stream::{self, BoxStream, StreamExt, TryStreamExt};
+use futures_util::TryFutureExt;
+use reqwest::Method;
+use serde::de::DeserializeOwned;
+use std::time::Duration;
+use time::{Date, Time};
+
+/// Number of daily summaries fetched at the same time for a date range
+const SUMMARY_RANGE_CONCURRENCY: usize = 4;
+
+impl FitbitClient {
+    /// Fetches an intraday activity series and splits it by resource key
+    ///
+    /// The response keys depend on the resource, e.g. `activities-steps` and
+    /// `activities-steps-intraday`.
+    async fn get_activity_intraday_series(
+        &self,
+        user_id: UserId,
+        resource: Resource,
+        date: FitbitDate,
+        detail_level: DetailLevel,
+        time_window: Option<(&str, &str)>,
+    ) -> Result<IntradayActivitySeries, ActivityError> {
+        let path = format!(
+            "/user/{}/activities/{}/date/{}/{}",
+            user_id,
+            resource.as_str(),
+            date,
+            intraday_path_suffix(detail_level, time_window)
+        );
+        let mut response: serde_json::Value =
+            self.get::<_, (), ActivityError>(&path, None).await?;
+
+        let key = resource.response_key();
+        let summary = take_key(&mut response, &key)?;
+        let intraday = take_key(&mut response, &format!("{}-intraday", key))?;
+        let extra = crate::types::extra::deserialize(response)
+            .map_err(|e| ActivityError::from(e.to_string()))?;
+
+        Ok(IntradayActivitySeries {
+            summary,
+            intraday,
+            extra,
+        })
+    }
+
+    /// Fetches a daily activity time series starting or ending on `date`
+    ///
+    /// `range_end` is either a period (e.g. `7d`) or the end date of a range.
+    /// The response key depends on the resource, e.g. `activities-steps`.
+    async fn get_activity_series(
+        &self,
+        user_id: UserId,
+        resource: Resource,
+        date: FitbitDate,
+        range_end: &str,
+    ) -> Result<Vec<ActivityTimeSeries>, ActivityError> {
+        let path = format!(
+            "/user/{}/activities/{}/date/{}/{}.json",
+            user_id,
+            resource.as_str(),
+            date,
+            range_end
+        );
+        let mut response: serde_json::Value =
+            self.get::<_, (), ActivityError>(&path, None).await?;
+
+        take_key(&mut response, &resource.response_key())
+    }
+}
+
+/// Removes `key` from a JSON object and deserializes it
+fn take_key<T: DeserializeOwned>(
+    response: &mut serde_json::Value,
+    key: &str,
+) -> Result<T, ActivityError> {
+    let value = response
+        .as_object_mut()
+        .and_then(|response| response.remove(key))
+        .ok_or_else(|| ActivityError::from(format!("Missing key '{}' in response", key)))?;
+    serde_json::from_value(value).map_err(|e| ActivityError::from(e.to_string()))
+}
 
 #[async_trait]
 impl ActivityClient for FitbitClient {
@@ -19,7 +113,7 @@ impl ActivityClient for FitbitClient {
     /// # Arguments
     ///
     /// * `user_id` - The user ID to get activity summary for, or "-" for current user
-    /// * `date` - The date in format YYYY-MM-DD
+    /// * `date` - The date
     ///
     /// # Returns
     ///
@@ -37,6 +131,7 @@ impl ActivityClient for FitbitClient {
     /// ```no_run
     /// use fitbit_sdk::client::FitbitClient;
     /// use fitbit_sdk::types::activity::{ActivityClient, ActivityError};
+    /// use fitbit_sdk::types::date::FitbitDate;
     /// use tokio;
     ///
     /// #[tokio::main]
@@ -44,7 +139,7 @@ impl ActivityClient for FitbitClient {
     ///     let client = FitbitClient::new::<ActivityError>()?;
     ///
     ///     // Get today's activity summary
-    ///     let summary = client.get_activity_summary("-", "today").await?;
+    ///     let summary = client.get_activity_summary("-", FitbitDate::Today).await?;
     ///     println!("Steps: {}", summary.steps);
     ///
     ///     Ok(())
@@ -52,14 +147,139 @@ impl ActivityClient for FitbitClient {
     /// ```
     async fn get_activity_summary<'a>(
         &'a self,
-        user_id: &'a str,
-        date: &'a str,
+        user_id: impl Into<UserId> + Send,
+        date: impl Into<FitbitDate> + Send,
     ) -> Result<ActivitySummary, ActivityError> {
+        let user_id: UserId = user_id.into();
+        let date: FitbitDate = date.into();
         let path = format!("/user/{}/activities/date/{}.json", user_id, date);
-        let response: ActivitySummaryResponse = self.get(&path, Option::<&()>::None).await?;
+        let response: ActivitySummaryResponse = self.get::<_, (), ActivityError>(&path, None).await?;
         Ok(response.summary)
     }
 
+    /// Gets the complete daily activity summary
+    ///
+    /// Retrieves the activity summary for a specific date together with the
+    /// activities logged on that day and the user's daily goals.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The user ID to get activity summary for, or "-" for current user
+    /// * `date` - The date
+    ///
+    /// # Returns
+    ///
+    /// Returns the logged activities, goals and summary on success.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `ActivityError` if:
+    /// - The request fails to send
+    /// - The API returns an error response
+    /// - The response cannot be parsed
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use fitbit_sdk::client::FitbitClient;
+    /// use fitbit_sdk::types::activity::{ActivityClient, ActivityError};
+    /// use fitbit_sdk::types::date::FitbitDate;
+    /// use tokio;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), ActivityError> {
+    ///     let client = FitbitClient::new::<ActivityError>()?;
+    ///
+    ///     let daily = client.get_activity_daily("-", FitbitDate::Today).await?;
+    ///     if let Some(steps_goal) = daily.goals.as_ref().and_then(|goals| goals.steps) {
+    ///         println!("Steps: {} / {}", daily.summary.steps, steps_goal);
+    ///     }
+    ///     for activity in &daily.activities {
+    ///         println!("{} at {}: {} kcal", activity.name, activity.start_time, activity.calories);
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    async fn get_activity_daily<'a>(
+        &'a self,
+        user_id: impl Into<UserId> + Send,
+        date: impl Into<FitbitDate> + Send,
+    ) -> Result<DailyActivityResponse, ActivityError> {
+        let user_id: UserId = user_id.into();
+        let date: FitbitDate = date.into();
+        let path = format!("/user/{}/activities/date/{}.json", user_id, date);
+        self.get::<_, (), ActivityError>(&path, None).await
+    }
+
+    /// Gets the activity summaries of a date range with their totals and averages
+    ///
+    /// Fitbit has no endpoint summarizing several days, so the summary of each
+    /// day is fetched separately, a few days at a time, and then aggregated.
+    /// Every day costs one request against the rate limit.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The user ID to get activity summaries for, or "-" for current user
+    /// * `start_date` - The first day of the range
+    /// * `end_date` - The last day of the range, included
+    ///
+    /// # Returns
+    ///
+    /// Returns the summary of each day together with the totals, the daily
+    /// averages and the day with the most steps on success. The summary is
+    /// empty if `end_date` is before `start_date`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `ActivityError` if any of the days fails with:
+    /// - The request fails to send
+    /// - The API returns an error response
+    /// - The response cannot be parsed
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use fitbit_sdk::client::FitbitClient;
+    /// use fitbit_sdk::types::activity::{ActivityClient, ActivityError};
+    /// use time::macros::date;
+    /// use tokio;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), ActivityError> {
+    ///     let client = FitbitClient::new::<ActivityError>()?;
+    ///
+    ///     let week = client
+    ///         .get_activity_summary_range("-", date!(2025 - 01 - 06), date!(2025 - 01 - 12))
+    ///         .await?;
+    ///     println!("Steps: {} ({:.0} per day)", week.totals.steps, week.averages.steps);
+    ///     if let Some(best_day) = &week.best_day {
+    ///         println!("Best day: {} with {} steps", best_day.date, best_day.summary.steps);
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    async fn get_activity_summary_range<'a>(
+        &'a self,
+        user_id: impl Into<UserId> + Send,
+        start_date: Date,
+        end_date: Date,
+    ) -> Result<ActivityRangeSummary, ActivityError> {
+        let user_id: UserId = user_id.into();
+        let dates = std::iter::successors(Some(start_date), |date| date.next_day())
+            .take_while(|date| *date <= end_date);
+        let days: Vec<(Date, ActivitySummary)> = stream::iter(dates)
+            .map(|date| {
+                self.get_activity_summary(user_id.clone(), date)
+                    .map_ok(move |summary| (date, summary))
+            })
+            .buffered(SUMMARY_RANGE_CONCURRENCY)
+            .try_collect()
+            .await?;
+        Ok(ActivityRangeSummary::from_days(days))
+    }
+
     /// Gets activity time series data
     ///
     /// Retrieves activity time series data for a specific resource over a period.
@@ -68,7 +288,7 @@ impl ActivityClient for FitbitClient {
     ///
     /// * `user_id` - The user ID to get activity time series for, or "-" for current user
     /// * `resource` - The resource type (e.g., steps, calories, distance)
-    /// * `date` - The base date in format YYYY-MM-DD
+    /// * `date` - The base date
     /// * `period` - The period (1d, 7d, 30d, 1w, 1m, 3m, 6m, 1y, max)
     ///
     /// # Returns
@@ -87,6 +307,7 @@ impl ActivityClient for FitbitClient {
     /// ```no_run
     /// use fitbit_sdk::client::FitbitClient;
     /// use fitbit_sdk::types::activity::{ActivityClient, ActivityError, Resource};
+    /// use fitbit_sdk::types::date::FitbitDate;
     /// use tokio;
     ///
     /// #[tokio::main]
@@ -94,10 +315,10 @@ impl ActivityClient for FitbitClient {
     ///     let client = FitbitClient::new::<ActivityError>()?;
     ///
     ///     // Get last 7 days of steps data
-    ///     let steps_data = client.get_activity_time_series("-", Resource::Steps, "today", "7d").await?;
+    ///     let steps_data = client.get_activity_time_series("-", Resource::Steps, FitbitDate::Today, "7d").await?;
     ///     
     ///     for data_point in &steps_data {
-    ///         println!("{}: {} steps", data_point.datetime, data_point.value);
+    ///         println!("{}: {} steps", data_point.date_time, data_point.value);
     ///     }
     ///
     ///     Ok(())
@@ -105,46 +326,177 @@ impl ActivityClient for FitbitClient {
     /// ```
     async fn get_activity_time_series<'a>(
         &'a self,
-        user_id: &'a str,
+        user_id: impl Into<UserId> + Send,
         resource: Resource,
-        date: &'a str,
+        date: impl Into<FitbitDate> + Send,
         period: &'a str,
     ) -> Result<Vec<ActivityTimeSeries>, ActivityError> {
+        self.get_activity_series(user_id.into(), resource, date.into(), period)
+            .await
+    }
+
+    /// Gets activity time series data for a date range
+    ///
+    /// Retrieves one data point per day between two dates for a specific resource.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The user ID to get activity time series for, or "-" for current user
+    /// * `resource` - The resource type (e.g., steps, calories, distance)
+    /// * `start_date` - The first date of the range
+    /// * `end_date` - The last date of the range (at most 1095 days after `start_date`)
+    ///
+    /// # Returns
+    ///
+    /// Returns the activity time series data on success.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `ActivityError` if:
+    /// - The request fails to send
+    /// - The API returns an error response
+    /// - The response cannot be parsed
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use fitbit_sdk::client::FitbitClient;
+    /// use fitbit_sdk::types::activity::{ActivityClient, ActivityError, Resource};
+    /// use time::macros::date;
+    /// use tokio;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), ActivityError> {
+    ///     let client = FitbitClient::new::<ActivityError>()?;
+    ///
+    ///     let distances = client
+    ///         .get_activity_time_series_range(
+    ///             "-",
+    ///             Resource::Distance,
+    ///             date!(2025 - 01 - 01),
+    ///             date!(2025 - 01 - 31),
+    ///         )
+    ///         .await?;
+    ///     let total: f64 = distances.iter().map(|point| point.value).sum();
+    ///     println!("Distance in January: {:.1}", total);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    async fn get_activity_time_series_range<'a>(
+        &'a self,
+        user_id: impl Into<UserId> + Send,
+        resource: Resource,
+        start_date: impl Into<FitbitDate> + Send,
+        end_date: impl Into<FitbitDate> + Send,
+    ) -> Result<Vec<ActivityTimeSeries>, ActivityError> {
+        let end_date: FitbitDate = end_date.into();
+        self.get_activity_series(
+            user_id.into(),
+            resource,
+            start_date.into(),
+            &end_date.to_string(),
+        )
+        .await
+    }
+
+    /// Gets the elevation climbed per day over a period
+    ///
+    /// Reads the `elevation` time series, or `tracker/elevation` for the
+    /// elevation recorded by the tracker only, and tags each value with its
+    /// unit: feet when the response is in US units, meters otherwise.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The user ID to get the elevation for, or "-" for current user
+    /// * `date` - The base date
+    /// * `period` - The period (1d, 7d, 30d, 1w, 1m, 3m, 6m, 1y, max)
+    /// * `tracker_only` - Whether to leave out the elevation of logged activities
+    ///
+    /// # Returns
+    ///
+    /// Returns the elevation of each day on success.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `ActivityError` if:
+    /// - The request fails to send
+    /// - The API returns an error response
+    /// - The response cannot be parsed
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use fitbit_sdk::client::FitbitClient;
+    /// use fitbit_sdk::types::activity::{ActivityClient, ActivityError};
+    /// use fitbit_sdk::types::date::FitbitDate;
+    /// use tokio;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), ActivityError> {
+    ///     let client = FitbitClient::new::<ActivityError>()?;
+    ///
+    ///     let elevation = client
+    ///         .get_elevation_time_series("-", FitbitDate::Today, "7d", false)
+    ///         .await?;
+    ///     for day in &elevation {
+    ///         println!("{}: {:.0} m", day.date, day.to_meters());
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    async fn get_elevation_time_series<'a>(
+        &'a self,
+        user_id: impl Into<UserId> + Send,
+        date: impl Into<FitbitDate> + Send,
+        period: &'a str,
+        tracker_only: bool,
+    ) -> Result<Vec<Elevation>, ActivityError> {
+        let resource = if tracker_only {
+            Resource::Tracker(TrackerResource::Elevation)
+        } else {
+            Resource::Elevation
+        };
         let path = format!(
             "/user/{}/activities/{}/date/{}/{}.json",
-            user_id,
+            user_id.into(),
             resource.as_str(),
-            date,
+            date.into(),
             period
         );
-        let response: serde_json::Value = self.get(&path, Option::<&()>::None).await?;
-        
-        // The response format differs based on resource type
-        let key = format!("activities-{}", resource.as_str());
-        let time_series: Vec<ActivityTimeSeries> = response
-            .get(&key)
-            .ok_or_else(|| ActivityError::from(format!("Missing key '{}' in response", key)))?
-            .as_array()
-            .ok_or_else(|| ActivityError::from("Expected array for time series data".to_string()))?
-            .iter()
-            .map(|item| serde_json::from_value(item.clone()))
-            .collect::<Result<Vec<_>, _>>()
-            .map_err(|e| ActivityError::from(e.to_string()))?;
-            
-        Ok(time_series)
+        let mut response = self
+            .get_raw::<serde_json::Value, ActivityError>(ApiVersion::V1, &path)
+            .await?;
+
+        let unit = ElevationUnit::for_unit_system(response.meta().unit_system());
+        let series: Vec<ActivityTimeSeries> =
+            take_key(&mut response.value, &resource.response_key())?;
+        Ok(series
+            .into_iter()
+            .map(|point| Elevation {
+                date: point.date_time,
+                value: point.value,
+                unit,
+            })
+            .collect())
     }
 
-    /// Gets lifetime activity statistics
+    /// Gets intraday activity time series data
     ///
-    /// Retrieves the lifetime statistics of an active user.
+    /// Retrieves a single day of activity data for a resource at the requested
+    /// granularity. Requires access to the Fitbit Intraday API.
     ///
     /// # Arguments
     ///
-    /// * `user_id` - The user ID to get lifetime stats for, or "-" for current user
+    /// * `user_id` - The user ID to get intraday data for, or "-" for current user
+    /// * `resource` - The resource type (e.g., steps, calories, distance)
+    /// * `date` - The date
+    /// * `detail_level` - The granularity of the data points (1sec is not supported)
     ///
     /// # Returns
     ///
-    /// Returns the lifetime activity statistics on success.
+    /// Returns the daily total and the per-timestamp values on success.
     ///
     /// # Errors
     ///
@@ -157,23 +509,1113 @@ impl ActivityClient for FitbitClient {
     ///
     /// ```no_run
     /// use fitbit_sdk::client::FitbitClient;
-    /// use fitbit_sdk::types::activity::{ActivityClient, ActivityError};
+    /// use fitbit_sdk::types::activity::{ActivityClient, ActivityError, Resource};
+    /// use fitbit_sdk::types::date::FitbitDate;
+    /// use fitbit_sdk::types::intraday::DetailLevel;
     /// use tokio;
     ///
     /// #[tokio::main]
     /// async fn main() -> Result<(), ActivityError> {
     ///     let client = FitbitClient::new::<ActivityError>()?;
     ///
-    ///     // Get lifetime stats
-    ///     let stats = client.get_lifetime_stats("-").await?;
-    ///     println!("Total distance: {}", stats.lifetime.total.distance);
+    ///     // Get today's steps minute by minute
+    ///     let steps = client
+    ///         .get_activity_intraday("-", Resource::Steps, FitbitDate::Today, DetailLevel::OneMinute)
+    ///         .await?;
+    ///     for point in &steps.intraday.dataset {
+    ///         println!("{}: {} steps", point.time, point.value);
+    ///     }
     ///
     ///     Ok(())
     /// }
     /// ```
-    async fn get_lifetime_stats<'a>(&'a self, user_id: &'a str) -> Result<ActivityLifetimeStats, ActivityError> {
-        let path = format!("/user/{}/activities.json", user_id);
-        let response: LifetimeStatsResponse = self.get(&path, Option::<&()>::None).await?;
-        Ok(response.lifetime)
+    async fn get_activity_intraday<'a>(
+        &'a self,
+        user_id: impl Into<UserId> + Send,
+        resource: Resource,
+        date: impl Into<FitbitDate> + Send,
+        detail_level: DetailLevel,
+    ) -> Result<IntradayActivitySeries, ActivityError> {
+        let user_id: UserId = user_id.into();
+        let date: FitbitDate = date.into();
+        self.get_activity_intraday_series(user_id, resource, date, detail_level, None)
+            .await
+    }
+
+    /// Gets intraday activity time series data for a time window
+    ///
+    /// Retrieves activity data for a resource between two times of a single day
+    /// at the requested granularity. Requires access to the Fitbit Intraday API.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The user ID to get intraday data for, or "-" for current user
+    /// * `resource` - The resource type (e.g., steps, calories, distance)
+    /// * `date` - The date
+    /// * `detail_level` - The granularity of the data points (1sec is not supported)
+    /// * `start_time` - The start of the window, to the minute
+    /// * `end_time` - The end of the window, to the minute
+    ///
+    /// Fetching only the window of a workout avoids downloading a full day of
+    /// data points; seconds of `start_time` and `end_time` are ignored.
+    ///
+    /// # Returns
+    ///
+    /// Returns the total for the window and the per-timestamp values on success.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `ActivityError` if:
+    /// - The request fails to send
+    /// - The API returns an error response
+    /// - The response cannot be parsed
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use fitbit_sdk::client::FitbitClient;
+    /// use fitbit_sdk::types::activity::{ActivityClient, ActivityError, Resource};
+    /// use fitbit_sdk::types::date::FitbitDate;
+    /// use fitbit_sdk::types::intraday::DetailLevel;
+    /// use time::macros::time;
+    /// use tokio;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), ActivityError> {
+    ///     let client = FitbitClient::new::<ActivityError>()?;
+    ///
+    ///     let calories = client
+    ///         .get_activity_intraday_window(
+    ///             "-",
+    ///             Resource::Calories,
+    ///             FitbitDate::Today,
+    ///             DetailLevel::FiveMinutes,
+    ///             time!(12:00),
+    ///             time!(13:00),
+    ///         )
+    ///         .await?;
+    ///     println!("{} data points", calories.intraday.dataset.len());
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    async fn get_activity_intraday_window<'a>(
+        &'a self,
+        user_id: impl Into<UserId> + Send,
+        resource: Resource,
+        date: impl Into<FitbitDate> + Send,
+        detail_level: DetailLevel,
+        start_time: Time,
+        end_time: Time,
+    ) -> Result<IntradayActivitySeries, ActivityError> {
+        let user_id: UserId = user_id.into();
+        let date: FitbitDate = date.into();
+        let format = |time: Time| format!("{:02}:{:02}", time.hour(), time.minute());
+        let (start_time, end_time) = (format(start_time), format(end_time));
+        self.get_activity_intraday_series(
+            user_id,
+            resource,
+            date,
+            detail_level,
+            Some((&start_time, &end_time)),
+        )
+        .await
+    }
+
+    /// Streams intraday activity time series data
+    ///
+    /// Retrieves a single day of activity data for a resource like
+    /// `get_activity_intraday`, yielding each data point while the response
+    /// is being received instead of buffering it. The daily total is not
+    /// returned.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The user ID to get intraday data for, or "-" for current user
+    /// * `resource` - The resource type (e.g., steps, calories, distance)
+    /// * `date` - The date
+    /// * `detail_level` - The granularity of the data points (1sec is not supported)
+    ///
+    /// # Returns
+    ///
+    /// Returns a stream of the values at each timestamp.
+    ///
+    /// # Errors
+    ///
+    /// The stream yields an `ActivityError` and ends if:
+    /// - The request fails to send, or the response fails to be received
+    /// - The API returns an error response
+    /// - The response cannot be parsed
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use fitbit_sdk::client::FitbitClient;
+    /// use fitbit_sdk::types::activity::{ActivityClient, ActivityError, Resource};
+    /// use fitbit_sdk::types::intraday::DetailLevel;
+    /// use futures_util::TryStreamExt;
+    /// use time::macros::date;
+    /// use tokio;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), ActivityError> {
+    ///     let client = FitbitClient::new::<ActivityError>()?;
+    ///
+    ///     let mut steps = client.stream_activity_intraday(
+    ///         "-",
+    ///         Resource::Steps,
+    ///         date!(2025 - 01 - 15),
+    ///         DetailLevel::OneMinute,
+    ///     );
+    ///     let mut active_minutes = 0;
+    ///     while let Some(point) = steps.try_next().await? {
+    ///         if point.value > 0.0 {
+    ///             active_minutes += 1;
+    ///         }
+    ///     }
+    ///     println!("{} minutes with steps", active_minutes);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    fn stream_activity_intraday<'a>(
+        &'a self,
+        user_id: impl Into<UserId> + Send,
+        resource: Resource,
+        date: impl Into<FitbitDate> + Send,
+        detail_level: DetailLevel,
+    ) -> BoxStream<'a, Result<IntradayDataPoint<f64>, ActivityError>> {
+        let user_id: UserId = user_id.into();
+        let date: FitbitDate = date.into();
+        let path = format!(
+            "/user/{}/activities/{}/date/{}/{}",
+            user_id,
+            resource.as_str(),
+            date,
+            intraday_path_suffix(detail_level, None)
+        );
+        self.stream_intraday::<f64, ActivityError>(ApiVersion::V1, path)
+            .boxed()
+    }
+
+    /// Gets lifetime activity statistics
+    ///
+    /// Retrieves the lifetime totals and best days of an active user, both
+    /// including manually logged activities and for trackers only.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The user ID to get lifetime stats for, or "-" for current user
+    ///
+    /// # Returns
+    ///
+    /// Returns the best days and lifetime totals on success.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `ActivityError` if:
+    /// - The request fails to send
+    /// - The API returns an error response
+    /// - The response cannot be parsed
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use fitbit_sdk::client::FitbitClient;
+    /// use fitbit_sdk::types::activity::{ActivityClient, ActivityError};
+    /// use tokio;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), ActivityError> {
+    ///     let client = FitbitClient::new::<ActivityError>()?;
+    ///
+    ///     // Get lifetime stats
+    ///     let stats = client.get_lifetime_stats("-").await?;
+    ///     println!("Total distance: {}", stats.lifetime.total.distance);
+    ///     println!("Best day: {} steps", stats.best.total.steps.value);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    async fn get_lifetime_stats<'a>(&'a self, user_id: impl Into<UserId> + Send) -> Result<ActivityLifetimeStats, ActivityError> {
+        let user_id: UserId = user_id.into();
+        let path = format!("/user/{}/activities.json", user_id);
+        self.get::<_, (), ActivityError>(&path, None).await
+    }
+
+    /// Gets lifetime statistics broken down by source
+    ///
+    /// Retrieves the same data as `get_lifetime_stats`, grouped into the
+    /// figures including manually logged activities and those recorded by the
+    /// user's devices only.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The user ID to get lifetime stats for, or "-" for current user
+    ///
+    /// # Returns
+    ///
+    /// Returns the lifetime totals and best days of each source on success.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `ActivityError` if:
+    /// - The request fails to send
+    /// - The API returns an error response
+    /// - The response cannot be parsed
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use fitbit_sdk::client::FitbitClient;
+    /// use fitbit_sdk::types::activity::{ActivityClient, ActivityError};
+    /// use tokio;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), ActivityError> {
+    ///     let client = FitbitClient::new::<ActivityError>()?;
+    ///
+    ///     let stats = client.get_lifetime_stats_detailed("-").await?;
+    ///     println!("Tracked steps: {}", stats.tracker.steps);
+    ///     println!("Logged steps: {}", stats.manual_steps());
+    ///     println!("Best tracked day: {} steps", stats.tracker.best_steps.value);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    async fn get_lifetime_stats_detailed<'a>(
+        &'a self,
+        user_id: impl Into<UserId> + Send,
+    ) -> Result<DetailedLifetimeStats, ActivityError> {
+        let stats = self.get_lifetime_stats(user_id).await?;
+        Ok(DetailedLifetimeStats::from_stats(&stats))
+    }
+
+    /// Logs an activity
+    ///
+    /// Creates a manual activity entry, either for an activity from the
+    /// activity catalog or for a custom activity.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The user ID to log the activity for, or "-" for current user
+    /// * `params` - The activity to log
+    ///
+    /// # Returns
+    ///
+    /// Returns the created activity log entry on success.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `ActivityError` if:
+    /// - The request fails to send
+    /// - The API returns an error response
+    /// - The response cannot be parsed
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use fitbit_sdk::client::FitbitClient;
+    /// use fitbit_sdk::types::activity::{ActivityClient, ActivityError, LogActivityParams};
+    /// use tokio;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), ActivityError> {
+    ///     let client = FitbitClient::new::<ActivityError>()?;
+    ///
+    ///     // Log a 30 minute run of 5 km
+    ///     let params = LogActivityParams::for_activity(90009, "2025-01-15", "07:30", 30 * 60 * 1000)
+    ///         .with_distance(5.0)
+    ///         .with_distance_unit("Kilometer");
+    ///     let log = client.log_activity("-", &params).await?;
+    ///     println!("Logged {} ({} calories)", log.name, log.calories);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    async fn log_activity<'a>(
+        &'a self,
+        user_id: impl Into<UserId> + Send,
+        params: &'a LogActivityParams,
+    ) -> Result<ActivityLog, ActivityError> {
+        let user_id: UserId = user_id.into();
+        let path = format!("/user/{}/activities.json", user_id);
+        let response: LogActivityResponse = self
+            .send_request::<_, (), _, ActivityError>(Method::POST, &path, None, Some(Body::Form(params)))
+            .await?;
+        Ok(response.activity_log)
+    }
+
+    /// Logs an activity from the activity catalog for the current user
+    ///
+    /// A shorthand for `log_activity` covering the common case: the calories
+    /// are estimated by Fitbit from the activity and the user's profile, and
+    /// no distance is recorded. Use `log_activity` to set either.
+    ///
+    /// # Arguments
+    ///
+    /// * `activity_id` - The ID of the activity from the activity catalog,
+    ///   e.g. one of `get_frequent_activities`
+    /// * `date` - The day of the activity
+    /// * `start_time` - The start of the activity, to the minute
+    /// * `duration` - The duration of the activity
+    ///
+    /// # Returns
+    ///
+    /// Returns the created activity log entry on success.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `ActivityError` if:
+    /// - The request fails to send
+    /// - The API returns an error response, e.g. for an unknown activity
+    /// - The response cannot be parsed
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use fitbit_sdk::client::FitbitClient;
+    /// use fitbit_sdk::types::activity::{ActivityClient, ActivityError};
+    /// use std::time::Duration;
+    /// use time::macros::{date, time};
+    /// use tokio;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), ActivityError> {
+    ///     let client = FitbitClient::new::<ActivityError>()?;
+    ///
+    ///     // Log a 30 minute run
+    ///     let log = client
+    ///         .quick_log_activity(90009, date!(2025 - 01 - 15), time!(07:30), Duration::from_secs(30 * 60))
+    ///         .await?;
+    ///     println!("Logged {} ({} calories)", log.name, log.calories);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    async fn quick_log_activity<'a>(
+        &'a self,
+        activity_id: i64,
+        date: Date,
+        start_time: Time,
+        duration: Duration,
+    ) -> Result<ActivityLog, ActivityError> {
+        let params = LogActivityParams::for_activity(
+            activity_id,
+            date.to_string(),
+            format!("{:02}:{:02}", start_time.hour(), start_time.minute()),
+            i64::try_from(duration.as_millis()).unwrap_or(i64::MAX),
+        );
+        self.log_activity("-", &params).await
+    }
+
+    /// Deletes an activity log entry
+    ///
+    /// Removes a logged activity from the user's activity log.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The user ID to delete the entry for, or "-" for current user
+    /// * `log_id` - The ID of the activity log entry
+    ///
+    /// # Returns
+    ///
+    /// Returns `()` on success.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `ActivityError` if:
+    /// - The request fails to send
+    /// - The API returns an error response
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use fitbit_sdk::client::FitbitClient;
+    /// use fitbit_sdk::types::activity::{ActivityClient, ActivityError};
+    /// use tokio;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), ActivityError> {
+    ///     let client = FitbitClient::new::<ActivityError>()?;
+    ///
+    ///     client.delete_activity_log("-", 123456789).await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    async fn delete_activity_log<'a>(&'a self, user_id: impl Into<UserId> + Send, log_id: i64) -> Result<(), ActivityError> {
+        let user_id: UserId = user_id.into();
+        let path = format!("/user/{}/activities/{}.json", user_id, log_id);
+        self.delete::<_, (), ActivityError>(&path, None).await
+    }
+
+    /// Gets a page of the activity log list
+    ///
+    /// Retrieves the user's logged, tracked and automatically detected
+    /// activities before or after a date, one page at a time.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The user ID to get activities for, or "-" for current user
+    /// * `params` - The date, sort order and page to list
+    ///
+    /// # Returns
+    ///
+    /// Returns the activity log entries and pagination information on success.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `ActivityError` if:
+    /// - The parameters are invalid, see `ListParams::validate`
+    /// - The request fails to send
+    /// - The API returns an error response
+    /// - The response cannot be parsed
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use fitbit_sdk::client::FitbitClient;
+    /// use fitbit_sdk::types::activity::{ActivityClient, ActivityError};
+    /// use fitbit_sdk::types::pagination::ListParams;
+    /// use tokio;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), ActivityError> {
+    ///     let client = FitbitClient::new::<ActivityError>()?;
+    ///
+    ///     // The 20 most recent activities
+    ///     let params = ListParams::before("today").with_limit(20);
+    ///     let page = client.get_activity_log_list("-", &params).await?;
+    ///     for activity in &page.activities {
+    ///         println!("{}: {} calories", activity.activity_name, activity.calories);
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    async fn get_activity_log_list<'a>(
+        &'a self,
+        user_id: impl Into<UserId> + Send,
+        params: &'a ListParams,
+    ) -> Result<ActivityLogList, ActivityError> {
+        params.validate().map_err(|e| ActivityError(FitbitError::RequestFailed(e)))?;
+        let user_id: UserId = user_id.into();
+        let path = format!("/user/{}/activities/list.json", user_id);
+        self.get::<_, _, ActivityError>(&path, Some(params)).await
+    }
+
+    /// Gets all activity log entries before or after a date
+    ///
+    /// Collects the entries of `stream_activity_log_list`, following the
+    /// `next` links until the last page.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The user ID to get activities for, or "-" for current user
+    /// * `params` - The date, sort order and page size to list
+    ///
+    /// # Returns
+    ///
+    /// Returns the activity log entries of all pages on success.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `ActivityError` if:
+    /// - The parameters are invalid, see `ListParams::validate`
+    /// - Any request fails to send
+    /// - The API returns an error response
+    /// - A response cannot be parsed
+    /// - There are more than `params.max_pages` pages
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use fitbit_sdk::client::FitbitClient;
+    /// use fitbit_sdk::types::activity::{ActivityClient, ActivityError};
+    /// use fitbit_sdk::types::pagination::ListParams;
+    /// use tokio;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), ActivityError> {
+    ///     let client = FitbitClient::new::<ActivityError>()?;
+    ///
+    ///     let activities = client
+    ///         .get_all_activity_log_list("-", &ListParams::after("2025-01-01"))
+    ///         .await?;
+    ///     println!("{} activities since January", activities.len());
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    async fn get_all_activity_log_list<'a>(
+        &'a self,
+        user_id: impl Into<UserId> + Send,
+        params: &'a ListParams,
+    ) -> Result<Vec<ActivityLogEntry>, ActivityError> {
+        self.stream_activity_log_list(user_id, params).try_collect().await
+    }
+
+    /// Streams the activity log entries before or after a date
+    ///
+    /// Fetches the first page like `get_activity_log_list` and follows the
+    /// `next` links, fetching each page only once the entries of the previous
+    /// page have been consumed.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The user ID to get activities for, or "-" for current user
+    /// * `params` - The date, sort order, page size and page limit to list
+    ///
+    /// # Returns
+    ///
+    /// Returns a stream of the activity log entries of all pages.
+    ///
+    /// # Errors
+    ///
+    /// The stream yields an `ActivityError` and ends if:
+    /// - The parameters are invalid, see `ListParams::validate`
+    /// - A request fails to send
+    /// - The API returns an error response
+    /// - A response cannot be parsed
+    /// - There are more than `params.max_pages` pages
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use fitbit_sdk::client::FitbitClient;
+    /// use fitbit_sdk::types::activity::{ActivityClient, ActivityError};
+    /// use fitbit_sdk::types::pagination::ListParams;
+    /// use futures_util::TryStreamExt;
+    /// use tokio;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), ActivityError> {
+    ///     let client = FitbitClient::new::<ActivityError>()?;
+    ///
+    ///     let params = ListParams::before("today").with_max_pages(5);
+    ///     let mut activities = client.stream_activity_log_list("-", &params);
+    ///     while let Some(activity) = activities.try_next().await? {
+    ///         if activity.tcx_link.is_some() {
+    ///             println!("Last GPS activity: {}", activity.activity_name);
+    ///             break;
+    ///         }
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    fn stream_activity_log_list<'a>(
+        &'a self,
+        user_id: impl Into<UserId> + Send,
+        params: &'a ListParams,
+    ) -> BoxStream<'a, Result<ActivityLogEntry, ActivityError>> {
+        if let Err(error) = params.validate() {
+            return stream::once(async { Err(ActivityError(FitbitError::RequestFailed(error))) }).boxed();
+        }
+        let user_id: UserId = user_id.into();
+        let path = format!("/user/{}/activities/list.json", user_id);
+        self.paginate::<ActivityLogList, ActivityLogEntry, _, ActivityError>(
+            ApiVersion::V1,
+            path,
+            params,
+            params.max_pages,
+        )
+        .boxed()
+    }
+
+    /// Gets the TCX export of a logged activity
+    ///
+    /// Retrieves the Training Center XML (TCX) of an activity recorded with GPS
+    /// or heart rate data, e.g. for import into other training tools.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The user ID the activity belongs to, or "-" for current user
+    /// * `log_id` - The ID of the activity log entry
+    ///
+    /// # Returns
+    ///
+    /// Returns the raw TCX document on success.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `ActivityError` if:
+    /// - The request fails to send
+    /// - The API returns an error response
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use fitbit_sdk::client::FitbitClient;
+    /// use fitbit_sdk::types::activity::{ActivityClient, ActivityError};
+    /// use tokio;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), ActivityError> {
+    ///     let client = FitbitClient::new::<ActivityError>()?;
+    ///
+    ///     let tcx = client.get_activity_tcx("-", 123456789).await?;
+    ///     std::fs::write("run.tcx", tcx).expect("failed to write TCX file");
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    async fn get_activity_tcx<'a>(&'a self, user_id: impl Into<UserId> + Send, log_id: i64) -> Result<String, ActivityError> {
+        let user_id: UserId = user_id.into();
+        let path = format!("/user/{}/activities/{}.tcx", user_id, log_id);
+        self.get_text::<ActivityError>(ApiVersion::V1, &path).await
+    }
+
+    /// Gets the parsed TCX export of a logged activity
+    ///
+    /// Retrieves the TCX of an activity and parses it into a [`Tcx`]. Requires
+    /// the `tcx` feature.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The user ID the activity belongs to, or "-" for current user
+    /// * `log_id` - The ID of the activity log entry
+    ///
+    /// # Returns
+    ///
+    /// Returns the parsed TCX document on success.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `ActivityError` if:
+    /// - The request fails to send
+    /// - The API returns an error response
+    /// - The response cannot be parsed
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use fitbit_sdk::client::FitbitClient;
+    /// use fitbit_sdk::types::activity::{ActivityClient, ActivityError};
+    /// use tokio;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), ActivityError> {
+    ///     let client = FitbitClient::new::<ActivityError>()?;
+    ///
+    ///     let tcx = client.get_activity_tcx_parsed("-", 123456789).await?;
+    ///     for activity in &tcx.activities.activity {
+    ///         println!("{}: {} laps", activity.sport, activity.laps.len());
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    #[cfg(feature = "tcx")]
+    async fn get_activity_tcx_parsed<'a>(&'a self, user_id: impl Into<UserId> + Send, log_id: i64) -> Result<Tcx, ActivityError> {
+        let user_id: UserId = user_id.into();
+        let xml = self.get_activity_tcx(user_id, log_id).await?;
+        xml.parse::<Tcx>().map_err(ActivityError::from)
+    }
+
+    /// Gets activity goals
+    ///
+    /// Retrieves the user's daily or weekly activity goals.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The user ID to get goals for, or "-" for current user
+    /// * `period` - Whether to get the daily or weekly goals
+    ///
+    /// # Returns
+    ///
+    /// Returns the activity goals on success.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `ActivityError` if:
+    /// - The request fails to send
+    /// - The API returns an error response
+    /// - The response cannot be parsed
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use fitbit_sdk::client::FitbitClient;
+    /// use fitbit_sdk::types::activity::{ActivityClient, ActivityError, GoalPeriod};
+    /// use tokio;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), ActivityError> {
+    ///     let client = FitbitClient::new::<ActivityError>()?;
+    ///
+    ///     let goals = client.get_activity_goals("-", GoalPeriod::Daily).await?;
+    ///     println!("Daily steps goal: {:?}", goals.steps);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    async fn get_activity_goals<'a>(
+        &'a self,
+        user_id: impl Into<UserId> + Send,
+        period: GoalPeriod,
+    ) -> Result<ActivityGoals, ActivityError> {
+        let user_id: UserId = user_id.into();
+        let path = format!("/user/{}/activities/goals/{}.json", user_id, period.as_str());
+        let response: ActivityGoalsResponse = self.get::<_, (), ActivityError>(&path, None).await?;
+        Ok(response.goals)
+    }
+
+    /// Updates activity goals
+    ///
+    /// Creates or updates the user's daily or weekly activity goals. Goals that
+    /// are not set in `params` are left unchanged.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The user ID to update goals for, or "-" for current user
+    /// * `period` - Whether to update the daily or weekly goals
+    /// * `params` - The goals to update
+    ///
+    /// # Returns
+    ///
+    /// Returns the updated activity goals on success.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `ActivityError` if:
+    /// - The request fails to send
+    /// - The API returns an error response
+    /// - The response cannot be parsed
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use fitbit_sdk::client::FitbitClient;
+    /// use fitbit_sdk::types::activity::{
+    ///     ActivityClient, ActivityError, ActivityGoalsParams, GoalPeriod,
+    /// };
+    /// use tokio;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), ActivityError> {
+    ///     let client = FitbitClient::new::<ActivityError>()?;
+    ///
+    ///     let params = ActivityGoalsParams::new()
+    ///         .with_steps(12000)
+    ///         .with_floors(15);
+    ///     let goals = client
+    ///         .update_activity_goals("-", GoalPeriod::Daily, &params)
+    ///         .await?;
+    ///     println!("New daily steps goal: {:?}", goals.steps);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    async fn update_activity_goals<'a>(
+        &'a self,
+        user_id: impl Into<UserId> + Send,
+        period: GoalPeriod,
+        params: &'a ActivityGoalsParams,
+    ) -> Result<ActivityGoals, ActivityError> {
+        let user_id: UserId = user_id.into();
+        let path = format!("/user/{}/activities/goals/{}.json", user_id, period.as_str());
+        let response: ActivityGoalsResponse = self
+            .send_request::<_, (), _, ActivityError>(Method::POST, &path, None, Some(Body::Form(params)))
+            .await?;
+        Ok(response.goals)
+    }
+
+    /// Gets favorite activities
+    ///
+    /// Retrieves the activities the user marked as favorites.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The user ID to get favorite activities for, or "-" for current user
+    ///
+    /// # Returns
+    ///
+    /// Returns the favorite activities on success.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `ActivityError` if:
+    /// - The request fails to send
+    /// - The API returns an error response
+    /// - The response cannot be parsed
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use fitbit_sdk::client::FitbitClient;
+    /// use fitbit_sdk::types::activity::{ActivityClient, ActivityError};
+    /// use tokio;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), ActivityError> {
+    ///     let client = FitbitClient::new::<ActivityError>()?;
+    ///
+    ///     for activity in client.get_favorite_activities("-").await? {
+    ///         println!("{}: {}", activity.activity_id, activity.name);
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    async fn get_favorite_activities<'a>(&'a self, user_id: impl Into<UserId> + Send) -> Result<Vec<FavoriteActivity>, ActivityError> {
+        let user_id: UserId = user_id.into();
+        let path = format!("/user/{}/activities/favorite.json", user_id);
+        self.get::<_, (), ActivityError>(&path, None).await
+    }
+
+    /// Gets frequent activities
+    ///
+    /// Retrieves the activities the user logs most often.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The user ID to get frequent activities for, or "-" for current user
+    ///
+    /// # Returns
+    ///
+    /// Returns the frequent activities on success.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `ActivityError` if:
+    /// - The request fails to send
+    /// - The API returns an error response
+    /// - The response cannot be parsed
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use fitbit_sdk::client::FitbitClient;
+    /// use fitbit_sdk::types::activity::{ActivityClient, ActivityError};
+    /// use tokio;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), ActivityError> {
+    ///     let client = FitbitClient::new::<ActivityError>()?;
+    ///
+    ///     for activity in client.get_frequent_activities("-").await? {
+    ///         println!("{}: {}", activity.activity_id, activity.name);
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    async fn get_frequent_activities<'a>(&'a self, user_id: impl Into<UserId> + Send) -> Result<Vec<FrequentActivity>, ActivityError> {
+        let user_id: UserId = user_id.into();
+        let path = format!("/user/{}/activities/frequent.json", user_id);
+        self.get::<_, (), ActivityError>(&path, None).await
+    }
+
+    /// Gets recent activities
+    ///
+    /// Retrieves the activities the user logged most recently.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The user ID to get recent activities for, or "-" for current user
+    ///
+    /// # Returns
+    ///
+    /// Returns the recent activities on success.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `ActivityError` if:
+    /// - The request fails to send
+    /// - The API returns an error response
+    /// - The response cannot be parsed
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use fitbit_sdk::client::FitbitClient;
+    /// use fitbit_sdk::types::activity::{ActivityClient, ActivityError};
+    /// use tokio;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), ActivityError> {
+    ///     let client = FitbitClient::new::<ActivityError>()?;
+    ///
+    ///     for activity in client.get_recent_activities("-").await? {
+    ///         println!("{}: {}", activity.activity_id, activity.name);
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    async fn get_recent_activities<'a>(&'a self, user_id: impl Into<UserId> + Send) -> Result<Vec<FrequentActivity>, ActivityError> {
+        let user_id: UserId = user_id.into();
+        let path = format!("/user/{}/activities/recent.json", user_id);
+        self.get::<_, (), ActivityError>(&path, None).await
+    }
+
+    /// Adds a favorite activity
+    ///
+    /// Marks an activity from the activity catalog as one of the user's favorites.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The user ID to add the favorite for, or "-" for current user
+    /// * `activity_id` - The ID of the activity
+    ///
+    /// # Returns
+    ///
+    /// Returns `()` on success.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `ActivityError` if:
+    /// - The request fails to send
+    /// - The API returns an error response
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use fitbit_sdk::client::FitbitClient;
+    /// use fitbit_sdk::types::activity::{ActivityClient, ActivityError};
+    /// use tokio;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), ActivityError> {
+    ///     let client = FitbitClient::new::<ActivityError>()?;
+    ///
+    ///     // Running
+    ///     client.add_favorite_activity("-", 90009).await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    async fn add_favorite_activity<'a>(&'a self, user_id: impl Into<UserId> + Send, activity_id: i64) -> Result<(), ActivityError> {
+        let user_id: UserId = user_id.into();
+        let path = format!("/user/{}/activities/favorite/{}.json", user_id, activity_id);
+        self.post::<_, (), ActivityError>(&path, None).await
+    }
+
+    /// Deletes a favorite activity
+    ///
+    /// Removes an activity from the user's favorites.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The user ID to remove the favorite for, or "-" for current user
+    /// * `activity_id` - The ID of the activity
+    ///
+    /// # Returns
+    ///
+    /// Returns `()` on success.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `ActivityError` if:
+    /// - The request fails to send
+    /// - The API returns an error response
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use fitbit_sdk::client::FitbitClient;
+    /// use fitbit_sdk::types::activity::{ActivityClient, ActivityError};
+    /// use tokio;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), ActivityError> {
+    ///     let client = FitbitClient::new::<ActivityError>()?;
+    ///
+    ///     client.delete_favorite_activity("-", 90009).await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    async fn delete_favorite_activity<'a>(&'a self, user_id: impl Into<UserId> + Send, activity_id: i64) -> Result<(), ActivityError> {
+        let user_id: UserId = user_id.into();
+        let path = format!("/user/{}/activities/favorite/{}.json", user_id, activity_id);
+        self.delete::<_, (), ActivityError>(&path, None).await
+    }
+
+    /// Browses the activity catalog
+    ///
+    /// Retrieves the public catalog of activities, grouped into categories and
+    /// sub-categories. The catalog does not depend on the user.
+    ///
+    /// # Returns
+    ///
+    /// Returns the top-level activity categories on success.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `ActivityError` if:
+    /// - The request fails to send
+    /// - The API returns an error response
+    /// - The response cannot be parsed
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use fitbit_sdk::client::FitbitClient;
+    /// use fitbit_sdk::types::activity::{ActivityClient, ActivityError};
+    /// use tokio;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), ActivityError> {
+    ///     let client = FitbitClient::new::<ActivityError>()?;
+    ///
+    ///     let categories = client.browse_activity_types().await?;
+    ///     if let Some(run) = categories.iter().find_map(|c| c.find_activity("Run")) {
+    ///         println!("Run has activity ID {}", run.id);
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    async fn browse_activity_types<'a>(&'a self) -> Result<Vec<ActivityCategory>, ActivityError> {
+        let response: ActivityCatalogResponse =
+            self.get::<_, (), ActivityError>("/activities.json", None).await?;
+        Ok(response.categories)
+    }
+
+    /// Gets an activity of the activity catalog
+    ///
+    /// Retrieves the details of a single activity, including its levels.
+    ///
+    /// # Arguments
+    ///
+    /// * `activity_id` - The ID of the activity
+    ///
+    /// # Returns
+    ///
+    /// Returns the activity on success.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `ActivityError` if:
+    /// - The request fails to send
+    /// - The API returns an error response
+    /// - The response cannot be parsed
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use fitbit_sdk::client::FitbitClient;
+    /// use fitbit_sdk::types::activity::{ActivityClient, ActivityError};
+    /// use tokio;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), ActivityError> {
+    ///     let client = FitbitClient::new::<ActivityError>()?;
+    ///
+    ///     let activity = client.get_activity_type(90009).await?;
+    ///     for level in &activity.activity_levels {
+    ///         println!("{}: {} METs", level.name, level.mets);
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    async fn get_activity_type<'a>(&'a self, activity_id: i64) -> Result<ActivityType, ActivityError> {
+        let path = format!("/activities/{}.json", activity_id);
+        let response: ActivityTypeResponse = self.get::<_, (), ActivityError>(&path, None).await?;
+        Ok(response.activity)
     }
 }
@@ -0,0 +1,159 @@
+//! Goal Progress Analysis
+//!
+//! This module contains functions matching the daily activity goals of a user
+//! with the activity summary of a day, to report the progress towards each goal.
+
+use crate::types::activity::{ActivityGoals, ActivitySummary, DailyActivityResponse};
+use std::fmt;
+
+/// Metric of a daily activity goal
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GoalMetric {
+    /// Steps taken
+    Steps,
+    /// Distance covered, in the user's distance unit
+    Distance,
+    /// Floors climbed
+    Floors,
+    /// Calories burned
+    CaloriesOut,
+    /// Minutes of moderate or intense activity
+    ActiveMinutes,
+    /// Active Zone Minutes
+    ActiveZoneMinutes,
+}
+
+impl GoalMetric {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            GoalMetric::Steps => "steps",
+            GoalMetric::Distance => "distance",
+            GoalMetric::Floors => "floors",
+            GoalMetric::CaloriesOut => "caloriesOut",
+            GoalMetric::ActiveMinutes => "activeMinutes",
+            GoalMetric::ActiveZoneMinutes => "activeZoneMinutes",
+        }
+    }
+}
+
+impl fmt::Display for GoalMetric {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Progress towards a daily activity goal
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GoalProgress {
+    /// Metric of the goal
+    pub metric: GoalMetric,
+    /// Value to reach
+    pub target: f64,
+    /// Value reached
+    pub actual: f64,
+    /// Percentage of the target reached, above 100 once the goal is exceeded
+    pub pct: f64,
+}
+
+impl GoalProgress {
+    /// Returns true if the target has been reached
+    pub fn is_met(&self) -> bool {
+        self.actual >= self.target
+    }
+}
+
+/// Computes the progress towards each goal from the summary of the day
+///
+/// Goals are returned in the order of `GoalMetric`. A goal is left out when
+/// it is not set, its target is not positive, or the summary does not report
+/// the metric, e.g. floors for a tracker without an altimeter.
+///
+/// # Examples
+///
+/// ```
+/// use fitbit_sdk::analysis::goals::{GoalMetric, goal_progress};
+/// use fitbit_sdk::types::activity::{ActivityGoals, ActivitySummary};
+///
+/// let goals: ActivityGoals = serde_json::from_value(serde_json::json!({
+///     "steps": 10000,
+///     "floors": 10,
+///     "caloriesOut": 2500
+/// }))
+/// .unwrap();
+/// let summary: ActivitySummary = serde_json::from_value(serde_json::json!({
+///     "steps": 7500,
+///     "distances": [],
+///     "caloriesOut": 2600,
+///     "sedentaryMinutes": 600,
+///     "lightlyActiveMinutes": 200,
+///     "fairlyActiveMinutes": 20,
+///     "veryActiveMinutes": 15
+/// }))
+/// .unwrap();
+///
+/// // No floors are reported, so the floors goal is left out
+/// let progress = goal_progress(&goals, &summary);
+/// assert_eq!(progress.len(), 2);
+/// assert_eq!(progress[0].metric, GoalMetric::Steps);
+/// assert_eq!(progress[0].pct, 75.0);
+/// assert!(!progress[0].is_met());
+/// assert_eq!(progress[1].metric, GoalMetric::CaloriesOut);
+/// assert!(progress[1].is_met());
+/// ```
+pub fn goal_progress(goals: &ActivityGoals, summary: &ActivitySummary) -> Vec<GoalProgress> {
+    let metrics = [
+        (
+            GoalMetric::Steps,
+            goals.steps.map(f64::from),
+            Some(f64::from(summary.steps)),
+        ),
+        (GoalMetric::Distance, goals.distance, summary.total_distance()),
+        (
+            GoalMetric::Floors,
+            goals.floors.map(f64::from),
+            summary.floors.map(f64::from),
+        ),
+        (
+            GoalMetric::CaloriesOut,
+            goals.calories_out.map(f64::from),
+            Some(f64::from(summary.calories)),
+        ),
+        (
+            GoalMetric::ActiveMinutes,
+            goals.active_minutes.map(f64::from),
+            Some(f64::from(summary.active_minutes())),
+        ),
+        (
+            GoalMetric::ActiveZoneMinutes,
+            goals.active_zone_minutes.map(f64::from),
+            summary
+                .active_zone_minutes
+                .as_ref()
+                .map(|azm| f64::from(azm.total_minutes)),
+        ),
+    ];
+
+    metrics
+        .into_iter()
+        .filter_map(|(metric, target, actual)| {
+            let (target, actual) = (target.filter(|target| *target > 0.0)?, actual?);
+            Some(GoalProgress {
+                metric,
+                target,
+                actual,
+                pct: actual / target * 100.0,
+            })
+        })
+        .collect()
+}
+
+/// Computes the progress towards each goal of a daily activity response
+///
+/// Returns no progress when the response has no goals, which is the case for
+/// users other than the authenticated one.
+pub fn daily_goal_progress(day: &DailyActivityResponse) -> Vec<GoalProgress> {
+    day.goals
+        .as_ref()
+        .map(|goals| goal_progress(goals, &day.summary))
+        .unwrap_or_default()
+}
@@ -0,0 +1,327 @@
+//! Heart Rate Analysis
+//!
+//! This module contains functions aggregating the heart rate of a date range:
+//! the time spent in each heart rate zone and the weekly cardio load.
+//!
+//! `time_in_zones` and `weekly_cardio_load` sum the daily time series, which
+//! only report the default heart rate zones (Out of Range, Fat Burn, Cardio,
+//! Peak). `intraday_time_in_zones` reconstructs the time in zone from the
+//! intraday samples instead, for the default zones, the user's custom zones
+//! or any given zones; `HeartRateClient::get_heart_rate_time_in_zones`
+//! fetches the samples of a date range and passes them to it.
+
+use crate::types::datetime::ApiField;
+use crate::types::heart_rate::{
+    DailyZoneTime, HeartRateTimeSeries, HeartRateZones, IntradayHeartRateSeries, IntradayZoneTime,
+    ZoneSelection, ZoneTime,
+};
+use crate::types::intraday::IntradayDataset;
+use time::{Date, Duration, Time};
+
+/// Seconds in a day, the end of the last sample of a day
+const SECONDS_PER_DAY: i64 = 86_400;
+
+/// Cardio load of a week
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WeeklyCardioLoad {
+    /// Monday starting the week
+    pub week_start: Date,
+    /// Minutes in the Fat Burn zone, plus twice the minutes in the Cardio and Peak zones
+    pub load: i64,
+    /// Number of days of the week with heart rate data
+    pub days: u32,
+}
+
+/// Returns how much a minute in the zone named `name` adds to the cardio load
+///
+/// The weights are those of Active Zone Minutes.
+fn zone_weight(name: &str) -> i64 {
+    match name {
+        "Fat Burn" => 1,
+        "Cardio" | "Peak" => 2,
+        _ => 0,
+    }
+}
+
+/// Returns the cardio load of a single day
+fn daily_cardio_load(day: &HeartRateTimeSeries) -> i64 {
+    day.value
+        .heart_rate_zones
+        .iter()
+        .map(|zone| zone_weight(&zone.name) * i64::from(zone.minutes.unwrap_or_default()))
+        .sum()
+}
+
+/// Sums the minutes and calories spent in each heart rate zone
+///
+/// Zones are returned in the order the API lists them, from the lowest to the
+/// highest intensity.
+///
+/// # Examples
+///
+/// ```
+/// use fitbit_sdk::analysis::heart::time_in_zones;
+/// use fitbit_sdk::types::heart_rate::HeartRateTimeSeries;
+///
+/// let day = |date: &str, cardio_minutes: i32| -> HeartRateTimeSeries {
+///     serde_json::from_value(serde_json::json!({
+///         "dateTime": date,
+///         "value": {
+///             "heartRateZones": [
+///                 {"name": "Fat Burn", "min": 97, "max": 136, "minutes": 40, "caloriesOut": 300.0},
+///                 {"name": "Cardio", "min": 136, "max": 165, "minutes": cardio_minutes, "caloriesOut": 150.0}
+///             ]
+///         }
+///     }))
+///     .unwrap()
+/// };
+///
+/// let zones = time_in_zones(&[day("2025-01-14", 10), day("2025-01-15", 25)]);
+/// assert_eq!(zones[0].name, "Fat Burn");
+/// assert_eq!(zones[0].minutes, 80.0);
+/// assert_eq!(zones[1].minutes, 35.0);
+/// assert_eq!(zones[1].calories_out, Some(300.0));
+/// ```
+pub fn time_in_zones(series: &[HeartRateTimeSeries]) -> Vec<ZoneTime> {
+    let mut zones: Vec<ZoneTime> = Vec::new();
+    for zone in series.iter().flat_map(|day| &day.value.heart_rate_zones) {
+        let index = match zones.iter().position(|total| total.name == zone.name) {
+            Some(index) => index,
+            None => {
+                zones.push(ZoneTime {
+                    name: zone.name.clone(),
+                    minutes: 0.0,
+                    calories_out: Some(0.0),
+                });
+                zones.len() - 1
+            }
+        };
+        zones[index].minutes += f64::from(zone.minutes.unwrap_or_default());
+        if let Some(calories_out) = &mut zones[index].calories_out {
+            *calories_out += zone.calories_out.unwrap_or_default();
+        }
+    }
+    zones
+}
+
+/// Computes the cardio load of each week, from Monday to Sunday
+///
+/// The cardio load weighs the minutes spent in each zone like Active Zone
+/// Minutes: one point per minute in the Fat Burn zone and two per minute in
+/// the Cardio and Peak zones. Weeks are returned in date order; days with an
+/// unparseable date are skipped.
+///
+/// # Examples
+///
+/// ```
+/// use fitbit_sdk::analysis::heart::weekly_cardio_load;
+/// use fitbit_sdk::types::heart_rate::HeartRateTimeSeries;
+/// use time::macros::date;
+///
+/// let day = |date: &str| -> HeartRateTimeSeries {
+///     serde_json::from_value(serde_json::json!({
+///         "dateTime": date,
+///         "value": {
+///             "heartRateZones": [
+///                 {"name": "Out of Range", "min": 30, "max": 97, "minutes": 1200},
+///                 {"name": "Fat Burn", "min": 97, "max": 136, "minutes": 30},
+///                 {"name": "Cardio", "min": 136, "max": 165, "minutes": 10},
+///                 {"name": "Peak", "min": 165, "max": 220, "minutes": 5}
+///             ]
+///         }
+///     }))
+///     .unwrap()
+/// };
+///
+/// // Sunday, then Monday and Tuesday of the following week
+/// let weeks = weekly_cardio_load(&[day("2025-01-12"), day("2025-01-13"), day("2025-01-14")]);
+/// assert_eq!(weeks.len(), 2);
+/// assert_eq!(weeks[0].week_start, date!(2025 - 01 - 06));
+/// assert_eq!(weeks[0].load, 60);
+/// assert_eq!(weeks[1].week_start, date!(2025 - 01 - 13));
+/// assert_eq!(weeks[1].load, 120);
+/// assert_eq!(weeks[1].days, 2);
+/// ```
+pub fn weekly_cardio_load(series: &[HeartRateTimeSeries]) -> Vec<WeeklyCardioLoad> {
+    let mut weeks: Vec<WeeklyCardioLoad> = Vec::new();
+    let mut days: Vec<(Date, i64)> = series
+        .iter()
+        .filter_map(|day| {
+            let date: Date = day.date_time.convert().ok()?;
+            Some((date, daily_cardio_load(day)))
+        })
+        .collect();
+    days.sort_by_key(|(date, _)| *date);
+
+    for (date, load) in days {
+        let weekday = date.weekday().number_days_from_monday();
+        let week_start = date - Duration::days(i64::from(weekday));
+        match weeks.last_mut() {
+            Some(week) if week.week_start == week_start => {
+                week.load += load;
+                week.days += 1;
+            }
+            _ => weeks.push(WeeklyCardioLoad {
+                week_start,
+                load,
+                days: 1,
+            }),
+        }
+    }
+    weeks
+}
+
+/// Reconstructs the time spent in each heart rate zone from intraday samples
+///
+/// Each sample's heart rate holds until the next sample, for at most the
+/// larger of `max_gap` and the interval of the dataset. Longer gaps, e.g.
+/// while the tracker was not worn, count as missing data rather than time in
+/// the zone of the sample before them. The last sample of a day holds for the
+/// interval of the dataset, without passing midnight.
+///
+/// A heart rate on the boundary of two zones counts towards the higher zone,
+/// as in `HeartRateZones::zone_for`. Heart rates outside every zone, and all
+/// samples of days without zones of the selected kind (e.g. without custom
+/// zones), count as `outside_minutes`. Days with no summary or an unparseable
+/// date are skipped.
+///
+/// # Examples
+///
+/// ```
+/// use fitbit_sdk::analysis::heart::intraday_time_in_zones;
+/// use fitbit_sdk::types::heart_rate::{IntradayHeartRateSeries, ZoneSelection};
+/// use time::Duration;
+///
+/// let day: IntradayHeartRateSeries = serde_json::from_value(serde_json::json!({
+///     "activities-heart": [{
+///         "dateTime": "2025-01-15",
+///         "value": {
+///             "customHeartRateZones": [
+///                 {"name": "Below", "min": 30, "max": 120},
+///                 {"name": "Custom Zone", "min": 120, "max": 150},
+///                 {"name": "Above", "min": 150, "max": 220}
+///             ],
+///             "heartRateZones": []
+///         }
+///     }],
+///     "activities-heart-intraday": {
+///         "dataset": [
+///             {"time": "08:00:00", "value": 119},
+///             // On the boundary, counted in the higher zone
+///             {"time": "08:01:00", "value": 120},
+///             // Followed by a gap of 9 minutes, only 1 of which is counted
+///             {"time": "08:02:00", "value": 150},
+///             {"time": "08:11:00", "value": 25}
+///         ],
+///         "datasetInterval": 1,
+///         "datasetType": "minute"
+///     }
+/// }))
+/// .unwrap();
+///
+/// let time = intraday_time_in_zones(&[day], &ZoneSelection::Custom, Duration::minutes(1));
+/// let zones = &time.days[0].zones;
+/// assert_eq!((zones[0].name.as_str(), zones[0].minutes), ("Below", 1.0));
+/// assert_eq!((zones[1].name.as_str(), zones[1].minutes), ("Custom Zone", 1.0));
+/// assert_eq!((zones[2].name.as_str(), zones[2].minutes), ("Above", 1.0));
+/// assert_eq!(time.outside_minutes, 1.0);
+/// assert_eq!(time.totals, *zones);
+/// ```
+pub fn intraday_time_in_zones(
+    days: &[IntradayHeartRateSeries],
+    zones: &ZoneSelection,
+    max_gap: Duration,
+) -> IntradayZoneTime {
+    let mut daily: Vec<DailyZoneTime> = days
+        .iter()
+        .filter_map(|day| {
+            let summary = day.summary.first()?;
+            let date: Date = summary.date_time.convert().ok()?;
+            let day_zones = match zones {
+                ZoneSelection::Default => Some(summary.value.default_zones()),
+                ZoneSelection::Custom => summary.value.custom_zones(),
+                ZoneSelection::Fixed(zones) => Some(zones.clone()),
+            };
+            Some(daily_zone_time(
+                date,
+                &day.intraday,
+                day_zones.as_ref(),
+                max_gap,
+            ))
+        })
+        .collect();
+    daily.sort_by_key(|day| day.date);
+
+    let mut totals: Vec<ZoneTime> = Vec::new();
+    for zone in daily.iter().flat_map(|day| &day.zones) {
+        match totals.iter_mut().find(|total| total.name == zone.name) {
+            Some(total) => total.minutes += zone.minutes,
+            None => totals.push(zone.clone()),
+        }
+    }
+    IntradayZoneTime {
+        outside_minutes: daily.iter().map(|day| day.outside_minutes).sum(),
+        days: daily,
+        totals,
+    }
+}
+
+/// Returns the time spent in each zone by the samples of a single day
+fn daily_zone_time(
+    date: Date,
+    dataset: &IntradayDataset<i32>,
+    zones: Option<&HeartRateZones>,
+    max_gap: Duration,
+) -> DailyZoneTime {
+    let unit = if dataset.dataset_type == "second" {
+        1
+    } else {
+        60
+    };
+    let interval = i64::from(dataset.dataset_interval.max(1)) * unit;
+    let max_hold = max_gap.whole_seconds().max(interval);
+
+    let mut samples: Vec<(i64, i32)> = dataset
+        .dataset
+        .iter()
+        .filter_map(|point| {
+            let time: Time = point.time.convert().ok()?;
+            let (hour, minute, second) = time.as_hms();
+            let seconds = i64::from(hour) * 3600 + i64::from(minute) * 60 + i64::from(second);
+            Some((seconds, point.value))
+        })
+        .collect();
+    samples.sort_by_key(|(seconds, _)| *seconds);
+
+    let mut day = DailyZoneTime {
+        date,
+        zones: zones
+            .map(|zones| {
+                zones
+                    .zones
+                    .iter()
+                    .map(|zone| ZoneTime {
+                        name: zone.name.clone(),
+                        minutes: 0.0,
+                        calories_out: None,
+                    })
+                    .collect()
+            })
+            .unwrap_or_default(),
+        outside_minutes: 0.0,
+    };
+    for (index, &(start, bpm)) in samples.iter().enumerate() {
+        let end = samples
+            .get(index + 1)
+            .map_or((start + interval).min(SECONDS_PER_DAY), |(next, _)| *next);
+        let minutes = (end - start).min(max_hold) as f64 / 60.0;
+        let zone = zones
+            .and_then(|zones| zones.zone_for(bpm))
+            .and_then(|zone| day.zones.iter_mut().find(|total| total.name == zone.name));
+        match zone {
+            Some(zone) => zone.minutes += minutes,
+            None => day.outside_minutes += minutes,
+        }
+    }
+    day
+}
@@ -0,0 +1,11 @@
+//! Analysis
+//!
+//! This module contains helpers deriving metrics from the data returned by the
+//! API. They operate on the typed responses only and never send requests.
+
+#[cfg(feature = "activity")]
+pub mod goals;
+#[cfg(feature = "sleep")]
+pub mod sleep;
+#[cfg(feature = "heart-rate")]
+pub mod heart;
@@ -0,0 +1,278 @@
+//! Sleep Analysis
+//!
+//! This module contains functions computing derived metrics from sleep logs:
+//! the share of each sleep stage, the longest bout of a stage, the transitions
+//! between stages and how consistent bedtimes and wake times are over several
+//! nights.
+//!
+//! Stage metrics are computed from `SleepLevels::data`, so they work for both
+//! stage logs (`deep`, `light`, `rem`, `wake`) and classic logs (`asleep`,
+//! `restless`, `awake`).
+
+use crate::types::datetime::{ApiDateTime, ApiField};
+use crate::types::sleep::{SleepEntry, SleepLevel, SleepLevelData, SleepLevels};
+use std::collections::BTreeMap;
+use time::PrimitiveDateTime;
+
+/// Standard deviation of bedtimes and wake times, in minutes, scoring zero consistency
+const INCONSISTENT_DEVIATION_MINUTES: f64 = 90.0;
+
+/// Share of the recorded time spent in each sleep stage
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct StageEfficiency {
+    /// Percentage of the time in deep sleep
+    pub deep: f64,
+    /// Percentage of the time in light sleep
+    pub light: f64,
+    /// Percentage of the time in REM sleep
+    pub rem: f64,
+    /// Percentage of the time awake (`wake` or `awake`)
+    pub wake: f64,
+    /// Percentage of the time asleep, in any stage
+    pub efficiency: f64,
+}
+
+/// Uninterrupted period spent in a single sleep level
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SleepBout {
+    /// Sleep level of the bout
+    pub level: SleepLevel,
+    /// Date-time the bout started
+    pub start: ApiDateTime,
+    /// Length of the bout in seconds
+    pub seconds: i32,
+}
+
+/// Number of changes from one sleep level to another
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct StageTransitions {
+    /// Total number of transitions
+    pub total: u32,
+    /// Number of transitions per pair of `(from, to)` levels
+    pub counts: BTreeMap<(SleepLevel, SleepLevel), u32>,
+}
+
+impl StageTransitions {
+    /// Returns the number of transitions from the `from` level to the `to` level
+    pub fn count(&self, from: &SleepLevel, to: &SleepLevel) -> u32 {
+        self.counts
+            .get(&(from.clone(), to.clone()))
+            .copied()
+            .unwrap_or_default()
+    }
+}
+
+/// Merges consecutive data points of the same level into bouts
+// The date-time is only `Copy` with the `typed-time` feature
+#[allow(clippy::clone_on_copy)]
+fn bouts(data: &[SleepLevelData]) -> Vec<SleepBout> {
+    let mut bouts: Vec<SleepBout> = Vec::new();
+    for point in data {
+        match bouts.last_mut() {
+            Some(bout) if bout.level == point.level => bout.seconds += point.seconds,
+            _ => bouts.push(SleepBout {
+                level: point.level.clone(),
+                start: point.datetime.clone(),
+                seconds: point.seconds,
+            }),
+        }
+    }
+    bouts
+}
+
+/// Computes the share of the recorded time spent in each sleep stage
+///
+/// Returns `None` if the levels hold no data.
+///
+/// # Examples
+///
+/// ```
+/// use fitbit_sdk::analysis::sleep::stage_efficiency;
+/// use fitbit_sdk::types::sleep::SleepLevels;
+///
+/// let levels: SleepLevels = serde_json::from_str(r#"{
+///     "summary": {},
+///     "data": [
+///         {"dateTime": "2025-01-14T23:00:00.000", "level": "wake", "seconds": 600},
+///         {"dateTime": "2025-01-14T23:10:00.000", "level": "light", "seconds": 3000},
+///         {"dateTime": "2025-01-15T00:00:00.000", "level": "deep", "seconds": 1200},
+///         {"dateTime": "2025-01-15T00:20:00.000", "level": "rem", "seconds": 1200}
+///     ]
+/// }"#).unwrap();
+///
+/// let stages = stage_efficiency(&levels).unwrap();
+/// assert_eq!(stages.light, 50.0);
+/// assert_eq!(stages.deep, 20.0);
+/// assert_eq!(stages.efficiency, 90.0);
+/// ```
+pub fn stage_efficiency(levels: &SleepLevels) -> Option<StageEfficiency> {
+    let total: i64 = levels.data.iter().map(|point| i64::from(point.seconds)).sum();
+    if total <= 0 {
+        return None;
+    }
+    let percentage = |matches: fn(&SleepLevel) -> bool| {
+        let seconds: i64 = levels
+            .data
+            .iter()
+            .filter(|point| matches(&point.level))
+            .map(|point| i64::from(point.seconds))
+            .sum();
+        seconds as f64 * 100.0 / total as f64
+    };
+    let wake = percentage(SleepLevel::is_awake);
+    Some(StageEfficiency {
+        deep: percentage(|level| *level == SleepLevel::Deep),
+        light: percentage(|level| *level == SleepLevel::Light),
+        rem: percentage(|level| *level == SleepLevel::Rem),
+        wake,
+        efficiency: 100.0 - wake,
+    })
+}
+
+/// Returns the longest uninterrupted bout of `level`, e.g. `SleepLevel::Deep`
+///
+/// Consecutive data points of the same level count as a single bout. When
+/// several bouts are equally long, the first one is returned.
+///
+/// # Examples
+///
+/// ```
+/// use fitbit_sdk::analysis::sleep::longest_bout;
+/// use fitbit_sdk::types::datetime::ApiField;
+/// use fitbit_sdk::types::sleep::{SleepLevel, SleepLevels};
+///
+/// let levels: SleepLevels = serde_json::from_str(r#"{
+///     "summary": {},
+///     "data": [
+///         {"dateTime": "2025-01-14T23:10:00.000", "level": "deep", "seconds": 900},
+///         {"dateTime": "2025-01-14T23:25:00.000", "level": "light", "seconds": 1800},
+///         {"dateTime": "2025-01-14T23:55:00.000", "level": "deep", "seconds": 1200},
+///         {"dateTime": "2025-01-15T00:15:00.000", "level": "deep", "seconds": 600}
+///     ]
+/// }"#).unwrap();
+///
+/// let bout = longest_bout(&levels, &SleepLevel::Deep).unwrap();
+/// assert_eq!(bout.start.to_api_string(), "2025-01-14T23:55:00.000");
+/// assert_eq!(bout.seconds, 1800);
+/// assert!(longest_bout(&levels, &SleepLevel::Rem).is_none());
+/// ```
+pub fn longest_bout(levels: &SleepLevels, level: &SleepLevel) -> Option<SleepBout> {
+    bouts(&levels.data)
+        .into_iter()
+        .filter(|bout| bout.level == *level)
+        .rev()
+        .max_by_key(|bout| bout.seconds)
+}
+
+/// Returns the longest uninterrupted bout of deep sleep
+pub fn longest_deep_sleep(levels: &SleepLevels) -> Option<SleepBout> {
+    longest_bout(levels, &SleepLevel::Deep)
+}
+
+/// Counts the transitions between sleep levels
+///
+/// # Examples
+///
+/// ```
+/// use fitbit_sdk::analysis::sleep::stage_transitions;
+/// use fitbit_sdk::types::sleep::{SleepLevel, SleepLevels};
+///
+/// let levels: SleepLevels = serde_json::from_str(r#"{
+///     "summary": {},
+///     "data": [
+///         {"dateTime": "2025-01-14T23:10:00.000", "level": "light", "seconds": 900},
+///         {"dateTime": "2025-01-14T23:25:00.000", "level": "deep", "seconds": 1800},
+///         {"dateTime": "2025-01-14T23:55:00.000", "level": "light", "seconds": 1200},
+///         {"dateTime": "2025-01-15T00:15:00.000", "level": "deep", "seconds": 600}
+///     ]
+/// }"#).unwrap();
+///
+/// let transitions = stage_transitions(&levels);
+/// assert_eq!(transitions.total, 3);
+/// assert_eq!(transitions.count(&SleepLevel::Light, &SleepLevel::Deep), 2);
+/// assert_eq!(transitions.count(&SleepLevel::Deep, &SleepLevel::Light), 1);
+/// ```
+pub fn stage_transitions(levels: &SleepLevels) -> StageTransitions {
+    let mut transitions = StageTransitions::default();
+    for pair in bouts(&levels.data).windows(2) {
+        transitions.total += 1;
+        *transitions
+            .counts
+            .entry((pair[0].level.clone(), pair[1].level.clone()))
+            .or_default() += 1;
+    }
+    transitions
+}
+
+/// Returns the minutes since noon of a sleep log date-time
+///
+/// Counting from noon keeps bedtimes around midnight close to each other.
+fn minutes_since_noon(datetime: &ApiDateTime) -> Option<f64> {
+    let datetime: PrimitiveDateTime = datetime.convert().ok()?;
+    let minutes = i32::from(datetime.hour()) * 60 + i32::from(datetime.minute());
+    Some(f64::from((minutes + 12 * 60) % (24 * 60)))
+}
+
+/// Returns the population standard deviation of `values`
+fn standard_deviation(values: &[f64]) -> f64 {
+    let mean = values.iter().sum::<f64>() / values.len() as f64;
+    let variance = values
+        .iter()
+        .map(|value| (value - mean).powi(2))
+        .sum::<f64>()
+        / values.len() as f64;
+    variance.sqrt()
+}
+
+/// Scores how consistent bedtimes and wake times are, from 0 to 100
+///
+/// Only main sleeps are considered, typically a week of them. The score is
+/// 100 when the user goes to bed and wakes up at the same time every night,
+/// and drops linearly to 0 as the average standard deviation of bedtimes and
+/// wake times reaches 90 minutes.
+///
+/// Returns `None` with fewer than two main sleeps.
+///
+/// # Examples
+///
+/// ```
+/// use fitbit_sdk::analysis::sleep::consistency_score;
+/// use fitbit_sdk::types::sleep::SleepEntry;
+///
+/// let entry = |start: &str, end: &str| -> SleepEntry {
+///     serde_json::from_value(serde_json::json!({
+///         "logId": 1, "startTime": start, "endTime": end, "duration": 28800000,
+///         "minutesToFallAsleep": 0, "timeInBed": 480, "minutesAsleep": 450,
+///         "efficiency": 94, "type": "stages", "isMainSleep": true
+///     }))
+///     .unwrap()
+/// };
+///
+/// let regular = [
+///     entry("2025-01-13T23:00:00.000", "2025-01-14T07:00:00.000"),
+///     entry("2025-01-14T23:00:00.000", "2025-01-15T07:00:00.000"),
+/// ];
+/// assert_eq!(consistency_score(&regular), Some(100.0));
+///
+/// let irregular = [
+///     entry("2025-01-13T22:30:00.000", "2025-01-14T06:30:00.000"),
+///     entry("2025-01-15T00:30:00.000", "2025-01-15T08:30:00.000"),
+/// ];
+/// // Bedtimes and wake times both deviate by an hour
+/// let score = consistency_score(&irregular).unwrap();
+/// assert!((score - 33.3).abs() < 0.1);
+/// ```
+pub fn consistency_score(entries: &[SleepEntry]) -> Option<f64> {
+    let (bedtimes, wake_times): (Vec<f64>, Vec<f64>) = entries
+        .iter()
+        .filter(|entry| entry.is_main_sleep)
+        .filter_map(|entry| {
+            Some((minutes_since_noon(&entry.start_time)?, minutes_since_noon(&entry.end_time)?))
+        })
+        .unzip();
+    if bedtimes.len() < 2 {
+        return None;
+    }
+    let deviation = (standard_deviation(&bedtimes) + standard_deviation(&wake_times)) / 2.0;
+    Some((100.0 * (1.0 - deviation / INCONSISTENT_DEVIATION_MINUTES)).clamp(0.0, 100.0))
+}
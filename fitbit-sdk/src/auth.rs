@@ -0,0 +1,721 @@
+//! OAuth 2.0 Authorization
+//!
+//! This module contains the implementation of the Fitbit OAuth 2.0 Authorization Code Grant flow.
+//! It provides functionality for building the authorization URL, exchanging
+//! authorization codes for access tokens, and supplying tokens to `FitbitClient`.
+
+use crate::types::auth::{AuthError, PkceChallenge, TokenProvider, TokenResponse, TokenStore};
+use async_trait::async_trait;
+use reqwest::{Client as ReqwestClient, Url};
+use std::fmt;
+use std::process::{Command, Stdio};
+use std::sync::{Arc, RwLock};
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::net::TcpListener;
+
+/// Fitbit OAuth 2.0 client
+///
+/// Implements the Authorization Code Grant flow used to obtain access tokens
+/// on behalf of a Fitbit user.
+///
+/// # Examples
+///
+/// ```no_run
+/// use fitbit_sdk::auth::OAuth2Client;
+/// use fitbit_sdk::types::auth::AuthError;
+///
+/// # async fn example() -> Result<(), AuthError> {
+/// let oauth = OAuth2Client::builder()
+///     .with_client_id("your-client-id")
+///     .with_client_secret("your-client-secret")
+///     .with_redirect_uri("http://localhost:8080/callback")
+///     .build()?;
+///
+/// // Send the user to this URL and wait for the redirect
+/// let url = oauth.authorize_url(&["activity", "profile"], Some("state"));
+/// println!("Open {}", url);
+///
+/// // Exchange the `code` query parameter from the redirect for tokens
+/// let token = oauth.exchange_code("code-from-redirect").await?;
+/// println!("Access token: {}", token.access_token);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct OAuth2Client {
+    /// The underlying HTTP client for making requests
+    client: ReqwestClient,
+    /// The OAuth 2.0 client ID of the registered application
+    client_id: String,
+    /// The OAuth 2.0 client secret of the registered application
+    client_secret: Option<String>,
+    /// The redirect URI registered for the application
+    redirect_uri: Option<String>,
+    /// The authorization page URL
+    authorize_url: Url,
+    /// The token endpoint URL
+    token_url: Url,
+}
+
+/// Builder for OAuth2Client
+///
+/// Provides a flexible way to configure and create an OAuth2Client.
+pub struct OAuth2ClientBuilder {
+    client_id: Option<String>,
+    client_secret: Option<String>,
+    redirect_uri: Option<String>,
+    authorize_url: String,
+    token_url: String,
+    client: Option<ReqwestClient>,
+}
+
+impl Default for OAuth2ClientBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl OAuth2ClientBuilder {
+    /// Creates a new builder with default configuration
+    pub fn new() -> Self {
+        Self {
+            client_id: None,
+            client_secret: None,
+            redirect_uri: None,
+            authorize_url: OAuth2Client::DEFAULT_AUTHORIZE_URL.to_string(),
+            token_url: OAuth2Client::DEFAULT_TOKEN_URL.to_string(),
+            client: None,
+        }
+    }
+
+    /// Sets the OAuth 2.0 client ID
+    pub fn with_client_id(mut self, client_id: impl Into<String>) -> Self {
+        self.client_id = Some(client_id.into());
+        self
+    }
+
+    /// Sets the OAuth 2.0 client secret
+    pub fn with_client_secret(mut self, client_secret: impl Into<String>) -> Self {
+        self.client_secret = Some(client_secret.into());
+        self
+    }
+
+    /// Sets the redirect URI registered for the application
+    pub fn with_redirect_uri(mut self, redirect_uri: impl Into<String>) -> Self {
+        self.redirect_uri = Some(redirect_uri.into());
+        self
+    }
+
+    /// Sets a custom authorization page URL
+    pub fn with_authorize_url(mut self, authorize_url: impl Into<String>) -> Self {
+        self.authorize_url = authorize_url.into();
+        self
+    }
+
+    /// Sets a custom token endpoint URL
+    pub fn with_token_url(mut self, token_url: impl Into<String>) -> Self {
+        self.token_url = token_url.into();
+        self
+    }
+
+    /// Sets a custom HTTP client
+    pub fn with_http_client(mut self, client: ReqwestClient) -> Self {
+        self.client = Some(client);
+        self
+    }
+
+    /// Builds the OAuth2Client with the specified configuration
+    pub fn build(self) -> Result<OAuth2Client, AuthError> {
+        // Get client credentials from environment or builder
+        let client_id = self.client_id
+            .or_else(|| std::env::var("FITBIT_CLIENT_ID").ok())
+            .ok_or_else(|| AuthError::from("Client ID must be provided either via builder or FITBIT_CLIENT_ID environment variable".to_string()))?;
+        let client_secret = self
+            .client_secret
+            .or_else(|| std::env::var("FITBIT_CLIENT_SECRET").ok());
+        let redirect_uri = self
+            .redirect_uri
+            .or_else(|| std::env::var("FITBIT_REDIRECT_URI").ok());
+
+        let authorize_url = Url::parse(&self.authorize_url)
+            .map_err(|e| AuthError::from(format!("Invalid authorize URL: {}", e)))?;
+        let token_url = Url::parse(&self.token_url)
+            .map_err(|e| AuthError::from(format!("Invalid token URL: {}", e)))?;
+
+        // Use provided client or create a new one
+        let client = if let Some(client) = self.client {
+            client
+        } else {
+            ReqwestClient::builder()
+                .user_agent(crate::client::FitbitClient::DEFAULT_USER_AGENT)
+                .build()
+                .map_err(|e| AuthError::from(e.to_string()))?
+        };
+
+        Ok(OAuth2Client {
+            client,
+            client_id,
+            client_secret,
+            redirect_uri,
+            authorize_url,
+            token_url,
+        })
+    }
+}
+
+impl OAuth2Client {
+    /// URL of the Fitbit authorization page
+    pub const DEFAULT_AUTHORIZE_URL: &str = "https://www.fitbit.com/oauth2/authorize";
+
+    /// URL of the Fitbit token endpoint
+    pub const DEFAULT_TOKEN_URL: &str = "https://api.fitbit.com/oauth2/token";
+
+    /// Creates a new OAuth2Client builder
+    pub fn builder() -> OAuth2ClientBuilder {
+        OAuth2ClientBuilder::new()
+    }
+
+    pub fn get_client_id(&self) -> &str {
+        &self.client_id
+    }
+
+    pub fn get_redirect_uri(&self) -> Option<&str> {
+        self.redirect_uri.as_deref()
+    }
+
+    /// Builds the URL the user should be sent to in order to authorize the application
+    ///
+    /// # Arguments
+    ///
+    /// * `scopes` - The scopes to request, as `Scope`s or their names (e.g. "activity", "sleep")
+    /// * `state` - Optional opaque value echoed back on the redirect to protect against CSRF
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fitbit_sdk::auth::OAuth2Client;
+    /// use fitbit_sdk::types::auth::Scope;
+    ///
+    /// let oauth = OAuth2Client::builder()
+    ///     .with_client_id("client-id")
+    ///     .with_client_secret("client-secret")
+    ///     .build()
+    ///     .unwrap();
+    /// let url = oauth.authorize_url(&[Scope::Activity, Scope::Sleep], Some("state"));
+    /// assert!(url.contains("scope=activity+sleep"));
+    /// ```
+    pub fn authorize_url<S: AsRef<str>>(
+        &self,
+        scopes: impl IntoIterator<Item = S>,
+        state: Option<&str>,
+    ) -> String {
+        self.build_authorize_url(scopes, state, None)
+    }
+
+    /// Builds the authorization URL including a PKCE code challenge
+    ///
+    /// Keep the `pkce` value around and pass its `code_verifier` to
+    /// `exchange_code_with_verifier` once the user is redirected back.
+    ///
+    /// # Arguments
+    ///
+    /// * `scopes` - The scopes to request, as `Scope`s or their names (e.g. "activity", "sleep")
+    /// * `state` - Optional opaque value echoed back on the redirect to protect against CSRF
+    /// * `pkce` - The PKCE challenge generated for this authorization request
+    pub fn authorize_url_with_pkce<S: AsRef<str>>(
+        &self,
+        scopes: impl IntoIterator<Item = S>,
+        state: Option<&str>,
+        pkce: &PkceChallenge,
+    ) -> String {
+        self.build_authorize_url(scopes, state, Some(pkce))
+    }
+
+    fn build_authorize_url<S: AsRef<str>>(
+        &self,
+        scopes: impl IntoIterator<Item = S>,
+        state: Option<&str>,
+        pkce: Option<&PkceChallenge>,
+    ) -> String {
+        let scopes: Vec<S> = scopes.into_iter().collect();
+        let scope = scopes.iter().map(AsRef::as_ref).collect::<Vec<_>>().join(" ");
+        let mut url = self.authorize_url.clone();
+        {
+            let mut query = url.query_pairs_mut();
+            query
+                .append_pair("response_type", "code")
+                .append_pair("client_id", &self.client_id)
+                .append_pair("scope", &scope);
+            if let Some(redirect_uri) = &self.redirect_uri {
+                query.append_pair("redirect_uri", redirect_uri);
+            }
+            if let Some(state) = state {
+                query.append_pair("state", state);
+            }
+            if let Some(pkce) = pkce {
+                query
+                    .append_pair("code_challenge", &pkce.code_challenge)
+                    .append_pair("code_challenge_method", pkce.code_challenge_method());
+            }
+        }
+        url.into()
+    }
+
+    /// Exchanges an authorization code for an access token
+    ///
+    /// # Arguments
+    ///
+    /// * `code` - The authorization code received on the redirect URI
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AuthError` if:
+    /// - The request fails to send
+    /// - The token endpoint returns an error response
+    /// - The response cannot be parsed
+    pub async fn exchange_code(&self, code: &str) -> Result<TokenResponse, AuthError> {
+        self.exchange_code_inner(code, None).await
+    }
+
+    /// Exchanges an authorization code obtained with PKCE for an access token
+    ///
+    /// No client secret is required for this exchange, which makes it suitable
+    /// for CLI and desktop applications.
+    ///
+    /// # Arguments
+    ///
+    /// * `code` - The authorization code received on the redirect URI
+    /// * `code_verifier` - The code verifier of the `PkceChallenge` used to build the authorization URL
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AuthError` if:
+    /// - The request fails to send
+    /// - The token endpoint returns an error response
+    /// - The response cannot be parsed
+    pub async fn exchange_code_with_verifier(
+        &self,
+        code: &str,
+        code_verifier: &str,
+    ) -> Result<TokenResponse, AuthError> {
+        self.exchange_code_inner(code, Some(code_verifier)).await
+    }
+
+    async fn exchange_code_inner(
+        &self,
+        code: &str,
+        code_verifier: Option<&str>,
+    ) -> Result<TokenResponse, AuthError> {
+        let mut params = vec![
+            ("client_id", self.client_id.as_str()),
+            ("grant_type", "authorization_code"),
+            ("code", code),
+        ];
+        if let Some(redirect_uri) = &self.redirect_uri {
+            params.push(("redirect_uri", redirect_uri));
+        }
+        if let Some(code_verifier) = code_verifier {
+            params.push(("code_verifier", code_verifier));
+        }
+
+        self.request_token(&params).await
+    }
+
+    /// Authorizes the application interactively and returns the user's tokens
+    ///
+    /// Runs the whole Authorization Code Grant flow with PKCE for command line
+    /// tools: listens on the port of the redirect URI, opens the authorization
+    /// page in the default browser, waits for Fitbit to redirect back, and
+    /// exchanges the code for tokens. Use `run_local_auth_flow_with` to show
+    /// the authorization page differently, e.g. by printing its URL where no
+    /// browser is available.
+    ///
+    /// The redirect URI must be an `http://localhost` or `http://127.0.0.1`
+    /// URL with a port (e.g. `http://localhost:8080/callback`), registered for
+    /// the application.
+    ///
+    /// # Arguments
+    ///
+    /// * `scopes` - The scopes to request, as `Scope`s or their names
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AuthError` if:
+    /// - The redirect URI is missing or not a local HTTP URL
+    /// - The port of the redirect URI cannot be listened on
+    /// - No browser can be opened
+    /// - The user denies the authorization, or the redirect carries an unexpected `state`
+    /// - The code exchange fails
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use fitbit_sdk::auth::OAuth2Client;
+    /// use fitbit_sdk::types::auth::{AuthError, Scope};
+    /// use tokio;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), AuthError> {
+    ///     let oauth = OAuth2Client::builder()
+    ///         .with_client_id("your-client-id")
+    ///         .with_redirect_uri("http://localhost:8080/callback")
+    ///         .build()?;
+    ///
+    ///     let token = oauth.run_local_auth_flow(&[Scope::Activity, Scope::Sleep]).await?;
+    ///     println!("Access token: {}", token.access_token);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn run_local_auth_flow<S: AsRef<str>>(
+        &self,
+        scopes: impl IntoIterator<Item = S>,
+    ) -> Result<TokenResponse, AuthError> {
+        self.run_local_auth_flow_with(scopes, |url| {
+            open_browser(url).map_err(|e| {
+                AuthError::from(format!(
+                    "Failed to open a browser: {}. Use run_local_auth_flow_with to show the authorization page",
+                    e
+                ))
+            })
+        })
+        .await
+    }
+
+    /// Authorizes the application interactively, letting `open` show the
+    /// authorization page to the user
+    ///
+    /// Behaves like `run_local_auth_flow`, calling `open` with the URL of the
+    /// authorization page once the redirect URI is listened on, instead of
+    /// opening a browser.
+    pub async fn run_local_auth_flow_with<S: AsRef<str>>(
+        &self,
+        scopes: impl IntoIterator<Item = S>,
+        open: impl FnOnce(&str) -> Result<(), AuthError>,
+    ) -> Result<TokenResponse, AuthError> {
+        let redirect_uri = self.redirect_uri.as_deref().ok_or_else(|| {
+            AuthError::from(
+                "The local auth flow requires a redirect URI such as http://localhost:8080/callback"
+                    .to_string(),
+            )
+        })?;
+        let redirect_uri = Url::parse(redirect_uri)
+            .map_err(|e| AuthError::from(format!("Invalid redirect URI {}: {}", redirect_uri, e)))?;
+        let port = match (redirect_uri.scheme(), redirect_uri.host_str(), redirect_uri.port()) {
+            ("http", Some("localhost" | "127.0.0.1"), Some(port)) => port,
+            _ => {
+                return Err(AuthError::from(format!(
+                    "The redirect URI {} is not a local HTTP URL with a port",
+                    redirect_uri
+                )));
+            }
+        };
+
+        let listener = TcpListener::bind(("127.0.0.1", port))
+            .await
+            .map_err(|e| AuthError::RequestFailed(format!("Failed to listen on port {}: {}", port, e)))?;
+
+        let pkce = PkceChallenge::new();
+        let state: String = rand::random::<[u8; 16]>()
+            .iter()
+            .map(|byte| format!("{:02x}", byte))
+            .collect();
+        open(&self.authorize_url_with_pkce(scopes, Some(&state), &pkce))?;
+
+        let code = loop {
+            let (stream, _) = listener
+                .accept()
+                .await
+                .map_err(|e| AuthError::RequestFailed(format!("Failed to accept redirect: {}", e)))?;
+            let mut stream = BufReader::new(stream);
+            let mut request_line = String::new();
+            stream
+                .read_line(&mut request_line)
+                .await
+                .map_err(|e| AuthError::RequestFailed(format!("Failed to read redirect: {}", e)))?;
+
+            // Ignore anything but the redirect, e.g. the browser asking for a favicon
+            let redirect = request_line
+                .split_whitespace()
+                .nth(1)
+                .and_then(|target| redirect_uri.join(target).ok())
+                .filter(|url| url.path() == redirect_uri.path());
+            let Some(redirect) = redirect else {
+                let _ = stream
+                    .get_mut()
+                    .write_all(b"HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\nConnection: close\r\n\r\n")
+                    .await;
+                continue;
+            };
+
+            let param = |name: &str| {
+                redirect
+                    .query_pairs()
+                    .find(|(key, _)| key == name)
+                    .map(|(_, value)| value.into_owned())
+            };
+            let result = match (param("code"), param("error")) {
+                _ if param("state").as_deref() != Some(state.as_str()) => Err(AuthError::from(
+                    "The redirect does not carry the state of the authorization request".to_string(),
+                )),
+                (Some(code), None) => Ok(code),
+                (_, error) => Err(AuthError::from(format!(
+                    "Authorization failed: {}",
+                    param("error_description").or(error).unwrap_or_default()
+                ))),
+            };
+
+            let message = match &result {
+                Ok(_) => "Authorization complete. You can close this window.",
+                Err(_) => "Authorization failed. You can close this window.",
+            };
+            let response = format!(
+                "HTTP/1.1 200 OK\r\nContent-Type: text/plain; charset=utf-8\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                message.len(),
+                message
+            );
+            let _ = stream.get_mut().write_all(response.as_bytes()).await;
+            break result?;
+        };
+
+        self.exchange_code_with_verifier(&code, &pkce.code_verifier)
+            .await
+    }
+
+    /// Exchanges a refresh token for a new access token
+    ///
+    /// Fitbit refresh tokens can only be used once; the returned `TokenResponse`
+    /// carries the replacement refresh token.
+    ///
+    /// # Arguments
+    ///
+    /// * `refresh_token` - The refresh token issued with the current access token
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AuthError` if:
+    /// - The request fails to send
+    /// - The token endpoint returns an error response
+    /// - The response cannot be parsed
+    pub async fn refresh_token(&self, refresh_token: &str) -> Result<TokenResponse, AuthError> {
+        let params = [
+            ("client_id", self.client_id.as_str()),
+            ("grant_type", "refresh_token"),
+            ("refresh_token", refresh_token),
+        ];
+
+        self.request_token(&params).await
+    }
+
+    /// Sends a form-encoded request to the token endpoint
+    async fn request_token(&self, params: &[(&str, &str)]) -> Result<TokenResponse, AuthError> {
+        let mut request = self.client.post(self.token_url.clone()).form(params);
+
+        // Confidential clients authenticate with HTTP Basic credentials
+        if let Some(client_secret) = &self.client_secret {
+            request = request.basic_auth(&self.client_id, Some(client_secret));
+        }
+
+        let response = request
+            .send()
+            .await
+            .map_err(|e| AuthError::RequestFailed(e.to_string()))?;
+
+        let status = response.status();
+        let body = response
+            .text()
+            .await
+            .map_err(|e| AuthError::from(format!("Failed to get response body: {}", e)))?;
+
+        if !status.is_success() {
+            return Err(AuthError::from(body));
+        }
+
+        serde_json::from_str(&body).map_err(|e| {
+            AuthError::from(format!(
+                "JSON parsing error: {}. Response body: {}",
+                e, body
+            ))
+        })
+    }
+}
+
+/// Token provider returning a fixed access token
+///
+/// This is what `FitbitClientBuilder::with_access_token` uses when no
+/// refresh token is configured.
+#[derive(Clone)]
+pub struct StaticTokenProvider {
+    access_token: String,
+}
+
+impl StaticTokenProvider {
+    /// Creates a provider that always returns `access_token`
+    pub fn new(access_token: impl Into<String>) -> Self {
+        Self {
+            access_token: access_token.into(),
+        }
+    }
+}
+
+impl fmt::Debug for StaticTokenProvider {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("StaticTokenProvider").finish_non_exhaustive()
+    }
+}
+
+#[async_trait]
+impl TokenProvider for StaticTokenProvider {
+    async fn access_token(&self) -> Result<String, AuthError> {
+        Ok(self.access_token.clone())
+    }
+}
+
+/// Token provider that refreshes expired access tokens
+///
+/// Holds the current access and refresh tokens and uses an `OAuth2Client` to
+/// obtain new ones when the API reports the access token as expired. Every
+/// refreshed token is handed to the optional `TokenStore`.
+///
+/// # Examples
+///
+/// ```no_run
+/// use fitbit_sdk::auth::{OAuth2Client, RefreshingTokenProvider};
+/// use fitbit_sdk::client::FitbitClient;
+/// use fitbit_sdk::types::user::UserError;
+///
+/// # fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let oauth = OAuth2Client::builder().build()?;
+/// let provider = RefreshingTokenProvider::new(oauth, "access-token", "refresh-token");
+///
+/// let client = FitbitClient::builder()
+///     .with_token_provider(provider)
+///     .build()?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct RefreshingTokenProvider {
+    /// The OAuth client used to refresh expired access tokens
+    oauth_client: OAuth2Client,
+    /// The current access and refresh tokens
+    tokens: RwLock<Tokens>,
+    /// Serializes token refreshes so a refresh token is only used once
+    refresh_lock: tokio::sync::Mutex<()>,
+    /// Storage notified whenever the tokens are refreshed
+    token_store: Option<Arc<dyn TokenStore>>,
+}
+
+/// The current access and refresh tokens
+struct Tokens {
+    access_token: String,
+    refresh_token: String,
+}
+
+impl RefreshingTokenProvider {
+    /// Creates a provider from an access token and its refresh token
+    pub fn new(
+        oauth_client: OAuth2Client,
+        access_token: impl Into<String>,
+        refresh_token: impl Into<String>,
+    ) -> Self {
+        Self {
+            oauth_client,
+            tokens: RwLock::new(Tokens {
+                access_token: access_token.into(),
+                refresh_token: refresh_token.into(),
+            }),
+            refresh_lock: tokio::sync::Mutex::new(()),
+            token_store: None,
+        }
+    }
+
+    /// Creates a provider from a token endpoint response
+    pub fn from_token(oauth_client: OAuth2Client, token: &TokenResponse) -> Self {
+        Self::new(
+            oauth_client,
+            token.access_token.clone(),
+            token.refresh_token.clone(),
+        )
+    }
+
+    /// Sets the storage notified whenever the tokens are refreshed
+    pub fn with_token_store(mut self, token_store: impl TokenStore + 'static) -> Self {
+        self.token_store = Some(Arc::new(token_store));
+        self
+    }
+
+    /// Returns the current refresh token
+    pub fn refresh_token(&self) -> String {
+        self.read_tokens().refresh_token.clone()
+    }
+
+    fn read_tokens(&self) -> std::sync::RwLockReadGuard<'_, Tokens> {
+        self.tokens.read().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl fmt::Debug for RefreshingTokenProvider {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RefreshingTokenProvider")
+            .field("oauth_client", &self.oauth_client)
+            .finish_non_exhaustive()
+    }
+}
+
+#[async_trait]
+impl TokenProvider for RefreshingTokenProvider {
+    async fn access_token(&self) -> Result<String, AuthError> {
+        Ok(self.read_tokens().access_token.clone())
+    }
+
+    /// Refreshes the access token unless another request already did
+    ///
+    /// If `expired_token` has been replaced in the meantime the refresh is
+    /// skipped, since Fitbit refresh tokens can only be used once.
+    async fn refresh(&self, expired_token: &str) -> Result<bool, AuthError> {
+        let _guard = self.refresh_lock.lock().await;
+
+        let refresh_token = {
+            let tokens = self.read_tokens();
+            if tokens.access_token != expired_token {
+                return Ok(true);
+            }
+            tokens.refresh_token.clone()
+        };
+
+        let token = self.oauth_client.refresh_token(&refresh_token).await?;
+
+        {
+            let mut tokens = self.tokens.write().unwrap_or_else(|e| e.into_inner());
+            tokens.access_token = token.access_token.clone();
+            tokens.refresh_token = token.refresh_token.clone();
+        }
+
+        if let Some(token_store) = &self.token_store {
+            if let Err(e) = token_store.save(&token).await {
+                tracing::warn!("Failed to persist refreshed token: {}", e);
+            }
+        }
+
+        Ok(true)
+    }
+}
+
+/// Opens `url` in the default browser
+fn open_browser(url: &str) -> std::io::Result<()> {
+    let mut command = if cfg!(target_os = "macos") {
+        Command::new("open")
+    } else if cfg!(windows) {
+        let mut command = Command::new("rundll32");
+        command.arg("url.dll,FileProtocolHandler");
+        command
+    } else {
+        Command::new("xdg-open")
+    };
+    command
+        .arg(url)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .map(|_| ())
+}
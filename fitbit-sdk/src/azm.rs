@@ -0,0 +1,257 @@
+//! Active Zone Minutes API
+//!
+//! This module contains the implementations for the Fitbit Active Zone Minutes (AZM) API endpoints.
+//! It provides functionality for retrieving daily and intraday Active Zone Minutes.
+
+use crate::client::FitbitClient;
+use crate::types::azm::{
+    AzmClient, AzmError, AzmIntraday, AzmIntradayResponse, AzmTimeSeries, AzmTimeSeriesResponse,
+};
+use crate::types::intraday::{DetailLevel, intraday_path_suffix};
+use crate::types::user::UserId;
+use async_trait::async_trait;
+
+#[async_trait]
+impl AzmClient for FitbitClient {
+    /// Gets Active Zone Minutes time series data
+    ///
+    /// Retrieves the daily Active Zone Minutes, broken down by fat burn, cardio
+    /// and peak zones, over a period.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The user ID to get Active Zone Minutes for, or "-" for current user
+    /// * `date` - The end date in format YYYY-MM-DD, or "today"
+    /// * `period` - The period (1d, 7d, 30d, 1w, 1m, 3m, 6m, 1y)
+    ///
+    /// # Returns
+    ///
+    /// Returns one entry per day with Active Zone Minutes on success.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AzmError` if:
+    /// - The request fails to send
+    /// - The API returns an error response
+    /// - The response cannot be parsed
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use fitbit_sdk::client::FitbitClient;
+    /// use fitbit_sdk::types::azm::{AzmClient, AzmError};
+    /// use tokio;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), AzmError> {
+    ///     let client = FitbitClient::new::<AzmError>()?;
+    ///
+    ///     for day in client.get_azm_time_series("-", "today", "7d").await? {
+    ///         println!(
+    ///             "{}: {} AZM (fat burn {}, cardio {}, peak {})",
+    ///             day.date_time,
+    ///             day.value.active_zone_minutes,
+    ///             day.value.fat_burn_active_zone_minutes,
+    ///             day.value.cardio_active_zone_minutes,
+    ///             day.value.peak_active_zone_minutes
+    ///         );
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    async fn get_azm_time_series<'a>(
+        &'a self,
+        user_id: impl Into<UserId> + Send,
+        date: &'a str,
+        period: &'a str,
+    ) -> Result<Vec<AzmTimeSeries>, AzmError> {
+        let user_id: UserId = user_id.into();
+        let path = format!(
+            "/user/{}/activities/active-zone-minutes/date/{}/{}.json",
+            user_id, date, period
+        );
+        let response: AzmTimeSeriesResponse = self.get::<_, (), AzmError>(&path, None).await?;
+        Ok(response.activities_active_zone_minutes)
+    }
+
+    /// Gets Active Zone Minutes time series data for a date range
+    ///
+    /// Retrieves the daily Active Zone Minutes between two dates (max 1095 days).
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The user ID to get Active Zone Minutes for, or "-" for current user
+    /// * `start_date` - The start date in format YYYY-MM-DD
+    /// * `end_date` - The end date in format YYYY-MM-DD, or "today"
+    ///
+    /// # Returns
+    ///
+    /// Returns one entry per day with Active Zone Minutes on success.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AzmError` if:
+    /// - The request fails to send
+    /// - The API returns an error response
+    /// - The response cannot be parsed
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use fitbit_sdk::client::FitbitClient;
+    /// use fitbit_sdk::types::azm::{AzmClient, AzmError};
+    /// use tokio;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), AzmError> {
+    ///     let client = FitbitClient::new::<AzmError>()?;
+    ///
+    ///     let days = client
+    ///         .get_azm_time_series_range("-", "2025-01-01", "2025-01-31")
+    ///         .await?;
+    ///     let total: i32 = days.iter().map(|day| day.value.active_zone_minutes).sum();
+    ///     println!("{} Active Zone Minutes in January", total);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    async fn get_azm_time_series_range<'a>(
+        &'a self,
+        user_id: impl Into<UserId> + Send,
+        start_date: &'a str,
+        end_date: &'a str,
+    ) -> Result<Vec<AzmTimeSeries>, AzmError> {
+        let user_id: UserId = user_id.into();
+        let path = format!(
+            "/user/{}/activities/active-zone-minutes/date/{}/{}.json",
+            user_id, start_date, end_date
+        );
+        let response: AzmTimeSeriesResponse = self.get::<_, (), AzmError>(&path, None).await?;
+        Ok(response.activities_active_zone_minutes)
+    }
+
+    /// Gets intraday Active Zone Minutes
+    ///
+    /// Retrieves the Active Zone Minutes for a single day at the requested
+    /// granularity. `DetailLevel::OneSecond` is not supported for this resource.
+    /// Requires access to the Fitbit Intraday API.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The user ID to get Active Zone Minutes for, or "-" for current user
+    /// * `date` - The date in format YYYY-MM-DD, or "today"
+    /// * `detail_level` - The granularity of the data points
+    ///
+    /// # Returns
+    ///
+    /// Returns the Active Zone Minutes per interval on success.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AzmError` if:
+    /// - The request fails to send
+    /// - The API returns an error response
+    /// - The response cannot be parsed
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use fitbit_sdk::client::FitbitClient;
+    /// use fitbit_sdk::types::azm::{AzmClient, AzmError};
+    /// use fitbit_sdk::types::intraday::DetailLevel;
+    /// use tokio;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), AzmError> {
+    ///     let client = FitbitClient::new::<AzmError>()?;
+    ///
+    ///     for day in client.get_azm_intraday("-", "today", DetailLevel::OneMinute).await? {
+    ///         for minute in &day.minutes {
+    ///             println!("{}: {} AZM", minute.minute, minute.value.active_zone_minutes);
+    ///         }
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    async fn get_azm_intraday<'a>(
+        &'a self,
+        user_id: impl Into<UserId> + Send,
+        date: &'a str,
+        detail_level: DetailLevel,
+    ) -> Result<Vec<AzmIntraday>, AzmError> {
+        let user_id: UserId = user_id.into();
+        let path = format!(
+            "/user/{}/activities/active-zone-minutes/date/{}/{}",
+            user_id,
+            date,
+            intraday_path_suffix(detail_level, None)
+        );
+        let response: AzmIntradayResponse = self.get::<_, (), AzmError>(&path, None).await?;
+        Ok(response.activities_active_zone_minutes_intraday)
+    }
+
+    /// Gets intraday Active Zone Minutes for a time window
+    ///
+    /// Retrieves the Active Zone Minutes between two times of a single day at the
+    /// requested granularity. Requires access to the Fitbit Intraday API.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The user ID to get Active Zone Minutes for, or "-" for current user
+    /// * `date` - The date in format YYYY-MM-DD, or "today"
+    /// * `detail_level` - The granularity of the data points
+    /// * `start_time` - The start of the window in format HH:mm
+    /// * `end_time` - The end of the window in format HH:mm
+    ///
+    /// # Returns
+    ///
+    /// Returns the Active Zone Minutes per interval on success.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AzmError` if:
+    /// - The request fails to send
+    /// - The API returns an error response
+    /// - The response cannot be parsed
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use fitbit_sdk::client::FitbitClient;
+    /// use fitbit_sdk::types::azm::{AzmClient, AzmError};
+    /// use fitbit_sdk::types::intraday::DetailLevel;
+    /// use tokio;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), AzmError> {
+    ///     let client = FitbitClient::new::<AzmError>()?;
+    ///
+    ///     let days = client
+    ///         .get_azm_intraday_window("-", "today", DetailLevel::FiveMinutes, "07:00", "08:00")
+    ///         .await?;
+    ///     println!("{} days of intraday AZM", days.len());
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    async fn get_azm_intraday_window<'a>(
+        &'a self,
+        user_id: impl Into<UserId> + Send,
+        date: &'a str,
+        detail_level: DetailLevel,
+        start_time: &'a str,
+        end_time: &'a str,
+    ) -> Result<Vec<AzmIntraday>, AzmError> {
+        let user_id: UserId = user_id.into();
+        let path = format!(
+            "/user/{}/activities/active-zone-minutes/date/{}/{}",
+            user_id,
+            date,
+            intraday_path_suffix(detail_level, Some((start_time, end_time)))
+        );
+        let response: AzmIntradayResponse = self.get::<_, (), AzmError>(&path, None).await?;
+        Ok(response.activities_active_zone_minutes_intraday)
+    }
+}
@@ -0,0 +1,791 @@
+//! Blocking Fitbit API client
+//!
+//! This module provides a synchronous `FitbitClient` for scripts and command
+//! line tools that do not want to set up an async runtime. It implements the
+//! same domain traits as the async client, with every method blocking the
+//! current thread until the response has been received.
+//!
+//! Like `reqwest::blocking`, the client drives the async client on a private
+//! runtime, so it must not be used from within an async context.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use fitbit_sdk::blocking::{FitbitClient, SleepClient, UserClient};
+//! use fitbit_sdk::types::date::FitbitDate;
+//! use fitbit_sdk::types::user::UserError;
+//!
+//! fn main() -> Result<(), Box<dyn std::error::Error>> {
+//!     let client = FitbitClient::new::<UserError>()?;
+//!
+//!     let profile = client.get_profile("-")?;
+//!     println!("User: {}", profile.display_name);
+//!
+//!     let sleep = client.get_sleep_logs("-", FitbitDate::Today)?;
+//!     println!("Minutes asleep: {}", sleep.summary.total_minutes_asleep);
+//!
+//!     Ok(())
+//! }
+//! ```
+
+use crate::client::{FitbitClient as AsyncFitbitClient, FitbitClientBuilder};
+#[cfg(feature = "activity")]
+use crate::types::activity::{
+    ActivityCategory, ActivityError, ActivityGoals, ActivityGoalsParams, ActivityLifetimeStats,
+    ActivityLog, ActivityLogEntry, ActivityLogList, ActivityRangeSummary, ActivitySummary,
+    ActivityTimeSeries, ActivityType, DailyActivityResponse, DetailedLifetimeStats, Elevation,
+    FavoriteActivity, FrequentActivity, GoalPeriod, IntradayActivitySeries, LogActivityParams,
+    Resource,
+};
+#[cfg(feature = "azm")]
+use crate::types::azm::{AzmError, AzmIntraday, AzmTimeSeries};
+#[cfg(feature = "body")]
+use crate::types::body::{
+    Bmi, BodyError, BodyFat, BodyResource, BodyTimeSeries, BodyWeight, FatGoal, LogBodyFatParams,
+    LogWeightParams, WeightGoal,
+};
+#[cfg(feature = "breathing-rate")]
+use crate::types::breathing_rate::{BreathingRateError, BreathingRateIntraday, BreathingRateSummary};
+#[cfg(feature = "cardio-fitness")]
+use crate::types::cardio_fitness::{CardioFitnessError, CardioFitnessScore};
+#[cfg(any(feature = "activity", feature = "sleep", feature = "body", feature = "nutrition"))]
+use crate::types::date::FitbitDate;
+use crate::types::error::{ClientBuildError, FitbitError};
+#[cfg(feature = "heart-rate")]
+use crate::types::heart_rate::{
+    HeartRateError, HeartRateTimeSeries, HeartRateZones, IntradayHeartRateSeries,
+    IntradayZoneTime, RestingHeartRate, ZoneSelection,
+};
+#[cfg(feature = "hrv")]
+use crate::types::hrv::{HrvError, HrvIntraday, HrvSummary};
+#[cfg(any(feature = "activity", feature = "heart-rate", feature = "azm"))]
+use crate::types::intraday::DetailLevel;
+#[cfg(feature = "nutrition")]
+use crate::types::nutrition::{
+    EditFoodLogParams, FoodEntry, FoodLog, LogFoodParams, LogWaterParams, NutritionError,
+    NutritionResource, NutritionTimeSeries, WaterEntry, WaterGoal, WaterLog,
+};
+#[cfg(feature = "activity")]
+use crate::types::pagination::ListParams;
+use crate::types::rate_limit::RateLimitStatus;
+use crate::types::response::ResponseMeta;
+use crate::types::request::{Body, RequestOptions};
+#[cfg(feature = "sleep")]
+use crate::types::sleep::{
+    LogSleepParams, SleepEntry, SleepError, SleepGoalResponse, SleepListParams,
+    SleepLog, SleepLogList,
+};
+#[cfg(feature = "subscriptions")]
+use crate::types::subscriptions::{CollectionType, Subscription, SubscriptionError};
+#[cfg(all(feature = "activity", feature = "tcx"))]
+use crate::types::tcx::Tcx;
+#[cfg(feature = "temperature")]
+use crate::types::temperature::{CoreTemperature, SkinTemperature, TemperatureError};
+#[cfg(feature = "user")]
+use crate::types::user::{Badge, UpdateProfileParams, UserError, UserProfile};
+#[cfg(feature = "wellness")]
+use crate::types::wellness::{RecoveryDay, WellnessError};
+#[cfg(any(
+    feature = "user",
+    feature = "activity",
+    feature = "sleep",
+    feature = "body",
+    feature = "nutrition",
+    feature = "heart-rate",
+    feature = "hrv",
+    feature = "breathing-rate",
+    feature = "temperature",
+    feature = "cardio-fitness",
+    feature = "azm",
+    feature = "subscriptions",
+))]
+use crate::types::user::UserId;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use std::error::Error as StdError;
+use std::fmt;
+use std::future::Future;
+use std::sync::Arc;
+#[cfg(feature = "activity")]
+use std::time::Duration;
+#[cfg(any(feature = "activity", feature = "heart-rate", feature = "wellness"))]
+use time::Date;
+#[cfg(feature = "activity")]
+use time::Time;
+use tokio::runtime::{Builder as RuntimeBuilder, Runtime};
+
+/// Blocking Fitbit API client
+///
+/// Wraps an async `FitbitClient` and the runtime driving it. Clones share
+/// the runtime, the token provider and the rate limit status.
+///
+/// # Examples
+///
+/// ```no_run
+/// use fitbit_sdk::blocking::FitbitClient;
+/// use fitbit_sdk::types::user::UserError;
+///
+/// # fn example() -> Result<(), UserError> {
+/// // Basic usage
+/// let client = FitbitClient::new::<UserError>()?;
+///
+/// // Using the builder pattern
+/// let client_with_token = FitbitClient::builder()
+///     .with_access_token("your-access-token")
+///     .build_blocking::<UserError>()?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct FitbitClient {
+    /// The async client sending the requests
+    inner: AsyncFitbitClient,
+    /// The runtime driving the async client, shared between clones
+    runtime: Arc<Runtime>,
+}
+
+impl fmt::Debug for FitbitClient {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FitbitClient")
+            .field("inner", &self.inner)
+            .finish_non_exhaustive()
+    }
+}
+
+impl FitbitClientBuilder {
+    /// Builds a blocking FitbitClient with the specified configuration
+    pub fn build_blocking<E>(self) -> Result<FitbitClient, E>
+    where
+        E: StdError + From<String> + From<ClientBuildError>,
+    {
+        let client = self.build()?;
+        FitbitClient::from_client(client)
+    }
+}
+
+impl FitbitClient {
+    /// Creates a new FitbitClient builder
+    ///
+    /// Finish the builder with `build_blocking` to get a blocking client.
+    pub fn builder() -> FitbitClientBuilder {
+        FitbitClientBuilder::new()
+    }
+
+    /// Creates a new blocking Fitbit API client configured from the environment
+    ///
+    /// See `FitbitClientBuilder::from_env` for the environment variables read.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The access token is not available in the environment
+    /// - An environment variable has an invalid value
+    /// - The HTTP client or the runtime cannot be initialized
+    pub fn new<E>() -> Result<Self, E>
+    where
+        E: StdError + From<String> + From<ClientBuildError>,
+    {
+        FitbitClientBuilder::from_env()?.build_blocking()
+    }
+
+    /// Creates a blocking client sending its requests with `client`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the runtime cannot be initialized.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fitbit_sdk::blocking::{FitbitClient, UserClient};
+    /// use fitbit_sdk::client::FitbitClient as AsyncFitbitClient;
+    /// use fitbit_sdk::transport::MockTransport;
+    /// use fitbit_sdk::types::user::UserError;
+    /// use reqwest::Method;
+    ///
+    /// # fn main() -> Result<(), UserError> {
+    /// let mock = MockTransport::new().with_json(
+    ///     Method::GET,
+    ///     "/1/user/-/badges.json",
+    ///     r#"{"badges": []}"#,
+    /// );
+    /// let client = FitbitClient::from_client::<UserError>(
+    ///     AsyncFitbitClient::builder()
+    ///         .with_access_token("test-token")
+    ///         .with_transport(mock)
+    ///         .build()?,
+    /// )?;
+    ///
+    /// assert!(client.get_badges("-")?.is_empty());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_client<E>(client: AsyncFitbitClient) -> Result<Self, E>
+    where
+        E: StdError + From<String>,
+    {
+        let runtime = RuntimeBuilder::new_current_thread()
+            .enable_all()
+            .build()
+            .map_err(|e| E::from(format!("Failed to create runtime: {}", e)))?;
+        Ok(Self {
+            inner: client,
+            runtime: Arc::new(runtime),
+        })
+    }
+
+    /// Returns the async client sending the requests
+    pub fn inner(&self) -> &AsyncFitbitClient {
+        &self.inner
+    }
+
+    /// Returns the rate limit status reported by the most recent response
+    pub fn rate_limit_status(&self) -> Option<RateLimitStatus> {
+        self.inner.rate_limit_status()
+    }
+
+    /// Returns the metadata of the most recent response
+    pub fn last_response_meta(&self) -> Option<ResponseMeta> {
+        self.inner.last_response_meta()
+    }
+
+    /// Returns a client that applies `options` to every request it sends
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a header name or value in `options` is not valid.
+    pub fn with_options<E>(&self, options: RequestOptions) -> Result<FitbitClient, E>
+    where
+        E: StdError + From<String>,
+    {
+        Ok(Self {
+            inner: self.inner.with_options::<E>(options)?,
+            runtime: self.runtime.clone(),
+        })
+    }
+
+    /// Returns a client sending its requests on behalf of the user of `access_token`
+    ///
+    /// See `fitbit_sdk::client::FitbitClient::as_user`.
+    pub fn as_user(&self, access_token: impl Into<String>) -> FitbitClient {
+        Self {
+            inner: self.inner.as_user(access_token),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Sends a request to any Fitbit API endpoint
+    ///
+    /// See `fitbit_sdk::client::FitbitClient::request`.
+    pub fn request<T, Q, B>(
+        &self,
+        method: reqwest::Method,
+        path: &str,
+        query: Option<&Q>,
+        body: Option<Body<'_, B>>,
+    ) -> Result<T, FitbitError>
+    where
+        T: DeserializeOwned,
+        Q: Serialize + ?Sized,
+        B: Serialize + ?Sized,
+    {
+        self.block_on(self.inner.request(method, path, query, body))
+    }
+
+    /// Runs `future` to completion on the runtime of this client
+    fn block_on<F: Future>(&self, future: F) -> F::Output {
+        self.runtime.block_on(future)
+    }
+}
+
+/// Declares a blocking domain trait mirroring an async one and implements it
+/// for the blocking client by running the async method to completion
+#[cfg(any(
+    feature = "user",
+    feature = "activity",
+    feature = "sleep",
+    feature = "body",
+    feature = "nutrition",
+    feature = "heart-rate",
+    feature = "hrv",
+    feature = "breathing-rate",
+    feature = "temperature",
+    feature = "cardio-fitness",
+    feature = "azm",
+    feature = "subscriptions",
+))]
+macro_rules! blocking_client {
+    (
+        $(#[$trait_attr:meta])*
+        pub trait $name:ident: $async_trait:path {
+            $(
+                $(#[$attr:meta])*
+                fn $method:ident(&self $(, $arg:ident: $ty:ty)* $(,)?) -> $ret:ty;
+            )*
+        }
+    ) => {
+        $(#[$trait_attr])*
+        pub trait $name {
+            $(
+                $(#[$attr])*
+                #[doc = concat!("Blocking version of `", stringify!($async_trait), "::", stringify!($method), "`")]
+                fn $method(&self $(, $arg: $ty)*) -> $ret;
+            )*
+        }
+
+        impl $name for FitbitClient {
+            $(
+                $(#[$attr])*
+                fn $method(&self $(, $arg: $ty)*) -> $ret {
+                    self.block_on(<AsyncFitbitClient as $async_trait>::$method(&self.inner $(, $arg)*))
+                }
+            )*
+        }
+    };
+}
+
+#[cfg(feature = "user")]
+blocking_client! {
+    /// Blocking version of the user endpoints
+    pub trait UserClient: crate::types::user::UserClient {
+        fn get_profile(&self, user_id: impl Into<UserId> + Send) -> Result<UserProfile, UserError>;
+        fn update_profile(&self, params: &UpdateProfileParams) -> Result<UserProfile, UserError>;
+        fn get_badges(&self, user_id: impl Into<UserId> + Send) -> Result<Vec<Badge>, UserError>;
+    }
+}
+
+#[cfg(feature = "activity")]
+blocking_client! {
+    /// Blocking version of the activity endpoints
+    pub trait ActivityClient: crate::types::activity::ActivityClient {
+        fn get_activity_summary(
+            &self,
+            user_id: impl Into<UserId> + Send,
+            date: impl Into<FitbitDate> + Send,
+        ) -> Result<ActivitySummary, ActivityError>;
+        fn get_activity_daily(
+            &self,
+            user_id: impl Into<UserId> + Send,
+            date: impl Into<FitbitDate> + Send,
+        ) -> Result<DailyActivityResponse, ActivityError>;
+        fn get_activity_summary_range(
+            &self,
+            user_id: impl Into<UserId> + Send,
+            start_date: Date,
+            end_date: Date,
+        ) -> Result<ActivityRangeSummary, ActivityError>;
+        fn get_activity_time_series(
+            &self,
+            user_id: impl Into<UserId> + Send,
+            resource: Resource,
+            date: impl Into<FitbitDate> + Send,
+            period: &str,
+        ) -> Result<Vec<ActivityTimeSeries>, ActivityError>;
+        fn get_activity_time_series_range(
+            &self,
+            user_id: impl Into<UserId> + Send,
+            resource: Resource,
+            start_date: impl Into<FitbitDate> + Send,
+            end_date: impl Into<FitbitDate> + Send,
+        ) -> Result<Vec<ActivityTimeSeries>, ActivityError>;
+        fn get_elevation_time_series(
+            &self,
+            user_id: impl Into<UserId> + Send,
+            date: impl Into<FitbitDate> + Send,
+            period: &str,
+            tracker_only: bool,
+        ) -> Result<Vec<Elevation>, ActivityError>;
+        fn get_activity_intraday(
+            &self,
+            user_id: impl Into<UserId> + Send,
+            resource: Resource,
+            date: impl Into<FitbitDate> + Send,
+            detail_level: DetailLevel,
+        ) -> Result<IntradayActivitySeries, ActivityError>;
+        fn get_activity_intraday_window(
+            &self,
+            user_id: impl Into<UserId> + Send,
+            resource: Resource,
+            date: impl Into<FitbitDate> + Send,
+            detail_level: DetailLevel,
+            start_time: Time,
+            end_time: Time,
+        ) -> Result<IntradayActivitySeries, ActivityError>;
+        fn get_lifetime_stats(&self, user_id: impl Into<UserId> + Send) -> Result<ActivityLifetimeStats, ActivityError>;
+        fn get_lifetime_stats_detailed(
+            &self,
+            user_id: impl Into<UserId> + Send,
+        ) -> Result<DetailedLifetimeStats, ActivityError>;
+        fn log_activity(
+            &self,
+            user_id: impl Into<UserId> + Send,
+            params: &LogActivityParams,
+        ) -> Result<ActivityLog, ActivityError>;
+        fn quick_log_activity(
+            &self,
+            activity_id: i64,
+            date: Date,
+            start_time: Time,
+            duration: Duration,
+        ) -> Result<ActivityLog, ActivityError>;
+        fn delete_activity_log(&self, user_id: impl Into<UserId> + Send, log_id: i64) -> Result<(), ActivityError>;
+        fn get_activity_log_list(
+            &self,
+            user_id: impl Into<UserId> + Send,
+            params: &ListParams,
+        ) -> Result<ActivityLogList, ActivityError>;
+        fn get_all_activity_log_list(
+            &self,
+            user_id: impl Into<UserId> + Send,
+            params: &ListParams,
+        ) -> Result<Vec<ActivityLogEntry>, ActivityError>;
+        fn get_activity_tcx(&self, user_id: impl Into<UserId> + Send, log_id: i64) -> Result<String, ActivityError>;
+        #[cfg(feature = "tcx")]
+        fn get_activity_tcx_parsed(&self, user_id: impl Into<UserId> + Send, log_id: i64) -> Result<Tcx, ActivityError>;
+        fn get_activity_goals(
+            &self,
+            user_id: impl Into<UserId> + Send,
+            period: GoalPeriod,
+        ) -> Result<ActivityGoals, ActivityError>;
+        fn update_activity_goals(
+            &self,
+            user_id: impl Into<UserId> + Send,
+            period: GoalPeriod,
+            params: &ActivityGoalsParams,
+        ) -> Result<ActivityGoals, ActivityError>;
+        fn get_favorite_activities(&self, user_id: impl Into<UserId> + Send) -> Result<Vec<FavoriteActivity>, ActivityError>;
+        fn get_frequent_activities(&self, user_id: impl Into<UserId> + Send) -> Result<Vec<FrequentActivity>, ActivityError>;
+        fn get_recent_activities(&self, user_id: impl Into<UserId> + Send) -> Result<Vec<FrequentActivity>, ActivityError>;
+        fn add_favorite_activity(&self, user_id: impl Into<UserId> + Send, activity_id: i64) -> Result<(), ActivityError>;
+        fn delete_favorite_activity(&self, user_id: impl Into<UserId> + Send, activity_id: i64) -> Result<(), ActivityError>;
+        fn browse_activity_types(&self) -> Result<Vec<ActivityCategory>, ActivityError>;
+        fn get_activity_type(&self, activity_id: i64) -> Result<ActivityType, ActivityError>;
+    }
+}
+
+#[cfg(feature = "sleep")]
+blocking_client! {
+    /// Blocking version of the sleep endpoints
+    pub trait SleepClient: crate::types::sleep::SleepClient {
+        fn get_sleep_logs(&self, user_id: impl Into<UserId> + Send, date: impl Into<FitbitDate> + Send) -> Result<SleepLog, SleepError>;
+        fn get_sleep_goal(&self, user_id: impl Into<UserId> + Send) -> Result<SleepGoalResponse, SleepError>;
+        fn update_sleep_goal(
+            &self,
+            user_id: impl Into<UserId> + Send,
+            min_duration: i32,
+        ) -> Result<SleepGoalResponse, SleepError>;
+        fn log_sleep(&self, user_id: impl Into<UserId> + Send, params: &LogSleepParams) -> Result<SleepEntry, SleepError>;
+        fn delete_sleep_log(&self, user_id: impl Into<UserId> + Send, log_id: i64) -> Result<(), SleepError>;
+        fn get_sleep_log_list(
+            &self,
+            user_id: impl Into<UserId> + Send,
+            params: &SleepListParams,
+        ) -> Result<SleepLogList, SleepError>;
+        fn get_all_sleep_log_list(
+            &self,
+            user_id: impl Into<UserId> + Send,
+            params: &SleepListParams,
+        ) -> Result<Vec<SleepEntry>, SleepError>;
+    }
+}
+
+#[cfg(feature = "body")]
+blocking_client! {
+    /// Blocking version of the body endpoints
+    pub trait BodyClient: crate::types::body::BodyClient {
+        fn get_body_weight(&self, user_id: impl Into<UserId> + Send, date: impl Into<FitbitDate> + Send) -> Result<Vec<BodyWeight>, BodyError>;
+        fn get_body_fat(&self, user_id: impl Into<UserId> + Send, date: impl Into<FitbitDate> + Send) -> Result<Vec<BodyFat>, BodyError>;
+        fn get_body_weight_range(
+            &self,
+            user_id: impl Into<UserId> + Send,
+            start_date: impl Into<FitbitDate> + Send,
+            end_date: impl Into<FitbitDate> + Send,
+        ) -> Result<Vec<BodyWeight>, BodyError>;
+        fn get_body_weight_period(
+            &self,
+            user_id: impl Into<UserId> + Send,
+            date: impl Into<FitbitDate> + Send,
+            period: &str,
+        ) -> Result<Vec<BodyWeight>, BodyError>;
+        fn get_body_fat_range(
+            &self,
+            user_id: impl Into<UserId> + Send,
+            start_date: impl Into<FitbitDate> + Send,
+            end_date: impl Into<FitbitDate> + Send,
+        ) -> Result<Vec<BodyFat>, BodyError>;
+        fn get_body_fat_period(
+            &self,
+            user_id: impl Into<UserId> + Send,
+            date: impl Into<FitbitDate> + Send,
+            period: &str,
+        ) -> Result<Vec<BodyFat>, BodyError>;
+        fn get_weight_goal(&self, user_id: impl Into<UserId> + Send) -> Result<WeightGoal, BodyError>;
+        fn get_fat_goal(&self, user_id: impl Into<UserId> + Send) -> Result<FatGoal, BodyError>;
+        fn log_body_weight(&self, user_id: impl Into<UserId> + Send, params: &LogWeightParams) -> Result<BodyWeight, BodyError>;
+        fn delete_body_weight_log(&self, user_id: impl Into<UserId> + Send, log_id: i64) -> Result<(), BodyError>;
+        fn log_body_fat(&self, user_id: impl Into<UserId> + Send, params: &LogBodyFatParams) -> Result<BodyFat, BodyError>;
+        fn delete_body_fat_log(&self, user_id: impl Into<UserId> + Send, log_id: i64) -> Result<(), BodyError>;
+        fn get_body_time_series(
+            &self,
+            user_id: impl Into<UserId> + Send,
+            resource: BodyResource,
+            date: impl Into<FitbitDate> + Send,
+            period: &str,
+        ) -> Result<Vec<BodyTimeSeries>, BodyError>;
+        fn get_bmi(
+            &self,
+            user_id: impl Into<UserId> + Send,
+            date: impl Into<FitbitDate> + Send,
+        ) -> Result<Option<Bmi>, BodyError>;
+    }
+}
+
+#[cfg(feature = "nutrition")]
+blocking_client! {
+    /// Blocking version of the nutrition endpoints
+    pub trait NutritionClient: crate::types::nutrition::NutritionClient {
+        fn get_water_logs(&self, user_id: impl Into<UserId> + Send, date: impl Into<FitbitDate> + Send) -> Result<WaterLog, NutritionError>;
+        fn get_food_logs(&self, user_id: impl Into<UserId> + Send, date: impl Into<FitbitDate> + Send) -> Result<FoodLog, NutritionError>;
+        fn log_water(&self, user_id: impl Into<UserId> + Send, params: &LogWaterParams) -> Result<WaterEntry, NutritionError>;
+        fn delete_water_log(&self, user_id: impl Into<UserId> + Send, log_id: i64) -> Result<(), NutritionError>;
+        fn get_water_goal(&self, user_id: impl Into<UserId> + Send) -> Result<WaterGoal, NutritionError>;
+        fn update_water_goal(&self, user_id: impl Into<UserId> + Send, target: f64) -> Result<WaterGoal, NutritionError>;
+        fn log_food(&self, user_id: impl Into<UserId> + Send, params: &LogFoodParams) -> Result<FoodEntry, NutritionError>;
+        fn edit_food_log(
+            &self,
+            user_id: impl Into<UserId> + Send,
+            log_id: i64,
+            params: &EditFoodLogParams,
+        ) -> Result<FoodEntry, NutritionError>;
+        fn delete_food_log(&self, user_id: impl Into<UserId> + Send, log_id: i64) -> Result<(), NutritionError>;
+        fn get_nutrition_time_series(
+            &self,
+            user_id: impl Into<UserId> + Send,
+            resource: NutritionResource,
+            date: impl Into<FitbitDate> + Send,
+            period: &str,
+        ) -> Result<Vec<NutritionTimeSeries>, NutritionError>;
+    }
+}
+
+#[cfg(feature = "heart-rate")]
+blocking_client! {
+    /// Blocking version of the heart rate endpoints
+    pub trait HeartRateClient: crate::types::heart_rate::HeartRateClient {
+        fn get_heart_rate_time_series(
+            &self,
+            user_id: impl Into<UserId> + Send,
+            date: &str,
+            period: &str,
+        ) -> Result<Vec<HeartRateTimeSeries>, HeartRateError>;
+        fn get_heart_rate_time_series_range(
+            &self,
+            user_id: impl Into<UserId> + Send,
+            start_date: &str,
+            end_date: &str,
+        ) -> Result<Vec<HeartRateTimeSeries>, HeartRateError>;
+        fn get_resting_heart_rate(
+            &self,
+            user_id: impl Into<UserId> + Send,
+            date: &str,
+            period: &str,
+        ) -> Result<Vec<RestingHeartRate>, HeartRateError>;
+        fn get_custom_heart_rate_zones(
+            &self,
+            user_id: impl Into<UserId> + Send,
+            date: &str,
+        ) -> Result<Option<HeartRateZones>, HeartRateError>;
+        fn get_heart_rate_intraday(
+            &self,
+            user_id: impl Into<UserId> + Send,
+            date: &str,
+            detail_level: DetailLevel,
+        ) -> Result<IntradayHeartRateSeries, HeartRateError>;
+        fn get_heart_rate_intraday_window(
+            &self,
+            user_id: impl Into<UserId> + Send,
+            date: &str,
+            detail_level: DetailLevel,
+            start_time: &str,
+            end_time: &str,
+        ) -> Result<IntradayHeartRateSeries, HeartRateError>;
+        fn get_heart_rate_time_in_zones(
+            &self,
+            user_id: impl Into<UserId> + Send,
+            start_date: Date,
+            end_date: Date,
+            detail_level: DetailLevel,
+            zones: &ZoneSelection,
+            max_gap: time::Duration,
+        ) -> Result<IntradayZoneTime, HeartRateError>;
+    }
+}
+
+#[cfg(feature = "hrv")]
+blocking_client! {
+    /// Blocking version of the heart rate variability endpoints
+    pub trait HrvClient: crate::types::hrv::HrvClient {
+        fn get_hrv_summary(&self, user_id: impl Into<UserId> + Send, date: &str) -> Result<Vec<HrvSummary>, HrvError>;
+        fn get_hrv_summary_range(
+            &self,
+            user_id: impl Into<UserId> + Send,
+            start_date: &str,
+            end_date: &str,
+        ) -> Result<Vec<HrvSummary>, HrvError>;
+        fn get_hrv_intraday(&self, user_id: impl Into<UserId> + Send, date: &str) -> Result<Vec<HrvIntraday>, HrvError>;
+        fn get_hrv_intraday_range(
+            &self,
+            user_id: impl Into<UserId> + Send,
+            start_date: &str,
+            end_date: &str,
+        ) -> Result<Vec<HrvIntraday>, HrvError>;
+    }
+}
+
+#[cfg(feature = "breathing-rate")]
+blocking_client! {
+    /// Blocking version of the breathing rate endpoints
+    pub trait BreathingRateClient: crate::types::breathing_rate::BreathingRateClient {
+        fn get_breathing_rate_summary(
+            &self,
+            user_id: impl Into<UserId> + Send,
+            date: &str,
+        ) -> Result<Vec<BreathingRateSummary>, BreathingRateError>;
+        fn get_breathing_rate_summary_range(
+            &self,
+            user_id: impl Into<UserId> + Send,
+            start_date: &str,
+            end_date: &str,
+        ) -> Result<Vec<BreathingRateSummary>, BreathingRateError>;
+        fn get_breathing_rate_intraday(
+            &self,
+            user_id: impl Into<UserId> + Send,
+            date: &str,
+        ) -> Result<Vec<BreathingRateIntraday>, BreathingRateError>;
+        fn get_breathing_rate_intraday_range(
+            &self,
+            user_id: impl Into<UserId> + Send,
+            start_date: &str,
+            end_date: &str,
+        ) -> Result<Vec<BreathingRateIntraday>, BreathingRateError>;
+    }
+}
+
+#[cfg(feature = "temperature")]
+blocking_client! {
+    /// Blocking version of the temperature endpoints
+    pub trait TemperatureClient: crate::types::temperature::TemperatureClient {
+        fn get_skin_temperature(
+            &self,
+            user_id: impl Into<UserId> + Send,
+            date: &str,
+        ) -> Result<Vec<SkinTemperature>, TemperatureError>;
+        fn get_skin_temperature_range(
+            &self,
+            user_id: impl Into<UserId> + Send,
+            start_date: &str,
+            end_date: &str,
+        ) -> Result<Vec<SkinTemperature>, TemperatureError>;
+        fn get_core_temperature(
+            &self,
+            user_id: impl Into<UserId> + Send,
+            date: &str,
+        ) -> Result<Vec<CoreTemperature>, TemperatureError>;
+        fn get_core_temperature_range(
+            &self,
+            user_id: impl Into<UserId> + Send,
+            start_date: &str,
+            end_date: &str,
+        ) -> Result<Vec<CoreTemperature>, TemperatureError>;
+    }
+}
+
+#[cfg(feature = "cardio-fitness")]
+blocking_client! {
+    /// Blocking version of the cardio fitness score endpoints
+    pub trait CardioFitnessClient: crate::types::cardio_fitness::CardioFitnessClient {
+        fn get_cardio_fitness_score(
+            &self,
+            user_id: impl Into<UserId> + Send,
+            date: &str,
+        ) -> Result<Vec<CardioFitnessScore>, CardioFitnessError>;
+        fn get_cardio_fitness_score_range(
+            &self,
+            user_id: impl Into<UserId> + Send,
+            start_date: &str,
+            end_date: &str,
+        ) -> Result<Vec<CardioFitnessScore>, CardioFitnessError>;
+    }
+}
+
+#[cfg(feature = "azm")]
+blocking_client! {
+    /// Blocking version of the active zone minutes endpoints
+    pub trait AzmClient: crate::types::azm::AzmClient {
+        fn get_azm_time_series(
+            &self,
+            user_id: impl Into<UserId> + Send,
+            date: &str,
+            period: &str,
+        ) -> Result<Vec<AzmTimeSeries>, AzmError>;
+        fn get_azm_time_series_range(
+            &self,
+            user_id: impl Into<UserId> + Send,
+            start_date: &str,
+            end_date: &str,
+        ) -> Result<Vec<AzmTimeSeries>, AzmError>;
+        fn get_azm_intraday(
+            &self,
+            user_id: impl Into<UserId> + Send,
+            date: &str,
+            detail_level: DetailLevel,
+        ) -> Result<Vec<AzmIntraday>, AzmError>;
+        fn get_azm_intraday_window(
+            &self,
+            user_id: impl Into<UserId> + Send,
+            date: &str,
+            detail_level: DetailLevel,
+            start_time: &str,
+            end_time: &str,
+        ) -> Result<Vec<AzmIntraday>, AzmError>;
+    }
+}
+
+#[cfg(feature = "subscriptions")]
+blocking_client! {
+    /// Blocking version of the subscription endpoints
+    pub trait SubscriptionClient: crate::types::subscriptions::SubscriptionClient {
+        fn create_subscription(
+            &self,
+            user_id: impl Into<UserId> + Send,
+            collection: Option<CollectionType>,
+            subscription_id: &str,
+            subscriber_id: Option<&str>,
+        ) -> Result<Subscription, SubscriptionError>;
+        fn list_subscriptions(
+            &self,
+            user_id: impl Into<UserId> + Send,
+            collection: Option<CollectionType>,
+        ) -> Result<Vec<Subscription>, SubscriptionError>;
+        fn delete_subscription(
+            &self,
+            user_id: impl Into<UserId> + Send,
+            collection: Option<CollectionType>,
+            subscription_id: &str,
+            subscriber_id: Option<&str>,
+        ) -> Result<(), SubscriptionError>;
+    }
+}
+
+#[cfg(feature = "wellness")]
+blocking_client! {
+    /// Blocking version of the wellness reports
+    pub trait WellnessClient: crate::types::wellness::WellnessClient {
+        fn get_recovery_report(
+            &self,
+            user_id: impl Into<UserId> + Send,
+            start_date: Date,
+            end_date: Date,
+        ) -> Result<Vec<RecoveryDay>, WellnessError>;
+    }
+}
@@ -1,13 +1,19 @@
 //! Body API
 //!
 //! This module contains the implementations for the Fitbit Body API endpoints.
-//! It provides functionality for retrieving body measurements and goals.
+//! It provides functionality for retrieving and logging body measurements and goals.
 
 use crate::client::FitbitClient;
 use crate::types::body::{
-    BodyClient, BodyError, BodyWeight, BodyFat, BodyGoals, WeightLogResponse, BodyFatResponse, BodyGoalsResponse,
+    BodyClient, BodyError, BodyWeight, BodyFat, FatGoal, FatGoalResponse, WeightLogResponse, BodyFatResponse,
+    BodyResource, BodyTimeSeries, LogBodyFatParams, LogBodyFatResponse, LogWeightParams, LogWeightResponse,
+    WeightGoal, WeightGoalResponse, Bmi,
 };
+use crate::types::date::FitbitDate;
+use crate::types::request::Body;
+use crate::types::user::UserId;
 use async_trait::async_trait;
+use reqwest::Method;
 
 #[async_trait]
 impl BodyClient for FitbitClient {
@@ -18,7 +24,7 @@ impl BodyClient for FitbitClient {
     /// # Arguments
     ///
     /// * `user_id` - The user ID to get weight for, or "-" for current user
-    /// * `date` - The date in format YYYY-MM-DD
+    /// * `date` - The date
     ///
     /// # Returns
     ///
@@ -36,6 +42,7 @@ impl BodyClient for FitbitClient {
     /// ```no_run
     /// use fitbit_sdk::client::FitbitClient;
     /// use fitbit_sdk::types::body::{BodyClient, BodyError};
+    /// use fitbit_sdk::types::date::FitbitDate;
     /// use tokio;
     ///
     /// #[tokio::main]
@@ -43,7 +50,7 @@ impl BodyClient for FitbitClient {
     ///     let client = FitbitClient::new::<BodyError>()?;
     ///
     ///     // Get today's weight data
-    ///     let weights = client.get_body_weight("-", "today").await?;
+    ///     let weights = client.get_body_weight("-", FitbitDate::Today).await?;
     ///     if let Some(weight) = weights.first() {
     ///         println!("Weight: {} {}", weight.weight, if weight.weight_in_kg.is_some() { "kg" } else { "lbs" });
     ///     }
@@ -53,11 +60,13 @@ impl BodyClient for FitbitClient {
     /// ```
     async fn get_body_weight<'a>(
         &'a self,
-        user_id: &'a str,
-        date: &'a str,
+        user_id: impl Into<UserId> + Send,
+        date: impl Into<FitbitDate> + Send,
     ) -> Result<Vec<BodyWeight>, BodyError> {
+        let user_id: UserId = user_id.into();
+        let date: FitbitDate = date.into();
         let path = format!("/user/{}/body/log/weight/date/{}.json", user_id, date);
-        let response: WeightLogResponse = self.get(&path, Option::<&()>::None).await?;
+        let response: WeightLogResponse = self.get::<_, (), BodyError>(&path, None).await?;
         Ok(response.weight)
     }
 
@@ -68,7 +77,7 @@ impl BodyClient for FitbitClient {
     /// # Arguments
     ///
     /// * `user_id` - The user ID to get body fat for, or "-" for current user
-    /// * `date` - The date in format YYYY-MM-DD
+    /// * `date` - The date
     ///
     /// # Returns
     ///
@@ -86,6 +95,7 @@ impl BodyClient for FitbitClient {
     /// ```no_run
     /// use fitbit_sdk::client::FitbitClient;
     /// use fitbit_sdk::types::body::{BodyClient, BodyError};
+    /// use fitbit_sdk::types::date::FitbitDate;
     /// use tokio;
     ///
     /// #[tokio::main]
@@ -93,7 +103,7 @@ impl BodyClient for FitbitClient {
     ///     let client = FitbitClient::new::<BodyError>()?;
     ///
     ///     // Get today's body fat data
-    ///     let fat_logs = client.get_body_fat("-", "today").await?;
+    ///     let fat_logs = client.get_body_fat("-", FitbitDate::Today).await?;
     ///     if let Some(fat) = fat_logs.first() {
     ///         println!("Body fat: {}%", fat.fat);
     ///     }
@@ -103,25 +113,29 @@ impl BodyClient for FitbitClient {
     /// ```
     async fn get_body_fat<'a>(
         &'a self,
-        user_id: &'a str,
-        date: &'a str,
+        user_id: impl Into<UserId> + Send,
+        date: impl Into<FitbitDate> + Send,
     ) -> Result<Vec<BodyFat>, BodyError> {
+        let user_id: UserId = user_id.into();
+        let date: FitbitDate = date.into();
         let path = format!("/user/{}/body/log/fat/date/{}.json", user_id, date);
-        let response: BodyFatResponse = self.get(&path, Option::<&()>::None).await?;
+        let response: BodyFatResponse = self.get::<_, (), BodyError>(&path, None).await?;
         Ok(response.fat)
     }
 
-    /// Gets the user's body goals
+    /// Gets the user's body weight logs for a date range
     ///
-    /// Retrieves a user's current weight and body fat percentage goals.
+    /// Retrieves all of the user's body weight log entries between two dates.
     ///
     /// # Arguments
     ///
-    /// * `user_id` - The user ID to get body goals for, or "-" for current user
+    /// * `user_id` - The user ID to get body weight for, or "-" for current user
+    /// * `start_date` - The start date
+    /// * `end_date` - The end date (max 31 days after the start date)
     ///
     /// # Returns
     ///
-    /// Returns the body goals on success.
+    /// Returns the body weight logs in the range on success.
     ///
     /// # Errors
     ///
@@ -135,22 +149,603 @@ impl BodyClient for FitbitClient {
     /// ```no_run
     /// use fitbit_sdk::client::FitbitClient;
     /// use fitbit_sdk::types::body::{BodyClient, BodyError};
+    /// use time::macros::date;
     /// use tokio;
     ///
     /// #[tokio::main]
     /// async fn main() -> Result<(), BodyError> {
     ///     let client = FitbitClient::new::<BodyError>()?;
     ///
-    ///     // Get body goals
-    ///     let goals = client.get_body_goals("-").await?;
-    ///     println!("Weight goal: {} {}", goals.goal.weight, goals.goal.weight_unit);
+    ///     let entries = client
+    ///         .get_body_weight_range("-", date!(2025 - 01 - 01), date!(2025 - 01 - 31))
+    ///         .await?;
+    ///     for entry in entries {
+    ///         println!("{} {}: {}", entry.date, entry.time, entry.weight);
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    async fn get_body_weight_range<'a>(
+        &'a self,
+        user_id: impl Into<UserId> + Send,
+        start_date: impl Into<FitbitDate> + Send,
+        end_date: impl Into<FitbitDate> + Send,
+    ) -> Result<Vec<BodyWeight>, BodyError> {
+        let user_id: UserId = user_id.into();
+        let start_date: FitbitDate = start_date.into();
+        let end_date: FitbitDate = end_date.into();
+        let path = format!("/user/{}/body/log/weight/date/{}/{}.json", user_id, start_date, end_date);
+        let response: WeightLogResponse = self.get::<_, (), BodyError>(&path, None).await?;
+        Ok(response.weight)
+    }
+
+    /// Gets the user's body weight logs for a period
+    ///
+    /// Retrieves all of the user's body weight log entries for a period ending on a date.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The user ID to get body weight for, or "-" for current user
+    /// * `date` - The end date
+    /// * `period` - The period (1d, 7d, 1w, 1m)
+    ///
+    /// # Returns
+    ///
+    /// Returns the body weight logs in the range on success.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `BodyError` if:
+    /// - The request fails to send
+    /// - The API returns an error response
+    /// - The response cannot be parsed
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use fitbit_sdk::client::FitbitClient;
+    /// use fitbit_sdk::types::body::{BodyClient, BodyError};
+    /// use fitbit_sdk::types::date::FitbitDate;
+    /// use tokio;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), BodyError> {
+    ///     let client = FitbitClient::new::<BodyError>()?;
+    ///
+    ///     for entry in client.get_body_weight_period("-", FitbitDate::Today, "1w").await? {
+    ///         println!("{} {}: {}", entry.date, entry.time, entry.weight);
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    async fn get_body_weight_period<'a>(
+        &'a self,
+        user_id: impl Into<UserId> + Send,
+        date: impl Into<FitbitDate> + Send,
+        period: &'a str,
+    ) -> Result<Vec<BodyWeight>, BodyError> {
+        let user_id: UserId = user_id.into();
+        let date: FitbitDate = date.into();
+        let path = format!("/user/{}/body/log/weight/date/{}/{}.json", user_id, date, period);
+        let response: WeightLogResponse = self.get::<_, (), BodyError>(&path, None).await?;
+        Ok(response.weight)
+    }
+
+    /// Gets the user's body fat logs for a date range
+    ///
+    /// Retrieves all of the user's body fat log entries between two dates.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The user ID to get body fat for, or "-" for current user
+    /// * `start_date` - The start date
+    /// * `end_date` - The end date (max 31 days after the start date)
+    ///
+    /// # Returns
+    ///
+    /// Returns the body fat logs in the range on success.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `BodyError` if:
+    /// - The request fails to send
+    /// - The API returns an error response
+    /// - The response cannot be parsed
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use fitbit_sdk::client::FitbitClient;
+    /// use fitbit_sdk::types::body::{BodyClient, BodyError};
+    /// use time::macros::date;
+    /// use tokio;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), BodyError> {
+    ///     let client = FitbitClient::new::<BodyError>()?;
+    ///
+    ///     let entries = client
+    ///         .get_body_fat_range("-", date!(2025 - 01 - 01), date!(2025 - 01 - 31))
+    ///         .await?;
+    ///     for entry in entries {
+    ///         println!("{} {}: {}%", entry.date, entry.time, entry.fat);
+    ///     }
     ///
     ///     Ok(())
     /// }
     /// ```
-    async fn get_body_goals<'a>(&'a self, user_id: &'a str) -> Result<BodyGoals, BodyError> {
-        let path = format!("/user/{}/body/goals.json", user_id);
-        let response: BodyGoalsResponse = self.get(&path, Option::<&()>::None).await?;
+    async fn get_body_fat_range<'a>(
+        &'a self,
+        user_id: impl Into<UserId> + Send,
+        start_date: impl Into<FitbitDate> + Send,
+        end_date: impl Into<FitbitDate> + Send,
+    ) -> Result<Vec<BodyFat>, BodyError> {
+        let user_id: UserId = user_id.into();
+        let start_date: FitbitDate = start_date.into();
+        let end_date: FitbitDate = end_date.into();
+        let path = format!("/user/{}/body/log/fat/date/{}/{}.json", user_id, start_date, end_date);
+        let response: BodyFatResponse = self.get::<_, (), BodyError>(&path, None).await?;
+        Ok(response.fat)
+    }
+
+    /// Gets the user's body fat logs for a period
+    ///
+    /// Retrieves all of the user's body fat log entries for a period ending on a date.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The user ID to get body fat for, or "-" for current user
+    /// * `date` - The end date
+    /// * `period` - The period (1d, 7d, 1w, 1m)
+    ///
+    /// # Returns
+    ///
+    /// Returns the body fat logs in the range on success.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `BodyError` if:
+    /// - The request fails to send
+    /// - The API returns an error response
+    /// - The response cannot be parsed
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use fitbit_sdk::client::FitbitClient;
+    /// use fitbit_sdk::types::body::{BodyClient, BodyError};
+    /// use fitbit_sdk::types::date::FitbitDate;
+    /// use tokio;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), BodyError> {
+    ///     let client = FitbitClient::new::<BodyError>()?;
+    ///
+    ///     for entry in client.get_body_fat_period("-", FitbitDate::Today, "1w").await? {
+    ///         println!("{} {}: {}%", entry.date, entry.time, entry.fat);
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    async fn get_body_fat_period<'a>(
+        &'a self,
+        user_id: impl Into<UserId> + Send,
+        date: impl Into<FitbitDate> + Send,
+        period: &'a str,
+    ) -> Result<Vec<BodyFat>, BodyError> {
+        let user_id: UserId = user_id.into();
+        let date: FitbitDate = date.into();
+        let path = format!("/user/{}/body/log/fat/date/{}/{}.json", user_id, date, period);
+        let response: BodyFatResponse = self.get::<_, (), BodyError>(&path, None).await?;
+        Ok(response.fat)
+    }
+
+    /// Gets the user's weight goal
+    ///
+    /// Retrieves the user's target weight, with the weight and date the goal
+    /// was set at.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The user ID to get the weight goal for, or "-" for current user
+    ///
+    /// # Returns
+    ///
+    /// Returns the weight goal on success.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `BodyError` if:
+    /// - The request fails to send
+    /// - The API returns an error response
+    /// - The response cannot be parsed
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use fitbit_sdk::client::FitbitClient;
+    /// use fitbit_sdk::types::body::{BodyClient, BodyError};
+    /// use fitbit_sdk::types::date::FitbitDate;
+    /// use tokio;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), BodyError> {
+    ///     let client = FitbitClient::new::<BodyError>()?;
+    ///
+    ///     let goal = client.get_weight_goal("-").await?;
+    ///     println!("Weight goal: {} (started at {})", goal.weight, goal.start_weight);
+    ///
+    ///     // Compare with the latest weight
+    ///     if let Some(entry) = client.get_body_weight("-", FitbitDate::Today).await?.last() {
+    ///         println!("Progress: {:?}%", goal.progress(entry.weight));
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    async fn get_weight_goal<'a>(&'a self, user_id: impl Into<UserId> + Send) -> Result<WeightGoal, BodyError> {
+        let user_id: UserId = user_id.into();
+        let path = format!("/user/{}/body/log/weight/goal.json", user_id);
+        let response: WeightGoalResponse = self.get::<_, (), BodyError>(&path, None).await?;
+        Ok(response.goal)
+    }
+
+    /// Gets the user's body fat goal
+    ///
+    /// Retrieves the user's target body fat percentage.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The user ID to get the body fat goal for, or "-" for current user
+    ///
+    /// # Returns
+    ///
+    /// Returns the body fat goal on success.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `BodyError` if:
+    /// - The request fails to send
+    /// - The API returns an error response
+    /// - The response cannot be parsed
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use fitbit_sdk::client::FitbitClient;
+    /// use fitbit_sdk::types::body::{BodyClient, BodyError};
+    /// use tokio;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), BodyError> {
+    ///     let client = FitbitClient::new::<BodyError>()?;
+    ///
+    ///     let goal = client.get_fat_goal("-").await?;
+    ///     println!("Body fat goal: {}%", goal.fat);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    async fn get_fat_goal<'a>(&'a self, user_id: impl Into<UserId> + Send) -> Result<FatGoal, BodyError> {
+        let user_id: UserId = user_id.into();
+        let path = format!("/user/{}/body/log/fat/goal.json", user_id);
+        let response: FatGoalResponse = self.get::<_, (), BodyError>(&path, None).await?;
         Ok(response.goal)
     }
+
+    /// Logs body weight
+    ///
+    /// Creates a body weight log entry for the user.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The user ID to log body weight for, or "-" for current user
+    /// * `params` - The weight value, date and time of the measurement
+    ///
+    /// # Returns
+    ///
+    /// Returns the created log entry on success.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `BodyError` if:
+    /// - The request fails to send
+    /// - The API returns an error response
+    /// - The response cannot be parsed
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use fitbit_sdk::client::FitbitClient;
+    /// use fitbit_sdk::types::body::{BodyClient, BodyError, LogWeightParams};
+    /// use tokio;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), BodyError> {
+    ///     let client = FitbitClient::new::<BodyError>()?;
+    ///
+    ///     let params = LogWeightParams::new(72.5, "2025-01-15").with_time("07:30:00");
+    ///     let entry = client.log_body_weight("-", &params).await?;
+    ///     println!("Logged weight {}: {}", entry.log_id, entry.weight);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    async fn log_body_weight<'a>(&'a self, user_id: impl Into<UserId> + Send, params: &'a LogWeightParams) -> Result<BodyWeight, BodyError> {
+        let user_id: UserId = user_id.into();
+        let path = format!("/user/{}/body/log/weight.json", user_id);
+        let response: LogWeightResponse = self
+            .send_request::<_, (), _, BodyError>(Method::POST, &path, None, Some(Body::Form(params)))
+            .await?;
+        Ok(response.weight_log)
+    }
+
+    /// Deletes a body weight log entry
+    ///
+    /// Removes a body weight log entry from the user's body log.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The user ID to delete the entry for, or "-" for current user
+    /// * `log_id` - The ID of the body weight log entry
+    ///
+    /// # Returns
+    ///
+    /// Returns `()` on success.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `BodyError` if:
+    /// - The request fails to send
+    /// - The API returns an error response
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use fitbit_sdk::client::FitbitClient;
+    /// use fitbit_sdk::types::body::{BodyClient, BodyError};
+    /// use tokio;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), BodyError> {
+    ///     let client = FitbitClient::new::<BodyError>()?;
+    ///
+    ///     client.delete_body_weight_log("-", 123456789).await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    async fn delete_body_weight_log<'a>(&'a self, user_id: impl Into<UserId> + Send, log_id: i64) -> Result<(), BodyError> {
+        let user_id: UserId = user_id.into();
+        let path = format!("/user/{}/body/log/weight/{}.json", user_id, log_id);
+        self.delete::<_, (), BodyError>(&path, None).await
+    }
+
+    /// Logs body fat
+    ///
+    /// Creates a body fat log entry for the user.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The user ID to log body fat for, or "-" for current user
+    /// * `params` - The fat value, date and time of the measurement
+    ///
+    /// # Returns
+    ///
+    /// Returns the created log entry on success.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `BodyError` if:
+    /// - The request fails to send
+    /// - The API returns an error response
+    /// - The response cannot be parsed
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use fitbit_sdk::client::FitbitClient;
+    /// use fitbit_sdk::types::body::{BodyClient, BodyError, LogBodyFatParams};
+    /// use tokio;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), BodyError> {
+    ///     let client = FitbitClient::new::<BodyError>()?;
+    ///
+    ///     let params = LogBodyFatParams::new(18.4, "2025-01-15").with_time("07:30:00");
+    ///     let entry = client.log_body_fat("-", &params).await?;
+    ///     println!("Logged body fat {}: {}%", entry.log_id, entry.fat);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    async fn log_body_fat<'a>(&'a self, user_id: impl Into<UserId> + Send, params: &'a LogBodyFatParams) -> Result<BodyFat, BodyError> {
+        let user_id: UserId = user_id.into();
+        let path = format!("/user/{}/body/log/fat.json", user_id);
+        let response: LogBodyFatResponse = self
+            .send_request::<_, (), _, BodyError>(Method::POST, &path, None, Some(Body::Form(params)))
+            .await?;
+        Ok(response.fat_log)
+    }
+
+    /// Deletes a body fat log entry
+    ///
+    /// Removes a body fat log entry from the user's body log.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The user ID to delete the entry for, or "-" for current user
+    /// * `log_id` - The ID of the body fat log entry
+    ///
+    /// # Returns
+    ///
+    /// Returns `()` on success.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `BodyError` if:
+    /// - The request fails to send
+    /// - The API returns an error response
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use fitbit_sdk::client::FitbitClient;
+    /// use fitbit_sdk::types::body::{BodyClient, BodyError};
+    /// use tokio;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), BodyError> {
+    ///     let client = FitbitClient::new::<BodyError>()?;
+    ///
+    ///     client.delete_body_fat_log("-", 123456789).await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    async fn delete_body_fat_log<'a>(&'a self, user_id: impl Into<UserId> + Send, log_id: i64) -> Result<(), BodyError> {
+        let user_id: UserId = user_id.into();
+        let path = format!("/user/{}/body/log/fat/{}.json", user_id, log_id);
+        self.delete::<_, (), BodyError>(&path, None).await
+    }
+
+    /// Gets body time series data
+    ///
+    /// Retrieves the daily BMI, body fat or weight over a period. Unlike the log
+    /// endpoints, which only return logged entries, the time series has one value
+    /// per day, carrying the last known value forward on days without a log.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The user ID to get body time series for, or "-" for current user
+    /// * `resource` - The resource type (BMI, fat or weight)
+    /// * `date` - The end date
+    /// * `period` - The period (1d, 7d, 30d, 1w, 1m, 3m, 6m, 1y, max)
+    ///
+    /// # Returns
+    ///
+    /// Returns one data point per day on success.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `BodyError` if:
+    /// - The request fails to send
+    /// - The API returns an error response
+    /// - The response cannot be parsed
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use fitbit_sdk::client::FitbitClient;
+    /// use fitbit_sdk::types::body::{BodyClient, BodyError, BodyResource};
+    /// use fitbit_sdk::types::date::FitbitDate;
+    /// use tokio;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), BodyError> {
+    ///     let client = FitbitClient::new::<BodyError>()?;
+    ///
+    ///     // Get last 30 days of weight data
+    ///     let weights = client
+    ///         .get_body_time_series("-", BodyResource::Weight, FitbitDate::Today, "30d")
+    ///         .await?;
+    ///
+    ///     for data_point in &weights {
+    ///         println!("{}: {}", data_point.date_time, data_point.value);
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    async fn get_body_time_series<'a>(
+        &'a self,
+        user_id: impl Into<UserId> + Send,
+        resource: BodyResource,
+        date: impl Into<FitbitDate> + Send,
+        period: &'a str,
+    ) -> Result<Vec<BodyTimeSeries>, BodyError> {
+        let user_id: UserId = user_id.into();
+        let date: FitbitDate = date.into();
+        let path = format!(
+            "/user/{}/body/{}/date/{}/{}.json",
+            user_id,
+            resource.as_str(),
+            date,
+            period
+        );
+        let mut response: serde_json::Value = self.get::<_, (), BodyError>(&path, None).await?;
+
+        let key = resource.response_key();
+        let time_series = response
+            .get_mut(&key)
+            .map(serde_json::Value::take)
+            .ok_or_else(|| BodyError::from(format!("Missing key '{}' in response", key)))?;
+        serde_json::from_value(time_series).map_err(|e| BodyError::from(e.to_string()))
+    }
+
+    /// Gets the BMI of a day
+    ///
+    /// Retrieves the BMI from the body time series, which carries the last
+    /// known value forward on days without a weight log.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The user ID to get the BMI for, or "-" for current user
+    /// * `date` - The date to get the BMI for
+    ///
+    /// # Returns
+    ///
+    /// Returns the BMI on success, or `None` if the user has not logged a
+    /// weight up to the date.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `BodyError` if:
+    /// - The request fails to send
+    /// - The API returns an error response
+    /// - The response cannot be parsed
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use fitbit_sdk::client::FitbitClient;
+    /// use fitbit_sdk::types::body::{Bmi, BodyClient, BodyError};
+    /// use fitbit_sdk::types::datetime::{ApiDate, ApiField};
+    /// use time::macros::date;
+    /// use tokio;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), BodyError> {
+    ///     let client = FitbitClient::new::<BodyError>()?;
+    ///
+    ///     let bmi = match client.get_bmi("-", date!(2025 - 01 - 15)).await? {
+    ///         Some(bmi) => Some(bmi),
+    ///         // Fall back to a weight and height known from elsewhere
+    ///         None => Bmi::compute(ApiDate::from_api_str("2025-01-15")?, 72.4, 176.5),
+    ///     };
+    ///     if let Some(bmi) = bmi {
+    ///         println!("BMI on {}: {:.1} ({:?})", bmi.date, bmi.value, bmi.category());
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    async fn get_bmi<'a>(
+        &'a self,
+        user_id: impl Into<UserId> + Send,
+        date: impl Into<FitbitDate> + Send,
+    ) -> Result<Option<Bmi>, BodyError> {
+        let series = self.get_body_time_series(user_id, BodyResource::Bmi, date, "1d").await?;
+        Ok(series
+            .into_iter()
+            .last()
+            .filter(|point| point.value > 0.0)
+            .map(|point| Bmi {
+                date: point.date_time,
+                value: point.value,
+            }))
+    }
 }
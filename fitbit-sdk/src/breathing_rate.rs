@@ -0,0 +1,225 @@
+//! Breathing Rate API
+//!
+//! This module contains the implementations for the Fitbit Breathing Rate API endpoints.
+//! It provides functionality for retrieving the breathing rate measured during sleep.
+
+use crate::client::FitbitClient;
+use crate::types::breathing_rate::{
+    BreathingRateClient, BreathingRateError, BreathingRateIntraday, BreathingRateIntradayResponse,
+    BreathingRateSummary, BreathingRateSummaryResponse,
+};
+use crate::types::user::UserId;
+use async_trait::async_trait;
+
+#[async_trait]
+impl BreathingRateClient for FitbitClient {
+    /// Gets the breathing rate summary for a specific date
+    ///
+    /// Retrieves the average breathing rate during the user's main sleep.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The user ID to get breathing rate for, or "-" for current user
+    /// * `date` - The date in format YYYY-MM-DD, or "today"
+    ///
+    /// # Returns
+    ///
+    /// Returns the breathing rate summary on success, or an empty list if none was recorded.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `BreathingRateError` if:
+    /// - The request fails to send
+    /// - The API returns an error response
+    /// - The response cannot be parsed
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use fitbit_sdk::client::FitbitClient;
+    /// use fitbit_sdk::types::breathing_rate::{BreathingRateClient, BreathingRateError};
+    /// use tokio;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), BreathingRateError> {
+    ///     let client = FitbitClient::new::<BreathingRateError>()?;
+    ///
+    ///     for br in client.get_breathing_rate_summary("-", "today").await? {
+    ///         println!("{}: {} breaths/min", br.date_time, br.value.breathing_rate);
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    async fn get_breathing_rate_summary<'a>(
+        &'a self,
+        user_id: impl Into<UserId> + Send,
+        date: &'a str,
+    ) -> Result<Vec<BreathingRateSummary>, BreathingRateError> {
+        let user_id: UserId = user_id.into();
+        let path = format!("/user/{}/br/date/{}.json", user_id, date);
+        let response: BreathingRateSummaryResponse =
+            self.get::<_, (), BreathingRateError>(&path, None).await?;
+        Ok(response.br)
+    }
+
+    /// Gets the breathing rate summaries for a date range
+    ///
+    /// Retrieves the average breathing rate for each day in the range (max 30 days).
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The user ID to get breathing rate for, or "-" for current user
+    /// * `start_date` - The start date in format YYYY-MM-DD
+    /// * `end_date` - The end date in format YYYY-MM-DD, or "today"
+    ///
+    /// # Returns
+    ///
+    /// Returns one summary per day with breathing rate data on success.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `BreathingRateError` if:
+    /// - The request fails to send
+    /// - The API returns an error response
+    /// - The response cannot be parsed
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use fitbit_sdk::client::FitbitClient;
+    /// use fitbit_sdk::types::breathing_rate::{BreathingRateClient, BreathingRateError};
+    /// use tokio;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), BreathingRateError> {
+    ///     let client = FitbitClient::new::<BreathingRateError>()?;
+    ///
+    ///     let days = client
+    ///         .get_breathing_rate_summary_range("-", "2025-01-01", "2025-01-30")
+    ///         .await?;
+    ///     println!("{} days of breathing rate data", days.len());
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    async fn get_breathing_rate_summary_range<'a>(
+        &'a self,
+        user_id: impl Into<UserId> + Send,
+        start_date: &'a str,
+        end_date: &'a str,
+    ) -> Result<Vec<BreathingRateSummary>, BreathingRateError> {
+        let user_id: UserId = user_id.into();
+        let path = format!("/user/{}/br/date/{}/{}.json", user_id, start_date, end_date);
+        let response: BreathingRateSummaryResponse =
+            self.get::<_, (), BreathingRateError>(&path, None).await?;
+        Ok(response.br)
+    }
+
+    /// Gets the breathing rate per sleep stage for a specific date
+    ///
+    /// Retrieves the average breathing rate during deep, REM and light sleep, and
+    /// over the full sleep. Requires access to the Fitbit Intraday API.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The user ID to get breathing rate for, or "-" for current user
+    /// * `date` - The date in format YYYY-MM-DD, or "today"
+    ///
+    /// # Returns
+    ///
+    /// Returns the breathing rate per sleep stage on success.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `BreathingRateError` if:
+    /// - The request fails to send
+    /// - The API returns an error response
+    /// - The response cannot be parsed
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use fitbit_sdk::client::FitbitClient;
+    /// use fitbit_sdk::types::breathing_rate::{BreathingRateClient, BreathingRateError};
+    /// use tokio;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), BreathingRateError> {
+    ///     let client = FitbitClient::new::<BreathingRateError>()?;
+    ///
+    ///     for day in client.get_breathing_rate_intraday("-", "today").await? {
+    ///         if let Some(deep) = &day.value.deep_sleep_summary {
+    ///             println!("Deep sleep: {} breaths/min", deep.breathing_rate);
+    ///         }
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    async fn get_breathing_rate_intraday<'a>(
+        &'a self,
+        user_id: impl Into<UserId> + Send,
+        date: &'a str,
+    ) -> Result<Vec<BreathingRateIntraday>, BreathingRateError> {
+        let user_id: UserId = user_id.into();
+        let path = format!("/user/{}/br/date/{}/all.json", user_id, date);
+        let response: BreathingRateIntradayResponse =
+            self.get::<_, (), BreathingRateError>(&path, None).await?;
+        Ok(response.br)
+    }
+
+    /// Gets the breathing rate per sleep stage for a date range
+    ///
+    /// Retrieves the per-stage breathing rate for each day in the range (max 30 days).
+    /// Requires access to the Fitbit Intraday API.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The user ID to get breathing rate for, or "-" for current user
+    /// * `start_date` - The start date in format YYYY-MM-DD
+    /// * `end_date` - The end date in format YYYY-MM-DD, or "today"
+    ///
+    /// # Returns
+    ///
+    /// Returns the breathing rate per sleep stage for each day on success.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `BreathingRateError` if:
+    /// - The request fails to send
+    /// - The API returns an error response
+    /// - The response cannot be parsed
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use fitbit_sdk::client::FitbitClient;
+    /// use fitbit_sdk::types::breathing_rate::{BreathingRateClient, BreathingRateError};
+    /// use tokio;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), BreathingRateError> {
+    ///     let client = FitbitClient::new::<BreathingRateError>()?;
+    ///
+    ///     let days = client
+    ///         .get_breathing_rate_intraday_range("-", "2025-01-01", "2025-01-07")
+    ///         .await?;
+    ///     println!("{} days of breathing rate data", days.len());
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    async fn get_breathing_rate_intraday_range<'a>(
+        &'a self,
+        user_id: impl Into<UserId> + Send,
+        start_date: &'a str,
+        end_date: &'a str,
+    ) -> Result<Vec<BreathingRateIntraday>, BreathingRateError> {
+        let user_id: UserId = user_id.into();
+        let path = format!("/user/{}/br/date/{}/{}/all.json", user_id, start_date, end_date);
+        let response: BreathingRateIntradayResponse =
+            self.get::<_, (), BreathingRateError>(&path, None).await?;
+        Ok(response.br)
+    }
+}
@@ -0,0 +1,129 @@
+//! Response Caches
+//!
+//! This module contains `InMemoryCache`, a least recently used cache of API
+//! responses kept in memory.
+
+use crate::types::cache::{Cache, CachedResponse};
+use async_trait::async_trait;
+use std::collections::{HashMap, VecDeque};
+use std::sync::Mutex;
+
+/// Cache keeping the most recently used responses in memory
+///
+/// When the cache is full, storing a response evicts the least recently used
+/// one. Stale responses are kept until evicted, so they can be revalidated.
+///
+/// # Examples
+///
+/// ```
+/// use fitbit_sdk::cache::InMemoryCache;
+/// use fitbit_sdk::client::FitbitClient;
+/// use fitbit_sdk::transport::MockTransport;
+/// use fitbit_sdk::types::user::{UserClient, UserError};
+/// use reqwest::Method;
+/// use std::sync::Arc;
+/// use std::time::Duration;
+/// use tokio;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), UserError> {
+///     let mock = Arc::new(MockTransport::new().with_json(
+///         Method::GET,
+///         "/1/user/-/badges.json",
+///         r#"{"badges": []}"#,
+///     ));
+///     let client = FitbitClient::builder()
+///         .with_access_token("test-token")
+///         .with_transport(mock.clone())
+///         .with_cache(InMemoryCache::new(100))
+///         .with_cache_ttl(Duration::from_secs(60))
+///         .build()?;
+///
+///     client.get_badges("-").await?;
+///     client.get_badges("-").await?;
+///
+///     // The second request was served from the cache
+///     assert_eq!(mock.requests().len(), 1);
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug)]
+pub struct InMemoryCache {
+    /// Maximum number of responses kept
+    capacity: usize,
+    /// The stored responses, in order of use
+    entries: Mutex<Entries>,
+}
+
+/// Responses of an `InMemoryCache`
+#[derive(Debug, Default)]
+struct Entries {
+    /// Responses keyed by cache key
+    responses: HashMap<String, CachedResponse>,
+    /// Cache keys, least recently used first
+    order: VecDeque<String>,
+}
+
+impl Entries {
+    /// Marks `key` as the most recently used
+    fn touch(&mut self, key: &str) {
+        if let Some(position) = self.order.iter().position(|k| k == key) {
+            self.order.remove(position);
+        }
+        self.order.push_back(key.to_string());
+    }
+}
+
+impl InMemoryCache {
+    /// Creates a cache keeping at most `capacity` responses
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            entries: Mutex::new(Entries::default()),
+        }
+    }
+
+    /// Returns the number of stored responses
+    pub fn len(&self) -> usize {
+        self.entries
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .responses
+            .len()
+    }
+
+    /// Returns true if no response is stored
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+#[async_trait]
+impl Cache for InMemoryCache {
+    async fn get(&self, key: &str) -> Option<CachedResponse> {
+        let mut entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+        let response = entries.responses.get(key).cloned()?;
+        entries.touch(key);
+        Some(response)
+    }
+
+    async fn put(&self, key: &str, response: CachedResponse) {
+        if self.capacity == 0 {
+            return;
+        }
+        let mut entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+        entries.responses.insert(key.to_string(), response);
+        entries.touch(key);
+        while entries.order.len() > self.capacity {
+            if let Some(evicted) = entries.order.pop_front() {
+                entries.responses.remove(&evicted);
+            }
+        }
+    }
+
+    async fn clear(&self) {
+        let mut entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+        entries.responses.clear();
+        entries.order.clear();
+    }
+}
@@ -0,0 +1,122 @@
+//! Cardio Fitness Score API
+//!
+//! This module contains the implementations for the Fitbit Cardio Fitness Score (VO2 Max) API endpoints.
+//! It provides functionality for retrieving the user's estimated VO2 Max.
+
+use crate::client::FitbitClient;
+use crate::types::cardio_fitness::{
+    CardioFitnessClient, CardioFitnessError, CardioFitnessResponse, CardioFitnessScore,
+};
+use crate::types::user::UserId;
+use async_trait::async_trait;
+
+#[async_trait]
+impl CardioFitnessClient for FitbitClient {
+    /// Gets the Cardio Fitness Score for a specific date
+    ///
+    /// Retrieves the user's estimated VO2 Max, either as a single value or a range.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The user ID to get the score for, or "-" for current user
+    /// * `date` - The date in format YYYY-MM-DD, or "today"
+    ///
+    /// # Returns
+    ///
+    /// Returns the Cardio Fitness Score on success, or an empty list if none was calculated.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `CardioFitnessError` if:
+    /// - The request fails to send
+    /// - The API returns an error response
+    /// - The response cannot be parsed
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use fitbit_sdk::client::FitbitClient;
+    /// use fitbit_sdk::types::cardio_fitness::{CardioFitnessClient, CardioFitnessError, Vo2Max};
+    /// use tokio;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), CardioFitnessError> {
+    ///     let client = FitbitClient::new::<CardioFitnessError>()?;
+    ///
+    ///     for score in client.get_cardio_fitness_score("-", "today").await? {
+    ///         match score.value.vo2_max {
+    ///             Vo2Max::Single(value) => println!("VO2 Max: {}", value),
+    ///             Vo2Max::Range(low, high) => println!("VO2 Max: {}-{}", low, high),
+    ///         }
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    async fn get_cardio_fitness_score<'a>(
+        &'a self,
+        user_id: impl Into<UserId> + Send,
+        date: &'a str,
+    ) -> Result<Vec<CardioFitnessScore>, CardioFitnessError> {
+        let user_id: UserId = user_id.into();
+        let path = format!("/user/{}/cardioscore/date/{}.json", user_id, date);
+        let response: CardioFitnessResponse =
+            self.get::<_, (), CardioFitnessError>(&path, None).await?;
+        Ok(response.cardio_score)
+    }
+
+    /// Gets the Cardio Fitness Score for a date range
+    ///
+    /// Retrieves the user's estimated VO2 Max for each day in the range (max 30 days).
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The user ID to get the score for, or "-" for current user
+    /// * `start_date` - The start date in format YYYY-MM-DD
+    /// * `end_date` - The end date in format YYYY-MM-DD, or "today"
+    ///
+    /// # Returns
+    ///
+    /// Returns one score per day on success.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `CardioFitnessError` if:
+    /// - The request fails to send
+    /// - The API returns an error response
+    /// - The response cannot be parsed
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use fitbit_sdk::client::FitbitClient;
+    /// use fitbit_sdk::types::cardio_fitness::{CardioFitnessClient, CardioFitnessError};
+    /// use tokio;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), CardioFitnessError> {
+    ///     let client = FitbitClient::new::<CardioFitnessError>()?;
+    ///
+    ///     let scores = client
+    ///         .get_cardio_fitness_score_range("-", "2025-01-01", "2025-01-30")
+    ///         .await?;
+    ///     for score in &scores {
+    ///         println!("{}: {}", score.date_time, score.value.vo2_max.midpoint());
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    async fn get_cardio_fitness_score_range<'a>(
+        &'a self,
+        user_id: impl Into<UserId> + Send,
+        start_date: &'a str,
+        end_date: &'a str,
+    ) -> Result<Vec<CardioFitnessScore>, CardioFitnessError> {
+        let user_id: UserId = user_id.into();
+        let path = format!("/user/{}/cardioscore/date/{}/{}.json", user_id, start_date, end_date);
+        let response: CardioFitnessResponse =
+            self.get::<_, (), CardioFitnessError>(&path, None).await?;
+        Ok(response.cardio_score)
+    }
+}
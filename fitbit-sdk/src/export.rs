@@ -0,0 +1,273 @@
+//! Data Export
+//!
+//! This module contains functions writing time series, intraday series and
+//! sleep logs as CSV or newline-delimited JSON, for loading into spreadsheets
+//! and data analysis tools.
+//!
+//! CSV output starts with a header row. Time series values are written as
+//! returned by the API, so no precision is lost. JSON output has one object
+//! per line, in the format of the Fitbit API.
+
+use crate::types::datetime::ApiField;
+use crate::types::intraday::IntradayDataset;
+#[cfg(feature = "sleep")]
+use crate::types::sleep::{SleepEntry, SleepLevelSummary};
+use crate::types::time_series::TimeSeriesPoint;
+use serde::Serialize;
+use std::fmt::Display;
+use std::io::{self, Write};
+
+/// Quotes a CSV field if it contains a delimiter, quote or line break
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// Writes a CSV row of `fields`
+fn write_csv_row<W: Write>(writer: &mut W, fields: &[&str]) -> io::Result<()> {
+    let row: Vec<String> = fields.iter().map(|field| csv_field(field)).collect();
+    writeln!(writer, "{}", row.join(","))
+}
+
+/// Writes `value` as a line of JSON
+fn write_json_line<W: Write, T: Serialize>(writer: &mut W, value: &T) -> io::Result<()> {
+    serde_json::to_writer(&mut *writer, value)?;
+    writer.write_all(b"\n")
+}
+
+/// Writes a daily time series as CSV with the columns `date` and `value`
+///
+/// Works with the time series of every domain, e.g. activity, body and
+/// nutrition time series.
+///
+/// # Examples
+///
+/// ```
+/// use fitbit_sdk::export::write_time_series_csv;
+/// use fitbit_sdk::types::activity::ActivityTimeSeries;
+///
+/// let steps: Vec<ActivityTimeSeries> = serde_json::from_str(r#"[
+///     {"dateTime": "2025-01-14", "value": "12045"},
+///     {"dateTime": "2025-01-15", "value": "10234"}
+/// ]"#).unwrap();
+///
+/// let mut csv = Vec::new();
+/// write_time_series_csv(&mut csv, &steps).unwrap();
+/// assert_eq!(String::from_utf8(csv).unwrap(), "date,value\n2025-01-14,12045\n2025-01-15,10234\n");
+/// ```
+pub fn write_time_series_csv<W: Write, T>(
+    mut writer: W,
+    points: &[TimeSeriesPoint<T>],
+) -> io::Result<()> {
+    write_csv_row(&mut writer, &["date", "value"])?;
+    for point in points {
+        write_csv_row(&mut writer, &[&point.date_time.to_api_string(), &point.raw_value])?;
+    }
+    Ok(())
+}
+
+/// Writes a daily time series as newline-delimited JSON
+///
+/// Each line holds a `dateTime` and a `value`, as returned by the API.
+///
+/// # Examples
+///
+/// ```
+/// use fitbit_sdk::export::write_time_series_jsonl;
+/// use fitbit_sdk::types::body::BodyTimeSeries;
+///
+/// let weights: Vec<BodyTimeSeries> =
+///     serde_json::from_str(r#"[{"dateTime": "2025-01-15", "value": "72.5"}]"#).unwrap();
+///
+/// let mut json = Vec::new();
+/// write_time_series_jsonl(&mut json, &weights).unwrap();
+/// assert_eq!(
+///     String::from_utf8(json).unwrap(),
+///     "{\"dateTime\":\"2025-01-15\",\"value\":\"72.5\"}\n"
+/// );
+/// ```
+pub fn write_time_series_jsonl<W: Write, T>(
+    mut writer: W,
+    points: &[TimeSeriesPoint<T>],
+) -> io::Result<()> {
+    for point in points {
+        write_json_line(&mut writer, point)?;
+    }
+    Ok(())
+}
+
+/// Intraday data point with the date it was measured on
+#[derive(Serialize)]
+struct IntradayRow<'a, T> {
+    date: &'a str,
+    time: &'a str,
+    value: &'a T,
+}
+
+/// Writes an intraday series as CSV with the columns `date`, `time` and `value`
+///
+/// Intraday data points only carry a time of day, so the date of the series
+/// is passed as `date`.
+///
+/// # Examples
+///
+/// ```
+/// use fitbit_sdk::export::write_intraday_csv;
+/// use fitbit_sdk::types::intraday::IntradayDataset;
+///
+/// let heart_rate: IntradayDataset<i32> = serde_json::from_str(r#"{
+///     "dataset": [{"time": "08:00:00", "value": 64}, {"time": "08:01:00", "value": 66}],
+///     "datasetInterval": 1,
+///     "datasetType": "minute"
+/// }"#).unwrap();
+///
+/// let mut csv = Vec::new();
+/// write_intraday_csv(&mut csv, "2025-01-15", &heart_rate).unwrap();
+/// assert_eq!(
+///     String::from_utf8(csv).unwrap(),
+///     "date,time,value\n2025-01-15,08:00:00,64\n2025-01-15,08:01:00,66\n"
+/// );
+/// ```
+pub fn write_intraday_csv<W: Write, T: Display>(
+    mut writer: W,
+    date: &str,
+    dataset: &IntradayDataset<T>,
+) -> io::Result<()> {
+    write_csv_row(&mut writer, &["date", "time", "value"])?;
+    for point in &dataset.dataset {
+        write_csv_row(&mut writer, &[date, &point.time.to_api_string(), &point.value.to_string()])?;
+    }
+    Ok(())
+}
+
+/// Writes an intraday series as newline-delimited JSON
+///
+/// Each line holds the `date` of the series and the `time` and `value` of a
+/// data point.
+///
+/// # Examples
+///
+/// ```
+/// use fitbit_sdk::export::write_intraday_jsonl;
+/// use fitbit_sdk::types::intraday::IntradayDataset;
+///
+/// let steps: IntradayDataset<f64> = serde_json::from_str(r#"{
+///     "dataset": [{"time": "08:00:00", "value": 12}],
+///     "datasetInterval": 1,
+///     "datasetType": "minute"
+/// }"#).unwrap();
+///
+/// let mut json = Vec::new();
+/// write_intraday_jsonl(&mut json, "2025-01-15", &steps).unwrap();
+/// assert_eq!(
+///     String::from_utf8(json).unwrap(),
+///     "{\"date\":\"2025-01-15\",\"time\":\"08:00:00\",\"value\":12.0}\n"
+/// );
+/// ```
+pub fn write_intraday_jsonl<W: Write, T: Serialize>(
+    mut writer: W,
+    date: &str,
+    dataset: &IntradayDataset<T>,
+) -> io::Result<()> {
+    for point in &dataset.dataset {
+        write_json_line(
+            &mut writer,
+            &IntradayRow {
+                date,
+                time: &point.time.to_api_string(),
+                value: &point.value,
+            },
+        )?;
+    }
+    Ok(())
+}
+
+/// Columns of the sleep log CSV
+#[cfg(feature = "sleep")]
+const SLEEP_CSV_HEADER: [&str; 14] = [
+    "log_id",
+    "start_time",
+    "end_time",
+    "duration",
+    "minutes_to_fall_asleep",
+    "minutes_asleep",
+    "time_in_bed",
+    "efficiency",
+    "type",
+    "is_main_sleep",
+    "deep_minutes",
+    "light_minutes",
+    "rem_minutes",
+    "wake_minutes",
+];
+
+/// Writes sleep log entries as CSV, one row per entry
+///
+/// The minutes spent in each sleep stage are left empty for entries without
+/// sleep stages (e.g. classic sleep logs).
+///
+/// # Examples
+///
+/// ```
+/// use fitbit_sdk::export::write_sleep_csv;
+/// use fitbit_sdk::types::sleep::SleepEntry;
+///
+/// let entry: SleepEntry = serde_json::from_str(r#"{
+///     "logId": 36543210987, "startTime": "2025-01-14T23:15:30.000",
+///     "endTime": "2025-01-15T06:57:30.000", "duration": 27720000,
+///     "minutesToFallAsleep": 0, "minutesAsleep": 405, "timeInBed": 462,
+///     "efficiency": 92, "type": "classic", "isMainSleep": true
+/// }"#).unwrap();
+///
+/// let mut csv = Vec::new();
+/// write_sleep_csv(&mut csv, &[entry]).unwrap();
+/// let csv = String::from_utf8(csv).unwrap();
+/// assert_eq!(
+///     csv.lines().nth(1).unwrap(),
+///     "36543210987,2025-01-14T23:15:30.000,2025-01-15T06:57:30.000,27720000,0,405,462,92,classic,true,,,,"
+/// );
+/// ```
+#[cfg(feature = "sleep")]
+pub fn write_sleep_csv<W: Write>(mut writer: W, entries: &[SleepEntry]) -> io::Result<()> {
+    write_csv_row(&mut writer, &SLEEP_CSV_HEADER)?;
+    for entry in entries {
+        let summary = entry.levels.as_ref().map(|levels| &levels.summary);
+        let minutes = |level: Option<&SleepLevelSummary>| {
+            level.map(|level| level.minutes.to_string()).unwrap_or_default()
+        };
+        write_csv_row(
+            &mut writer,
+            &[
+                &entry.log_id.to_string(),
+                &entry.start_time.to_api_string(),
+                &entry.end_time.to_api_string(),
+                &entry.duration.to_string(),
+                &entry.minutes_to_fall_asleep.to_string(),
+                &entry.minutes_asleep.to_string(),
+                &entry.time_in_bed.to_string(),
+                &entry.efficiency.to_string(),
+                &entry.type_,
+                &entry.is_main_sleep.to_string(),
+                &minutes(summary.and_then(|summary| summary.deep.as_ref())),
+                &minutes(summary.and_then(|summary| summary.light.as_ref())),
+                &minutes(summary.and_then(|summary| summary.rem.as_ref())),
+                &minutes(summary.and_then(|summary| summary.wake.as_ref())),
+            ],
+        )?;
+    }
+    Ok(())
+}
+
+/// Writes sleep log entries as newline-delimited JSON, one entry per line
+///
+/// Entries are written in the format of the API, including their sleep stages.
+#[cfg(feature = "sleep")]
+pub fn write_sleep_jsonl<W: Write>(mut writer: W, entries: &[SleepEntry]) -> io::Result<()> {
+    for entry in entries {
+        write_json_line(&mut writer, entry)?;
+    }
+    Ok(())
+}
@@ -0,0 +1,517 @@
+//! Heart Rate API
+//!
+//! This module contains the implementations for the Fitbit Heart Rate API endpoints.
+//! It provides functionality for retrieving heart rate zones and resting heart rate.
+
+use crate::analysis::heart::intraday_time_in_zones;
+use crate::client::{ApiVersion, FitbitClient};
+use crate::types::heart_rate::{
+    HeartRateClient, HeartRateError, HeartRateTimeSeries, HeartRateTimeSeriesResponse,
+    HeartRateZones, IntradayHeartRateSeries, IntradayZoneTime, RestingHeartRate, ZoneSelection,
+};
+use crate::types::intraday::{DetailLevel, IntradayDataPoint, intraday_path_suffix};
+use crate::types::user::UserId;
+use async_trait::async_trait;
+use futures_util::stream::{self, BoxStream, StreamExt, TryStreamExt};
+use time::{Date, Duration};
+
+/// Number of intraday days fetched at the same time for a date range
+const INTRADAY_RANGE_CONCURRENCY: usize = 4;
+
+#[async_trait]
+impl HeartRateClient for FitbitClient {
+    /// Gets heart rate time series data
+    ///
+    /// Retrieves the daily heart rate zones and resting heart rate over a period.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The user ID to get heart rate data for, or "-" for current user
+    /// * `date` - The end date in format YYYY-MM-DD, or "today"
+    /// * `period` - The period (1d, 7d, 30d, 1w, 1m)
+    ///
+    /// # Returns
+    ///
+    /// Returns one entry per day on success.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `HeartRateError` if:
+    /// - The request fails to send
+    /// - The API returns an error response
+    /// - The response cannot be parsed
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use fitbit_sdk::client::FitbitClient;
+    /// use fitbit_sdk::types::heart_rate::{HeartRateClient, HeartRateError};
+    /// use tokio;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), HeartRateError> {
+    ///     let client = FitbitClient::new::<HeartRateError>()?;
+    ///
+    ///     // Get the last 7 days of heart rate zones
+    ///     let days = client.get_heart_rate_time_series("-", "today", "7d").await?;
+    ///     for day in &days {
+    ///         for zone in &day.value.heart_rate_zones {
+    ///             println!("{} {}: {:?} minutes", day.date_time, zone.name, zone.minutes);
+    ///         }
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    async fn get_heart_rate_time_series<'a>(
+        &'a self,
+        user_id: impl Into<UserId> + Send,
+        date: &'a str,
+        period: &'a str,
+    ) -> Result<Vec<HeartRateTimeSeries>, HeartRateError> {
+        let user_id: UserId = user_id.into();
+        let path = format!(
+            "/user/{}/activities/heart/date/{}/{}.json",
+            user_id, date, period
+        );
+        let response: HeartRateTimeSeriesResponse =
+            self.get::<_, (), HeartRateError>(&path, None).await?;
+        Ok(response.activities_heart)
+    }
+
+    /// Gets heart rate time series data for a date range
+    ///
+    /// Retrieves the daily heart rate zones and resting heart rate between two dates.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The user ID to get heart rate data for, or "-" for current user
+    /// * `start_date` - The start date in format YYYY-MM-DD
+    /// * `end_date` - The end date in format YYYY-MM-DD, or "today"
+    ///
+    /// # Returns
+    ///
+    /// Returns one entry per day on success.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `HeartRateError` if:
+    /// - The request fails to send
+    /// - The API returns an error response
+    /// - The response cannot be parsed
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use fitbit_sdk::client::FitbitClient;
+    /// use fitbit_sdk::types::heart_rate::{HeartRateClient, HeartRateError};
+    /// use tokio;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), HeartRateError> {
+    ///     let client = FitbitClient::new::<HeartRateError>()?;
+    ///
+    ///     let days = client
+    ///         .get_heart_rate_time_series_range("-", "2025-01-01", "2025-01-31")
+    ///         .await?;
+    ///     println!("{} days of heart rate data", days.len());
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    async fn get_heart_rate_time_series_range<'a>(
+        &'a self,
+        user_id: impl Into<UserId> + Send,
+        start_date: &'a str,
+        end_date: &'a str,
+    ) -> Result<Vec<HeartRateTimeSeries>, HeartRateError> {
+        let user_id: UserId = user_id.into();
+        let path = format!(
+            "/user/{}/activities/heart/date/{}/{}.json",
+            user_id, start_date, end_date
+        );
+        let response: HeartRateTimeSeriesResponse =
+            self.get::<_, (), HeartRateError>(&path, None).await?;
+        Ok(response.activities_heart)
+    }
+
+    /// Gets the resting heart rate over a period
+    ///
+    /// Retrieves the heart rate time series and keeps only the days for which
+    /// Fitbit calculated a resting heart rate.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The user ID to get resting heart rate for, or "-" for current user
+    /// * `date` - The end date in format YYYY-MM-DD, or "today"
+    /// * `period` - The period (1d, 7d, 30d, 1w, 1m)
+    ///
+    /// # Returns
+    ///
+    /// Returns the resting heart rate per day on success.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `HeartRateError` if:
+    /// - The request fails to send
+    /// - The API returns an error response
+    /// - The response cannot be parsed
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use fitbit_sdk::client::FitbitClient;
+    /// use fitbit_sdk::types::heart_rate::{HeartRateClient, HeartRateError};
+    /// use tokio;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), HeartRateError> {
+    ///     let client = FitbitClient::new::<HeartRateError>()?;
+    ///
+    ///     for rhr in client.get_resting_heart_rate("-", "today", "30d").await? {
+    ///         println!("{}: {} bpm", rhr.date, rhr.value);
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    async fn get_resting_heart_rate<'a>(
+        &'a self,
+        user_id: impl Into<UserId> + Send,
+        date: &'a str,
+        period: &'a str,
+    ) -> Result<Vec<RestingHeartRate>, HeartRateError> {
+        let user_id: UserId = user_id.into();
+        let series = self.get_heart_rate_time_series(user_id, date, period).await?;
+        Ok(series
+            .iter()
+            .filter_map(HeartRateTimeSeries::resting_heart_rate)
+            .collect())
+    }
+
+    /// Gets the user's custom heart rate zones
+    ///
+    /// Retrieves the heart rate time series of a day and keeps the boundaries
+    /// of the custom zones the user set in their profile. Use
+    /// `HeartRateZones::zone_for` to map a heart rate to its zone.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The user ID to get the zones for, or "-" for current user
+    /// * `date` - The date in format YYYY-MM-DD, or "today"
+    ///
+    /// # Returns
+    ///
+    /// Returns the custom zones on success, or `None` if the user has not
+    /// set any.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `HeartRateError` if:
+    /// - The request fails to send
+    /// - The API returns an error response
+    /// - The response cannot be parsed
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use fitbit_sdk::client::FitbitClient;
+    /// use fitbit_sdk::types::heart_rate::{HeartRateClient, HeartRateError};
+    /// use tokio;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), HeartRateError> {
+    ///     let client = FitbitClient::new::<HeartRateError>()?;
+    ///
+    ///     if let Some(zones) = client.get_custom_heart_rate_zones("-", "today").await? {
+    ///         if let Some(zone) = zones.zone_for(142) {
+    ///             println!("142 bpm is in {}", zone.name);
+    ///         }
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    async fn get_custom_heart_rate_zones<'a>(
+        &'a self,
+        user_id: impl Into<UserId> + Send,
+        date: &'a str,
+    ) -> Result<Option<HeartRateZones>, HeartRateError> {
+        let user_id: UserId = user_id.into();
+        let series = self.get_heart_rate_time_series(user_id, date, "1d").await?;
+        Ok(series.first().and_then(|day| day.value.custom_zones()))
+    }
+
+    /// Gets intraday heart rate data
+    ///
+    /// Retrieves the heart rate for a single day at the requested granularity.
+    /// Requires access to the Fitbit Intraday API.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The user ID to get heart rate data for, or "-" for current user
+    /// * `date` - The date in format YYYY-MM-DD, or "today"
+    /// * `detail_level` - The granularity of the data points
+    ///
+    /// # Returns
+    ///
+    /// Returns the daily summary and per-timestamp heart rate on success.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `HeartRateError` if:
+    /// - The request fails to send
+    /// - The API returns an error response
+    /// - The response cannot be parsed
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use fitbit_sdk::client::FitbitClient;
+    /// use fitbit_sdk::types::heart_rate::{HeartRateClient, HeartRateError};
+    /// use fitbit_sdk::types::intraday::DetailLevel;
+    /// use tokio;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), HeartRateError> {
+    ///     let client = FitbitClient::new::<HeartRateError>()?;
+    ///
+    ///     let series = client
+    ///         .get_heart_rate_intraday("-", "today", DetailLevel::OneMinute)
+    ///         .await?;
+    ///     for point in &series.intraday.dataset {
+    ///         println!("{}: {} bpm", point.time, point.value);
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    async fn get_heart_rate_intraday<'a>(
+        &'a self,
+        user_id: impl Into<UserId> + Send,
+        date: &'a str,
+        detail_level: DetailLevel,
+    ) -> Result<IntradayHeartRateSeries, HeartRateError> {
+        let user_id: UserId = user_id.into();
+        let path = format!(
+            "/user/{}/activities/heart/date/{}/{}",
+            user_id,
+            date,
+            intraday_path_suffix(detail_level, None)
+        );
+        self.get::<_, (), HeartRateError>(&path, None).await
+    }
+
+    /// Gets intraday heart rate data for a time window
+    ///
+    /// Retrieves the heart rate between two times of a single day at the
+    /// requested granularity. Requires access to the Fitbit Intraday API.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The user ID to get heart rate data for, or "-" for current user
+    /// * `date` - The date in format YYYY-MM-DD, or "today"
+    /// * `detail_level` - The granularity of the data points
+    /// * `start_time` - The start of the window in format HH:mm
+    /// * `end_time` - The end of the window in format HH:mm
+    ///
+    /// # Returns
+    ///
+    /// Returns the daily summary and per-timestamp heart rate on success.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `HeartRateError` if:
+    /// - The request fails to send
+    /// - The API returns an error response
+    /// - The response cannot be parsed
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use fitbit_sdk::client::FitbitClient;
+    /// use fitbit_sdk::types::heart_rate::{HeartRateClient, HeartRateError};
+    /// use fitbit_sdk::types::intraday::DetailLevel;
+    /// use tokio;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), HeartRateError> {
+    ///     let client = FitbitClient::new::<HeartRateError>()?;
+    ///
+    ///     // Heart rate during a morning workout
+    ///     let series = client
+    ///         .get_heart_rate_intraday_window("-", "today", DetailLevel::OneSecond, "07:00", "08:00")
+    ///         .await?;
+    ///     println!("{} samples", series.intraday.dataset.len());
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    async fn get_heart_rate_intraday_window<'a>(
+        &'a self,
+        user_id: impl Into<UserId> + Send,
+        date: &'a str,
+        detail_level: DetailLevel,
+        start_time: &'a str,
+        end_time: &'a str,
+    ) -> Result<IntradayHeartRateSeries, HeartRateError> {
+        let user_id: UserId = user_id.into();
+        let path = format!(
+            "/user/{}/activities/heart/date/{}/{}",
+            user_id,
+            date,
+            intraday_path_suffix(detail_level, Some((start_time, end_time)))
+        );
+        self.get::<_, (), HeartRateError>(&path, None).await
+    }
+
+    /// Gets the time spent in each heart rate zone over a date range
+    ///
+    /// Fitbit only serves intraday heart rate one day at a time, so the
+    /// samples of each day are fetched separately, a few days at a time, and
+    /// then classified with `analysis::heart::intraday_time_in_zones`. Every
+    /// day costs one request against the rate limit.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The user ID to get heart rate data for, or "-" for current user
+    /// * `start_date` - The first day of the range
+    /// * `end_date` - The last day of the range, included
+    /// * `detail_level` - The interval of the samples
+    /// * `zones` - The zones the samples are classified into
+    /// * `max_gap` - The longest gap between samples counted as time in a zone
+    ///
+    /// # Returns
+    ///
+    /// Returns the time in each zone per day and over the whole range on
+    /// success. The result is empty if `end_date` is before `start_date`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `HeartRateError` if any of the days fails with:
+    /// - The request fails to send
+    /// - The API returns an error response
+    /// - The response cannot be parsed
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use fitbit_sdk::client::FitbitClient;
+    /// use fitbit_sdk::types::heart_rate::{HeartRateClient, HeartRateError, ZoneSelection};
+    /// use fitbit_sdk::types::intraday::DetailLevel;
+    /// use time::Duration;
+    /// use time::macros::date;
+    /// use tokio;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), HeartRateError> {
+    ///     let client = FitbitClient::new::<HeartRateError>()?;
+    ///
+    ///     let time = client
+    ///         .get_heart_rate_time_in_zones(
+    ///             "-",
+    ///             date!(2025 - 01 - 13),
+    ///             date!(2025 - 01 - 19),
+    ///             DetailLevel::OneMinute,
+    ///             &ZoneSelection::Custom,
+    ///             Duration::minutes(5),
+    ///         )
+    ///         .await?;
+    ///     for zone in &time.totals {
+    ///         println!("{}: {:.1} minutes", zone.name, zone.minutes);
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    async fn get_heart_rate_time_in_zones<'a>(
+        &'a self,
+        user_id: impl Into<UserId> + Send,
+        start_date: Date,
+        end_date: Date,
+        detail_level: DetailLevel,
+        zones: &'a ZoneSelection,
+        max_gap: Duration,
+    ) -> Result<IntradayZoneTime, HeartRateError> {
+        let user_id: UserId = user_id.into();
+        let dates = std::iter::successors(Some(start_date), |date| date.next_day())
+            .take_while(|date| *date <= end_date);
+        let days: Vec<IntradayHeartRateSeries> = stream::iter(dates)
+            .map(|date| {
+                let user_id = user_id.clone();
+                async move {
+                    self.get_heart_rate_intraday(user_id, &date.to_string(), detail_level)
+                        .await
+                }
+            })
+            .buffered(INTRADAY_RANGE_CONCURRENCY)
+            .try_collect()
+            .await?;
+        Ok(intraday_time_in_zones(&days, zones, max_gap))
+    }
+
+    /// Streams intraday heart rate data
+    ///
+    /// Retrieves the heart rate for a single day like `get_heart_rate_intraday`,
+    /// yielding each data point while the response is being received instead
+    /// of buffering it. Use it to backfill 1-second heart rate, whose daily
+    /// responses are several megabytes. The daily summary is not returned.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The user ID to get heart rate data for, or "-" for current user
+    /// * `date` - The date in format YYYY-MM-DD, or "today"
+    /// * `detail_level` - The granularity of the data points
+    ///
+    /// # Returns
+    ///
+    /// Returns a stream of the heart rate in beats per minute at each timestamp.
+    ///
+    /// # Errors
+    ///
+    /// The stream yields a `HeartRateError` and ends if:
+    /// - The request fails to send, or the response fails to be received
+    /// - The API returns an error response
+    /// - The response cannot be parsed
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use fitbit_sdk::client::FitbitClient;
+    /// use fitbit_sdk::types::heart_rate::{HeartRateClient, HeartRateError};
+    /// use fitbit_sdk::types::intraday::DetailLevel;
+    /// use futures_util::TryStreamExt;
+    /// use tokio;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), HeartRateError> {
+    ///     let client = FitbitClient::new::<HeartRateError>()?;
+    ///
+    ///     let mut points =
+    ///         client.stream_heart_rate_intraday("-", "2025-01-15", DetailLevel::OneSecond);
+    ///     let mut max = 0;
+    ///     while let Some(point) = points.try_next().await? {
+    ///         max = max.max(point.value);
+    ///     }
+    ///     println!("Max heart rate: {} bpm", max);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    fn stream_heart_rate_intraday<'a>(
+        &'a self,
+        user_id: impl Into<UserId> + Send,
+        date: &'a str,
+        detail_level: DetailLevel,
+    ) -> BoxStream<'a, Result<IntradayDataPoint<i32>, HeartRateError>> {
+        let user_id: UserId = user_id.into();
+        let path = format!(
+            "/user/{}/activities/heart/date/{}/{}",
+            user_id,
+            date,
+            intraday_path_suffix(detail_level, None)
+        );
+        self.stream_intraday::<i32, HeartRateError>(ApiVersion::V1, path)
+            .boxed()
+    }
+}
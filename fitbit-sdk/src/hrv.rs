@@ -0,0 +1,208 @@
+//! Heart Rate Variability API
+//!
+//! This module contains the implementations for the Fitbit Heart Rate Variability (HRV) API endpoints.
+//! It provides functionality for retrieving daily and intraday HRV measured during sleep.
+
+use crate::client::FitbitClient;
+use crate::types::hrv::{
+    HrvClient, HrvError, HrvIntraday, HrvIntradayResponse, HrvSummary, HrvSummaryResponse,
+};
+use crate::types::user::UserId;
+use async_trait::async_trait;
+
+#[async_trait]
+impl HrvClient for FitbitClient {
+    /// Gets the HRV summary for a specific date
+    ///
+    /// Retrieves the daily and deep sleep RMSSD measured during the user's main sleep.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The user ID to get HRV for, or "-" for current user
+    /// * `date` - The date in format YYYY-MM-DD, or "today"
+    ///
+    /// # Returns
+    ///
+    /// Returns the HRV summary on success, or an empty list if no HRV was recorded.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `HrvError` if:
+    /// - The request fails to send
+    /// - The API returns an error response
+    /// - The response cannot be parsed
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use fitbit_sdk::client::FitbitClient;
+    /// use fitbit_sdk::types::hrv::{HrvClient, HrvError};
+    /// use tokio;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), HrvError> {
+    ///     let client = FitbitClient::new::<HrvError>()?;
+    ///
+    ///     for hrv in client.get_hrv_summary("-", "today").await? {
+    ///         println!("{}: {} ms", hrv.date_time, hrv.value.daily_rmssd);
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    async fn get_hrv_summary<'a>(&'a self, user_id: impl Into<UserId> + Send, date: &'a str) -> Result<Vec<HrvSummary>, HrvError> {
+        let user_id: UserId = user_id.into();
+        let path = format!("/user/{}/hrv/date/{}.json", user_id, date);
+        let response: HrvSummaryResponse = self.get::<_, (), HrvError>(&path, None).await?;
+        Ok(response.hrv)
+    }
+
+    /// Gets the HRV summaries for a date range
+    ///
+    /// Retrieves the daily and deep sleep RMSSD for each day in the range (max 30 days).
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The user ID to get HRV for, or "-" for current user
+    /// * `start_date` - The start date in format YYYY-MM-DD
+    /// * `end_date` - The end date in format YYYY-MM-DD, or "today"
+    ///
+    /// # Returns
+    ///
+    /// Returns one summary per day with HRV data on success.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `HrvError` if:
+    /// - The request fails to send
+    /// - The API returns an error response
+    /// - The response cannot be parsed
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use fitbit_sdk::client::FitbitClient;
+    /// use fitbit_sdk::types::hrv::{HrvClient, HrvError};
+    /// use tokio;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), HrvError> {
+    ///     let client = FitbitClient::new::<HrvError>()?;
+    ///
+    ///     let days = client.get_hrv_summary_range("-", "2025-01-01", "2025-01-30").await?;
+    ///     println!("{} days of HRV data", days.len());
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    async fn get_hrv_summary_range<'a>(
+        &'a self,
+        user_id: impl Into<UserId> + Send,
+        start_date: &'a str,
+        end_date: &'a str,
+    ) -> Result<Vec<HrvSummary>, HrvError> {
+        let user_id: UserId = user_id.into();
+        let path = format!("/user/{}/hrv/date/{}/{}.json", user_id, start_date, end_date);
+        let response: HrvSummaryResponse = self.get::<_, (), HrvError>(&path, None).await?;
+        Ok(response.hrv)
+    }
+
+    /// Gets intraday HRV for a specific date
+    ///
+    /// Retrieves HRV measured over 5-minute intervals during the user's main sleep.
+    /// Requires access to the Fitbit Intraday API.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The user ID to get HRV for, or "-" for current user
+    /// * `date` - The date in format YYYY-MM-DD, or "today"
+    ///
+    /// # Returns
+    ///
+    /// Returns the intraday HRV on success.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `HrvError` if:
+    /// - The request fails to send
+    /// - The API returns an error response
+    /// - The response cannot be parsed
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use fitbit_sdk::client::FitbitClient;
+    /// use fitbit_sdk::types::hrv::{HrvClient, HrvError};
+    /// use tokio;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), HrvError> {
+    ///     let client = FitbitClient::new::<HrvError>()?;
+    ///
+    ///     for day in client.get_hrv_intraday("-", "today").await? {
+    ///         for minute in &day.minutes {
+    ///             println!("{}: {} ms", minute.minute, minute.value.rmssd);
+    ///         }
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    async fn get_hrv_intraday<'a>(&'a self, user_id: impl Into<UserId> + Send, date: &'a str) -> Result<Vec<HrvIntraday>, HrvError> {
+        let user_id: UserId = user_id.into();
+        let path = format!("/user/{}/hrv/date/{}/all.json", user_id, date);
+        let response: HrvIntradayResponse = self.get::<_, (), HrvError>(&path, None).await?;
+        Ok(response.hrv)
+    }
+
+    /// Gets intraday HRV for a date range
+    ///
+    /// Retrieves HRV measured over 5-minute intervals for each day in the range (max 30 days).
+    /// Requires access to the Fitbit Intraday API.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The user ID to get HRV for, or "-" for current user
+    /// * `start_date` - The start date in format YYYY-MM-DD
+    /// * `end_date` - The end date in format YYYY-MM-DD, or "today"
+    ///
+    /// # Returns
+    ///
+    /// Returns the intraday HRV per day on success.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `HrvError` if:
+    /// - The request fails to send
+    /// - The API returns an error response
+    /// - The response cannot be parsed
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use fitbit_sdk::client::FitbitClient;
+    /// use fitbit_sdk::types::hrv::{HrvClient, HrvError};
+    /// use tokio;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), HrvError> {
+    ///     let client = FitbitClient::new::<HrvError>()?;
+    ///
+    ///     let days = client.get_hrv_intraday_range("-", "2025-01-01", "2025-01-07").await?;
+    ///     println!("{} days of intraday HRV", days.len());
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    async fn get_hrv_intraday_range<'a>(
+        &'a self,
+        user_id: impl Into<UserId> + Send,
+        start_date: &'a str,
+        end_date: &'a str,
+    ) -> Result<Vec<HrvIntraday>, HrvError> {
+        let user_id: UserId = user_id.into();
+        let path = format!("/user/{}/hrv/date/{}/{}/all.json", user_id, start_date, end_date);
+        let response: HrvIntradayResponse = self.get::<_, (), HrvError>(&path, None).await?;
+        Ok(response.hrv)
+    }
+}
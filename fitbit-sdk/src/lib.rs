@@ -1,7 +1,44 @@
 pub mod client;
+#[cfg(feature = "blocking")]
+pub mod blocking;
+pub mod auth;
+pub mod token_store;
+pub mod cache;
+pub mod rate_limit;
+#[cfg(feature = "metrics")]
+pub mod metrics;
+pub mod transport;
+#[cfg(feature = "user")]
 pub mod user;
+#[cfg(feature = "activity")]
 pub mod activity;
+#[cfg(feature = "sleep")]
 pub mod sleep;
+#[cfg(feature = "body")]
 pub mod body;
+#[cfg(feature = "nutrition")]
 pub mod nutrition;
+#[cfg(feature = "heart-rate")]
+pub mod heart_rate;
+#[cfg(feature = "hrv")]
+pub mod hrv;
+#[cfg(feature = "breathing-rate")]
+pub mod breathing_rate;
+#[cfg(feature = "temperature")]
+pub mod temperature;
+#[cfg(feature = "cardio-fitness")]
+pub mod cardio_fitness;
+#[cfg(feature = "azm")]
+pub mod azm;
+#[cfg(feature = "subscriptions")]
+pub mod subscriptions;
+#[cfg(feature = "subscriptions")]
+pub mod webhook;
+#[cfg(feature = "sync")]
+pub mod sync;
+#[cfg(feature = "wellness")]
+pub mod wellness;
+pub mod export;
+pub mod analysis;
+pub mod prelude;
 pub mod types;
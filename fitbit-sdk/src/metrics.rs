@@ -0,0 +1,55 @@
+//! Metrics
+//!
+//! This module contains `MetricsCrateRecorder`, which reports the requests
+//! `FitbitClient` sends to the [`metrics`](https://docs.rs/metrics) crate, so
+//! any of its exporters (e.g. Prometheus) can publish them.
+
+use crate::types::metrics::{MetricsRecorder, RequestMetrics};
+
+/// Recorder reporting requests to the `metrics` crate
+///
+/// Every request increments the `fitbit_requests_total` counter and records
+/// its duration in seconds in the `fitbit_request_duration_seconds` histogram.
+/// Requests without a 2xx response also increment `fitbit_request_errors_total`.
+/// The metrics are labelled with the `method`, `api_version`, `endpoint` (see
+/// `RequestMetrics::endpoint`) and `status` of the request; `status` is
+/// `error` when no response was received.
+///
+/// # Examples
+///
+/// ```no_run
+/// use fitbit_sdk::client::FitbitClient;
+/// use fitbit_sdk::metrics::MetricsCrateRecorder;
+/// use fitbit_sdk::types::user::UserError;
+///
+/// # fn example() -> Result<(), UserError> {
+/// let client = FitbitClient::builder()
+///     .with_metrics_recorder(MetricsCrateRecorder)
+///     .build()?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MetricsCrateRecorder;
+
+impl MetricsRecorder for MetricsCrateRecorder {
+    fn record(&self, metrics: &RequestMetrics) {
+        let labels = [
+            ("method", metrics.method.to_string()),
+            ("api_version", metrics.api_version.as_str().to_string()),
+            ("endpoint", metrics.endpoint.clone()),
+            (
+                "status",
+                metrics
+                    .status
+                    .map_or_else(|| "error".to_string(), |status| status.as_u16().to_string()),
+            ),
+        ];
+        ::metrics::counter!("fitbit_requests_total", &labels).increment(1);
+        ::metrics::histogram!("fitbit_request_duration_seconds", &labels)
+            .record(metrics.duration.as_secs_f64());
+        if !metrics.is_success() {
+            ::metrics::counter!("fitbit_request_errors_total", &labels).increment(1);
+        }
+    }
+}
@@ -1,13 +1,20 @@
 //! Nutrition API
 //!
 //! This module contains the implementations for the Fitbit Nutrition API endpoints.
-//! It provides functionality for retrieving nutrition data and food logs.
+//! It provides functionality for retrieving and writing nutrition data, food logs and water logs.
 
 use crate::client::FitbitClient;
+use crate::types::date::FitbitDate;
 use crate::types::nutrition::{
-    NutritionClient, NutritionError, WaterLog, WaterLogResponse, FoodLog, FoodLogResponse,
+    EditFoodLogParams, FoodEntry, FoodLog, FoodLogResponse, LogFoodParams, LogFoodResponse,
+    LogWaterParams, LogWaterResponse, NutritionClient, NutritionError, NutritionResource,
+    NutritionTimeSeries, UpdateWaterGoalParams, WaterEntry, WaterGoal, WaterGoalResponse,
+    WaterLog, WaterLogResponse,
 };
+use crate::types::request::Body;
+use crate::types::user::UserId;
 use async_trait::async_trait;
+use reqwest::Method;
 
 #[async_trait]
 impl NutritionClient for FitbitClient {
@@ -18,7 +25,7 @@ impl NutritionClient for FitbitClient {
     /// # Arguments
     ///
     /// * `user_id` - The user ID to get water logs for, or "-" for current user
-    /// * `date` - The date in format YYYY-MM-DD
+    /// * `date` - The date
     ///
     /// # Returns
     ///
@@ -35,6 +42,7 @@ impl NutritionClient for FitbitClient {
     ///
     /// ```no_run
     /// use fitbit_sdk::client::FitbitClient;
+    /// use fitbit_sdk::types::date::FitbitDate;
     /// use fitbit_sdk::types::nutrition::{NutritionClient, NutritionError};
     /// use tokio;
     ///
@@ -43,7 +51,7 @@ impl NutritionClient for FitbitClient {
     ///     let client = FitbitClient::new::<NutritionError>()?;
     ///
     ///     // Get today's water consumption
-    ///     let water_logs = client.get_water_logs("-", "today").await?;
+    ///     let water_logs = client.get_water_logs("-", FitbitDate::Today).await?;
     ///     println!("Total water: {} ml", water_logs.summary.water);
     ///
     ///     Ok(())
@@ -51,11 +59,13 @@ impl NutritionClient for FitbitClient {
     /// ```
     async fn get_water_logs<'a>(
         &'a self,
-        user_id: &'a str,
-        date: &'a str,
+        user_id: impl Into<UserId> + Send,
+        date: impl Into<FitbitDate> + Send,
     ) -> Result<WaterLog, NutritionError> {
+        let user_id: UserId = user_id.into();
+        let date: FitbitDate = date.into();
         let path = format!("/user/{}/foods/log/water/date/{}.json", user_id, date);
-        let response: WaterLogResponse = self.get(&path, Option::<&()>::None).await?;
+        let response: WaterLogResponse = self.get::<_, (), NutritionError>(&path, None).await?;
         Ok(response.water_log)
     }
 
@@ -66,7 +76,7 @@ impl NutritionClient for FitbitClient {
     /// # Arguments
     ///
     /// * `user_id` - The user ID to get food logs for, or "-" for current user
-    /// * `date` - The date in format YYYY-MM-DD
+    /// * `date` - The date
     ///
     /// # Returns
     ///
@@ -83,6 +93,7 @@ impl NutritionClient for FitbitClient {
     ///
     /// ```no_run
     /// use fitbit_sdk::client::FitbitClient;
+    /// use fitbit_sdk::types::date::FitbitDate;
     /// use fitbit_sdk::types::nutrition::{NutritionClient, NutritionError};
     /// use tokio;
     ///
@@ -91,7 +102,7 @@ impl NutritionClient for FitbitClient {
     ///     let client = FitbitClient::new::<NutritionError>()?;
     ///
     ///     // Get today's food logs
-    ///     let food_logs = client.get_food_logs("-", "today").await?;
+    ///     let food_logs = client.get_food_logs("-", FitbitDate::Today).await?;
     ///     println!("Total calories: {}", food_logs.summary.calories);
     ///
     ///     Ok(())
@@ -99,11 +110,408 @@ impl NutritionClient for FitbitClient {
     /// ```
     async fn get_food_logs<'a>(
         &'a self,
-        user_id: &'a str,
-        date: &'a str,
+        user_id: impl Into<UserId> + Send,
+        date: impl Into<FitbitDate> + Send,
     ) -> Result<FoodLog, NutritionError> {
+        let user_id: UserId = user_id.into();
+        let date: FitbitDate = date.into();
         let path = format!("/user/{}/foods/log/date/{}.json", user_id, date);
-        let response: FoodLogResponse = self.get(&path, Option::<&()>::None).await?;
+        let response: FoodLogResponse = self.get::<_, (), NutritionError>(&path, None).await?;
         Ok(response.food_log)
     }
+
+    /// Logs water intake
+    ///
+    /// Creates a water log entry for the user.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The user ID to log water for, or "-" for current user
+    /// * `params` - The amount, unit and date of the intake
+    ///
+    /// # Returns
+    ///
+    /// Returns the created water log entry on success.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `NutritionError` if:
+    /// - The request fails to send
+    /// - The API returns an error response
+    /// - The response cannot be parsed
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use fitbit_sdk::client::FitbitClient;
+    /// use fitbit_sdk::types::nutrition::{LogWaterParams, NutritionClient, NutritionError, WaterUnit};
+    /// use tokio;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), NutritionError> {
+    ///     let client = FitbitClient::new::<NutritionError>()?;
+    ///
+    ///     let params = LogWaterParams::new(250.0, "2025-01-15").with_unit(WaterUnit::Milliliter);
+    ///     let logged = client.log_water("-", &params).await?;
+    ///     println!("Logged water {}", logged.log_id);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    async fn log_water<'a>(&'a self, user_id: impl Into<UserId> + Send, params: &'a LogWaterParams) -> Result<WaterEntry, NutritionError> {
+        let user_id: UserId = user_id.into();
+        let path = format!("/user/{}/foods/log/water.json", user_id);
+        let response: LogWaterResponse = self
+            .send_request::<_, (), _, NutritionError>(Method::POST, &path, None, Some(Body::Form(params)))
+            .await?;
+        Ok(response.water_log)
+    }
+
+    /// Deletes a water log entry
+    ///
+    /// Removes a water log entry from the user's food log.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The user ID to delete the entry for, or "-" for current user
+    /// * `log_id` - The ID of the water log entry
+    ///
+    /// # Returns
+    ///
+    /// Returns `()` on success.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `NutritionError` if:
+    /// - The request fails to send
+    /// - The API returns an error response
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use fitbit_sdk::client::FitbitClient;
+    /// use fitbit_sdk::types::nutrition::{NutritionClient, NutritionError};
+    /// use tokio;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), NutritionError> {
+    ///     let client = FitbitClient::new::<NutritionError>()?;
+    ///
+    ///     client.delete_water_log("-", 123456789).await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    async fn delete_water_log<'a>(&'a self, user_id: impl Into<UserId> + Send, log_id: i64) -> Result<(), NutritionError> {
+        let user_id: UserId = user_id.into();
+        let path = format!("/user/{}/foods/log/water/{}.json", user_id, log_id);
+        self.delete::<_, (), NutritionError>(&path, None).await
+    }
+
+    /// Gets the user's water goal
+    ///
+    /// Retrieves the user's current daily water goal.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The user ID to get the water goal for, or "-" for current user
+    ///
+    /// # Returns
+    ///
+    /// Returns the water goal on success.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `NutritionError` if:
+    /// - The request fails to send
+    /// - The API returns an error response
+    /// - The response cannot be parsed
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use fitbit_sdk::client::FitbitClient;
+    /// use fitbit_sdk::types::nutrition::{NutritionClient, NutritionError};
+    /// use tokio;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), NutritionError> {
+    ///     let client = FitbitClient::new::<NutritionError>()?;
+    ///
+    ///     let goal = client.get_water_goal("-").await?;
+    ///     println!("Water goal: {} since {}", goal.goal, goal.start_date);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    async fn get_water_goal<'a>(&'a self, user_id: impl Into<UserId> + Send) -> Result<WaterGoal, NutritionError> {
+        let user_id: UserId = user_id.into();
+        let path = format!("/user/{}/foods/log/water/goal.json", user_id);
+        let response: WaterGoalResponse = self.get::<_, (), NutritionError>(&path, None).await?;
+        Ok(response.goal)
+    }
+
+    /// Updates the user's water goal
+    ///
+    /// Sets the user's daily water goal.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The user ID to update the water goal for, or "-" for current user
+    /// * `target` - The daily water goal in the user's unit
+    ///
+    /// # Returns
+    ///
+    /// Returns the updated water goal on success.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `NutritionError` if:
+    /// - The request fails to send
+    /// - The API returns an error response
+    /// - The response cannot be parsed
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use fitbit_sdk::client::FitbitClient;
+    /// use fitbit_sdk::types::nutrition::{NutritionClient, NutritionError};
+    /// use tokio;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), NutritionError> {
+    ///     let client = FitbitClient::new::<NutritionError>()?;
+    ///
+    ///     let goal = client.update_water_goal("-", 2000.0).await?;
+    ///     println!("New water goal: {}", goal.goal);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    async fn update_water_goal<'a>(&'a self, user_id: impl Into<UserId> + Send, target: f64) -> Result<WaterGoal, NutritionError> {
+        let user_id: UserId = user_id.into();
+        let path = format!("/user/{}/foods/log/water/goal.json", user_id);
+        let params = UpdateWaterGoalParams { target };
+        let response: WaterGoalResponse = self
+            .send_request::<_, (), _, NutritionError>(Method::POST, &path, None, Some(Body::Form(&params)))
+            .await?;
+        Ok(response.goal)
+    }
+
+    /// Logs a food
+    ///
+    /// Creates a food log entry for the user, either for a food from the food
+    /// database or for a custom food with its calories.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The user ID to log the food for, or "-" for current user
+    /// * `params` - The food, meal, amount and date of the entry
+    ///
+    /// # Returns
+    ///
+    /// Returns the created food log entry on success.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `NutritionError` if:
+    /// - The request fails to send
+    /// - The API returns an error response
+    /// - The response cannot be parsed
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use fitbit_sdk::client::FitbitClient;
+    /// use fitbit_sdk::types::nutrition::{LogFoodParams, MealType, NutritionClient, NutritionError};
+    /// use tokio;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), NutritionError> {
+    ///     let client = FitbitClient::new::<NutritionError>()?;
+    ///
+    ///     // 2 servings of a custom granola bar for breakfast
+    ///     let params = LogFoodParams::custom("Granola bar", 190, MealType::Breakfast, 304, 2.0, "2025-01-15")
+    ///         .with_brand_name("Acme");
+    ///     let entry = client.log_food("-", &params).await?;
+    ///     println!("Logged food {}: {}", entry.log_id, entry.logged_food.name);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    async fn log_food<'a>(&'a self, user_id: impl Into<UserId> + Send, params: &'a LogFoodParams) -> Result<FoodEntry, NutritionError> {
+        let user_id: UserId = user_id.into();
+        let path = format!("/user/{}/foods/log.json", user_id);
+        let response: LogFoodResponse = self
+            .send_request::<_, (), _, NutritionError>(Method::POST, &path, None, Some(Body::Form(params)))
+            .await?;
+        Ok(response.food_log)
+    }
+
+    /// Edits a food log entry
+    ///
+    /// Changes the meal, amount or unit of a food log entry. Calories can only
+    /// be changed for entries of custom foods.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The user ID to edit the entry for, or "-" for current user
+    /// * `log_id` - The ID of the food log entry
+    /// * `params` - The new meal, amount and unit of the entry
+    ///
+    /// # Returns
+    ///
+    /// Returns the edited food log entry on success.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `NutritionError` if:
+    /// - The request fails to send
+    /// - The API returns an error response
+    /// - The response cannot be parsed
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use fitbit_sdk::client::FitbitClient;
+    /// use fitbit_sdk::types::nutrition::{EditFoodLogParams, MealType, NutritionClient, NutritionError};
+    /// use tokio;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), NutritionError> {
+    ///     let client = FitbitClient::new::<NutritionError>()?;
+    ///
+    ///     let params = EditFoodLogParams::new(MealType::Lunch, 147, 150.0);
+    ///     let entry = client.edit_food_log("-", 123456789, &params).await?;
+    ///     println!("{} kcal", entry.nutritional_values.calories);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    async fn edit_food_log<'a>(
+        &'a self,
+        user_id: impl Into<UserId> + Send,
+        log_id: i64,
+        params: &'a EditFoodLogParams,
+    ) -> Result<FoodEntry, NutritionError> {
+        let user_id: UserId = user_id.into();
+        let path = format!("/user/{}/foods/log/{}.json", user_id, log_id);
+        let response: LogFoodResponse = self
+            .send_request::<_, (), _, NutritionError>(Method::POST, &path, None, Some(Body::Form(params)))
+            .await?;
+        Ok(response.food_log)
+    }
+
+    /// Deletes a food log entry
+    ///
+    /// Removes a food log entry from the user's food log.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The user ID to delete the entry for, or "-" for current user
+    /// * `log_id` - The ID of the food log entry
+    ///
+    /// # Returns
+    ///
+    /// Returns `()` on success.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `NutritionError` if:
+    /// - The request fails to send
+    /// - The API returns an error response
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use fitbit_sdk::client::FitbitClient;
+    /// use fitbit_sdk::types::nutrition::{NutritionClient, NutritionError};
+    /// use tokio;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), NutritionError> {
+    ///     let client = FitbitClient::new::<NutritionError>()?;
+    ///
+    ///     client.delete_food_log("-", 123456789).await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    async fn delete_food_log<'a>(&'a self, user_id: impl Into<UserId> + Send, log_id: i64) -> Result<(), NutritionError> {
+        let user_id: UserId = user_id.into();
+        let path = format!("/user/{}/foods/log/{}.json", user_id, log_id);
+        self.delete::<_, (), NutritionError>(&path, None).await
+    }
+
+    /// Gets nutrition time series data
+    ///
+    /// Retrieves the daily calorie intake or water consumption over a period.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The user ID to get nutrition time series for, or "-" for current user
+    /// * `resource` - The resource type (calories in or water)
+    /// * `date` - The end date
+    /// * `period` - The period (1d, 7d, 30d, 1w, 1m, 3m, 6m, 1y)
+    ///
+    /// # Returns
+    ///
+    /// Returns one data point per day on success.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `NutritionError` if:
+    /// - The request fails to send
+    /// - The API returns an error response
+    /// - The response cannot be parsed
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use fitbit_sdk::client::FitbitClient;
+    /// use fitbit_sdk::types::date::FitbitDate;
+    /// use fitbit_sdk::types::nutrition::{NutritionClient, NutritionError, NutritionResource};
+    /// use tokio;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), NutritionError> {
+    ///     let client = FitbitClient::new::<NutritionError>()?;
+    ///
+    ///     // Get last 7 days of calorie intake
+    ///     let intake = client
+    ///         .get_nutrition_time_series("-", NutritionResource::CaloriesIn, FitbitDate::Today, "7d")
+    ///         .await?;
+    ///
+    ///     for data_point in &intake {
+    ///         println!("{}: {} kcal", data_point.date_time, data_point.value);
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    async fn get_nutrition_time_series<'a>(
+        &'a self,
+        user_id: impl Into<UserId> + Send,
+        resource: NutritionResource,
+        date: impl Into<FitbitDate> + Send,
+        period: &'a str,
+    ) -> Result<Vec<NutritionTimeSeries>, NutritionError> {
+        let user_id: UserId = user_id.into();
+        let date: FitbitDate = date.into();
+        let path = format!(
+            "/user/{}/foods/log/{}/date/{}/{}.json",
+            user_id,
+            resource.as_str(),
+            date,
+            period
+        );
+        let mut response: serde_json::Value = self.get::<_, (), NutritionError>(&path, None).await?;
+
+        let key = resource.response_key();
+        let time_series = response
+            .get_mut(&key)
+            .map(serde_json::Value::take)
+            .ok_or_else(|| NutritionError::from(format!("Missing key '{}' in response", key)))?;
+        serde_json::from_value(time_series).map_err(|e| NutritionError::from(e.to_string()))
+    }
 }
@@ -0,0 +1,108 @@
+//! Prelude
+//!
+//! This module re-exports the client, the domain traits and the most used
+//! types, so that a single glob import gives access to the whole API:
+//!
+//! ```no_run
+//! use fitbit_sdk::prelude::*;
+//!
+//! #[tokio::main]
+//! async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//!     let client = FitbitClient::new::<FitbitError>()?;
+//!
+//!     let profile = client.get_profile("-").await?;
+//!     let sleep = client.get_sleep_logs("-", FitbitDate::Today).await?;
+//!     println!("{} slept {} minutes", profile.display_name, sleep.summary.total_minutes_asleep);
+//!
+//!     Ok(())
+//! }
+//! ```
+
+pub use crate::client::{ApiVersion, FitbitClient};
+#[cfg(feature = "activity")]
+pub use crate::types::activity::ActivityClient;
+#[cfg(feature = "azm")]
+pub use crate::types::azm::AzmClient;
+#[cfg(feature = "body")]
+pub use crate::types::body::BodyClient;
+#[cfg(feature = "breathing-rate")]
+pub use crate::types::breathing_rate::BreathingRateClient;
+#[cfg(feature = "cardio-fitness")]
+pub use crate::types::cardio_fitness::CardioFitnessClient;
+pub use crate::types::date::FitbitDate;
+pub use crate::types::datetime::ApiField;
+pub use crate::types::error::FitbitError;
+#[cfg(feature = "heart-rate")]
+pub use crate::types::heart_rate::HeartRateClient;
+#[cfg(feature = "hrv")]
+pub use crate::types::hrv::HrvClient;
+#[cfg(feature = "nutrition")]
+pub use crate::types::nutrition::NutritionClient;
+#[cfg(feature = "sleep")]
+pub use crate::types::sleep::SleepClient;
+#[cfg(feature = "subscriptions")]
+pub use crate::types::subscriptions::SubscriptionClient;
+#[cfg(feature = "sync")]
+pub use crate::types::sync::SyncClient;
+#[cfg(feature = "temperature")]
+pub use crate::types::temperature::TemperatureClient;
+#[cfg(feature = "user")]
+pub use crate::types::user::UserClient;
+pub use crate::types::user::UserId;
+#[cfg(feature = "wellness")]
+pub use crate::types::wellness::WellnessClient;
+
+/// Every domain client of the Fitbit API
+///
+/// Implemented for any type implementing all the domain traits, like
+/// `FitbitClient`. Use it as a bound for code that calls several domains,
+/// instead of listing every trait. Only available with the `full` feature.
+///
+/// # Examples
+///
+/// ```no_run
+/// use fitbit_sdk::prelude::*;
+///
+/// async fn morning_report(api: &impl FitbitApi) -> Result<(), Box<dyn std::error::Error>> {
+///     let sleep = api.get_sleep_logs("-", FitbitDate::Today).await?;
+///     let activity = api.get_activity_summary("-", FitbitDate::Yesterday).await?;
+///     println!(
+///         "{} minutes asleep after {} steps",
+///         sleep.summary.total_minutes_asleep, activity.steps
+///     );
+///     Ok(())
+/// }
+/// ```
+#[cfg(feature = "full")]
+pub trait FitbitApi:
+    ActivityClient
+    + AzmClient
+    + BodyClient
+    + BreathingRateClient
+    + CardioFitnessClient
+    + HeartRateClient
+    + HrvClient
+    + NutritionClient
+    + SleepClient
+    + SubscriptionClient
+    + TemperatureClient
+    + UserClient
+{
+}
+
+#[cfg(feature = "full")]
+impl<T> FitbitApi for T where
+    T: ActivityClient
+        + AzmClient
+        + BodyClient
+        + BreathingRateClient
+        + CardioFitnessClient
+        + HeartRateClient
+        + HrvClient
+        + NutritionClient
+        + SleepClient
+        + SubscriptionClient
+        + TemperatureClient
+        + UserClient
+{
+}
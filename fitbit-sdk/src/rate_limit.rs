@@ -0,0 +1,167 @@
+//! Rate Limiting
+//!
+//! This module contains `RateLimiter`, the token bucket `FitbitClient` uses
+//! to stay under the Fitbit API rate limit on the client side.
+
+use crate::types::rate_limit::{RateLimit, RateLimitMode, RateLimitStatus};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Token bucket enforcing a `RateLimit`
+///
+/// The bucket starts full and refills continuously at `requests / period`.
+/// Every request takes a token; in `RateLimitMode::Wait`, requests finding
+/// the bucket empty wait their turn in order, and in `RateLimitMode::Reject`
+/// they fail. The bucket also drains to the remaining quota reported by the
+/// API, so requests sent by other clients of the same user are accounted for.
+/// Once the API reports no requests left, or answers `429 Too Many Requests`,
+/// the bucket stays empty until the API rate limit window resets, and is full
+/// again afterwards.
+///
+/// # Examples
+///
+/// ```
+/// use fitbit_sdk::rate_limit::RateLimiter;
+/// use fitbit_sdk::types::rate_limit::{RateLimit, RateLimitMode};
+/// use std::time::Duration;
+/// use tokio;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let limiter = RateLimiter::new(
+///         RateLimit::new(2, Duration::from_secs(60)).with_mode(RateLimitMode::Reject),
+///     );
+///
+///     assert!(limiter.acquire().await.is_ok());
+///     assert!(limiter.acquire().await.is_ok());
+///     assert!(limiter.acquire().await.is_err());
+/// }
+/// ```
+#[derive(Debug)]
+pub struct RateLimiter {
+    /// The enforced rate limit
+    limit: RateLimit,
+    /// The tokens left
+    bucket: Mutex<Bucket>,
+    /// Held by the request waiting for a token, so requests are served in order
+    queue: tokio::sync::Mutex<()>,
+}
+
+/// Tokens of a `RateLimiter`
+#[derive(Debug)]
+struct Bucket {
+    /// Tokens left, including fractions of a token refilled so far
+    tokens: f64,
+    /// When the tokens were last refilled
+    refilled_at: Instant,
+    /// When the API rate limit window resets, if the API reported no requests left
+    blocked_until: Option<Instant>,
+}
+
+impl RateLimiter {
+    /// Creates a limiter with a full bucket
+    pub fn new(limit: RateLimit) -> Self {
+        Self {
+            limit,
+            bucket: Mutex::new(Bucket {
+                tokens: f64::from(limit.requests),
+                refilled_at: Instant::now(),
+                blocked_until: None,
+            }),
+            queue: tokio::sync::Mutex::new(()),
+        }
+    }
+
+    /// Returns the enforced rate limit
+    pub fn limit(&self) -> RateLimit {
+        self.limit
+    }
+
+    /// Tokens refilled per second
+    fn refill_rate(&self) -> f64 {
+        f64::from(self.limit.requests) / self.limit.period.as_secs_f64().max(f64::EPSILON)
+    }
+
+    /// Adds the tokens refilled since the last refill
+    ///
+    /// No tokens are added before the API rate limit window resets; the
+    /// bucket is full once it has.
+    fn refill(&self, bucket: &mut Bucket) {
+        let now = Instant::now();
+        if let Some(blocked_until) = bucket.blocked_until {
+            if now < blocked_until {
+                bucket.refilled_at = now;
+                return;
+            }
+            bucket.blocked_until = None;
+            bucket.tokens = f64::from(self.limit.requests);
+        }
+        let refilled = (now - bucket.refilled_at).as_secs_f64() * self.refill_rate();
+        bucket.tokens = (bucket.tokens + refilled).min(f64::from(self.limit.requests));
+        bucket.refilled_at = now;
+    }
+
+    /// Takes a token for a request
+    ///
+    /// # Errors
+    ///
+    /// In `RateLimitMode::Reject`, returns an error if no token is left.
+    /// A limit of zero requests rejects every request in either mode.
+    pub async fn acquire(&self) -> Result<(), String> {
+        if self.limit.requests == 0 {
+            return Err(format!(
+                "Client-side rate limit of 0 requests per {:?} rejects every request",
+                self.limit.period
+            ));
+        }
+        let _turn = self.queue.lock().await;
+        loop {
+            let wait = {
+                let mut bucket = self.bucket.lock().unwrap_or_else(|e| e.into_inner());
+                self.refill(&mut bucket);
+                if bucket.tokens >= 1.0 {
+                    bucket.tokens -= 1.0;
+                    return Ok(());
+                }
+                match bucket.blocked_until {
+                    Some(blocked_until) => blocked_until - Instant::now(),
+                    None => Duration::from_secs_f64((1.0 - bucket.tokens) / self.refill_rate()),
+                }
+            };
+            if self.limit.mode == RateLimitMode::Reject {
+                return Err(format!(
+                    "Client-side rate limit of {} requests per {:?} reached, retry in {:?}",
+                    self.limit.requests, self.limit.period, wait
+                ));
+            }
+            // Keep our turn while waiting, so later requests queue behind this one
+            tokio::time::sleep(wait).await;
+        }
+    }
+
+    /// Drains the bucket to the remaining quota reported by the API
+    ///
+    /// If no requests remain, the bucket stays empty until `status.reset_at`.
+    pub fn update(&self, status: &RateLimitStatus) {
+        let mut bucket = self.bucket.lock().unwrap_or_else(|e| e.into_inner());
+        self.refill(&mut bucket);
+        bucket.tokens = bucket.tokens.min(f64::from(status.remaining));
+        if status.is_exhausted() {
+            bucket.blocked_until = Some(Instant::now() + status.reset_in());
+        }
+    }
+
+    /// Empties the bucket until `wait` has passed
+    ///
+    /// Used when the API answers `429 Too Many Requests`, with the wait given
+    /// by the response. An earlier block lasting longer is kept.
+    pub fn block_for(&self, wait: Duration) {
+        let mut bucket = self.bucket.lock().unwrap_or_else(|e| e.into_inner());
+        self.refill(&mut bucket);
+        bucket.tokens = 0.0;
+        let blocked_until = Instant::now() + wait;
+        if bucket.blocked_until.is_none_or(|until| until < blocked_until) {
+            bucket.blocked_until = Some(blocked_until);
+        }
+    }
+}
@@ -2,12 +2,23 @@
 //!
 //! This module contains the implementations for the Fitbit Sleep API endpoints.
 //! It provides functionality for retrieving sleep data and logs.
+//!
+//! All sleep endpoints use version 1.2 of the Fitbit Web API, whose sleep logs
+//! include sleep stages (deep, light, REM and wake).
 
-use crate::client::FitbitClient;
+use crate::client::{ApiVersion, FitbitClient};
+use crate::types::date::FitbitDate;
+use crate::types::error::FitbitError;
 use crate::types::sleep::{
-    SleepClient, SleepError, SleepLog, SleepLogResponse, SleepGoal, SleepGoalResponse,
+    LogSleepParams, LogSleepResponse, SleepClient, SleepEntry, SleepError, SleepListParams,
+    SleepLog, SleepLogList, SleepLogResponse, SleepGoalResponse,
+    UpdateSleepGoalParams,
 };
+use crate::types::request::Body;
+use crate::types::user::UserId;
 use async_trait::async_trait;
+use futures_util::stream::{self, BoxStream, StreamExt, TryStreamExt};
+use reqwest::Method;
 
 #[async_trait]
 impl SleepClient for FitbitClient {
@@ -19,7 +30,7 @@ impl SleepClient for FitbitClient {
     /// # Arguments
     ///
     /// * `user_id` - The user ID to get sleep logs for, or "-" for current user
-    /// * `date` - The date in format YYYY-MM-DD
+    /// * `date` - The date
     ///
     /// # Returns
     ///
@@ -36,6 +47,7 @@ impl SleepClient for FitbitClient {
     ///
     /// ```no_run
     /// use fitbit_sdk::client::FitbitClient;
+    /// use fitbit_sdk::types::date::FitbitDate;
     /// use fitbit_sdk::types::sleep::{SleepClient, SleepError};
     /// use tokio;
     ///
@@ -44,19 +56,26 @@ impl SleepClient for FitbitClient {
     ///     let client = FitbitClient::new::<SleepError>()?;
     ///
     ///     // Get today's sleep data
-    ///     let sleep_logs = client.get_sleep_logs("-", "today").await?;
+    ///     let sleep_logs = client.get_sleep_logs("-", FitbitDate::Today).await?;
     ///     println!("Total sleep: {} minutes", sleep_logs.summary.total_minutes_asleep);
+    ///     if let Some(stages) = &sleep_logs.summary.stages {
+    ///         println!("Deep sleep: {} minutes", stages.deep);
+    ///     }
     ///
     ///     Ok(())
     /// }
     /// ```
     async fn get_sleep_logs<'a>(
         &'a self,
-        user_id: &'a str,
-        date: &'a str,
+        user_id: impl Into<UserId> + Send,
+        date: impl Into<FitbitDate> + Send,
     ) -> Result<SleepLog, SleepError> {
+        let user_id: UserId = user_id.into();
+        let date: FitbitDate = date.into();
         let path = format!("/user/{}/sleep/date/{}.json", user_id, date);
-        let response: SleepLogResponse = self.get(&path, Option::<&()>::None).await?;
+        let response: SleepLogResponse = self
+            .get_versioned::<_, (), SleepError>(ApiVersion::V1_2, &path, None)
+            .await?;
         Ok(response.sleep_log)
     }
 
@@ -70,7 +89,8 @@ impl SleepClient for FitbitClient {
     ///
     /// # Returns
     ///
-    /// Returns the sleep goal on success.
+    /// Returns the sleep goal and the sleep consistency information behind
+    /// it on success.
     ///
     /// # Errors
     ///
@@ -91,15 +111,349 @@ impl SleepClient for FitbitClient {
     ///     let client = FitbitClient::new::<SleepError>()?;
     ///
     ///     // Get sleep goal
-    ///     let goal = client.get_sleep_goal("-").await?;
-    ///     println!("Sleep goal: {} minutes", goal.goal);
+    ///     let response = client.get_sleep_goal("-").await?;
+    ///     println!("Sleep goal: {} minutes", response.goal.min_duration);
+    ///     if let Some(consistency) = response.consistency {
+    ///         println!("Recommended: {:?} minutes", consistency.recommended_sleep_goal);
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    async fn get_sleep_goal<'a>(&'a self, user_id: impl Into<UserId> + Send) -> Result<SleepGoalResponse, SleepError> {
+        let user_id: UserId = user_id.into();
+        let path = format!("/user/{}/sleep/goal.json", user_id);
+        self.get_versioned::<_, (), SleepError>(ApiVersion::V1_2, &path, None)
+            .await
+    }
+
+    /// Updates the sleep goal
+    ///
+    /// Sets the user's target sleep duration.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The user ID to update the goal for, or "-" for current user
+    /// * `min_duration` - The target sleep duration in minutes
+    ///
+    /// # Returns
+    ///
+    /// Returns the updated goal and sleep consistency information on success.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `SleepError` if:
+    /// - The request fails to send
+    /// - The API returns an error response
+    /// - The response cannot be parsed
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use fitbit_sdk::client::FitbitClient;
+    /// use fitbit_sdk::types::sleep::{SleepClient, SleepError};
+    /// use tokio;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), SleepError> {
+    ///     let client = FitbitClient::new::<SleepError>()?;
+    ///
+    ///     // Aim for 8 hours of sleep
+    ///     let update = client.update_sleep_goal("-", 480).await?;
+    ///     println!("Sleep goal: {} minutes", update.goal.min_duration);
     ///
     ///     Ok(())
     /// }
     /// ```
-    async fn get_sleep_goal<'a>(&'a self, user_id: &'a str) -> Result<SleepGoal, SleepError> {
+    async fn update_sleep_goal<'a>(
+        &'a self,
+        user_id: impl Into<UserId> + Send,
+        min_duration: i32,
+    ) -> Result<SleepGoalResponse, SleepError> {
+        let user_id: UserId = user_id.into();
         let path = format!("/user/{}/sleep/goal.json", user_id);
-        let response: SleepGoalResponse = self.get(&path, Option::<&()>::None).await?;
-        Ok(response.goal)
+        let params = UpdateSleepGoalParams { min_duration };
+        self.send_versioned_request::<_, (), _, SleepError>(
+            ApiVersion::V1_2,
+            Method::POST,
+            &path,
+            None,
+            Some(Body::Form(&params)),
+        )
+        .await
+    }
+
+    /// Logs a sleep entry
+    ///
+    /// Creates a manual sleep entry for the user.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The user ID to log the sleep for, or "-" for current user
+    /// * `params` - The start time, duration and date of the sleep
+    ///
+    /// # Returns
+    ///
+    /// Returns the created sleep entry on success.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `SleepError` if:
+    /// - The request fails to send
+    /// - The API returns an error response
+    /// - The response cannot be parsed
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use fitbit_sdk::client::FitbitClient;
+    /// use fitbit_sdk::types::sleep::{LogSleepParams, SleepClient, SleepError};
+    /// use tokio;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), SleepError> {
+    ///     let client = FitbitClient::new::<SleepError>()?;
+    ///
+    ///     // Log 7.5 hours of sleep starting at 23:00
+    ///     let params = LogSleepParams::new("23:00", 450 * 60 * 1000, "2025-01-14");
+    ///     let entry = client.log_sleep("-", &params).await?;
+    ///     println!("Logged sleep {}", entry.log_id);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    async fn log_sleep<'a>(&'a self, user_id: impl Into<UserId> + Send, params: &'a LogSleepParams) -> Result<SleepEntry, SleepError> {
+        let user_id: UserId = user_id.into();
+        let path = format!("/user/{}/sleep.json", user_id);
+        let response: LogSleepResponse = self
+            .send_versioned_request::<_, (), _, SleepError>(
+                ApiVersion::V1_2,
+                Method::POST,
+                &path,
+                None,
+                Some(Body::Form(params)),
+            )
+            .await?;
+        Ok(response.sleep)
+    }
+
+    /// Deletes a sleep log entry
+    ///
+    /// Removes a sleep entry from the user's sleep log.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The user ID to delete the entry for, or "-" for current user
+    /// * `log_id` - The ID of the sleep log entry
+    ///
+    /// # Returns
+    ///
+    /// Returns `()` on success.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `SleepError` if:
+    /// - The request fails to send
+    /// - The API returns an error response
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use fitbit_sdk::client::FitbitClient;
+    /// use fitbit_sdk::types::sleep::{SleepClient, SleepError};
+    /// use tokio;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), SleepError> {
+    ///     let client = FitbitClient::new::<SleepError>()?;
+    ///
+    ///     client.delete_sleep_log("-", 123456789).await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    async fn delete_sleep_log<'a>(&'a self, user_id: impl Into<UserId> + Send, log_id: i64) -> Result<(), SleepError> {
+        let user_id: UserId = user_id.into();
+        let path = format!("/user/{}/sleep/{}.json", user_id, log_id);
+        self.send_versioned_request::<_, (), (), SleepError>(
+            ApiVersion::V1_2,
+            Method::DELETE,
+            &path,
+            None,
+            None,
+        )
+        .await
+    }
+
+    /// Gets a page of sleep log entries
+    ///
+    /// Retrieves the user's sleep log entries before or after a date, one page
+    /// at a time.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The user ID to get sleep logs for, or "-" for current user
+    /// * `params` - The date, sort order and page to list
+    ///
+    /// # Returns
+    ///
+    /// Returns the sleep log entries and pagination information on success.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `SleepError` if:
+    /// - The parameters are invalid, see `ListParams::validate`
+    /// - The request fails to send
+    /// - The API returns an error response
+    /// - The response cannot be parsed
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use fitbit_sdk::client::FitbitClient;
+    /// use fitbit_sdk::types::sleep::{SleepClient, SleepError, SleepListParams};
+    /// use tokio;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), SleepError> {
+    ///     let client = FitbitClient::new::<SleepError>()?;
+    ///
+    ///     // The 10 most recent sleep logs
+    ///     let params = SleepListParams::before("today").with_limit(10);
+    ///     let page = client.get_sleep_log_list("-", &params).await?;
+    ///     for entry in &page.sleep {
+    ///         println!("{}: {} minutes asleep", entry.start_time, entry.minutes_asleep);
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    async fn get_sleep_log_list<'a>(
+        &'a self,
+        user_id: impl Into<UserId> + Send,
+        params: &'a SleepListParams,
+    ) -> Result<SleepLogList, SleepError> {
+        params.validate().map_err(|e| SleepError(FitbitError::RequestFailed(e)))?;
+        let user_id: UserId = user_id.into();
+        let path = format!("/user/{}/sleep/list.json", user_id);
+        self.get_versioned::<_, _, SleepError>(ApiVersion::V1_2, &path, Some(params))
+            .await
+    }
+
+    /// Gets all sleep log entries before or after a date
+    ///
+    /// Collects the entries of `stream_sleep_log_list`, following the `next`
+    /// links until the last page.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The user ID to get sleep logs for, or "-" for current user
+    /// * `params` - The date, sort order and page size to list
+    ///
+    /// # Returns
+    ///
+    /// Returns the sleep log entries of all pages on success.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `SleepError` if:
+    /// - The parameters are invalid, see `ListParams::validate`
+    /// - Any request fails to send
+    /// - The API returns an error response
+    /// - A response cannot be parsed
+    /// - There are more than `params.max_pages` pages
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use fitbit_sdk::client::FitbitClient;
+    /// use fitbit_sdk::types::sleep::{SleepClient, SleepError, SleepListParams};
+    /// use tokio;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), SleepError> {
+    ///     let client = FitbitClient::new::<SleepError>()?;
+    ///
+    ///     let entries = client
+    ///         .get_all_sleep_log_list("-", &SleepListParams::after("2025-01-01"))
+    ///         .await?;
+    ///     println!("{} sleep logs since January", entries.len());
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    async fn get_all_sleep_log_list<'a>(
+        &'a self,
+        user_id: impl Into<UserId> + Send,
+        params: &'a SleepListParams,
+    ) -> Result<Vec<SleepEntry>, SleepError> {
+        self.stream_sleep_log_list(user_id, params).try_collect().await
+    }
+
+    /// Streams the sleep log entries before or after a date
+    ///
+    /// Fetches the first page like `get_sleep_log_list` and follows the
+    /// `next` links, fetching each page only once the entries of the previous
+    /// page have been consumed.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The user ID to get sleep logs for, or "-" for current user
+    /// * `params` - The date, sort order, page size and page limit to list
+    ///
+    /// # Returns
+    ///
+    /// Returns a stream of the sleep log entries of all pages.
+    ///
+    /// # Errors
+    ///
+    /// The stream yields a `SleepError` and ends if:
+    /// - The parameters are invalid, see `ListParams::validate`
+    /// - A request fails to send
+    /// - The API returns an error response
+    /// - A response cannot be parsed
+    /// - There are more than `params.max_pages` pages
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use fitbit_sdk::client::FitbitClient;
+    /// use fitbit_sdk::types::sleep::{SleepClient, SleepError, SleepListParams};
+    /// use futures_util::TryStreamExt;
+    /// use tokio;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), SleepError> {
+    ///     let client = FitbitClient::new::<SleepError>()?;
+    ///
+    ///     let params = SleepListParams::before("today").with_max_pages(5);
+    ///     let mut entries = client.stream_sleep_log_list("-", &params);
+    ///     while let Some(entry) = entries.try_next().await? {
+    ///         if entry.efficiency < 80 {
+    ///             println!("Restless night on {}", entry.start_time);
+    ///             break;
+    ///         }
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    fn stream_sleep_log_list<'a>(
+        &'a self,
+        user_id: impl Into<UserId> + Send,
+        params: &'a SleepListParams,
+    ) -> BoxStream<'a, Result<SleepEntry, SleepError>> {
+        if let Err(error) = params.validate() {
+            return stream::once(async { Err(SleepError(FitbitError::RequestFailed(error))) }).boxed();
+        }
+        let user_id: UserId = user_id.into();
+        let path = format!("/user/{}/sleep/list.json", user_id);
+        self.paginate::<SleepLogList, SleepEntry, _, SleepError>(
+            ApiVersion::V1_2,
+            path,
+            params,
+            params.max_pages,
+        )
+        .boxed()
     }
 }
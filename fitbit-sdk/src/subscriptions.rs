@@ -0,0 +1,203 @@
+//! Subscriptions API
+//!
+//! This module contains the implementations for the Fitbit Subscriptions API endpoints.
+//! It provides functionality for registering for update notifications about a user's data.
+
+use crate::client::{ApiVersion, FitbitClient};
+use crate::types::error::FitbitError;
+use crate::types::request::SUBSCRIBER_ID_HEADER;
+use crate::types::subscriptions::{
+    CollectionType, Subscription, SubscriptionClient, SubscriptionError, SubscriptionListResponse,
+    subscriptions_path,
+};
+use crate::types::user::UserId;
+use async_trait::async_trait;
+use reqwest::Method;
+use reqwest::header::{HeaderMap, HeaderValue};
+
+#[async_trait]
+impl SubscriptionClient for FitbitClient {
+    /// Creates a subscription to update notifications
+    ///
+    /// Registers the application to be notified when the user's data in the
+    /// collection changes. Without a collection, the subscription covers all collections.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The user ID to subscribe to, or "-" for current user
+    /// * `collection` - The collection to subscribe to, or `None` for all collections
+    /// * `subscription_id` - A unique ID for the subscription chosen by the application
+    /// * `subscriber_id` - The subscriber endpoint to notify, or `None` for the default subscriber
+    ///
+    /// # Returns
+    ///
+    /// Returns the created subscription on success. The Fitbit API also succeeds
+    /// if the same subscription already exists.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `SubscriptionError` if:
+    /// - The request fails to send
+    /// - The API returns an error response (e.g. the subscription ID is already in use)
+    /// - The response cannot be parsed
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use fitbit_sdk::client::FitbitClient;
+    /// use fitbit_sdk::types::subscriptions::{CollectionType, SubscriptionClient, SubscriptionError};
+    /// use tokio;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), SubscriptionError> {
+    ///     let client = FitbitClient::new::<SubscriptionError>()?;
+    ///
+    ///     let subscription = client
+    ///         .create_subscription("-", Some(CollectionType::Sleep), "sleep-1", None)
+    ///         .await?;
+    ///     println!("Subscribed to {}", subscription.collection_type);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    async fn create_subscription<'a>(
+        &'a self,
+        user_id: impl Into<UserId> + Send,
+        collection: Option<CollectionType>,
+        subscription_id: &'a str,
+        subscriber_id: Option<&'a str>,
+    ) -> Result<Subscription, SubscriptionError> {
+        let user_id: UserId = user_id.into();
+        let path = format!("{}/{}.json", subscriptions_path(&user_id, collection), subscription_id);
+        self.send_request_with_headers::<_, (), (), SubscriptionError>(
+            ApiVersion::V1,
+            Method::POST,
+            &path,
+            None,
+            None,
+            &subscriber_headers(subscriber_id)?,
+        )
+        .await
+    }
+
+    /// Lists subscriptions to update notifications
+    ///
+    /// Retrieves the application's subscriptions for the user.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The user ID to list subscriptions for, or "-" for current user
+    /// * `collection` - The collection to list subscriptions for, or `None` for all collections
+    ///
+    /// # Returns
+    ///
+    /// Returns the subscriptions on success.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `SubscriptionError` if:
+    /// - The request fails to send
+    /// - The API returns an error response
+    /// - The response cannot be parsed
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use fitbit_sdk::client::FitbitClient;
+    /// use fitbit_sdk::types::subscriptions::{SubscriptionClient, SubscriptionError};
+    /// use tokio;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), SubscriptionError> {
+    ///     let client = FitbitClient::new::<SubscriptionError>()?;
+    ///
+    ///     for subscription in client.list_subscriptions("-", None).await? {
+    ///         println!("{}: {}", subscription.subscription_id, subscription.collection_type);
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    async fn list_subscriptions<'a>(
+        &'a self,
+        user_id: impl Into<UserId> + Send,
+        collection: Option<CollectionType>,
+    ) -> Result<Vec<Subscription>, SubscriptionError> {
+        let user_id: UserId = user_id.into();
+        let path = format!("{}.json", subscriptions_path(&user_id, collection));
+        let response: SubscriptionListResponse =
+            self.get::<_, (), SubscriptionError>(&path, None).await?;
+        Ok(response.api_subscriptions)
+    }
+
+    /// Deletes a subscription to update notifications
+    ///
+    /// Stops update notifications for the subscription.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The user ID the subscription is for, or "-" for current user
+    /// * `collection` - The collection of the subscription, or `None` for all collections
+    /// * `subscription_id` - The ID the subscription was created with
+    /// * `subscriber_id` - The subscriber endpoint of the subscription, or `None` for the default subscriber
+    ///
+    /// # Returns
+    ///
+    /// Returns `()` on success.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `SubscriptionError` if:
+    /// - The request fails to send
+    /// - The API returns an error response
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use fitbit_sdk::client::FitbitClient;
+    /// use fitbit_sdk::types::subscriptions::{CollectionType, SubscriptionClient, SubscriptionError};
+    /// use tokio;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), SubscriptionError> {
+    ///     let client = FitbitClient::new::<SubscriptionError>()?;
+    ///
+    ///     client
+    ///         .delete_subscription("-", Some(CollectionType::Sleep), "sleep-1", None)
+    ///         .await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    async fn delete_subscription<'a>(
+        &'a self,
+        user_id: impl Into<UserId> + Send,
+        collection: Option<CollectionType>,
+        subscription_id: &'a str,
+        subscriber_id: Option<&'a str>,
+    ) -> Result<(), SubscriptionError> {
+        let user_id: UserId = user_id.into();
+        let path = format!("{}/{}.json", subscriptions_path(&user_id, collection), subscription_id);
+        self.send_request_with_headers::<_, (), (), SubscriptionError>(
+            ApiVersion::V1,
+            Method::DELETE,
+            &path,
+            None,
+            None,
+            &subscriber_headers(subscriber_id)?,
+        )
+        .await
+    }
+}
+
+/// Builds the request headers selecting the subscriber endpoint, if any
+fn subscriber_headers(subscriber_id: Option<&str>) -> Result<HeaderMap, SubscriptionError> {
+    let mut headers = HeaderMap::new();
+    if let Some(subscriber_id) = subscriber_id {
+        let value = HeaderValue::from_str(subscriber_id).map_err(|e| {
+            SubscriptionError(FitbitError::RequestFailed(format!("Invalid subscriber ID: {}", e)))
+        })?;
+        headers.insert(SUBSCRIBER_ID_HEADER, value);
+    }
+    Ok(headers)
+}
@@ -0,0 +1,275 @@
+//! Data Sync
+//!
+//! This module contains the implementation of `SyncClient`, which pulls a
+//! user's activity, sleep, body and heart rate data day by day since a
+//! checkpoint, and `JsonlSink`, which appends the records to a JSON Lines file.
+//!
+//! Ranges are split to respect the maximum range of each endpoint, and sleep
+//! logs are listed page by page.
+
+use crate::client::FitbitClient;
+use crate::types::activity::ActivityClient;
+use crate::types::body::BodyClient;
+use crate::types::date::date_windows;
+use crate::types::datetime::ApiField;
+use crate::types::heart_rate::HeartRateClient;
+use crate::types::sleep::{SleepClient, SleepListParams};
+use crate::types::sync::{
+    SyncCheckpoint, SyncClient, SyncData, SyncError, SyncParams, SyncRecord, SyncSink,
+    SyncSummary,
+};
+use crate::types::user::UserId;
+use async_trait::async_trait;
+use futures_util::TryStreamExt;
+use std::path::Path;
+use time::{Date, OffsetDateTime};
+use tokio::fs::{File, OpenOptions};
+use tokio::io::{AsyncWriteExt, BufWriter};
+
+/// Maximum number of days of a weight or body fat log range
+const BODY_RANGE_DAYS: i64 = 31;
+
+/// Maximum number of days of an activity or heart rate time series range
+const TIME_SERIES_RANGE_DAYS: i64 = 365;
+
+/// Returns yesterday's date in the local time zone, falling back to UTC
+fn yesterday() -> Date {
+    let now = OffsetDateTime::now_local().unwrap_or_else(|_| OffsetDateTime::now_utc());
+    now.date().previous_day().unwrap_or(Date::MIN)
+}
+
+#[async_trait]
+impl SyncClient for FitbitClient {
+    /// Pulls the data of the days after a checkpoint into a sink
+    ///
+    /// Syncs the days after `params.checkpoint`, or from `params.start_date`
+    /// on the first sync, up to `params.end_date` (yesterday by default). The
+    /// data types in `params.data` are pulled one after the other and every
+    /// record is written to `sink`, which is finished once all records have
+    /// been written.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The user ID to sync data for, or "-" for current user
+    /// * `params` - The checkpoint, days and data types to sync
+    /// * `sink` - The sink receiving the records
+    ///
+    /// # Returns
+    ///
+    /// Returns the checkpoint to pass to the next sync and the number of
+    /// records written on success. When the checkpoint is already up to date,
+    /// nothing is pulled and the checkpoint is returned unchanged.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `SyncError` if:
+    /// - Any request fails to send
+    /// - The API returns an error response
+    /// - A response cannot be parsed
+    /// - The sink fails to write or finish
+    ///
+    /// The checkpoint is not advanced on error, so the next sync pulls the
+    /// same days again; records written before the error may be written twice.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use fitbit_sdk::client::FitbitClient;
+    /// use fitbit_sdk::sync::JsonlSink;
+    /// use fitbit_sdk::types::sync::{SyncCheckpoint, SyncClient, SyncError, SyncParams};
+    /// use time::macros::date;
+    /// use tokio;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = FitbitClient::new::<SyncError>()?;
+    ///
+    ///     let checkpoint: SyncCheckpoint = std::fs::read_to_string("checkpoint.json")
+    ///         .map(|json| serde_json::from_str(&json))
+    ///         .unwrap_or(Ok(SyncCheckpoint::default()))?;
+    ///
+    ///     let mut sink = JsonlSink::create("fitbit.jsonl").await?;
+    ///     let params = SyncParams::new(checkpoint, date!(2025 - 01 - 01));
+    ///     let summary = client.sync("-", &params, &mut sink).await?;
+    ///
+    ///     std::fs::write("checkpoint.json", serde_json::to_string(&summary.checkpoint)?)?;
+    ///     println!("Synced {} records", summary.records);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    async fn sync<'a, S>(
+        &'a self,
+        user_id: impl Into<UserId> + Send,
+        params: &'a SyncParams,
+        sink: &'a mut S,
+    ) -> Result<SyncSummary, SyncError>
+    where
+        S: SyncSink + ?Sized,
+    {
+        let user_id: UserId = user_id.into();
+        let start = match params.checkpoint.synced_through {
+            Some(synced_through) => match synced_through.next_day() {
+                Some(start) => start,
+                None => {
+                    return Ok(SyncSummary {
+                        checkpoint: params.checkpoint,
+                        records: 0,
+                    });
+                }
+            },
+            None => params.start_date,
+        };
+        let end = params.end_date.unwrap_or_else(yesterday);
+        if start > end {
+            return Ok(SyncSummary {
+                checkpoint: params.checkpoint,
+                records: 0,
+            });
+        }
+
+        let mut records = 0;
+        for data in &params.data {
+            match data {
+                SyncData::Activity => {
+                    for resource in &params.activity_resources {
+                        for (from, to) in date_windows(start, end, TIME_SERIES_RANGE_DAYS) {
+                            let points = self
+                                .get_activity_time_series_range(&user_id, *resource, from, to)
+                                .await?;
+                            for point in points {
+                                sink.write(SyncRecord::Activity {
+                                    resource: *resource,
+                                    point,
+                                })
+                                .await?;
+                                records += 1;
+                            }
+                        }
+                    }
+                }
+                SyncData::Sleep => {
+                    // Sleep logs belong to their date of sleep, usually the day
+                    // they end on, and a night's sleep starts the day before
+                    let after = start.previous_day().unwrap_or(start);
+                    let list_params = SleepListParams::after(after.to_string());
+                    let (first_day, last_day) = (start.to_string(), end.to_string());
+                    let mut entries = self.stream_sleep_log_list(&user_id, &list_params);
+                    while let Some(entry) = entries.try_next().await? {
+                        let day = match &entry.date_of_sleep {
+                            Some(date) => date.to_api_string(),
+                            None => entry.end_time.to_api_string().chars().take(10).collect(),
+                        };
+                        if day > last_day {
+                            break;
+                        }
+                        if day >= first_day {
+                            sink.write(SyncRecord::Sleep(entry)).await?;
+                            records += 1;
+                        }
+                    }
+                }
+                SyncData::Body => {
+                    for (from, to) in date_windows(start, end, BODY_RANGE_DAYS) {
+                        for weight in self.get_body_weight_range(&user_id, from, to).await? {
+                            sink.write(SyncRecord::Weight(weight)).await?;
+                            records += 1;
+                        }
+                        for fat in self.get_body_fat_range(&user_id, from, to).await? {
+                            sink.write(SyncRecord::BodyFat(fat)).await?;
+                            records += 1;
+                        }
+                    }
+                }
+                SyncData::HeartRate => {
+                    for (from, to) in date_windows(start, end, TIME_SERIES_RANGE_DAYS) {
+                        let days = self
+                            .get_heart_rate_time_series_range(
+                                &user_id,
+                                &from.to_string(),
+                                &to.to_string(),
+                            )
+                            .await?;
+                        for day in days {
+                            sink.write(SyncRecord::HeartRate(day)).await?;
+                            records += 1;
+                        }
+                    }
+                }
+            }
+        }
+        sink.finish().await?;
+
+        Ok(SyncSummary {
+            checkpoint: SyncCheckpoint::through(end),
+            records,
+        })
+    }
+}
+
+/// Sink appending each record as a line of JSON to a file
+///
+/// Records are buffered and flushed when the sync finishes.
+///
+/// # Examples
+///
+/// ```no_run
+/// use fitbit_sdk::sync::JsonlSink;
+/// use fitbit_sdk::types::sync::SyncError;
+///
+/// # async fn example() -> Result<(), SyncError> {
+/// let mut sink = JsonlSink::create("fitbit.jsonl").await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct JsonlSink {
+    writer: BufWriter<File>,
+}
+
+impl JsonlSink {
+    /// Creates a sink appending to `file`
+    pub fn new(file: File) -> Self {
+        Self {
+            writer: BufWriter::new(file),
+        }
+    }
+
+    /// Opens the file at `path` for appending, creating it if needed
+    ///
+    /// # Errors
+    ///
+    /// Returns `SyncError::SinkFailed` if the file cannot be opened.
+    pub async fn create(path: impl AsRef<Path>) -> Result<Self, SyncError> {
+        let path = path.as_ref();
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .await
+            .map_err(|e| {
+                SyncError::SinkFailed(format!("Failed to open {}: {}", path.display(), e))
+            })?;
+        Ok(Self::new(file))
+    }
+}
+
+#[async_trait]
+impl SyncSink for JsonlSink {
+    async fn write(&mut self, record: SyncRecord) -> Result<(), SyncError> {
+        let mut line = serde_json::to_vec(&record)
+            .map_err(|e| SyncError::SinkFailed(format!("Failed to serialize record: {}", e)))?;
+        line.push(b'\n');
+        self.writer
+            .write_all(&line)
+            .await
+            .map_err(|e| SyncError::SinkFailed(format!("Failed to write record: {}", e)))
+    }
+
+    async fn finish(&mut self) -> Result<(), SyncError> {
+        self.writer
+            .flush()
+            .await
+            .map_err(|e| SyncError::SinkFailed(format!("Failed to flush records: {}", e)))
+    }
+}
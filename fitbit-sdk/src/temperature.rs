@@ -0,0 +1,222 @@
+//! Temperature API
+//!
+//! This module contains the implementations for the Fitbit Temperature API endpoints.
+//! It provides functionality for retrieving nightly skin temperature and logged core temperature.
+
+use crate::client::FitbitClient;
+use crate::types::temperature::{
+    CoreTemperature, CoreTemperatureResponse, SkinTemperature, SkinTemperatureResponse,
+    TemperatureClient, TemperatureError,
+};
+use crate::types::user::UserId;
+use async_trait::async_trait;
+
+#[async_trait]
+impl TemperatureClient for FitbitClient {
+    /// Gets the skin temperature for a specific date
+    ///
+    /// Retrieves the variation of the user's skin temperature from their baseline
+    /// during the main sleep.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The user ID to get skin temperature for, or "-" for current user
+    /// * `date` - The date in format YYYY-MM-DD, or "today"
+    ///
+    /// # Returns
+    ///
+    /// Returns the skin temperature on success, or an empty list if none was recorded.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `TemperatureError` if:
+    /// - The request fails to send
+    /// - The API returns an error response
+    /// - The response cannot be parsed
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use fitbit_sdk::client::FitbitClient;
+    /// use fitbit_sdk::types::temperature::{TemperatureClient, TemperatureError};
+    /// use tokio;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), TemperatureError> {
+    ///     let client = FitbitClient::new::<TemperatureError>()?;
+    ///
+    ///     for temp in client.get_skin_temperature("-", "today").await? {
+    ///         println!("{}: {:+}", temp.date_time, temp.value.nightly_relative);
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    async fn get_skin_temperature<'a>(
+        &'a self,
+        user_id: impl Into<UserId> + Send,
+        date: &'a str,
+    ) -> Result<Vec<SkinTemperature>, TemperatureError> {
+        let user_id: UserId = user_id.into();
+        let path = format!("/user/{}/temp/skin/date/{}.json", user_id, date);
+        let response: SkinTemperatureResponse =
+            self.get::<_, (), TemperatureError>(&path, None).await?;
+        Ok(response.temp_skin)
+    }
+
+    /// Gets the skin temperature for a date range
+    ///
+    /// Retrieves the nightly skin temperature variation for each day in the range (max 30 days).
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The user ID to get skin temperature for, or "-" for current user
+    /// * `start_date` - The start date in format YYYY-MM-DD
+    /// * `end_date` - The end date in format YYYY-MM-DD, or "today"
+    ///
+    /// # Returns
+    ///
+    /// Returns one entry per night with skin temperature data on success.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `TemperatureError` if:
+    /// - The request fails to send
+    /// - The API returns an error response
+    /// - The response cannot be parsed
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use fitbit_sdk::client::FitbitClient;
+    /// use fitbit_sdk::types::temperature::{TemperatureClient, TemperatureError};
+    /// use tokio;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), TemperatureError> {
+    ///     let client = FitbitClient::new::<TemperatureError>()?;
+    ///
+    ///     let nights = client
+    ///         .get_skin_temperature_range("-", "2025-01-01", "2025-01-30")
+    ///         .await?;
+    ///     println!("{} nights of skin temperature data", nights.len());
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    async fn get_skin_temperature_range<'a>(
+        &'a self,
+        user_id: impl Into<UserId> + Send,
+        start_date: &'a str,
+        end_date: &'a str,
+    ) -> Result<Vec<SkinTemperature>, TemperatureError> {
+        let user_id: UserId = user_id.into();
+        let path = format!("/user/{}/temp/skin/date/{}/{}.json", user_id, start_date, end_date);
+        let response: SkinTemperatureResponse =
+            self.get::<_, (), TemperatureError>(&path, None).await?;
+        Ok(response.temp_skin)
+    }
+
+    /// Gets the core temperature for a specific date
+    ///
+    /// Retrieves the core temperature measurements the user logged on the day.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The user ID to get core temperature for, or "-" for current user
+    /// * `date` - The date in format YYYY-MM-DD, or "today"
+    ///
+    /// # Returns
+    ///
+    /// Returns the logged core temperatures on success.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `TemperatureError` if:
+    /// - The request fails to send
+    /// - The API returns an error response
+    /// - The response cannot be parsed
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use fitbit_sdk::client::FitbitClient;
+    /// use fitbit_sdk::types::temperature::{TemperatureClient, TemperatureError};
+    /// use tokio;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), TemperatureError> {
+    ///     let client = FitbitClient::new::<TemperatureError>()?;
+    ///
+    ///     for temp in client.get_core_temperature("-", "today").await? {
+    ///         println!("{}: {}", temp.date_time, temp.value);
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    async fn get_core_temperature<'a>(
+        &'a self,
+        user_id: impl Into<UserId> + Send,
+        date: &'a str,
+    ) -> Result<Vec<CoreTemperature>, TemperatureError> {
+        let user_id: UserId = user_id.into();
+        let path = format!("/user/{}/temp/core/date/{}.json", user_id, date);
+        let response: CoreTemperatureResponse =
+            self.get::<_, (), TemperatureError>(&path, None).await?;
+        Ok(response.temp_core)
+    }
+
+    /// Gets the core temperature for a date range
+    ///
+    /// Retrieves the core temperature measurements logged in the range (max 30 days).
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The user ID to get core temperature for, or "-" for current user
+    /// * `start_date` - The start date in format YYYY-MM-DD
+    /// * `end_date` - The end date in format YYYY-MM-DD, or "today"
+    ///
+    /// # Returns
+    ///
+    /// Returns the logged core temperatures on success.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `TemperatureError` if:
+    /// - The request fails to send
+    /// - The API returns an error response
+    /// - The response cannot be parsed
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use fitbit_sdk::client::FitbitClient;
+    /// use fitbit_sdk::types::temperature::{TemperatureClient, TemperatureError};
+    /// use tokio;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), TemperatureError> {
+    ///     let client = FitbitClient::new::<TemperatureError>()?;
+    ///
+    ///     let temps = client
+    ///         .get_core_temperature_range("-", "2025-01-01", "2025-01-30")
+    ///         .await?;
+    ///     println!("{} core temperature logs", temps.len());
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    async fn get_core_temperature_range<'a>(
+        &'a self,
+        user_id: impl Into<UserId> + Send,
+        start_date: &'a str,
+        end_date: &'a str,
+    ) -> Result<Vec<CoreTemperature>, TemperatureError> {
+        let user_id: UserId = user_id.into();
+        let path = format!("/user/{}/temp/core/date/{}/{}.json", user_id, start_date, end_date);
+        let response: CoreTemperatureResponse =
+            self.get::<_, (), TemperatureError>(&path, None).await?;
+        Ok(response.temp_core)
+    }
+}
@@ -0,0 +1,184 @@
+//! Token Stores
+//!
+//! This module contains `TokenStore` implementations persisting OAuth tokens
+//! across restarts: `FileTokenStore`, which keeps them in a JSON file, and
+//! `KeyringTokenStore` (with the `keyring` feature), which keeps them in the
+//! operating system keyring.
+
+use crate::types::auth::{AuthError, TokenResponse, TokenStore};
+use async_trait::async_trait;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use tokio::io::AsyncWriteExt;
+
+/// Token store keeping the most recent token in a JSON file
+///
+/// The file is only readable and writable by its owner on Unix, as it holds
+/// the refresh token. Tokens are written to a temporary file first and then
+/// moved into place, so a crash never leaves a truncated file behind.
+///
+/// # Examples
+///
+/// ```no_run
+/// use fitbit_sdk::auth::OAuth2Client;
+/// use fitbit_sdk::client::FitbitClient;
+/// use fitbit_sdk::token_store::FileTokenStore;
+/// use fitbit_sdk::types::auth::TokenStore;
+/// use fitbit_sdk::types::user::UserError;
+///
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let store = FileTokenStore::new("fitbit-token.json");
+/// let token = store.load().await?.expect("run the authorization flow first");
+///
+/// let client = FitbitClient::builder()
+///     .with_token(&token)
+///     .with_oauth_client(OAuth2Client::builder().build()?)
+///     .with_token_store(store)
+///     .build()?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct FileTokenStore {
+    /// Path of the JSON file
+    path: PathBuf,
+}
+
+impl FileTokenStore {
+    /// Creates a store keeping the token in the file at `path`
+    ///
+    /// The file and its parent directories are created on the first save.
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+
+    /// Returns the path of the JSON file
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+#[async_trait]
+impl TokenStore for FileTokenStore {
+    async fn load(&self) -> Result<Option<TokenResponse>, AuthError> {
+        let json = match tokio::fs::read_to_string(&self.path).await {
+            Ok(json) => json,
+            Err(e) if e.kind() == ErrorKind::NotFound => return Ok(None),
+            Err(e) => {
+                return Err(AuthError::from(format!(
+                    "Failed to read {}: {}",
+                    self.path.display(),
+                    e
+                )));
+            }
+        };
+        serde_json::from_str(&json)
+            .map(Some)
+            .map_err(|e| AuthError::from(format!("Failed to parse {}: {}", self.path.display(), e)))
+    }
+
+    async fn save(&self, token: &TokenResponse) -> Result<(), AuthError> {
+        let json = serde_json::to_vec_pretty(token)
+            .map_err(|e| AuthError::from(format!("Failed to serialize token: {}", e)))?;
+        let write_error = |e: std::io::Error| {
+            AuthError::from(format!("Failed to write {}: {}", self.path.display(), e))
+        };
+
+        if let Some(parent) = self.path.parent().filter(|parent| !parent.as_os_str().is_empty()) {
+            tokio::fs::create_dir_all(parent).await.map_err(write_error)?;
+        }
+
+        let mut temporary = self.path.clone().into_os_string();
+        temporary.push(".tmp");
+        // A temporary file left by an interrupted save keeps its mode when
+        // opened, so start over with a new file created with restricted access
+        match tokio::fs::remove_file(&temporary).await {
+            Ok(()) => {}
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+            Err(e) => return Err(write_error(e)),
+        }
+        let mut options = tokio::fs::OpenOptions::new();
+        options.write(true).create_new(true);
+        #[cfg(unix)]
+        options.mode(0o600);
+        let mut file = options.open(&temporary).await.map_err(write_error)?;
+
+        file.write_all(&json).await.map_err(write_error)?;
+        file.sync_all().await.map_err(write_error)?;
+        tokio::fs::rename(&temporary, &self.path)
+            .await
+            .map_err(write_error)
+    }
+}
+
+/// Token store keeping the most recent token in the operating system keyring
+///
+/// Uses the macOS Keychain, the Windows Credential Manager or the Linux
+/// kernel keyring. The token is stored as JSON under a service and user name.
+///
+/// # Examples
+///
+/// ```no_run
+/// use fitbit_sdk::token_store::KeyringTokenStore;
+/// use fitbit_sdk::types::auth::{AuthError, TokenStore};
+///
+/// # async fn example() -> Result<(), AuthError> {
+/// let store = KeyringTokenStore::new("my-fitbit-app", "default")?;
+/// if let Some(token) = store.load().await? {
+///     println!("Token for user {}", token.user_id);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[cfg(feature = "keyring")]
+#[derive(Debug, Clone)]
+pub struct KeyringTokenStore {
+    /// The keyring entry holding the token
+    entry: std::sync::Arc<keyring::Entry>,
+}
+
+#[cfg(feature = "keyring")]
+impl KeyringTokenStore {
+    /// Creates a store keeping the token under `service` and `user`
+    ///
+    /// # Errors
+    ///
+    /// Returns an `AuthError` if the keyring entry cannot be created, e.g.
+    /// because `service` or `user` is empty.
+    pub fn new(service: &str, user: &str) -> Result<Self, AuthError> {
+        let entry = keyring::Entry::new(service, user)
+            .map_err(|e| AuthError::from(format!("Failed to open keyring entry: {}", e)))?;
+        Ok(Self {
+            entry: std::sync::Arc::new(entry),
+        })
+    }
+}
+
+#[cfg(feature = "keyring")]
+#[async_trait]
+impl TokenStore for KeyringTokenStore {
+    async fn load(&self) -> Result<Option<TokenResponse>, AuthError> {
+        // Keyring backends block, so they are called off the async runtime
+        let entry = self.entry.clone();
+        let password = tokio::task::spawn_blocking(move || entry.get_password())
+            .await
+            .map_err(|e| AuthError::from(e.to_string()))?;
+        match password {
+            Ok(json) => serde_json::from_str(&json)
+                .map(Some)
+                .map_err(|e| AuthError::from(format!("Failed to parse keyring token: {}", e))),
+            Err(keyring::Error::NoEntry) => Ok(None),
+            Err(e) => Err(AuthError::from(format!("Failed to read keyring: {}", e))),
+        }
+    }
+
+    async fn save(&self, token: &TokenResponse) -> Result<(), AuthError> {
+        let json = serde_json::to_string(token)
+            .map_err(|e| AuthError::from(format!("Failed to serialize token: {}", e)))?;
+        let entry = self.entry.clone();
+        tokio::task::spawn_blocking(move || entry.set_password(&json))
+            .await
+            .map_err(|e| AuthError::from(e.to_string()))?
+            .map_err(|e| AuthError::from(format!("Failed to write keyring: {}", e)))
+    }
+}
@@ -0,0 +1,222 @@
+//! HTTP Transports
+//!
+//! This module contains the transports `FitbitClient` sends its requests over:
+//! `ReqwestTransport`, used by default, and `MockTransport`, which serves
+//! canned responses for tests.
+
+use crate::client::FitbitClient;
+use crate::types::transport::{
+    StreamingResponse, Transport, TransportError, TransportResponse,
+};
+use async_trait::async_trait;
+use bytes::Bytes;
+use futures_util::stream;
+use reqwest::header::{CONTENT_TYPE, HeaderMap, HeaderValue};
+use reqwest::{Client as ReqwestClient, Method, StatusCode};
+use std::collections::HashMap;
+use std::sync::RwLock;
+
+/// Transport sending requests over the network with reqwest
+///
+/// This is what `FitbitClient` uses unless another transport is configured.
+#[derive(Debug, Clone)]
+pub struct ReqwestTransport {
+    client: ReqwestClient,
+}
+
+impl ReqwestTransport {
+    /// Creates a transport sending requests with `client`
+    pub fn new(client: ReqwestClient) -> Self {
+        Self { client }
+    }
+}
+
+impl Default for ReqwestTransport {
+    fn default() -> Self {
+        Self::new(
+            ReqwestClient::builder()
+                .user_agent(FitbitClient::DEFAULT_USER_AGENT)
+                .build()
+                .unwrap_or_default(),
+        )
+    }
+}
+
+#[async_trait]
+impl Transport for ReqwestTransport {
+    async fn send(&self, request: reqwest::Request) -> Result<TransportResponse, TransportError> {
+        let response = self
+            .client
+            .execute(request)
+            .await
+            .map_err(|e| TransportError::RequestFailed(e.to_string()))?;
+
+        let status = response.status();
+        let headers = response.headers().clone();
+        let body = response.bytes().await.map_err(|e| {
+            TransportError::RequestFailed(format!("Failed to get response body: {}", e))
+        })?;
+
+        Ok(TransportResponse {
+            status,
+            headers,
+            body,
+        })
+    }
+
+    async fn send_streaming(
+        &self,
+        request: reqwest::Request,
+    ) -> Result<StreamingResponse, TransportError> {
+        let response = self
+            .client
+            .execute(request)
+            .await
+            .map_err(|e| TransportError::RequestFailed(e.to_string()))?;
+
+        let status = response.status();
+        let headers = response.headers().clone();
+        let body = stream::try_unfold(response, |mut response| async move {
+            let chunk = response.chunk().await.map_err(|e| {
+                TransportError::RequestFailed(format!("Failed to get response body: {}", e))
+            })?;
+            Ok(chunk.map(|chunk| (chunk, response)))
+        });
+
+        Ok(StreamingResponse {
+            status,
+            headers,
+            body: Box::pin(body),
+        })
+    }
+}
+
+/// Transport serving canned responses keyed by method and path
+///
+/// The path is the path of the request URL, including the API version
+/// (e.g. `/1/user/-/profile.json`); the query string is ignored. Requests
+/// without a canned response receive a `404 Not Found` in the format of the
+/// Fitbit API. Every request is recorded and can be inspected with `requests`.
+///
+/// # Examples
+///
+/// ```
+/// use fitbit_sdk::client::FitbitClient;
+/// use fitbit_sdk::transport::MockTransport;
+/// use fitbit_sdk::types::user::{UserClient, UserError};
+/// use reqwest::Method;
+/// use std::sync::Arc;
+/// use tokio;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), UserError> {
+///     let mock = Arc::new(MockTransport::new().with_json(
+///         Method::GET,
+///         "/1/user/-/profile.json",
+///         r#"{"user": {
+///             "fullName": "Jane Doe", "displayName": "Jane", "dateOfBirth": "1990-01-01",
+///             "gender": "FEMALE", "heightUnit": "METRIC", "weightUnit": "METRIC",
+///             "height": 170.0, "weight": 60.5, "averageDailySteps": 8000,
+///             "avatar": "", "avatar150": "", "avatar640": ""
+///         }}"#,
+///     ));
+///     let client = FitbitClient::builder()
+///         .with_access_token("test-token")
+///         .with_transport(mock.clone())
+///         .build()?;
+///
+///     let profile = client.get_profile("-").await?;
+///     assert_eq!(profile.display_name, "Jane");
+///
+///     // Requests without a canned response fail like a missing resource
+///     assert!(client.get_badges("-").await.is_err());
+///
+///     assert_eq!(
+///         mock.requests(),
+///         vec![
+///             (Method::GET, "/1/user/-/profile.json".to_string()),
+///             (Method::GET, "/1/user/-/badges.json".to_string()),
+///         ]
+///     );
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Default)]
+pub struct MockTransport {
+    /// Canned responses keyed by method and path
+    responses: HashMap<(Method, String), TransportResponse>,
+    /// Method and path of every request received
+    requests: RwLock<Vec<(Method, String)>>,
+}
+
+impl MockTransport {
+    /// Creates a transport without any canned response
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Serves `body` as JSON with a `200 OK` status for `method` and `path`
+    pub fn with_json(self, method: Method, path: impl Into<String>, body: impl Into<Bytes>) -> Self {
+        self.with_status(method, path, StatusCode::OK, body)
+    }
+
+    /// Serves `body` as JSON with `status` for `method` and `path`
+    pub fn with_status(
+        self,
+        method: Method,
+        path: impl Into<String>,
+        status: StatusCode,
+        body: impl Into<Bytes>,
+    ) -> Self {
+        let mut headers = HeaderMap::new();
+        headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+        self.with_response(
+            method,
+            path,
+            TransportResponse {
+                status,
+                headers,
+                body: body.into(),
+            },
+        )
+    }
+
+    /// Serves `response` for `method` and `path`
+    pub fn with_response(
+        mut self,
+        method: Method,
+        path: impl Into<String>,
+        response: TransportResponse,
+    ) -> Self {
+        self.responses.insert((method, path.into()), response);
+        self
+    }
+
+    /// Returns the method and path of every request received, oldest first
+    pub fn requests(&self) -> Vec<(Method, String)> {
+        self.requests.read().unwrap_or_else(|e| e.into_inner()).clone()
+    }
+}
+
+#[async_trait]
+impl Transport for MockTransport {
+    async fn send(&self, request: reqwest::Request) -> Result<TransportResponse, TransportError> {
+        let key = (request.method().clone(), request.url().path().to_string());
+        self.requests
+            .write()
+            .unwrap_or_else(|e| e.into_inner())
+            .push(key.clone());
+
+        Ok(self.responses.get(&key).cloned().unwrap_or_else(|| {
+            TransportResponse {
+                status: StatusCode::NOT_FOUND,
+                headers: HeaderMap::new(),
+                body: format!(
+                    r#"{{"errors":[{{"errorType":"not_found","message":"No mock response for {} {}"}}],"success":false}}"#,
+                    key.0, key.1
+                )
+                .into(),
+            }
+        }))
+    }
+}
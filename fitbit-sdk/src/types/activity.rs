@@ -2,55 +2,236 @@
 //!
 //! This module contains the types and functions for the Fitbit Activity API.
 //!
+use crate::types::auth::Scope;
+use crate::types::date::FitbitDate;
+use crate::types::datetime::{ApiDate, ApiTime, ApiTimestamp};
+use crate::types::error::{FitbitError, domain_error};
+use crate::types::heart_rate::HeartRateZone;
+use crate::types::intraday::{DetailLevel, IntradayDataPoint, IntradayDataset};
+use crate::types::locale::UnitSystem;
+use crate::types::pagination::{ListParams, Paginated, Pagination};
+#[cfg(feature = "tcx")]
+use crate::types::tcx::Tcx;
+use crate::types::time_series::TimeSeriesPoint;
+use crate::types::user::UserId;
 use async_trait::async_trait;
+use futures_util::stream::BoxStream;
 use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::time::Duration;
 use thiserror::Error;
+use time::{Date, Time};
 
 /// Error types for the Activity API
 #[derive(Debug, Error)]
-pub enum ActivityError {
-    #[error("API request failed: {0}")]
-    RequestFailed(String),
-    #[error("API error: {0}")]
-    ApiError(String),
-}
+#[error(transparent)]
+pub struct ActivityError(#[from] pub FitbitError);
 
-impl From<String> for ActivityError {
-    fn from(error: String) -> Self {
-        ActivityError::ApiError(error)
-    }
-}
+domain_error!(ActivityError, scopes [Scope::Activity]);
 
 #[async_trait]
 pub trait ActivityClient {
     async fn get_activity_summary<'a>(
         &'a self,
-        user_id: &'a str,
-        date: &'a str,
+        user_id: impl Into<UserId> + Send,
+        date: impl Into<FitbitDate> + Send,
     ) -> Result<ActivitySummary, ActivityError>;
 
+    async fn get_activity_daily<'a>(
+        &'a self,
+        user_id: impl Into<UserId> + Send,
+        date: impl Into<FitbitDate> + Send,
+    ) -> Result<DailyActivityResponse, ActivityError>;
+
+    async fn get_activity_summary_range<'a>(
+        &'a self,
+        user_id: impl Into<UserId> + Send,
+        start_date: Date,
+        end_date: Date,
+    ) -> Result<ActivityRangeSummary, ActivityError>;
+
     async fn get_activity_time_series<'a>(
         &'a self,
-        user_id: &'a str,
+        user_id: impl Into<UserId> + Send,
         resource: Resource,
-        date: &'a str,
+        date: impl Into<FitbitDate> + Send,
         period: &'a str,
     ) -> Result<Vec<ActivityTimeSeries>, ActivityError>;
+    async fn get_activity_time_series_range<'a>(
+        &'a self,
+        user_id: impl Into<UserId> + Send,
+        resource: Resource,
+        start_date: impl Into<FitbitDate> + Send,
+        end_date: impl Into<FitbitDate> + Send,
+    ) -> Result<Vec<ActivityTimeSeries>, ActivityError>;
+
+    async fn get_elevation_time_series<'a>(
+        &'a self,
+        user_id: impl Into<UserId> + Send,
+        date: impl Into<FitbitDate> + Send,
+        period: &'a str,
+        tracker_only: bool,
+    ) -> Result<Vec<Elevation>, ActivityError>;
+
+    async fn get_activity_intraday<'a>(
+        &'a self,
+        user_id: impl Into<UserId> + Send,
+        resource: Resource,
+        date: impl Into<FitbitDate> + Send,
+        detail_level: DetailLevel,
+    ) -> Result<IntradayActivitySeries, ActivityError>;
+
+    async fn get_activity_intraday_window<'a>(
+        &'a self,
+        user_id: impl Into<UserId> + Send,
+        resource: Resource,
+        date: impl Into<FitbitDate> + Send,
+        detail_level: DetailLevel,
+        start_time: Time,
+        end_time: Time,
+    ) -> Result<IntradayActivitySeries, ActivityError>;
+
+    fn stream_activity_intraday<'a>(
+        &'a self,
+        user_id: impl Into<UserId> + Send,
+        resource: Resource,
+        date: impl Into<FitbitDate> + Send,
+        detail_level: DetailLevel,
+    ) -> BoxStream<'a, Result<IntradayDataPoint<f64>, ActivityError>>;
+
+    async fn get_lifetime_stats<'a>(&'a self, user_id: impl Into<UserId> + Send) -> Result<ActivityLifetimeStats, ActivityError>;
+
+    async fn get_lifetime_stats_detailed<'a>(
+        &'a self,
+        user_id: impl Into<UserId> + Send,
+    ) -> Result<DetailedLifetimeStats, ActivityError>;
+
+    async fn log_activity<'a>(
+        &'a self,
+        user_id: impl Into<UserId> + Send,
+        params: &'a LogActivityParams,
+    ) -> Result<ActivityLog, ActivityError>;
+
+    async fn quick_log_activity<'a>(
+        &'a self,
+        activity_id: i64,
+        date: Date,
+        start_time: Time,
+        duration: Duration,
+    ) -> Result<ActivityLog, ActivityError>;
 
-    async fn get_lifetime_stats<'a>(&'a self, user_id: &'a str) -> Result<ActivityLifetimeStats, ActivityError>;
+    async fn delete_activity_log<'a>(&'a self, user_id: impl Into<UserId> + Send, log_id: i64) -> Result<(), ActivityError>;
+
+    async fn get_activity_log_list<'a>(
+        &'a self,
+        user_id: impl Into<UserId> + Send,
+        params: &'a ListParams,
+    ) -> Result<ActivityLogList, ActivityError>;
+
+    async fn get_all_activity_log_list<'a>(
+        &'a self,
+        user_id: impl Into<UserId> + Send,
+        params: &'a ListParams,
+    ) -> Result<Vec<ActivityLogEntry>, ActivityError>;
+
+    fn stream_activity_log_list<'a>(
+        &'a self,
+        user_id: impl Into<UserId> + Send,
+        params: &'a ListParams,
+    ) -> BoxStream<'a, Result<ActivityLogEntry, ActivityError>>;
+
+    async fn get_activity_tcx<'a>(&'a self, user_id: impl Into<UserId> + Send, log_id: i64) -> Result<String, ActivityError>;
+
+    #[cfg(feature = "tcx")]
+    async fn get_activity_tcx_parsed<'a>(&'a self, user_id: impl Into<UserId> + Send, log_id: i64) -> Result<Tcx, ActivityError>;
+
+    async fn get_activity_goals<'a>(
+        &'a self,
+        user_id: impl Into<UserId> + Send,
+        period: GoalPeriod,
+    ) -> Result<ActivityGoals, ActivityError>;
+
+    async fn update_activity_goals<'a>(
+        &'a self,
+        user_id: impl Into<UserId> + Send,
+        period: GoalPeriod,
+        params: &'a ActivityGoalsParams,
+    ) -> Result<ActivityGoals, ActivityError>;
+
+    async fn get_favorite_activities<'a>(&'a self, user_id: impl Into<UserId> + Send) -> Result<Vec<FavoriteActivity>, ActivityError>;
+
+    async fn get_frequent_activities<'a>(&'a self, user_id: impl Into<UserId> + Send) -> Result<Vec<FrequentActivity>, ActivityError>;
+
+    async fn get_recent_activities<'a>(&'a self, user_id: impl Into<UserId> + Send) -> Result<Vec<FrequentActivity>, ActivityError>;
+
+    async fn add_favorite_activity<'a>(&'a self, user_id: impl Into<UserId> + Send, activity_id: i64) -> Result<(), ActivityError>;
+
+    async fn delete_favorite_activity<'a>(&'a self, user_id: impl Into<UserId> + Send, activity_id: i64) -> Result<(), ActivityError>;
+
+    async fn browse_activity_types<'a>(&'a self) -> Result<Vec<ActivityCategory>, ActivityError>;
+
+    async fn get_activity_type<'a>(&'a self, activity_id: i64) -> Result<ActivityType, ActivityError>;
 }
 
 /// Activity summary for a specific date
-#[derive(Debug, Deserialize)]
+///
+/// # Examples
+///
+/// ```
+/// use fitbit_sdk::types::activity::ActivitySummary;
+///
+/// let json = r#"{
+///     "steps": 9120,
+///     "distances": [{"activity": "total", "distance": 6.5}],
+///     "calories": 2410,
+///     "sedentaryMinutes": 620,
+///     "lightlyActiveMinutes": 210,
+///     "fairlyActiveMinutes": 25,
+///     "veryActiveMinutes": 30,
+///     "heartRateZones": [
+///         {"name": "Fat Burn", "min": 98, "max": 137, "minutes": 41, "caloriesOut": 310.5}
+///     ],
+///     "activeZoneMinutes": {
+///         "totalMinutes": 52,
+///         "minutesInHeartRateZones": [
+///             {"zoneName": "Fat Burn", "type": "FAT_BURN", "minutes": 28, "minuteMultiplier": 1, "order": 1},
+///             {"zoneName": "Cardio", "type": "CARDIO", "minutes": 24, "minuteMultiplier": 2, "order": 2}
+///         ]
+///     }
+/// }"#;
+///
+/// let summary: ActivitySummary = serde_json::from_str(json).unwrap();
+/// assert_eq!(summary.heart_rate_zones[0].minutes, Some(41));
+/// let azm = summary.active_zone_minutes.as_ref().unwrap();
+/// assert_eq!(azm.total_minutes, 52);
+/// assert_eq!(azm.minutes_in_heart_rate_zones[1].zone_type, "CARDIO");
+///
+/// let json = serde_json::to_string(&summary).unwrap();
+/// assert_eq!(serde_json::from_str::<ActivitySummary>(&json).unwrap(), summary);
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ActivitySummary {
     /// Total steps taken for the day
     pub steps: i32,
     /// Total distance traveled for the day
+    #[serde(default)]
     pub distances: Vec<Distance>,
     /// Total calories burned for the day
+    #[serde(rename = "caloriesOut", alias = "calories")]
     pub calories: i32,
+    /// Calories burned by activity, on top of the basal metabolic rate
+    #[serde(rename = "activityCalories", default)]
+    pub activity_calories: Option<i32>,
+    /// Calories burned by the basal metabolic rate, i.e. at rest
+    #[serde(rename = "caloriesBMR", default)]
+    pub calories_bmr: Option<i32>,
+    /// Calories burned above the basal metabolic rate during active periods
+    #[serde(rename = "marginalCalories", default)]
+    pub marginal_calories: Option<i32>,
     /// Total number of floors climbed for the day
     pub floors: Option<i32>,
+    /// Elevation climbed for the day, in meters or feet depending on the unit system
+    pub elevation: Option<f64>,
     /// Total active minutes for the day
     #[serde(rename = "sedentaryMinutes")]
     pub sedentary_minutes: i32,
@@ -63,79 +244,526 @@ pub struct ActivitySummary {
     /// Minutes spent in intense activity
     #[serde(rename = "veryActiveMinutes")]
     pub very_active_minutes: i32,
-    /// Rest heart rate
-    #[serde(rename = "restingHeartRate")]
+    /// Rest heart rate, only returned with the `heartrate` scope
+    #[serde(rename = "restingHeartRate", default)]
     pub resting_heart_rate: Option<i32>,
+    /// Time spent in the default heart rate zones, only returned with the
+    /// `heartrate` scope
+    #[serde(rename = "heartRateZones", default)]
+    pub heart_rate_zones: Vec<HeartRateZone>,
+    /// Active Zone Minutes earned during the day
+    #[serde(rename = "activeZoneMinutes", default)]
+    pub active_zone_minutes: Option<SummaryActiveZoneMinutes>,
+    /// Legacy activity score, -1 when not computed
+    #[serde(rename = "activeScore", default)]
+    pub active_score: Option<i32>,
+    /// Estimated total calories Fitbit uses while the day is incomplete
+    #[serde(rename = "calorieEstimationMu", default)]
+    pub calorie_estimation_mu: Option<i32>,
+    /// Total calories burned, without the estimate for the rest of the day
+    #[serde(rename = "caloriesOutUnestimated", default)]
+    pub calories_out_unestimated: Option<i32>,
+    /// Whether `calories` includes an estimate for the rest of the day
+    #[serde(rename = "useEstimation", default)]
+    pub use_estimation: Option<bool>,
+    /// Fields of the response not modeled above
+    #[serde(flatten, deserialize_with = "crate::types::extra::deserialize")]
+    pub extra: Map<String, Value>,
+}
+
+impl ActivitySummary {
+    /// Returns the total distance of the day, in the user's distance unit
+    pub fn total_distance(&self) -> Option<f64> {
+        self.distances
+            .iter()
+            .find(|distance| distance.activity == "total")
+            .map(|distance| distance.distance)
+    }
+
+    /// Returns the minutes spent in moderate or intense activity
+    pub fn active_minutes(&self) -> i32 {
+        self.fairly_active_minutes + self.very_active_minutes
+    }
+}
+
+/// Activity summaries of a date range, with their totals and averages
+///
+/// Fitbit has no endpoint summarizing several days, so
+/// `ActivityClient::get_activity_summary_range` fetches the summary of each
+/// day and aggregates them with `ActivityRangeSummary::from_days`.
+///
+/// # Examples
+///
+/// ```
+/// use fitbit_sdk::types::activity::{ActivityRangeSummary, ActivitySummary};
+/// use time::macros::date;
+///
+/// let summary = |steps: i32, resting_heart_rate: Option<i32>| ActivitySummary {
+///     steps,
+///     distances: Vec::new(),
+///     calories: 2000,
+///     activity_calories: Some(600),
+///     calories_bmr: Some(1400),
+///     marginal_calories: Some(350),
+///     floors: Some(10),
+///     elevation: Some(30.0),
+///     sedentary_minutes: 600,
+///     lightly_active_minutes: 200,
+///     fairly_active_minutes: 20,
+///     very_active_minutes: 10,
+///     resting_heart_rate,
+///     heart_rate_zones: Vec::new(),
+///     active_zone_minutes: None,
+///     active_score: None,
+///     calorie_estimation_mu: None,
+///     calories_out_unestimated: None,
+///     use_estimation: None,
+///     extra: Default::default(),
+/// };
+///
+/// let range = ActivityRangeSummary::from_days(vec![
+///     (date!(2025 - 01 - 13), summary(8000, Some(60))),
+///     (date!(2025 - 01 - 14), summary(12000, None)),
+///     (date!(2025 - 01 - 15), summary(10000, Some(58))),
+/// ]);
+/// assert_eq!(range.totals.steps, 30000);
+/// assert_eq!(range.totals.active_minutes, 90);
+/// assert_eq!(range.totals.calories_bmr, 4200);
+/// assert_eq!(range.averages.steps, 10000.0);
+/// assert_eq!(range.averages.resting_heart_rate, Some(59.0));
+/// assert_eq!(range.best_day.unwrap().date, date!(2025 - 01 - 14));
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ActivityRangeSummary {
+    /// Summary of each day, in date order
+    pub days: Vec<DailySummary>,
+    /// Sums over all days
+    pub totals: ActivityTotals,
+    /// Averages per day
+    pub averages: ActivityAverages,
+    /// Day with the most steps, `None` for an empty range
+    pub best_day: Option<DailySummary>,
+}
+
+impl ActivityRangeSummary {
+    /// Aggregates the summaries of several days
+    ///
+    /// The days are sorted by date. When several days have the most steps,
+    /// the earliest one is the best day.
+    pub fn from_days(days: impl IntoIterator<Item = (Date, ActivitySummary)>) -> Self {
+        let mut days: Vec<DailySummary> = days
+            .into_iter()
+            .map(|(date, summary)| DailySummary { date, summary })
+            .collect();
+        days.sort_by_key(|day| day.date);
+
+        let mut totals = ActivityTotals::default();
+        let mut resting_heart_rates = Vec::new();
+        for day in &days {
+            let summary = &day.summary;
+            totals.steps += i64::from(summary.steps);
+            totals.distance += summary.total_distance().unwrap_or_default();
+            totals.calories += i64::from(summary.calories);
+            totals.activity_calories += i64::from(summary.activity_calories.unwrap_or_default());
+            totals.calories_bmr += i64::from(summary.calories_bmr.unwrap_or_default());
+            totals.marginal_calories += i64::from(summary.marginal_calories.unwrap_or_default());
+            totals.floors += i64::from(summary.floors.unwrap_or_default());
+            totals.sedentary_minutes += i64::from(summary.sedentary_minutes);
+            totals.lightly_active_minutes += i64::from(summary.lightly_active_minutes);
+            totals.active_minutes += i64::from(summary.active_minutes());
+            totals.active_zone_minutes += summary
+                .active_zone_minutes
+                .as_ref()
+                .map_or(0, |azm| i64::from(azm.total_minutes));
+            resting_heart_rates.extend(summary.resting_heart_rate);
+        }
+
+        let average = |total: f64, count: usize| {
+            if count == 0 { 0.0 } else { total / count as f64 }
+        };
+        let count = days.len();
+        let averages = ActivityAverages {
+            steps: average(totals.steps as f64, count),
+            distance: average(totals.distance, count),
+            calories: average(totals.calories as f64, count),
+            activity_calories: average(totals.activity_calories as f64, count),
+            calories_bmr: average(totals.calories_bmr as f64, count),
+            marginal_calories: average(totals.marginal_calories as f64, count),
+            floors: average(totals.floors as f64, count),
+            sedentary_minutes: average(totals.sedentary_minutes as f64, count),
+            lightly_active_minutes: average(totals.lightly_active_minutes as f64, count),
+            active_minutes: average(totals.active_minutes as f64, count),
+            active_zone_minutes: average(totals.active_zone_minutes as f64, count),
+            resting_heart_rate: (!resting_heart_rates.is_empty()).then(|| {
+                let sum: i32 = resting_heart_rates.iter().sum();
+                average(f64::from(sum), resting_heart_rates.len())
+            }),
+        };
+
+        // `max_by_key` keeps the last maximum, so the days are searched in reverse
+        let best_day = days.iter().rev().max_by_key(|day| day.summary.steps).cloned();
+
+        Self {
+            days,
+            totals,
+            averages,
+            best_day,
+        }
+    }
+}
+
+/// Activity summary of a single day
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DailySummary {
+    /// Date of the summary
+    pub date: Date,
+    /// Activity summary of the day
+    pub summary: ActivitySummary,
+}
+
+/// Activity totals over a date range
+///
+/// Days without a value (e.g. floors on trackers without an altimeter) count as zero.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct ActivityTotals {
+    /// Steps taken
+    pub steps: i64,
+    /// Distance traveled, in the user's distance unit
+    pub distance: f64,
+    /// Calories burned
+    pub calories: i64,
+    /// Calories burned by activity, on top of the basal metabolic rate
+    pub activity_calories: i64,
+    /// Calories burned by the basal metabolic rate
+    pub calories_bmr: i64,
+    /// Calories burned above the basal metabolic rate during active periods
+    pub marginal_calories: i64,
+    /// Floors climbed
+    pub floors: i64,
+    /// Minutes spent sedentary
+    pub sedentary_minutes: i64,
+    /// Minutes spent in light activity
+    pub lightly_active_minutes: i64,
+    /// Minutes spent in moderate or intense activity
+    pub active_minutes: i64,
+    /// Active Zone Minutes earned
+    pub active_zone_minutes: i64,
+}
+
+/// Daily activity averages over a date range
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct ActivityAverages {
+    /// Steps taken per day
+    pub steps: f64,
+    /// Distance traveled per day, in the user's distance unit
+    pub distance: f64,
+    /// Calories burned per day
+    pub calories: f64,
+    /// Calories burned by activity per day, on top of the basal metabolic rate
+    pub activity_calories: f64,
+    /// Calories burned by the basal metabolic rate per day
+    pub calories_bmr: f64,
+    /// Calories burned above the basal metabolic rate during active periods per day
+    pub marginal_calories: f64,
+    /// Floors climbed per day
+    pub floors: f64,
+    /// Minutes spent sedentary per day
+    pub sedentary_minutes: f64,
+    /// Minutes spent in light activity per day
+    pub lightly_active_minutes: f64,
+    /// Minutes spent in moderate or intense activity per day
+    pub active_minutes: f64,
+    /// Active Zone Minutes earned per day
+    pub active_zone_minutes: f64,
+    /// Resting heart rate, averaged over the days it was measured
+    pub resting_heart_rate: Option<f64>,
+}
+
+/// Active Zone Minutes in a daily activity summary
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SummaryActiveZoneMinutes {
+    /// Total Active Zone Minutes
+    #[serde(rename = "totalMinutes", default)]
+    pub total_minutes: i32,
+    /// Active Zone Minutes per heart rate zone
+    #[serde(rename = "minutesInHeartRateZones", default)]
+    pub minutes_in_heart_rate_zones: Vec<ZoneMinutes>,
+    /// Fields of the response not modeled above
+    #[serde(flatten, deserialize_with = "crate::types::extra::deserialize")]
+    pub extra: Map<String, Value>,
+}
+
+/// Active Zone Minutes earned in a single heart rate zone
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ZoneMinutes {
+    /// Name of the zone
+    #[serde(rename = "zoneName")]
+    pub zone_name: String,
+    /// Type of the zone (e.g. "OUT_OF_ZONE", "FAT_BURN", "CARDIO", "PEAK")
+    #[serde(rename = "type")]
+    pub zone_type: String,
+    /// Active Zone Minutes earned in the zone
+    pub minutes: i32,
+    /// Active Zone Minutes earned per minute in the zone
+    #[serde(rename = "minuteMultiplier", default)]
+    pub minute_multiplier: i32,
+    /// Position of the zone, from lowest to highest intensity
+    #[serde(default)]
+    pub order: i32,
+    /// Fields of the response not modeled above
+    #[serde(flatten, deserialize_with = "crate::types::extra::deserialize")]
+    pub extra: Map<String, Value>,
 }
 
 /// Distance information for various activity types
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Distance {
     pub activity: String,
     pub distance: f64,
+    /// Fields of the response not modeled above
+    #[serde(flatten, deserialize_with = "crate::types::extra::deserialize")]
+    pub extra: Map<String, Value>,
 }
 
 /// Activity resource types for time series
-#[derive(Debug, Clone, Copy)]
+///
+/// Resources wrapped in `Resource::Tracker` only include data recorded by the
+/// tracker, excluding manually logged activities.
+///
+/// # Examples
+///
+/// ```
+/// use fitbit_sdk::types::activity::{Resource, TrackerResource};
+///
+/// let segments = [
+///     (Resource::ActivityCalories, "activityCalories"),
+///     (Resource::Calories, "calories"),
+///     (Resource::CaloriesBmr, "caloriesBMR"),
+///     (Resource::Distance, "distance"),
+///     (Resource::Elevation, "elevation"),
+///     (Resource::Floors, "floors"),
+///     (Resource::SedentaryMinutes, "minutesSedentary"),
+///     (Resource::LightlyActiveMinutes, "minutesLightlyActive"),
+///     (Resource::FairlyActiveMinutes, "minutesFairlyActive"),
+///     (Resource::VeryActiveMinutes, "minutesVeryActive"),
+///     (Resource::Steps, "steps"),
+///     (Resource::Tracker(TrackerResource::ActivityCalories), "tracker/activityCalories"),
+///     (Resource::Tracker(TrackerResource::Calories), "tracker/calories"),
+///     (Resource::Tracker(TrackerResource::Distance), "tracker/distance"),
+///     (Resource::Tracker(TrackerResource::Elevation), "tracker/elevation"),
+///     (Resource::Tracker(TrackerResource::Floors), "tracker/floors"),
+///     (Resource::Tracker(TrackerResource::SedentaryMinutes), "tracker/minutesSedentary"),
+///     (Resource::Tracker(TrackerResource::LightlyActiveMinutes), "tracker/minutesLightlyActive"),
+///     (Resource::Tracker(TrackerResource::FairlyActiveMinutes), "tracker/minutesFairlyActive"),
+///     (Resource::Tracker(TrackerResource::VeryActiveMinutes), "tracker/minutesVeryActive"),
+///     (Resource::Tracker(TrackerResource::Steps), "tracker/steps"),
+/// ];
+/// for (resource, segment) in segments {
+///     assert_eq!(resource.as_str(), segment);
+/// }
+///
+/// assert_eq!(Resource::Steps.response_key(), "activities-steps");
+/// assert_eq!(
+///     Resource::Tracker(TrackerResource::Steps).response_key(),
+///     "activities-tracker-steps"
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Resource {
-    Steps,
+    ActivityCalories,
     Calories,
+    CaloriesBmr,
     Distance,
+    Elevation,
     Floors,
-    Minutes,
-    ActiveMinutes,
     SedentaryMinutes,
     LightlyActiveMinutes,
     FairlyActiveMinutes,
     VeryActiveMinutes,
+    Steps,
+    /// Resource recorded by the tracker only
+    Tracker(TrackerResource),
 }
 
 impl Resource {
     pub fn as_str(&self) -> &'static str {
         match self {
-            Resource::Steps => "steps",
+            Resource::ActivityCalories => "activityCalories",
             Resource::Calories => "calories",
+            Resource::CaloriesBmr => "caloriesBMR",
             Resource::Distance => "distance",
+            Resource::Elevation => "elevation",
             Resource::Floors => "floors",
-            Resource::Minutes => "minutes",
-            Resource::ActiveMinutes => "minutesAsleep",
             Resource::SedentaryMinutes => "minutesSedentary",
             Resource::LightlyActiveMinutes => "minutesLightlyActive",
             Resource::FairlyActiveMinutes => "minutesFairlyActive",
             Resource::VeryActiveMinutes => "minutesVeryActive",
+            Resource::Steps => "steps",
+            Resource::Tracker(resource) => resource.as_str(),
+        }
+    }
+
+    /// Returns the key holding the time series in the API response
+    pub fn response_key(&self) -> String {
+        format!("activities-{}", self.as_str().replace('/', "-"))
+    }
+}
+
+/// Activity resource types recorded by the tracker
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TrackerResource {
+    ActivityCalories,
+    Calories,
+    Distance,
+    Elevation,
+    Floors,
+    SedentaryMinutes,
+    LightlyActiveMinutes,
+    FairlyActiveMinutes,
+    VeryActiveMinutes,
+    Steps,
+}
+
+impl TrackerResource {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TrackerResource::ActivityCalories => "tracker/activityCalories",
+            TrackerResource::Calories => "tracker/calories",
+            TrackerResource::Distance => "tracker/distance",
+            TrackerResource::Elevation => "tracker/elevation",
+            TrackerResource::Floors => "tracker/floors",
+            TrackerResource::SedentaryMinutes => "tracker/minutesSedentary",
+            TrackerResource::LightlyActiveMinutes => "tracker/minutesLightlyActive",
+            TrackerResource::FairlyActiveMinutes => "tracker/minutesFairlyActive",
+            TrackerResource::VeryActiveMinutes => "tracker/minutesVeryActive",
+            TrackerResource::Steps => "tracker/steps",
         }
     }
 }
 
 /// Activity time series data point
-#[derive(Debug, Deserialize)]
-pub struct ActivityTimeSeries {
-    /// Date for the data point
-    pub datetime: String,
-    /// Value for the data point
-    pub value: String,
+pub type ActivityTimeSeries = TimeSeriesPoint<f64>;
+
+/// Unit of an elevation
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ElevationUnit {
+    Meters,
+    Feet,
+}
+
+impl ElevationUnit {
+    /// Meters in a foot
+    const METERS_PER_FOOT: f64 = 0.3048;
+
+    /// Returns the unit elevations are reported in for a unit system
+    pub fn for_unit_system(unit_system: UnitSystem) -> Self {
+        match unit_system {
+            UnitSystem::Us => ElevationUnit::Feet,
+            UnitSystem::Metric | UnitSystem::Uk => ElevationUnit::Meters,
+        }
+    }
+}
+
+/// Elevation climbed on a day, in the unit of the response
+///
+/// # Examples
+///
+/// ```
+/// use fitbit_sdk::types::activity::{Elevation, ElevationUnit};
+/// use fitbit_sdk::types::datetime::{ApiDate, ApiField};
+///
+/// let elevation = Elevation {
+///     date: ApiDate::from_api_str("2025-01-15").unwrap(),
+///     value: 100.0,
+///     unit: ElevationUnit::Feet,
+/// };
+/// assert_eq!(elevation.to_meters(), 30.48);
+/// assert_eq!(elevation.to_feet(), 100.0);
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Elevation {
+    /// Date of the elevation
+    #[serde(with = "crate::types::datetime::api_field")]
+    pub date: ApiDate,
+    /// Elevation climbed, in `unit`
+    pub value: f64,
+    /// Unit of the value
+    pub unit: ElevationUnit,
+}
+
+impl Elevation {
+    /// Returns the elevation in meters
+    pub fn to_meters(&self) -> f64 {
+        match self.unit {
+            ElevationUnit::Meters => self.value,
+            ElevationUnit::Feet => self.value * ElevationUnit::METERS_PER_FOOT,
+        }
+    }
+
+    /// Returns the elevation in feet
+    pub fn to_feet(&self) -> f64 {
+        match self.unit {
+            ElevationUnit::Meters => self.value / ElevationUnit::METERS_PER_FOOT,
+            ElevationUnit::Feet => self.value,
+        }
+    }
+}
+
+/// Intraday activity data for a single day
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IntradayActivitySeries {
+    /// Daily total for the requested resource
+    pub summary: Vec<ActivityTimeSeries>,
+    /// Value of the resource at each timestamp
+    pub intraday: IntradayDataset<f64>,
+    /// Fields of the response not modeled above
+    #[serde(flatten, deserialize_with = "crate::types::extra::deserialize")]
+    pub extra: Map<String, Value>,
 }
 
 /// Lifetime activity statistics
-#[derive(Debug, Deserialize)]
+///
+/// Both the best days and the lifetime totals come in two flavours: `total`
+/// includes manually logged activities, `tracker` only counts the data
+/// recorded by the user's devices.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ActivityLifetimeStats {
     /// Best day statistics
     pub best: BestStats,
-    /// Total lifetime statistics
+    /// Lifetime totals
+    pub lifetime: LifetimeTotals,
+    /// Fields of the response not modeled above
+    #[serde(flatten, deserialize_with = "crate::types::extra::deserialize")]
+    pub extra: Map<String, Value>,
+}
+
+/// Lifetime totals
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LifetimeTotals {
+    /// Totals including manually logged activities
     pub total: TotalStats,
+    /// Totals recorded by trackers
+    pub tracker: TotalStats,
+    /// Fields of the response not modeled above
+    #[serde(flatten, deserialize_with = "crate::types::extra::deserialize")]
+    pub extra: Map<String, Value>,
 }
 
 /// Best day statistics
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct BestStats {
     /// Best total distance
     pub total: BestTotal,
     /// Best tracker distance
     pub tracker: BestTracker,
+    /// Fields of the response not modeled above
+    #[serde(flatten, deserialize_with = "crate::types::extra::deserialize")]
+    pub extra: Map<String, Value>,
 }
 
 /// Best total statistics
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct BestTotal {
     /// Best total distance
     pub distance: BestDistance,
@@ -143,10 +771,13 @@ pub struct BestTotal {
     pub steps: BestSteps,
     /// Best total floors
     pub floors: Option<BestFloors>,
+    /// Fields of the response not modeled above
+    #[serde(flatten, deserialize_with = "crate::types::extra::deserialize")]
+    pub extra: Map<String, Value>,
 }
 
 /// Best tracker statistics
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct BestTracker {
     /// Best tracker distance
     pub distance: BestDistance,
@@ -154,31 +785,46 @@ pub struct BestTracker {
     pub steps: BestSteps,
     /// Best tracker floors
     pub floors: Option<BestFloors>,
+    /// Fields of the response not modeled above
+    #[serde(flatten, deserialize_with = "crate::types::extra::deserialize")]
+    pub extra: Map<String, Value>,
 }
 
 /// Best distance information
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct BestDistance {
-    pub date: String,
+    #[serde(with = "crate::types::datetime::api_field")]
+    pub date: ApiDate,
     pub value: f64,
+    /// Fields of the response not modeled above
+    #[serde(flatten, deserialize_with = "crate::types::extra::deserialize")]
+    pub extra: Map<String, Value>,
 }
 
 /// Best steps information
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct BestSteps {
-    pub date: String,
+    #[serde(with = "crate::types::datetime::api_field")]
+    pub date: ApiDate,
     pub value: i32,
+    /// Fields of the response not modeled above
+    #[serde(flatten, deserialize_with = "crate::types::extra::deserialize")]
+    pub extra: Map<String, Value>,
 }
 
 /// Best floors information
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct BestFloors {
-    pub date: String,
+    #[serde(with = "crate::types::datetime::api_field")]
+    pub date: ApiDate,
     pub value: i32,
+    /// Fields of the response not modeled above
+    #[serde(flatten, deserialize_with = "crate::types::extra::deserialize")]
+    pub extra: Map<String, Value>,
 }
 
 /// Total lifetime statistics
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct TotalStats {
     /// Total lifetime distance
     pub distance: f64,
@@ -186,16 +832,729 @@ pub struct TotalStats {
     pub steps: i64,
     /// Total lifetime floors
     pub floors: Option<i64>,
+    /// Total lifetime calories burned, `-1` when not tracked
+    #[serde(rename = "caloriesOut")]
+    pub calories_out: Option<i64>,
+    /// Total lifetime active score, `-1` when not tracked
+    #[serde(rename = "activeScore")]
+    pub active_score: Option<i64>,
+    /// Fields of the response not modeled above
+    #[serde(flatten, deserialize_with = "crate::types::extra::deserialize")]
+    pub extra: Map<String, Value>,
+}
+
+/// Lifetime totals and best days counted from one source of activity data
+#[derive(Debug, Clone, PartialEq)]
+pub struct LifetimeSourceStats {
+    /// Lifetime distance
+    pub distance: f64,
+    /// Lifetime steps
+    pub steps: i64,
+    /// Lifetime floors, `None` without an altimeter
+    pub floors: Option<i64>,
+    /// Lifetime calories burned, `None` when not tracked
+    pub calories_out: Option<i64>,
+    /// Lifetime active score, `None` when not tracked
+    pub active_score: Option<i64>,
+    /// Day with the longest distance
+    pub best_distance: BestDistance,
+    /// Day with the most steps
+    pub best_steps: BestSteps,
+    /// Day with the most floors
+    pub best_floors: Option<BestFloors>,
+}
+
+impl LifetimeSourceStats {
+    /// Combines the lifetime totals and best days of a source, dropping the
+    /// `-1` Fitbit reports for untracked calories and active score
+    fn new(
+        totals: &TotalStats,
+        best_distance: &BestDistance,
+        best_steps: &BestSteps,
+        best_floors: Option<&BestFloors>,
+    ) -> Self {
+        let tracked = |value: Option<i64>| value.filter(|value| *value >= 0);
+        Self {
+            distance: totals.distance,
+            steps: totals.steps,
+            floors: totals.floors,
+            calories_out: tracked(totals.calories_out),
+            active_score: tracked(totals.active_score),
+            best_distance: best_distance.clone(),
+            best_steps: best_steps.clone(),
+            best_floors: best_floors.cloned(),
+        }
+    }
+}
+
+/// Lifetime statistics broken down by source
+///
+/// Groups the `total` and `tracker` branches of `ActivityLifetimeStats` by
+/// source rather than by kind of statistic, so the figures recorded by the
+/// user's devices can be compared with those including manual logs.
+///
+/// # Examples
+///
+/// ```
+/// use fitbit_sdk::types::activity::{ActivityLifetimeStats, DetailedLifetimeStats};
+///
+/// let best = serde_json::json!({
+///     "distance": {"date": "2024-06-08", "value": 24.3},
+///     "steps": {"date": "2024-06-08", "value": 31245}
+/// });
+/// let stats: ActivityLifetimeStats = serde_json::from_value(serde_json::json!({
+///     "best": {"total": best, "tracker": best},
+///     "lifetime": {
+///         "total": {"distance": 8123.5, "steps": 10345678, "caloriesOut": -1},
+///         "tracker": {"distance": 7980.0, "steps": 10212345, "caloriesOut": -1}
+///     }
+/// }))
+/// .unwrap();
+///
+/// let detailed = DetailedLifetimeStats::from_stats(&stats);
+/// assert_eq!(detailed.tracker.steps, 10212345);
+/// assert_eq!(detailed.total.calories_out, None);
+/// assert_eq!(detailed.manual_steps(), 133333);
+/// assert_eq!(detailed.manual_distance(), 143.5);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct DetailedLifetimeStats {
+    /// Statistics including manually logged activities
+    pub total: LifetimeSourceStats,
+    /// Statistics recorded by the user's devices only
+    pub tracker: LifetimeSourceStats,
+}
+
+impl DetailedLifetimeStats {
+    /// Groups lifetime statistics by source
+    pub fn from_stats(stats: &ActivityLifetimeStats) -> Self {
+        let (best, lifetime) = (&stats.best, &stats.lifetime);
+        Self {
+            total: LifetimeSourceStats::new(
+                &lifetime.total,
+                &best.total.distance,
+                &best.total.steps,
+                best.total.floors.as_ref(),
+            ),
+            tracker: LifetimeSourceStats::new(
+                &lifetime.tracker,
+                &best.tracker.distance,
+                &best.tracker.steps,
+                best.tracker.floors.as_ref(),
+            ),
+        }
+    }
+
+    /// Returns the lifetime distance of manually logged activities
+    pub fn manual_distance(&self) -> f64 {
+        self.total.distance - self.tracker.distance
+    }
+
+    /// Returns the lifetime steps of manually logged activities
+    pub fn manual_steps(&self) -> i64 {
+        self.total.steps - self.tracker.steps
+    }
 }
 
 /// Response wrapper for activity summary
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ActivitySummaryResponse {
     pub summary: ActivitySummary,
+    /// Activities logged on the day, as returned by `get_activity_daily`
+    #[serde(default)]
+    pub activities: Vec<ActivityLog>,
+    /// Daily activity goals, as returned by `get_activity_daily`
+    #[serde(default)]
+    pub goals: Option<ActivityGoals>,
+    /// Fields of the response not modeled above
+    #[serde(flatten, deserialize_with = "crate::types::extra::deserialize")]
+    pub extra: Map<String, Value>,
+}
+
+/// Complete daily activity summary
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DailyActivityResponse {
+    /// Activities logged on the day
+    pub activities: Vec<ActivityLog>,
+    /// Daily activity goals, only returned for the authenticated user
+    pub goals: Option<ActivityGoals>,
+    /// Activity summary for the day
+    pub summary: ActivitySummary,
+    /// Fields of the response not modeled above
+    #[serde(flatten, deserialize_with = "crate::types::extra::deserialize")]
+    pub extra: Map<String, Value>,
+}
+
+/// Parameters for logging an activity
+///
+/// Either `activity_id` (an activity from the catalog) or `activity_name`
+/// together with `manual_calories` (a custom activity) must be set.
+#[derive(Debug, Serialize)]
+pub struct LogActivityParams {
+    /// ID of the activity from the activity catalog
+    #[serde(rename = "activityId", skip_serializing_if = "Option::is_none")]
+    pub activity_id: Option<i64>,
+    /// Name of a custom activity
+    #[serde(rename = "activityName", skip_serializing_if = "Option::is_none")]
+    pub activity_name: Option<String>,
+    /// Calories burned, overriding the estimate for catalog activities
+    #[serde(rename = "manualCalories", skip_serializing_if = "Option::is_none")]
+    pub manual_calories: Option<i32>,
+    /// Start time of the activity in format HH:mm
+    #[serde(rename = "startTime")]
+    pub start_time: String,
+    /// Duration of the activity in milliseconds
+    #[serde(rename = "durationMillis")]
+    pub duration_millis: i64,
+    /// Date of the activity in format YYYY-MM-DD
+    pub date: String,
+    /// Distance covered, in the user's unit unless `distance_unit` is set
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub distance: Option<f64>,
+    /// Unit of the distance (e.g. "Kilometer", "Mile", "Steps")
+    #[serde(rename = "distanceUnit", skip_serializing_if = "Option::is_none")]
+    pub distance_unit: Option<String>,
+}
+
+impl LogActivityParams {
+    /// Create parameters for logging an activity from the activity catalog
+    pub fn for_activity(
+        activity_id: i64,
+        date: impl Into<String>,
+        start_time: impl Into<String>,
+        duration_millis: i64,
+    ) -> Self {
+        Self {
+            activity_id: Some(activity_id),
+            activity_name: None,
+            manual_calories: None,
+            start_time: start_time.into(),
+            duration_millis,
+            date: date.into(),
+            distance: None,
+            distance_unit: None,
+        }
+    }
+
+    /// Create parameters for logging a custom activity
+    pub fn custom(
+        activity_name: impl Into<String>,
+        manual_calories: i32,
+        date: impl Into<String>,
+        start_time: impl Into<String>,
+        duration_millis: i64,
+    ) -> Self {
+        Self {
+            activity_id: None,
+            activity_name: Some(activity_name.into()),
+            manual_calories: Some(manual_calories),
+            start_time: start_time.into(),
+            duration_millis,
+            date: date.into(),
+            distance: None,
+            distance_unit: None,
+        }
+    }
+
+    /// Set the calories burned
+    pub fn with_calories(mut self, calories: i32) -> Self {
+        self.manual_calories = Some(calories);
+        self
+    }
+
+    /// Set the distance
+    pub fn with_distance(mut self, distance: f64) -> Self {
+        self.distance = Some(distance);
+        self
+    }
+
+    /// Set the distance unit
+    pub fn with_distance_unit(mut self, distance_unit: impl Into<String>) -> Self {
+        self.distance_unit = Some(distance_unit.into());
+        self
+    }
+}
+
+/// Logged activity entry
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ActivityLog {
+    /// ID of the activity
+    #[serde(rename = "activityId")]
+    pub activity_id: i64,
+    /// ID of the parent activity
+    #[serde(rename = "activityParentId")]
+    pub activity_parent_id: Option<i64>,
+    /// Name of the parent activity
+    #[serde(rename = "activityParentName")]
+    pub activity_parent_name: Option<String>,
+    /// Calories burned during the activity
+    pub calories: i32,
+    /// Description of the activity
+    #[serde(default)]
+    pub description: String,
+    /// Distance covered during the activity
+    pub distance: Option<f64>,
+    /// Duration of the activity in milliseconds
+    pub duration: i64,
+    /// Whether the entry has a start time
+    #[serde(rename = "hasStartTime")]
+    pub has_start_time: bool,
+    /// Whether the activity is one of the user's favorites
+    #[serde(rename = "isFavorite")]
+    pub is_favorite: bool,
+    /// ID of the log entry
+    #[serde(rename = "logId")]
+    pub log_id: i64,
+    /// Name of the activity
+    pub name: String,
+    /// Start date of the activity in format YYYY-MM-DD
+    #[serde(rename = "startDate", default, with = "crate::types::datetime::option_api_field")]
+    pub start_date: Option<ApiDate>,
+    /// Start time of the activity in format HH:mm
+    #[serde(rename = "startTime", with = "crate::types::datetime::api_field")]
+    pub start_time: ApiTime,
+    /// Steps taken during the activity
+    pub steps: Option<i32>,
+    /// Whether the activity earned Active Zone Minutes
+    #[serde(rename = "hasActiveZoneMinutes", default)]
+    pub has_active_zone_minutes: Option<bool>,
+    /// When the entry was last modified
+    #[serde(rename = "lastModified", default, with = "crate::types::datetime::option_api_field")]
+    pub last_modified: Option<ApiTimestamp>,
+    /// Fields of the response not modeled above
+    #[serde(flatten, deserialize_with = "crate::types::extra::deserialize")]
+    pub extra: Map<String, Value>,
+}
+
+/// Response wrapper for a logged activity
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LogActivityResponse {
+    #[serde(rename = "activityLog")]
+    pub activity_log: ActivityLog,
+    /// Fields of the response not modeled above
+    #[serde(flatten, deserialize_with = "crate::types::extra::deserialize")]
+    pub extra: Map<String, Value>,
+}
+
+/// Entry of the activity log list
+///
+/// Unlike `ActivityLog`, entries of the list carry the full start date-time
+/// with the user's UTC offset, and include the activities recorded by the
+/// tracker or detected automatically.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ActivityLogEntry {
+    /// ID of the log entry
+    #[serde(rename = "logId")]
+    pub log_id: i64,
+    /// Name of the activity
+    #[serde(rename = "activityName")]
+    pub activity_name: String,
+    /// ID of the activity type
+    #[serde(rename = "activityTypeId")]
+    pub activity_type_id: i64,
+    /// How the activity was logged (auto_detected, manual, mobile_run, tracker)
+    #[serde(rename = "logType")]
+    pub log_type: String,
+    /// Start of the activity
+    #[serde(rename = "startTime", with = "crate::types::datetime::api_field")]
+    pub start_time: ApiTimestamp,
+    /// Duration of the activity in milliseconds
+    pub duration: i64,
+    /// Duration of the activity without pauses in milliseconds
+    #[serde(rename = "activeDuration")]
+    pub active_duration: i64,
+    /// Calories burned during the activity
+    pub calories: i32,
+    /// Steps taken during the activity
+    pub steps: Option<i32>,
+    /// Distance covered during the activity
+    pub distance: Option<f64>,
+    /// Unit of the distance
+    #[serde(rename = "distanceUnit")]
+    pub distance_unit: Option<String>,
+    /// Average pace in seconds per distance unit
+    #[serde(default)]
+    pub pace: Option<f64>,
+    /// Average speed in distance units per hour
+    #[serde(default)]
+    pub speed: Option<f64>,
+    /// Elevation gained during the activity, in the user's elevation unit
+    #[serde(rename = "elevationGain", default)]
+    pub elevation_gain: Option<f64>,
+    /// Number of pool lengths swum, for swims
+    #[serde(rename = "swimLengths", default)]
+    pub swim_lengths: Option<i32>,
+    /// Length of the pool, for swims
+    #[serde(rename = "poolLength", default)]
+    pub pool_length: Option<f64>,
+    /// Unit of the pool length
+    #[serde(rename = "poolLengthUnit", default)]
+    pub pool_length_unit: Option<String>,
+    /// Average heart rate during the activity in beats per minute
+    #[serde(rename = "averageHeartRate")]
+    pub average_heart_rate: Option<i32>,
+    /// Time spent in each heart rate zone during the activity
+    #[serde(rename = "heartRateZones", default)]
+    pub heart_rate_zones: Vec<HeartRateZone>,
+    /// Time spent at each activity level during the activity
+    #[serde(rename = "activityLevel", default)]
+    pub activity_level: Vec<ActivityLevelMinutes>,
+    /// Link to the TCX export of the activity, when it has GPS or heart rate data
+    #[serde(rename = "tcxLink")]
+    pub tcx_link: Option<String>,
+    /// Link to the intraday calories of the activity
+    #[serde(rename = "caloriesLink", default)]
+    pub calories_link: Option<String>,
+    /// Link to the intraday heart rate of the activity
+    #[serde(rename = "heartRateLink", default)]
+    pub heart_rate_link: Option<String>,
+    /// Whether the activity earned Active Zone Minutes
+    #[serde(rename = "hasActiveZoneMinutes", default)]
+    pub has_active_zone_minutes: Option<bool>,
+    /// When the entry was last modified
+    #[serde(rename = "lastModified", default, with = "crate::types::datetime::option_api_field")]
+    pub last_modified: Option<ApiTimestamp>,
+    /// Duration of the activity in milliseconds, before the user edited it
+    #[serde(rename = "originalDuration", default)]
+    pub original_duration: Option<i64>,
+    /// Start of the activity, before the user edited it
+    #[serde(rename = "originalStartTime", default, with = "crate::types::datetime::option_api_field")]
+    pub original_start_time: Option<ApiTimestamp>,
+    /// Which values the user entered instead of the tracker recording them
+    #[serde(rename = "manualValuesSpecified", default)]
+    pub manual_values_specified: Option<ManualValuesSpecified>,
+    /// App or device that recorded the activity
+    #[serde(default)]
+    pub source: Option<ActivitySource>,
+    /// Fields of the response not modeled above
+    #[serde(flatten, deserialize_with = "crate::types::extra::deserialize")]
+    pub extra: Map<String, Value>,
+}
+
+/// Values of a logged activity entered by the user
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ManualValuesSpecified {
+    /// Whether the calories were entered by the user
+    pub calories: bool,
+    /// Whether the distance was entered by the user
+    pub distance: bool,
+    /// Whether the steps were entered by the user
+    pub steps: bool,
+    /// Fields of the response not modeled above
+    #[serde(flatten, deserialize_with = "crate::types::extra::deserialize")]
+    pub extra: Map<String, Value>,
+}
+
+/// App or device that recorded a logged activity
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ActivitySource {
+    /// ID of the app or device
+    pub id: String,
+    /// Name of the app or device
+    pub name: String,
+    /// Kind of source (app or tracker)
+    #[serde(rename = "type")]
+    pub source_type: String,
+    /// URL of the app or device
+    pub url: Option<String>,
+    /// Features of the tracker used for the activity (e.g. GPS, HEARTRATE)
+    #[serde(rename = "trackerFeatures", default)]
+    pub tracker_features: Vec<String>,
+    /// Fields of the response not modeled above
+    #[serde(flatten, deserialize_with = "crate::types::extra::deserialize")]
+    pub extra: Map<String, Value>,
+}
+
+/// Time spent at an activity level during a logged activity
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ActivityLevelMinutes {
+    /// Name of the activity level (sedentary, lightly, fairly, very)
+    pub name: String,
+    /// Minutes spent at the activity level
+    pub minutes: i32,
+    /// Fields of the response not modeled above
+    #[serde(flatten, deserialize_with = "crate::types::extra::deserialize")]
+    pub extra: Map<String, Value>,
+}
+
+/// Page of the activity log list
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ActivityLogList {
+    /// Activity log entries in the page
+    pub activities: Vec<ActivityLogEntry>,
+    /// Pagination information
+    pub pagination: Pagination,
+    /// Fields of the response not modeled above
+    #[serde(flatten, deserialize_with = "crate::types::extra::deserialize")]
+    pub extra: Map<String, Value>,
+}
+
+impl Paginated<ActivityLogEntry> for ActivityLogList {
+    fn pagination(&self) -> &Pagination {
+        &self.pagination
+    }
+
+    fn into_items(self) -> Vec<ActivityLogEntry> {
+        self.activities
+    }
+}
+
+/// Period of activity goals
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GoalPeriod {
+    Daily,
+    Weekly,
+}
+
+impl GoalPeriod {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            GoalPeriod::Daily => "daily",
+            GoalPeriod::Weekly => "weekly",
+        }
+    }
+}
+
+/// Activity goals for a period
+///
+/// Weekly goals only include steps, distance and floors.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ActivityGoals {
+    /// Active minutes goal
+    #[serde(rename = "activeMinutes")]
+    pub active_minutes: Option<i32>,
+    /// Active Zone Minutes goal
+    #[serde(rename = "activeZoneMinutes")]
+    pub active_zone_minutes: Option<i32>,
+    /// Calories burned goal
+    #[serde(rename = "caloriesOut")]
+    pub calories_out: Option<i32>,
+    /// Distance goal in the user's unit
+    pub distance: Option<f64>,
+    /// Floors climbed goal
+    pub floors: Option<i32>,
+    /// Steps goal
+    pub steps: Option<i32>,
+    /// Fields of the response not modeled above
+    #[serde(flatten, deserialize_with = "crate::types::extra::deserialize")]
+    pub extra: Map<String, Value>,
+}
+
+/// Parameters for updating activity goals
+#[derive(Debug, Serialize, Default)]
+pub struct ActivityGoalsParams {
+    /// Active minutes goal (daily goals only)
+    #[serde(rename = "activeMinutes", skip_serializing_if = "Option::is_none")]
+    pub active_minutes: Option<i32>,
+    /// Calories burned goal (daily goals only)
+    #[serde(rename = "caloriesOut", skip_serializing_if = "Option::is_none")]
+    pub calories_out: Option<i32>,
+    /// Distance goal in the user's unit
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub distance: Option<f64>,
+    /// Floors climbed goal
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub floors: Option<i32>,
+    /// Steps goal
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub steps: Option<i32>,
+}
+
+impl ActivityGoalsParams {
+    /// Create a new ActivityGoalsParams with default values
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the active minutes goal
+    pub fn with_active_minutes(mut self, active_minutes: i32) -> Self {
+        self.active_minutes = Some(active_minutes);
+        self
+    }
+
+    /// Set the calories burned goal
+    pub fn with_calories_out(mut self, calories_out: i32) -> Self {
+        self.calories_out = Some(calories_out);
+        self
+    }
+
+    /// Set the distance goal
+    pub fn with_distance(mut self, distance: f64) -> Self {
+        self.distance = Some(distance);
+        self
+    }
+
+    /// Set the floors goal
+    pub fn with_floors(mut self, floors: i32) -> Self {
+        self.floors = Some(floors);
+        self
+    }
+
+    /// Set the steps goal
+    pub fn with_steps(mut self, steps: i32) -> Self {
+        self.steps = Some(steps);
+        self
+    }
+}
+
+/// Response wrapper for activity goals
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ActivityGoalsResponse {
+    pub goals: ActivityGoals,
+    /// Fields of the response not modeled above
+    #[serde(flatten, deserialize_with = "crate::types::extra::deserialize")]
+    pub extra: Map<String, Value>,
+}
+
+/// Activity marked as a favorite by the user
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FavoriteActivity {
+    /// ID of the activity
+    #[serde(rename = "activityId")]
+    pub activity_id: i64,
+    /// Description of the activity
+    #[serde(default)]
+    pub description: String,
+    /// Metabolic equivalent of the activity
+    pub mets: f64,
+    /// Name of the activity
+    pub name: String,
+    /// Fields of the response not modeled above
+    #[serde(flatten, deserialize_with = "crate::types::extra::deserialize")]
+    pub extra: Map<String, Value>,
+}
+
+/// Activity the user logs frequently or logged recently
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FrequentActivity {
+    /// ID of the activity
+    #[serde(rename = "activityId")]
+    pub activity_id: i64,
+    /// Calories burned in the last logged entry
+    pub calories: i32,
+    /// Description of the activity
+    #[serde(default)]
+    pub description: String,
+    /// Distance covered in the last logged entry
+    pub distance: f64,
+    /// Duration of the last logged entry in milliseconds
+    pub duration: i64,
+    /// Name of the activity
+    pub name: String,
+    /// Fields of the response not modeled above
+    #[serde(flatten, deserialize_with = "crate::types::extra::deserialize")]
+    pub extra: Map<String, Value>,
+}
+
+/// Category of the activity catalog
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ActivityCategory {
+    /// ID of the category
+    pub id: i64,
+    /// Name of the category
+    pub name: String,
+    /// Activities in the category
+    #[serde(default)]
+    pub activities: Vec<ActivityType>,
+    /// Nested categories
+    #[serde(rename = "subCategories", default)]
+    pub sub_categories: Vec<ActivityCategory>,
+    /// Fields of the response not modeled above
+    #[serde(flatten, deserialize_with = "crate::types::extra::deserialize")]
+    pub extra: Map<String, Value>,
+}
+
+impl ActivityCategory {
+    /// Finds an activity in the category or its sub-categories by name, ignoring case
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fitbit_sdk::types::activity::ActivityCategory;
+    ///
+    /// let category: ActivityCategory = serde_json::from_str(r#"{
+    ///     "id": 9000,
+    ///     "name": "Running",
+    ///     "activities": [],
+    ///     "subCategories": [{
+    ///         "id": 9001,
+    ///         "name": "Outdoor",
+    ///         "activities": [{"id": 90009, "name": "Run", "hasSpeed": true, "accessLevel": "PUBLIC"}]
+    ///     }]
+    /// }"#).unwrap();
+    ///
+    /// assert_eq!(category.find_activity("run").map(|a| a.id), Some(90009));
+    /// assert!(category.find_activity("swim").is_none());
+    /// ```
+    pub fn find_activity(&self, name: &str) -> Option<&ActivityType> {
+        self.activities
+            .iter()
+            .find(|activity| activity.name.eq_ignore_ascii_case(name))
+            .or_else(|| {
+                self.sub_categories
+                    .iter()
+                    .find_map(|category| category.find_activity(name))
+            })
+    }
+}
+
+/// Activity of the activity catalog
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ActivityType {
+    /// ID of the activity, used when logging it
+    pub id: i64,
+    /// Name of the activity
+    pub name: String,
+    /// Access level of the activity (e.g. "PUBLIC")
+    #[serde(rename = "accessLevel")]
+    pub access_level: String,
+    /// Whether the activity has speed-dependent levels
+    #[serde(rename = "hasSpeed")]
+    pub has_speed: bool,
+    /// Metabolic equivalent of the activity, for activities without levels
+    pub mets: Option<f64>,
+    /// Speed-dependent levels of the activity
+    #[serde(rename = "activityLevels", default)]
+    pub activity_levels: Vec<ActivityLevel>,
+    /// Fields of the response not modeled above
+    #[serde(flatten, deserialize_with = "crate::types::extra::deserialize")]
+    pub extra: Map<String, Value>,
+}
+
+/// Speed-dependent level of an activity
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ActivityLevel {
+    /// ID of the activity level
+    pub id: i64,
+    /// Name of the activity level
+    pub name: String,
+    /// Metabolic equivalent of the activity level
+    pub mets: f64,
+    /// Lower bound of the speed in miles per hour
+    #[serde(rename = "minSpeedMPH")]
+    pub min_speed_mph: Option<f64>,
+    /// Upper bound of the speed in miles per hour
+    #[serde(rename = "maxSpeedMPH")]
+    pub max_speed_mph: Option<f64>,
+    /// Fields of the response not modeled above
+    #[serde(flatten, deserialize_with = "crate::types::extra::deserialize")]
+    pub extra: Map<String, Value>,
+}
+
+/// Response wrapper for the activity catalog
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ActivityCatalogResponse {
+    pub categories: Vec<ActivityCategory>,
+    /// Fields of the response not modeled above
+    #[serde(flatten, deserialize_with = "crate::types::extra::deserialize")]
+    pub extra: Map<String, Value>,
 }
 
-/// Response wrapper for lifetime statistics
-#[derive(Debug, Deserialize)]
-pub struct LifetimeStatsResponse {
-    pub lifetime: ActivityLifetimeStats,
+/// Response wrapper for an activity of the activity catalog
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ActivityTypeResponse {
+    pub activity: ActivityType,
+    /// Fields of the response not modeled above
+    #[serde(flatten, deserialize_with = "crate::types::extra::deserialize")]
+    pub extra: Map<String, Value>,
 }
@@ -0,0 +1,332 @@
+//! Authorization API Types
+//!
+//! This module contains the types for the Fitbit OAuth 2.0 authorization flow.
+//!
+use async_trait::async_trait;
+use base64::Engine;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use sha2::{Digest, Sha256};
+use std::fmt;
+use std::str::FromStr;
+use std::sync::Arc;
+use thiserror::Error;
+
+/// Error types for the OAuth 2.0 authorization flow
+#[derive(Debug, Error)]
+pub enum AuthError {
+    #[error("API request failed: {0}")]
+    RequestFailed(String),
+    #[error("API error: {0}")]
+    ApiError(String),
+}
+
+impl From<String> for AuthError {
+    fn from(error: String) -> Self {
+        AuthError::ApiError(error)
+    }
+}
+
+/// OAuth 2.0 scope granting access to a category of the user's data
+///
+/// # Examples
+///
+/// ```
+/// use fitbit_sdk::types::auth::Scope;
+///
+/// assert_eq!(Scope::Heartrate.as_str(), "heartrate");
+/// assert_eq!("respiratory_rate".parse::<Scope>(), Ok(Scope::RespiratoryRate));
+/// assert_eq!(Scope::join(&[Scope::Activity, Scope::Sleep]), "activity sleep");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Scope {
+    /// Activity and exercise data, including active zone minutes
+    Activity,
+    /// Cardio fitness score (VO2 max)
+    CardioFitness,
+    /// Electrocardiogram readings
+    Electrocardiogram,
+    /// Heart rate and heart rate variability
+    Heartrate,
+    /// Irregular rhythm notifications
+    IrregularRhythmNotifications,
+    /// GPS and other location data
+    Location,
+    /// Food and water logs
+    Nutrition,
+    /// Blood oxygen saturation (SpO2)
+    OxygenSaturation,
+    /// Personal information and badges
+    Profile,
+    /// Breathing rate
+    RespiratoryRate,
+    /// Devices, alarms and user settings
+    Settings,
+    /// Sleep logs and goals
+    Sleep,
+    /// Friends and leaderboards
+    Social,
+    /// Skin and core temperature
+    Temperature,
+    /// Weight, BMI and body fat
+    Weight,
+}
+
+impl Scope {
+    /// Every scope an application can request
+    pub const ALL: [Scope; 15] = [
+        Scope::Activity,
+        Scope::CardioFitness,
+        Scope::Electrocardiogram,
+        Scope::Heartrate,
+        Scope::IrregularRhythmNotifications,
+        Scope::Location,
+        Scope::Nutrition,
+        Scope::OxygenSaturation,
+        Scope::Profile,
+        Scope::RespiratoryRate,
+        Scope::Settings,
+        Scope::Sleep,
+        Scope::Social,
+        Scope::Temperature,
+        Scope::Weight,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Scope::Activity => "activity",
+            Scope::CardioFitness => "cardio_fitness",
+            Scope::Electrocardiogram => "electrocardiogram",
+            Scope::Heartrate => "heartrate",
+            Scope::IrregularRhythmNotifications => "irregular_rhythm_notifications",
+            Scope::Location => "location",
+            Scope::Nutrition => "nutrition",
+            Scope::OxygenSaturation => "oxygen_saturation",
+            Scope::Profile => "profile",
+            Scope::RespiratoryRate => "respiratory_rate",
+            Scope::Settings => "settings",
+            Scope::Sleep => "sleep",
+            Scope::Social => "social",
+            Scope::Temperature => "temperature",
+            Scope::Weight => "weight",
+        }
+    }
+
+    /// Joins scopes with spaces, as in the `scope` parameter of an authorize URL
+    pub fn join(scopes: &[Scope]) -> String {
+        scopes
+            .iter()
+            .map(Scope::as_str)
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    /// Returns the scopes missing for a request if `body` is an
+    /// `insufficient_scope` error of the API
+    ///
+    /// The scopes are taken from the error message ("...permission to read
+    /// sleep data...") when it names one, and are `required` otherwise.
+    pub(crate) fn insufficient_scope(body: &str, required: &[Scope]) -> Option<Vec<Scope>> {
+        let response: ScopeErrorResponse = serde_json::from_str(body).ok()?;
+        let error = response
+            .errors
+            .into_iter()
+            .find(|error| error.error_type == "insufficient_scope")?;
+        let named = error
+            .message
+            .split_once(" data")
+            .and_then(|(before, _)| before.split_whitespace().last())
+            .and_then(|word| word.parse().ok());
+        Some(named.map_or_else(|| required.to_vec(), |scope| vec![scope]))
+    }
+}
+
+impl fmt::Display for Scope {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl AsRef<str> for Scope {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl FromStr for Scope {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Scope::ALL
+            .into_iter()
+            .find(|scope| scope.as_str() == s)
+            .ok_or_else(|| format!("Unknown scope: {}", s))
+    }
+}
+
+/// Error body returned by the API for a request missing a scope
+#[derive(Debug, Deserialize)]
+struct ScopeErrorResponse {
+    errors: Vec<ScopeErrorDetail>,
+}
+
+/// Individual error entry in an API error body
+#[derive(Debug, Deserialize)]
+struct ScopeErrorDetail {
+    #[serde(rename = "errorType")]
+    error_type: String,
+    #[serde(default)]
+    message: String,
+}
+
+/// Source of access tokens for `FitbitClient`
+///
+/// The client asks the provider for a token before every request, which allows
+/// rotating tokens, shared token caches and refreshes coordinated across processes.
+///
+/// # Examples
+///
+/// ```no_run
+/// use async_trait::async_trait;
+/// use fitbit_sdk::client::FitbitClient;
+/// use fitbit_sdk::types::auth::{AuthError, TokenProvider};
+/// use fitbit_sdk::types::user::UserError;
+///
+/// struct EnvTokenProvider;
+///
+/// #[async_trait]
+/// impl TokenProvider for EnvTokenProvider {
+///     async fn access_token(&self) -> Result<String, AuthError> {
+///         std::env::var("MY_APP_TOKEN").map_err(|e| AuthError::from(e.to_string()))
+///     }
+/// }
+///
+/// # fn example() -> Result<(), UserError> {
+/// let client = FitbitClient::builder()
+///     .with_token_provider(EnvTokenProvider)
+///     .build()?;
+/// # Ok(())
+/// # }
+/// ```
+#[async_trait]
+pub trait TokenProvider: Send + Sync {
+    /// Returns the access token to use for the next request
+    async fn access_token(&self) -> Result<String, AuthError>;
+
+    /// Called when the API rejects `expired_token` as expired
+    ///
+    /// Returns `true` if a new access token is available and the request
+    /// should be retried. The default implementation cannot refresh.
+    async fn refresh(&self, expired_token: &str) -> Result<bool, AuthError> {
+        let _ = expired_token;
+        Ok(false)
+    }
+}
+
+/// Persistent storage for OAuth tokens
+///
+/// `FitbitClient` calls `save` every time it refreshes an expired access token,
+/// so applications can persist the rotated refresh token and resume after a restart.
+#[async_trait]
+pub trait TokenStore: Send + Sync {
+    /// Loads the most recently saved token, if any
+    async fn load(&self) -> Result<Option<TokenResponse>, AuthError>;
+
+    /// Saves a newly issued token
+    async fn save(&self, token: &TokenResponse) -> Result<(), AuthError>;
+}
+
+#[async_trait]
+impl<T: TokenStore + ?Sized> TokenStore for Arc<T> {
+    async fn load(&self) -> Result<Option<TokenResponse>, AuthError> {
+        (**self).load().await
+    }
+
+    async fn save(&self, token: &TokenResponse) -> Result<(), AuthError> {
+        (**self).save(token).await
+    }
+}
+
+/// Token response returned by the Fitbit token endpoint
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TokenResponse {
+    /// The access token used to authorize API requests
+    pub access_token: String,
+    /// Lifetime of the access token in seconds
+    pub expires_in: u64,
+    /// The refresh token used to obtain a new access token
+    pub refresh_token: String,
+    /// Space-separated list of scopes granted by the user
+    pub scope: String,
+    /// Type of the access token (always "Bearer")
+    pub token_type: String,
+    /// The encoded ID of the user who authorized the application
+    pub user_id: String,
+    /// Fields of the response not modeled above
+    #[serde(flatten, deserialize_with = "crate::types::extra::deserialize")]
+    pub extra: Map<String, Value>,
+}
+
+/// PKCE (Proof Key for Code Exchange) code verifier and challenge pair
+///
+/// Public clients such as CLI and desktop applications should use PKCE
+/// instead of a client secret. Send `code_challenge` with the authorization
+/// request and `code_verifier` with the token exchange.
+///
+/// # Examples
+///
+/// ```
+/// use fitbit_sdk::types::auth::PkceChallenge;
+///
+/// let pkce = PkceChallenge::new();
+/// assert_eq!(pkce.code_verifier.len(), 43);
+/// assert_eq!(pkce.code_challenge_method(), "S256");
+/// ```
+#[derive(Debug, Clone)]
+pub struct PkceChallenge {
+    /// High-entropy random string kept secret by the client
+    pub code_verifier: String,
+    /// Base64url-encoded SHA-256 hash of the code verifier
+    pub code_challenge: String,
+}
+
+impl Default for PkceChallenge {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PkceChallenge {
+    /// Generate a new random code verifier and its S256 challenge
+    pub fn new() -> Self {
+        let bytes: [u8; 32] = rand::random();
+        Self::from_verifier(URL_SAFE_NO_PAD.encode(bytes))
+    }
+
+    /// Compute the S256 challenge for an existing code verifier
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fitbit_sdk::types::auth::PkceChallenge;
+    ///
+    /// // Test vector from RFC 7636, Appendix B
+    /// let pkce = PkceChallenge::from_verifier("dBjftJeZ4CVP-mB92K27uhbUJU1p1r_wW1gFWFOEjXk");
+    /// assert_eq!(pkce.code_challenge, "E9Melhoa2OwvFrEMTJguCHaoeK1t8URWbuGJSstw-cM");
+    /// ```
+    pub fn from_verifier(code_verifier: impl Into<String>) -> Self {
+        let code_verifier = code_verifier.into();
+        let digest = Sha256::digest(code_verifier.as_bytes());
+        Self {
+            code_challenge: URL_SAFE_NO_PAD.encode(digest),
+            code_verifier,
+        }
+    }
+
+    /// The challenge method sent with the authorization request
+    pub fn code_challenge_method(&self) -> &'static str {
+        "S256"
+    }
+}
@@ -0,0 +1,134 @@
+//! Active Zone Minutes API Types
+//!
+//! This module contains the types and functions for the Fitbit Active Zone Minutes (AZM) API.
+//!
+use crate::types::auth::Scope;
+use crate::types::datetime::{ApiDate, ApiDateTime};
+use crate::types::error::{FitbitError, domain_error};
+use crate::types::intraday::DetailLevel;
+use crate::types::user::UserId;
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use thiserror::Error;
+
+/// Error types for the Active Zone Minutes API
+#[derive(Debug, Error)]
+#[error(transparent)]
+pub struct AzmError(#[from] pub FitbitError);
+
+domain_error!(AzmError, scopes [Scope::Activity]);
+
+#[async_trait]
+pub trait AzmClient {
+    async fn get_azm_time_series<'a>(
+        &'a self,
+        user_id: impl Into<UserId> + Send,
+        date: &'a str,
+        period: &'a str,
+    ) -> Result<Vec<AzmTimeSeries>, AzmError>;
+
+    async fn get_azm_time_series_range<'a>(
+        &'a self,
+        user_id: impl Into<UserId> + Send,
+        start_date: &'a str,
+        end_date: &'a str,
+    ) -> Result<Vec<AzmTimeSeries>, AzmError>;
+
+    async fn get_azm_intraday<'a>(
+        &'a self,
+        user_id: impl Into<UserId> + Send,
+        date: &'a str,
+        detail_level: DetailLevel,
+    ) -> Result<Vec<AzmIntraday>, AzmError>;
+
+    async fn get_azm_intraday_window<'a>(
+        &'a self,
+        user_id: impl Into<UserId> + Send,
+        date: &'a str,
+        detail_level: DetailLevel,
+        start_time: &'a str,
+        end_time: &'a str,
+    ) -> Result<Vec<AzmIntraday>, AzmError>;
+}
+
+/// Active Zone Minutes for a single day
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AzmTimeSeries {
+    /// Date of the data point
+    #[serde(rename = "dateTime", with = "crate::types::datetime::api_field")]
+    pub date_time: ApiDate,
+    /// Active Zone Minutes earned on the day
+    pub value: AzmValue,
+    /// Fields of the response not modeled above
+    #[serde(flatten, deserialize_with = "crate::types::extra::deserialize")]
+    pub extra: Map<String, Value>,
+}
+
+/// Active Zone Minutes broken down by heart rate zone
+///
+/// Minutes in the cardio and peak zones count double towards the total.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AzmValue {
+    /// Total Active Zone Minutes
+    #[serde(rename = "activeZoneMinutes", default)]
+    pub active_zone_minutes: i32,
+    /// Active Zone Minutes earned in the fat burn zone
+    #[serde(rename = "fatBurnActiveZoneMinutes", default)]
+    pub fat_burn_active_zone_minutes: i32,
+    /// Active Zone Minutes earned in the cardio zone
+    #[serde(rename = "cardioActiveZoneMinutes", default)]
+    pub cardio_active_zone_minutes: i32,
+    /// Active Zone Minutes earned in the peak zone
+    #[serde(rename = "peakActiveZoneMinutes", default)]
+    pub peak_active_zone_minutes: i32,
+    /// Fields of the response not modeled above
+    #[serde(flatten, deserialize_with = "crate::types::extra::deserialize")]
+    pub extra: Map<String, Value>,
+}
+
+/// Intraday Active Zone Minutes for a single day
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AzmIntraday {
+    /// Date of the data
+    #[serde(rename = "dateTime", with = "crate::types::datetime::api_field")]
+    pub date_time: ApiDate,
+    /// Active Zone Minutes per interval; intervals without any are omitted
+    pub minutes: Vec<AzmMinute>,
+    /// Fields of the response not modeled above
+    #[serde(flatten, deserialize_with = "crate::types::extra::deserialize")]
+    pub extra: Map<String, Value>,
+}
+
+/// Active Zone Minutes earned in a single interval
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AzmMinute {
+    /// Start of the interval in format YYYY-MM-DDTHH:mm:ss
+    #[serde(with = "crate::types::datetime::api_field")]
+    pub minute: ApiDateTime,
+    /// Active Zone Minutes earned in the interval
+    pub value: AzmValue,
+    /// Fields of the response not modeled above
+    #[serde(flatten, deserialize_with = "crate::types::extra::deserialize")]
+    pub extra: Map<String, Value>,
+}
+
+/// Response wrapper for Active Zone Minutes time series
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AzmTimeSeriesResponse {
+    #[serde(rename = "activities-active-zone-minutes")]
+    pub activities_active_zone_minutes: Vec<AzmTimeSeries>,
+    /// Fields of the response not modeled above
+    #[serde(flatten, deserialize_with = "crate::types::extra::deserialize")]
+    pub extra: Map<String, Value>,
+}
+
+/// Response wrapper for intraday Active Zone Minutes
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AzmIntradayResponse {
+    #[serde(rename = "activities-active-zone-minutes-intraday")]
+    pub activities_active_zone_minutes_intraday: Vec<AzmIntraday>,
+    /// Fields of the response not modeled above
+    #[serde(flatten, deserialize_with = "crate::types::extra::deserialize")]
+    pub extra: Map<String, Value>,
+}
@@ -2,58 +2,110 @@
 //!
 //! This module contains the types and functions for the Fitbit Body API.
 //!
+use crate::types::auth::Scope;
+use crate::types::date::FitbitDate;
+use crate::types::datetime::{ApiDate, ApiTime};
+use crate::types::error::{FitbitError, domain_error};
+use crate::types::time_series::TimeSeriesPoint;
+use crate::types::user::UserId;
 use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
 use thiserror::Error;
 
 /// Error types for the Body API
 #[derive(Debug, Error)]
-pub enum BodyError {
-    #[error("API request failed: {0}")]
-    RequestFailed(String),
-    #[error("API error: {0}")]
-    ApiError(String),
-}
+#[error(transparent)]
+pub struct BodyError(#[from] pub FitbitError);
 
-impl From<String> for BodyError {
-    fn from(error: String) -> Self {
-        BodyError::ApiError(error)
-    }
-}
+domain_error!(BodyError, scopes [Scope::Weight]);
 
 #[async_trait]
 pub trait BodyClient {
-    async fn get_body_weight<'a>(&'a self, user_id: &'a str, date: &'a str) -> Result<Vec<BodyWeight>, BodyError>;
-    async fn get_body_fat<'a>(&'a self, user_id: &'a str, date: &'a str) -> Result<Vec<BodyFat>, BodyError>;
-    async fn get_body_goals<'a>(&'a self, user_id: &'a str) -> Result<BodyGoals, BodyError>;
+    async fn get_body_weight<'a>(&'a self, user_id: impl Into<UserId> + Send, date: impl Into<FitbitDate> + Send) -> Result<Vec<BodyWeight>, BodyError>;
+    async fn get_body_fat<'a>(&'a self, user_id: impl Into<UserId> + Send, date: impl Into<FitbitDate> + Send) -> Result<Vec<BodyFat>, BodyError>;
+    async fn get_body_weight_range<'a>(
+        &'a self,
+        user_id: impl Into<UserId> + Send,
+        start_date: impl Into<FitbitDate> + Send,
+        end_date: impl Into<FitbitDate> + Send,
+    ) -> Result<Vec<BodyWeight>, BodyError>;
+    async fn get_body_weight_period<'a>(
+        &'a self,
+        user_id: impl Into<UserId> + Send,
+        date: impl Into<FitbitDate> + Send,
+        period: &'a str,
+    ) -> Result<Vec<BodyWeight>, BodyError>;
+    async fn get_body_fat_range<'a>(
+        &'a self,
+        user_id: impl Into<UserId> + Send,
+        start_date: impl Into<FitbitDate> + Send,
+        end_date: impl Into<FitbitDate> + Send,
+    ) -> Result<Vec<BodyFat>, BodyError>;
+    async fn get_body_fat_period<'a>(
+        &'a self,
+        user_id: impl Into<UserId> + Send,
+        date: impl Into<FitbitDate> + Send,
+        period: &'a str,
+    ) -> Result<Vec<BodyFat>, BodyError>;
+    async fn get_weight_goal<'a>(&'a self, user_id: impl Into<UserId> + Send) -> Result<WeightGoal, BodyError>;
+    async fn get_fat_goal<'a>(&'a self, user_id: impl Into<UserId> + Send) -> Result<FatGoal, BodyError>;
+    async fn log_body_weight<'a>(&'a self, user_id: impl Into<UserId> + Send, params: &'a LogWeightParams) -> Result<BodyWeight, BodyError>;
+    async fn delete_body_weight_log<'a>(&'a self, user_id: impl Into<UserId> + Send, log_id: i64) -> Result<(), BodyError>;
+    async fn log_body_fat<'a>(&'a self, user_id: impl Into<UserId> + Send, params: &'a LogBodyFatParams) -> Result<BodyFat, BodyError>;
+    async fn delete_body_fat_log<'a>(&'a self, user_id: impl Into<UserId> + Send, log_id: i64) -> Result<(), BodyError>;
+    async fn get_body_time_series<'a>(
+        &'a self,
+        user_id: impl Into<UserId> + Send,
+        resource: BodyResource,
+        date: impl Into<FitbitDate> + Send,
+        period: &'a str,
+    ) -> Result<Vec<BodyTimeSeries>, BodyError>;
+    async fn get_bmi<'a>(
+        &'a self,
+        user_id: impl Into<UserId> + Send,
+        date: impl Into<FitbitDate> + Send,
+    ) -> Result<Option<Bmi>, BodyError>;
 }
 
 /// Body weight log entry
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct BodyWeight {
     /// Date of the weight measurement
-    pub date: String,
+    #[serde(with = "crate::types::datetime::api_field")]
+    pub date: ApiDate,
     /// Time of the weight measurement
-    pub time: String,
+    #[serde(with = "crate::types::datetime::api_field")]
+    pub time: ApiTime,
     /// Weight value in user's preferred unit
     pub weight: f64,
     /// Weight value in kilograms (if available)
     #[serde(rename = "weightInKg")]
     pub weight_in_kg: Option<f64>,
+    /// Body mass index calculated from the weight
+    pub bmi: Option<f64>,
+    /// Body fat percentage measured with the weight, e.g. by an Aria scale
+    #[serde(default)]
+    pub fat: Option<f64>,
     /// Log ID
     #[serde(rename = "logId")]
     pub log_id: i64,
     /// Source of the log entry
     pub source: Option<String>,
+    /// Fields of the response not modeled above
+    #[serde(flatten, deserialize_with = "crate::types::extra::deserialize")]
+    pub extra: Map<String, Value>,
 }
 
 /// Body fat percentage log entry
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct BodyFat {
     /// Date of the body fat measurement
-    pub date: String,
+    #[serde(with = "crate::types::datetime::api_field")]
+    pub date: ApiDate,
     /// Time of the body fat measurement
-    pub time: String,
+    #[serde(with = "crate::types::datetime::api_field")]
+    pub time: ApiTime,
     /// Body fat percentage
     pub fat: f64,
     /// Log ID
@@ -61,34 +113,289 @@ pub struct BodyFat {
     pub log_id: i64,
     /// Source of the log entry
     pub source: Option<String>,
+    /// Fields of the response not modeled above
+    #[serde(flatten, deserialize_with = "crate::types::extra::deserialize")]
+    pub extra: Map<String, Value>,
+}
+
+/// Direction of a weight goal
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum WeightGoalType {
+    Lose,
+    Gain,
+    Maintain,
 }
 
-/// Body goals information
-#[derive(Debug, Deserialize)]
-pub struct BodyGoals {
-    /// Weight goal
+/// Weight goal of a user
+///
+/// # Examples
+///
+/// ```
+/// use fitbit_sdk::types::body::WeightGoal;
+///
+/// let goal: WeightGoal = serde_json::from_str(r#"{
+///     "goalType": "LOSE",
+///     "startDate": "2024-11-01",
+///     "startWeight": 76,
+///     "weight": 70,
+///     "weightThreshold": 0.05
+/// }"#)
+/// .unwrap();
+///
+/// assert_eq!(goal.progress(73.0), Some(50.0));
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WeightGoal {
+    /// Whether the user wants to lose, gain or maintain weight
+    #[serde(rename = "goalType")]
+    pub goal_type: Option<WeightGoalType>,
+    /// Date the goal was set
+    #[serde(rename = "startDate", with = "crate::types::datetime::api_field")]
+    pub start_date: ApiDate,
+    /// Weight when the goal was set, in the user's weight unit
+    #[serde(rename = "startWeight")]
+    pub start_weight: f64,
+    /// Target weight, in the user's weight unit
     pub weight: f64,
-    /// Weight unit for the goal
-    #[serde(rename = "weightUnit")]
-    pub weight_unit: String,
-    /// Body fat percentage goal
-    pub fat: Option<f64>,
+    /// Weight difference within which the goal counts as reached
+    #[serde(rename = "weightThreshold")]
+    pub weight_threshold: Option<f64>,
+    /// Fields of the response not modeled above
+    #[serde(flatten, deserialize_with = "crate::types::extra::deserialize")]
+    pub extra: Map<String, Value>,
+}
+
+impl WeightGoal {
+    /// Returns the percentage of the way from the start weight to the target
+    /// covered at `weight`
+    ///
+    /// The percentage is negative when `weight` moved away from the target
+    /// and above 100 once the target is passed. Returns `None` if the start
+    /// weight is the target, as for maintenance goals.
+    pub fn progress(&self, weight: f64) -> Option<f64> {
+        let total = self.weight - self.start_weight;
+        if total == 0.0 {
+            return None;
+        }
+        Some((weight - self.start_weight) / total * 100.0)
+    }
+
+    /// Returns true if `weight` is within the threshold of the target
+    pub fn is_reached(&self, weight: f64) -> bool {
+        (weight - self.weight).abs() <= self.weight_threshold.unwrap_or(0.0)
+    }
+}
+
+/// Body fat goal of a user
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FatGoal {
+    /// Target body fat percentage
+    pub fat: f64,
+    /// Fields of the response not modeled above
+    #[serde(flatten, deserialize_with = "crate::types::extra::deserialize")]
+    pub extra: Map<String, Value>,
 }
 
 /// Response wrapper for weight logs
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct WeightLogResponse {
     pub weight: Vec<BodyWeight>,
+    /// Fields of the response not modeled above
+    #[serde(flatten, deserialize_with = "crate::types::extra::deserialize")]
+    pub extra: Map<String, Value>,
 }
 
 /// Response wrapper for body fat logs
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct BodyFatResponse {
     pub fat: Vec<BodyFat>,
+    /// Fields of the response not modeled above
+    #[serde(flatten, deserialize_with = "crate::types::extra::deserialize")]
+    pub extra: Map<String, Value>,
+}
+
+/// Response wrapper for weight goals
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WeightGoalResponse {
+    pub goal: WeightGoal,
+    /// Fields of the response not modeled above
+    #[serde(flatten, deserialize_with = "crate::types::extra::deserialize")]
+    pub extra: Map<String, Value>,
+}
+
+/// Response wrapper for body fat goals
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FatGoalResponse {
+    pub goal: FatGoal,
+    /// Fields of the response not modeled above
+    #[serde(flatten, deserialize_with = "crate::types::extra::deserialize")]
+    pub extra: Map<String, Value>,
+}
+
+/// Parameters for logging body weight
+#[derive(Debug, Serialize)]
+pub struct LogWeightParams {
+    /// Weight in the user's unit
+    pub weight: f64,
+    /// Date of the measurement in format YYYY-MM-DD
+    pub date: String,
+    /// Time of the measurement in format HH:mm:ss
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time: Option<String>,
+}
+
+impl LogWeightParams {
+    /// Create new LogWeightParams
+    pub fn new(weight: f64, date: impl Into<String>) -> Self {
+        Self {
+            weight,
+            date: date.into(),
+            time: None,
+        }
+    }
+
+    /// Set the time of the measurement
+    pub fn with_time(mut self, time: impl Into<String>) -> Self {
+        self.time = Some(time.into());
+        self
+    }
+}
+
+/// Parameters for logging body fat
+#[derive(Debug, Serialize)]
+pub struct LogBodyFatParams {
+    /// Body fat percentage
+    pub fat: f64,
+    /// Date of the measurement in format YYYY-MM-DD
+    pub date: String,
+    /// Time of the measurement in format HH:mm:ss
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time: Option<String>,
+}
+
+impl LogBodyFatParams {
+    /// Create new LogBodyFatParams
+    pub fn new(fat: f64, date: impl Into<String>) -> Self {
+        Self {
+            fat,
+            date: date.into(),
+            time: None,
+        }
+    }
+
+    /// Set the time of the measurement
+    pub fn with_time(mut self, time: impl Into<String>) -> Self {
+        self.time = Some(time.into());
+        self
+    }
+}
+
+/// Response wrapper for a logged weight entry
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LogWeightResponse {
+    #[serde(rename = "weightLog")]
+    pub weight_log: BodyWeight,
+    /// Fields of the response not modeled above
+    #[serde(flatten, deserialize_with = "crate::types::extra::deserialize")]
+    pub extra: Map<String, Value>,
+}
+
+/// Response wrapper for a logged body fat entry
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LogBodyFatResponse {
+    #[serde(rename = "fatLog")]
+    pub fat_log: BodyFat,
+    /// Fields of the response not modeled above
+    #[serde(flatten, deserialize_with = "crate::types::extra::deserialize")]
+    pub extra: Map<String, Value>,
+}
+
+/// Body resource types for time series
+#[derive(Debug, Clone, Copy)]
+pub enum BodyResource {
+    Bmi,
+    Fat,
+    Weight,
+}
+
+impl BodyResource {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            BodyResource::Bmi => "bmi",
+            BodyResource::Fat => "fat",
+            BodyResource::Weight => "weight",
+        }
+    }
+
+    /// Returns the key holding the time series in the API response
+    pub fn response_key(&self) -> String {
+        format!("body-{}", self.as_str())
+    }
+}
+
+/// Body time series data point (BMI, body fat percentage, or weight in the user's unit)
+pub type BodyTimeSeries = TimeSeriesPoint<f64>;
+
+/// Body mass index of a day
+///
+/// # Examples
+///
+/// ```
+/// use fitbit_sdk::types::body::{Bmi, BmiCategory};
+/// use fitbit_sdk::types::datetime::{ApiDate, ApiField};
+///
+/// let date = ApiDate::from_api_str("2025-01-15").unwrap();
+/// let bmi = Bmi::compute(date, 72.4, 176.5).unwrap();
+/// assert_eq!((bmi.value * 100.0).round() / 100.0, 23.24);
+/// assert_eq!(bmi.category(), BmiCategory::Normal);
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Bmi {
+    /// Date of the BMI
+    #[serde(with = "crate::types::datetime::api_field")]
+    pub date: ApiDate,
+    /// Body mass index in kg/m²
+    pub value: f64,
+}
+
+impl Bmi {
+    /// Computes the BMI from a weight in kilograms and a height in centimeters
+    ///
+    /// Use this as a fallback when Fitbit has no BMI for a day, e.g. with the
+    /// weight of a log entry and the height of the user's profile. Returns
+    /// `None` if the height is not positive.
+    pub fn compute(date: ApiDate, weight_kg: f64, height_cm: f64) -> Option<Self> {
+        if height_cm <= 0.0 {
+            return None;
+        }
+        let height_m = height_cm / 100.0;
+        Some(Bmi {
+            date,
+            value: weight_kg / (height_m * height_m),
+        })
+    }
+
+    /// Returns the WHO weight category of the BMI
+    pub fn category(&self) -> BmiCategory {
+        match self.value {
+            value if value < 18.5 => BmiCategory::Underweight,
+            value if value < 25.0 => BmiCategory::Normal,
+            value if value < 30.0 => BmiCategory::Overweight,
+            _ => BmiCategory::Obese,
+        }
+    }
 }
 
-/// Response wrapper for body goals
-#[derive(Debug, Deserialize)]
-pub struct BodyGoalsResponse {
-    pub goal: BodyGoals,
+/// Weight category of a BMI
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BmiCategory {
+    /// Below 18.5
+    Underweight,
+    /// From 18.5 to below 25
+    Normal,
+    /// From 25 to below 30
+    Overweight,
+    /// 30 and above
+    Obese,
 }
@@ -0,0 +1,123 @@
+//! Breathing Rate API Types
+//!
+//! This module contains the types and functions for the Fitbit Breathing Rate API.
+//!
+use crate::types::auth::Scope;
+use crate::types::datetime::ApiDate;
+use crate::types::error::{FitbitError, domain_error};
+use crate::types::user::UserId;
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use thiserror::Error;
+
+/// Error types for the Breathing Rate API
+#[derive(Debug, Error)]
+#[error(transparent)]
+pub struct BreathingRateError(#[from] pub FitbitError);
+
+domain_error!(BreathingRateError, scopes [Scope::RespiratoryRate]);
+
+#[async_trait]
+pub trait BreathingRateClient {
+    async fn get_breathing_rate_summary<'a>(
+        &'a self,
+        user_id: impl Into<UserId> + Send,
+        date: &'a str,
+    ) -> Result<Vec<BreathingRateSummary>, BreathingRateError>;
+
+    async fn get_breathing_rate_summary_range<'a>(
+        &'a self,
+        user_id: impl Into<UserId> + Send,
+        start_date: &'a str,
+        end_date: &'a str,
+    ) -> Result<Vec<BreathingRateSummary>, BreathingRateError>;
+
+    async fn get_breathing_rate_intraday<'a>(
+        &'a self,
+        user_id: impl Into<UserId> + Send,
+        date: &'a str,
+    ) -> Result<Vec<BreathingRateIntraday>, BreathingRateError>;
+
+    async fn get_breathing_rate_intraday_range<'a>(
+        &'a self,
+        user_id: impl Into<UserId> + Send,
+        start_date: &'a str,
+        end_date: &'a str,
+    ) -> Result<Vec<BreathingRateIntraday>, BreathingRateError>;
+}
+
+/// Average breathing rate during the main sleep of a day
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BreathingRateSummary {
+    /// Date of the main sleep
+    #[serde(rename = "dateTime", with = "crate::types::datetime::api_field")]
+    pub date_time: ApiDate,
+    /// Breathing rate over the whole sleep
+    pub value: BreathingRate,
+    /// Fields of the response not modeled above
+    #[serde(flatten, deserialize_with = "crate::types::extra::deserialize")]
+    pub extra: Map<String, Value>,
+}
+
+/// Breathing rate value
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BreathingRate {
+    /// Average breaths per minute
+    #[serde(rename = "breathingRate")]
+    pub breathing_rate: f64,
+    /// Fields of the response not modeled above
+    #[serde(flatten, deserialize_with = "crate::types::extra::deserialize")]
+    pub extra: Map<String, Value>,
+}
+
+/// Breathing rate per sleep stage for a day
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BreathingRateIntraday {
+    /// Date of the main sleep
+    #[serde(rename = "dateTime", with = "crate::types::datetime::api_field")]
+    pub date_time: ApiDate,
+    /// Breathing rate broken down by sleep stage
+    pub value: BreathingRateStages,
+    /// Fields of the response not modeled above
+    #[serde(flatten, deserialize_with = "crate::types::extra::deserialize")]
+    pub extra: Map<String, Value>,
+}
+
+/// Breathing rate broken down by sleep stage
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BreathingRateStages {
+    /// Average breathing rate during deep sleep
+    #[serde(rename = "deepSleepSummary")]
+    pub deep_sleep_summary: Option<BreathingRate>,
+    /// Average breathing rate during REM sleep
+    #[serde(rename = "remSleepSummary")]
+    pub rem_sleep_summary: Option<BreathingRate>,
+    /// Average breathing rate during light sleep
+    #[serde(rename = "lightSleepSummary")]
+    pub light_sleep_summary: Option<BreathingRate>,
+    /// Average breathing rate over the full sleep
+    #[serde(rename = "fullSleepSummary")]
+    pub full_sleep_summary: Option<BreathingRate>,
+    /// Fields of the response not modeled above
+    #[serde(flatten, deserialize_with = "crate::types::extra::deserialize")]
+    pub extra: Map<String, Value>,
+}
+
+/// Response wrapper for breathing rate summaries
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BreathingRateSummaryResponse {
+    pub br: Vec<BreathingRateSummary>,
+    /// Fields of the response not modeled above
+    #[serde(flatten, deserialize_with = "crate::types::extra::deserialize")]
+    pub extra: Map<String, Value>,
+}
+
+/// Response wrapper for intraday breathing rate
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BreathingRateIntradayResponse {
+    pub br: Vec<BreathingRateIntraday>,
+    /// Fields of the response not modeled above
+    #[serde(flatten, deserialize_with = "crate::types::extra::deserialize")]
+    pub extra: Map<String, Value>,
+}
@@ -0,0 +1,147 @@
+//! Cache Types
+//!
+//! This module contains the types for caching API responses, so repeated
+//! identical requests can be answered without reaching the Fitbit API.
+//!
+use crate::types::transport::TransportResponse;
+use async_trait::async_trait;
+use reqwest::header::{CACHE_CONTROL, ETAG, HeaderMap, LAST_MODIFIED};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// Response stored in a cache
+#[derive(Debug, Clone)]
+pub struct CachedResponse {
+    /// The response as received from the API
+    pub response: TransportResponse,
+    /// When the response stops being fresh and must be revalidated
+    pub expires_at: Instant,
+}
+
+impl CachedResponse {
+    /// Returns true if the response can still be served without asking the API
+    pub fn is_fresh(&self) -> bool {
+        Instant::now() < self.expires_at
+    }
+
+    /// Returns the `ETag` of the response, used to revalidate it with `If-None-Match`
+    pub fn etag(&self) -> Option<&str> {
+        self.response.headers.get(ETAG)?.to_str().ok()
+    }
+
+    /// Returns the `Last-Modified` date of the response, used to revalidate
+    /// it with `If-Modified-Since`
+    pub fn last_modified(&self) -> Option<&str> {
+        self.response.headers.get(LAST_MODIFIED)?.to_str().ok()
+    }
+}
+
+/// Caching directives of a response's `Cache-Control` header
+///
+/// # Examples
+///
+/// ```
+/// use fitbit_sdk::types::cache::CacheControl;
+/// use reqwest::header::{CACHE_CONTROL, HeaderMap, HeaderValue};
+/// use std::time::Duration;
+///
+/// let mut headers = HeaderMap::new();
+/// headers.insert(CACHE_CONTROL, HeaderValue::from_static("private, max-age=300"));
+///
+/// let cache_control = CacheControl::from_headers(&headers);
+/// assert_eq!(cache_control.max_age, Some(Duration::from_secs(300)));
+/// assert!(!cache_control.no_store);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct CacheControl {
+    /// The response must not be stored
+    pub no_store: bool,
+    /// The response may be stored but must be revalidated before each use
+    pub no_cache: bool,
+    /// How long the response stays fresh
+    pub max_age: Option<Duration>,
+}
+
+impl CacheControl {
+    /// Parses the `Cache-Control` headers of a response
+    ///
+    /// Unknown directives are ignored.
+    pub fn from_headers(headers: &HeaderMap) -> Self {
+        let mut cache_control = Self::default();
+        let directives = headers
+            .get_all(CACHE_CONTROL)
+            .iter()
+            .filter_map(|value| value.to_str().ok())
+            .flat_map(|value| value.split(','));
+        for directive in directives {
+            let directive = directive.trim().to_ascii_lowercase();
+            match directive.split_once('=') {
+                Some(("max-age", seconds)) => {
+                    cache_control.max_age = seconds
+                        .trim_matches('"')
+                        .parse()
+                        .ok()
+                        .map(Duration::from_secs);
+                }
+                _ if directive == "no-store" => cache_control.no_store = true,
+                _ if directive == "no-cache" => cache_control.no_cache = true,
+                _ => {}
+            }
+        }
+        cache_control
+    }
+
+    /// Returns how long a response stays fresh, or `default_ttl` without a `max-age`
+    ///
+    /// Responses that must be revalidated before each use are never fresh.
+    pub fn ttl(&self, default_ttl: Duration) -> Duration {
+        if self.no_cache {
+            Duration::ZERO
+        } else {
+            self.max_age.unwrap_or(default_ttl)
+        }
+    }
+}
+
+/// Storage for API responses
+///
+/// `FitbitClient` looks up every GET request in the cache before sending it.
+/// Fresh responses are served from the cache; stale responses with an `ETag`
+/// or `Last-Modified` header are revalidated with a conditional request, and
+/// reused if the API replies `304 Not Modified`. Every other request that
+/// succeeds clears the cache, since it may have changed the data of any
+/// cached response.
+///
+/// Responses are keyed by URL (including the query string), locale headers
+/// and a digest of the access token, so a cache can be shared between the
+/// clients of different users. A refreshed access token starts with an empty
+/// cache.
+///
+/// Caches cannot fail: a response that cannot be stored is simply fetched
+/// again next time.
+#[async_trait]
+pub trait Cache: Send + Sync {
+    /// Returns the response stored under `key`, fresh or not
+    async fn get(&self, key: &str) -> Option<CachedResponse>;
+
+    /// Stores `response` under `key`, replacing any previous response
+    async fn put(&self, key: &str, response: CachedResponse);
+
+    /// Removes every stored response
+    async fn clear(&self);
+}
+
+#[async_trait]
+impl<T: Cache + ?Sized> Cache for Arc<T> {
+    async fn get(&self, key: &str) -> Option<CachedResponse> {
+        (**self).get(key).await
+    }
+
+    async fn put(&self, key: &str, response: CachedResponse) {
+        (**self).put(key, response).await
+    }
+
+    async fn clear(&self) {
+        (**self).clear().await
+    }
+}
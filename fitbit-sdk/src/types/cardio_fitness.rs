@@ -0,0 +1,143 @@
+//! Cardio Fitness Score API Types
+//!
+//! This module contains the types and functions for the Fitbit Cardio Fitness Score (VO2 Max) API.
+//!
+use crate::types::auth::Scope;
+use crate::types::datetime::ApiDate;
+use crate::types::error::{FitbitError, domain_error};
+use crate::types::user::UserId;
+use async_trait::async_trait;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::{Map, Value};
+use std::str::FromStr;
+use thiserror::Error;
+
+/// Error types for the Cardio Fitness Score API
+#[derive(Debug, Error)]
+#[error(transparent)]
+pub struct CardioFitnessError(#[from] pub FitbitError);
+
+domain_error!(CardioFitnessError, scopes [Scope::CardioFitness]);
+
+#[async_trait]
+pub trait CardioFitnessClient {
+    async fn get_cardio_fitness_score<'a>(
+        &'a self,
+        user_id: impl Into<UserId> + Send,
+        date: &'a str,
+    ) -> Result<Vec<CardioFitnessScore>, CardioFitnessError>;
+
+    async fn get_cardio_fitness_score_range<'a>(
+        &'a self,
+        user_id: impl Into<UserId> + Send,
+        start_date: &'a str,
+        end_date: &'a str,
+    ) -> Result<Vec<CardioFitnessScore>, CardioFitnessError>;
+}
+
+/// Cardio Fitness Score for a single day
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CardioFitnessScore {
+    /// Date of the score
+    #[serde(rename = "dateTime", with = "crate::types::datetime::api_field")]
+    pub date_time: ApiDate,
+    /// Cardio Fitness Score values for the day
+    pub value: CardioFitnessValue,
+    /// Fields of the response not modeled above
+    #[serde(flatten, deserialize_with = "crate::types::extra::deserialize")]
+    pub extra: Map<String, Value>,
+}
+
+/// Cardio Fitness Score values
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CardioFitnessValue {
+    /// Estimated VO2 Max in mL/kg/min
+    #[serde(rename = "vo2Max")]
+    pub vo2_max: Vo2Max,
+    /// Fields of the response not modeled above
+    #[serde(flatten, deserialize_with = "crate::types::extra::deserialize")]
+    pub extra: Map<String, Value>,
+}
+
+/// Estimated VO2 Max in mL/kg/min
+///
+/// Fitbit reports a single value when the user has a GPS-tracked run, and a
+/// range estimated from resting heart rate otherwise.
+///
+/// # Examples
+///
+/// ```
+/// use fitbit_sdk::types::cardio_fitness::Vo2Max;
+///
+/// assert_eq!("44".parse::<Vo2Max>(), Ok(Vo2Max::Single(44.0)));
+/// assert_eq!("43-47".parse::<Vo2Max>(), Ok(Vo2Max::Range(43.0, 47.0)));
+/// assert_eq!(Vo2Max::Range(43.0, 47.0).midpoint(), 45.0);
+/// assert!("high".parse::<Vo2Max>().is_err());
+/// assert_eq!(Vo2Max::Range(43.0, 47.0).to_string(), "43-47");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Vo2Max {
+    /// A single estimated value
+    Single(f64),
+    /// A range of estimated values (low, high)
+    Range(f64, f64),
+}
+
+impl Vo2Max {
+    /// Single value, or the middle of the range
+    pub fn midpoint(&self) -> f64 {
+        match *self {
+            Vo2Max::Single(value) => value,
+            Vo2Max::Range(low, high) => (low + high) / 2.0,
+        }
+    }
+}
+
+impl FromStr for Vo2Max {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parse = |value: &str| {
+            value
+                .trim()
+                .parse::<f64>()
+                .map_err(|_| format!("invalid VO2 Max value: {}", s))
+        };
+        match s.split_once('-') {
+            Some((low, high)) => Ok(Vo2Max::Range(parse(low)?, parse(high)?)),
+            None => Ok(Vo2Max::Single(parse(s)?)),
+        }
+    }
+}
+
+impl std::fmt::Display for Vo2Max {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Vo2Max::Single(value) => write!(f, "{}", value),
+            Vo2Max::Range(low, high) => write!(f, "{}-{}", low, high),
+        }
+    }
+}
+
+impl Serialize for Vo2Max {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Vo2Max {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        value.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+/// Response wrapper for Cardio Fitness Scores
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CardioFitnessResponse {
+    #[serde(rename = "cardioScore")]
+    pub cardio_score: Vec<CardioFitnessScore>,
+    /// Fields of the response not modeled above
+    #[serde(flatten, deserialize_with = "crate::types::extra::deserialize")]
+    pub extra: Map<String, Value>,
+}
@@ -0,0 +1,207 @@
+//! Chrono Types
+//!
+//! This module contains the `chrono` support of the date and time fields, for
+//! applications built on [`chrono`](https://docs.rs/chrono) rather than `time`.
+//!
+//! `chrono` types implement `ApiField`, so any field can be converted with
+//! `ApiField::convert`, with or without the `typed-time` feature. `ToChrono`
+//! and `FromChrono` convert between the `time` and `chrono` types directly.
+//!
+//! | `time`              | `chrono`                                   |
+//! |---------------------|--------------------------------------------|
+//! | `Date`              | `NaiveDate`                                |
+//! | `Time`              | `NaiveTime`                                |
+//! | `PrimitiveDateTime` | `NaiveDateTime`                            |
+//! | `OffsetDateTime`    | `DateTime<FixedOffset>`, `DateTime<Utc>`   |
+//!
+//! # Examples
+//!
+//! ```
+//! use chrono::{NaiveDate, NaiveDateTime};
+//! use fitbit_sdk::types::chrono::{FromChrono, ToChrono};
+//! use fitbit_sdk::types::datetime::ApiField;
+//! use time::macros::date;
+//! use time::Date;
+//!
+//! // A field, e.g. the `dateTime` of a time series point
+//! let field = "2025-01-15".to_string();
+//! let day: NaiveDate = field.convert().unwrap();
+//! assert_eq!(day, NaiveDate::from_ymd_opt(2025, 1, 15).unwrap());
+//!
+//! assert_eq!(date!(2025 - 01 - 15).to_chrono(), day);
+//! assert_eq!(Date::from_chrono(day), Ok(date!(2025 - 01 - 15)));
+//!
+//! let start = NaiveDateTime::from_api_str("2025-01-14T23:15:30.000").unwrap();
+//! assert_eq!(start.to_api_string(), "2025-01-14T23:15:30.000");
+//! ```
+
+use crate::types::datetime::{ApiField, invalid};
+use ::chrono::{
+    DateTime, Datelike, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, SecondsFormat, Timelike,
+    Utc,
+};
+use time::{Date, Month, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset};
+
+impl ApiField for NaiveDate {
+    fn from_api_str(s: &str) -> Result<Self, String> {
+        NaiveDate::parse_from_str(s, "%Y-%m-%d").map_err(|e| invalid("date", s, e))
+    }
+
+    fn to_api_string(&self) -> String {
+        self.format("%Y-%m-%d").to_string()
+    }
+}
+
+impl ApiField for NaiveTime {
+    fn from_api_str(s: &str) -> Result<Self, String> {
+        NaiveTime::parse_from_str(s, "%H:%M:%S")
+            .or_else(|_| NaiveTime::parse_from_str(s, "%H:%M"))
+            .map_err(|e| invalid("time", s, e))
+    }
+
+    fn to_api_string(&self) -> String {
+        self.format("%H:%M:%S").to_string()
+    }
+}
+
+impl ApiField for NaiveDateTime {
+    fn from_api_str(s: &str) -> Result<Self, String> {
+        NaiveDateTime::parse_from_str(s, "%Y-%m-%dT%H:%M:%S%.f")
+            .or_else(|_| NaiveDateTime::parse_from_str(s, "%Y-%m-%dT%H:%M:%S"))
+            .map_err(|e| invalid("date-time", s, e))
+    }
+
+    fn to_api_string(&self) -> String {
+        self.format("%Y-%m-%dT%H:%M:%S%.3f").to_string()
+    }
+}
+
+impl ApiField for DateTime<FixedOffset> {
+    fn from_api_str(s: &str) -> Result<Self, String> {
+        DateTime::parse_from_rfc3339(s).map_err(|e| invalid("timestamp", s, e))
+    }
+
+    fn to_api_string(&self) -> String {
+        self.to_rfc3339_opts(SecondsFormat::AutoSi, true)
+    }
+}
+
+impl ApiField for DateTime<Utc> {
+    fn from_api_str(s: &str) -> Result<Self, String> {
+        DateTime::<FixedOffset>::from_api_str(s).map(|timestamp| timestamp.to_utc())
+    }
+
+    fn to_api_string(&self) -> String {
+        self.to_rfc3339_opts(SecondsFormat::AutoSi, true)
+    }
+}
+
+/// Conversion of a `time` value into its `chrono` counterpart
+///
+/// Every `time` value can be represented in `chrono`, so the conversion cannot fail.
+pub trait ToChrono {
+    /// The `chrono` type
+    type Chrono;
+
+    /// Converts the value into its `chrono` counterpart
+    fn to_chrono(&self) -> Self::Chrono;
+}
+
+impl ToChrono for Date {
+    type Chrono = NaiveDate;
+
+    fn to_chrono(&self) -> NaiveDate {
+        let month = u32::from(u8::from(self.month()));
+        NaiveDate::from_ymd_opt(self.year(), month, u32::from(self.day()))
+            .expect("time dates are within the range of chrono dates")
+    }
+}
+
+impl ToChrono for Time {
+    type Chrono = NaiveTime;
+
+    fn to_chrono(&self) -> NaiveTime {
+        let (hour, minute, second, nanosecond) = self.as_hms_nano();
+        NaiveTime::from_hms_nano_opt(
+            u32::from(hour),
+            u32::from(minute),
+            u32::from(second),
+            nanosecond,
+        )
+        .expect("time times are valid chrono times")
+    }
+}
+
+impl ToChrono for PrimitiveDateTime {
+    type Chrono = NaiveDateTime;
+
+    fn to_chrono(&self) -> NaiveDateTime {
+        NaiveDateTime::new(self.date().to_chrono(), self.time().to_chrono())
+    }
+}
+
+impl ToChrono for OffsetDateTime {
+    type Chrono = DateTime<FixedOffset>;
+
+    fn to_chrono(&self) -> DateTime<FixedOffset> {
+        let offset = FixedOffset::east_opt(self.offset().whole_seconds())
+            .expect("time offsets are within the range of chrono offsets");
+        NaiveDateTime::new(self.date().to_chrono(), self.time().to_chrono())
+            .and_local_timezone(offset)
+            .single()
+            .expect("fixed offsets map local date-times unambiguously")
+    }
+}
+
+/// Conversion of a `chrono` value into its `time` counterpart
+///
+/// `chrono` supports a wider range of dates than `time` and leap seconds, so
+/// the conversion fails for values `time` cannot represent.
+pub trait FromChrono<T>: Sized {
+    /// Converts a `chrono` value into its `time` counterpart
+    fn from_chrono(value: T) -> Result<Self, String>;
+}
+
+impl FromChrono<NaiveDate> for Date {
+    fn from_chrono(value: NaiveDate) -> Result<Self, String> {
+        // `chrono` months and days always fit in a `u8`
+        let month = Month::try_from(value.month() as u8).map_err(|e| e.to_string())?;
+        Date::from_calendar_date(value.year(), month, value.day() as u8)
+            .map_err(|e| invalid("date", &value.to_string(), e))
+    }
+}
+
+impl FromChrono<NaiveTime> for Time {
+    fn from_chrono(value: NaiveTime) -> Result<Self, String> {
+        Time::from_hms_nano(
+            value.hour() as u8,
+            value.minute() as u8,
+            value.second() as u8,
+            value.nanosecond(),
+        )
+        .map_err(|e| invalid("time", &value.to_string(), e))
+    }
+}
+
+impl FromChrono<NaiveDateTime> for PrimitiveDateTime {
+    fn from_chrono(value: NaiveDateTime) -> Result<Self, String> {
+        Ok(PrimitiveDateTime::new(
+            Date::from_chrono(value.date())?,
+            Time::from_chrono(value.time())?,
+        ))
+    }
+}
+
+impl FromChrono<DateTime<FixedOffset>> for OffsetDateTime {
+    fn from_chrono(value: DateTime<FixedOffset>) -> Result<Self, String> {
+        let offset = UtcOffset::from_whole_seconds(value.offset().local_minus_utc())
+            .map_err(|e| invalid("offset", &value.offset().to_string(), e))?;
+        Ok(PrimitiveDateTime::from_chrono(value.naive_local())?.assume_offset(offset))
+    }
+}
+
+impl FromChrono<DateTime<Utc>> for OffsetDateTime {
+    fn from_chrono(value: DateTime<Utc>) -> Result<Self, String> {
+        OffsetDateTime::from_chrono(value.fixed_offset())
+    }
+}
@@ -0,0 +1,95 @@
+//! Date Types
+//!
+//! This module contains the date type accepted by the Fitbit API endpoints.
+//!
+use std::fmt;
+use std::str::FromStr;
+use time::macros::format_description;
+use time::{Date, OffsetDateTime};
+#[cfg(any(feature = "sync", feature = "wellness"))]
+use time::Duration;
+
+/// Date of a Fitbit API request
+///
+/// Endpoints accept any `impl Into<FitbitDate>`, so a `time::Date` can be passed
+/// directly. Strings are parsed with `str::parse`, which accepts "today",
+/// "yesterday" and dates in format YYYY-MM-DD.
+///
+/// # Examples
+///
+/// ```
+/// use fitbit_sdk::types::date::FitbitDate;
+/// use time::macros::date;
+///
+/// assert_eq!(FitbitDate::Today.to_string(), "today");
+/// assert_eq!(FitbitDate::from(date!(2025 - 01 - 15)).to_string(), "2025-01-15");
+///
+/// let parsed: FitbitDate = "2025-01-15".parse().unwrap();
+/// assert_eq!(parsed, FitbitDate::On(date!(2025 - 01 - 15)));
+/// assert_eq!("today".parse::<FitbitDate>(), Ok(FitbitDate::Today));
+/// assert!("2025-02-30".parse::<FitbitDate>().is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FitbitDate {
+    /// The current day in the user's time zone
+    Today,
+    /// The day before the current day in the local time zone
+    Yesterday,
+    /// A specific calendar date
+    On(Date),
+}
+
+impl From<Date> for FitbitDate {
+    fn from(date: Date) -> Self {
+        FitbitDate::On(date)
+    }
+}
+
+impl FromStr for FitbitDate {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "today" => Ok(FitbitDate::Today),
+            "yesterday" => Ok(FitbitDate::Yesterday),
+            _ => Date::parse(s, format_description!("[year]-[month]-[day]"))
+                .map(FitbitDate::On)
+                .map_err(|e| format!("Invalid date '{}': {}", s, e)),
+        }
+    }
+}
+
+impl fmt::Display for FitbitDate {
+    /// Formats the date as a path segment of the Fitbit API
+    ///
+    /// `Yesterday` is resolved against the local clock, falling back to UTC
+    /// when the local offset cannot be determined.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FitbitDate::Today => f.write_str("today"),
+            FitbitDate::Yesterday => {
+                let now = OffsetDateTime::now_local().unwrap_or_else(|_| OffsetDateTime::now_utc());
+                write!(f, "{}", now.date().previous_day().unwrap_or(Date::MIN))
+            }
+            FitbitDate::On(date) => write!(f, "{}", date),
+        }
+    }
+}
+
+/// Splits the days from `start` to `end` into ranges of at most `days` days
+#[cfg(any(feature = "sync", feature = "wellness"))]
+pub(crate) fn date_windows(start: Date, end: Date, days: i64) -> Vec<(Date, Date)> {
+    let mut windows = Vec::new();
+    let mut from = start;
+    while from <= end {
+        let to = from
+            .checked_add(Duration::days(days - 1))
+            .map_or(end, |to| to.min(end));
+        windows.push((from, to));
+        match to.next_day() {
+            Some(next) => from = next,
+            None => break,
+        }
+    }
+    windows
+}
@@ -0,0 +1,212 @@
+//! Date and Time Field Types
+//!
+//! This module contains the types of the date and time fields of API responses.
+//!
+//! By default these fields are strings, as returned by the Fitbit API. With the
+//! `typed-time` feature they are parsed into `time` types during
+//! deserialization, and formatted back into the API format when serialized:
+//!
+//! | Alias          | Default  | `typed-time`        | API format                |
+//! |----------------|----------|---------------------|---------------------------|
+//! | `ApiDate`      | `String` | `Date`              | `2025-01-15`              |
+//! | `ApiTime`      | `String` | `Time`              | `08:00:00` or `08:00`     |
+//! | `ApiDateTime`  | `String` | `PrimitiveDateTime` | `2025-01-15T08:00:00.000` |
+//! | `ApiTimestamp` | `String` | `OffsetDateTime`    | `2025-01-15T08:00:00.000Z`|
+//!
+//! Fitbit reports most date-times in the user's local time without an offset,
+//! hence `PrimitiveDateTime`. The `ApiField` trait converts between both
+//! representations, for code that has to compile with and without the feature.
+//!
+//! `typed-time` is not additive. Cargo unifies features across the dependency
+//! graph, so enabling it in any crate changes the types of these fields for
+//! every crate in the graph that uses `fitbit-sdk`. Libraries built on the SDK
+//! should not enable it, and should read these fields through `ApiField`.
+//!
+use std::fmt::Display;
+use time::format_description::well_known::Rfc3339;
+use time::macros::format_description;
+use time::{Date, OffsetDateTime, PrimitiveDateTime, Time};
+
+/// Calendar date field
+#[cfg(feature = "typed-time")]
+pub type ApiDate = Date;
+/// Calendar date field
+#[cfg(not(feature = "typed-time"))]
+pub type ApiDate = String;
+
+/// Time of day field
+#[cfg(feature = "typed-time")]
+pub type ApiTime = Time;
+/// Time of day field
+#[cfg(not(feature = "typed-time"))]
+pub type ApiTime = String;
+
+/// Date-time field in the user's local time
+#[cfg(feature = "typed-time")]
+pub type ApiDateTime = PrimitiveDateTime;
+/// Date-time field in the user's local time
+#[cfg(not(feature = "typed-time"))]
+pub type ApiDateTime = String;
+
+/// Date-time field with a UTC offset
+#[cfg(feature = "typed-time")]
+pub type ApiTimestamp = OffsetDateTime;
+/// Date-time field with a UTC offset
+#[cfg(not(feature = "typed-time"))]
+pub type ApiTimestamp = String;
+
+/// Value of a date or time field, convertible from and to the API format
+///
+/// # Examples
+///
+/// ```
+/// use fitbit_sdk::types::datetime::ApiField;
+/// use time::macros::{date, datetime, time};
+/// use time::{Date, PrimitiveDateTime, Time};
+///
+/// assert_eq!(Date::from_api_str("2025-01-15"), Ok(date!(2025 - 01 - 15)));
+/// assert_eq!(Time::from_api_str("08:00"), Ok(time!(08:00)));
+/// assert_eq!(time!(08:00).to_api_string(), "08:00:00");
+///
+/// let start = PrimitiveDateTime::from_api_str("2025-01-14T23:15:30.000").unwrap();
+/// assert_eq!(start, datetime!(2025-01-14 23:15:30));
+/// assert_eq!(start.to_api_string(), "2025-01-14T23:15:30.000");
+///
+/// // Fields can be converted regardless of the `typed-time` feature
+/// let field = "2025-01-15".to_string();
+/// assert_eq!(field.convert::<Date>(), Ok(date!(2025 - 01 - 15)));
+/// assert!(Date::from_api_str("2025-02-30").is_err());
+/// ```
+pub trait ApiField: Sized {
+    /// Parses a value in the API format
+    fn from_api_str(s: &str) -> Result<Self, String>;
+
+    /// Formats the value in the API format
+    fn to_api_string(&self) -> String;
+
+    /// Converts the value into another representation of the same field
+    fn convert<U: ApiField>(&self) -> Result<U, String> {
+        U::from_api_str(&self.to_api_string())
+    }
+}
+
+/// Formats a parse error of `s`
+pub(crate) fn invalid(kind: &str, s: &str, e: impl Display) -> String {
+    format!("Invalid {} '{}': {}", kind, s, e)
+}
+
+impl ApiField for String {
+    fn from_api_str(s: &str) -> Result<Self, String> {
+        Ok(s.to_string())
+    }
+
+    fn to_api_string(&self) -> String {
+        self.clone()
+    }
+}
+
+impl ApiField for Date {
+    fn from_api_str(s: &str) -> Result<Self, String> {
+        Date::parse(s, format_description!("[year]-[month]-[day]"))
+            .map_err(|e| invalid("date", s, e))
+    }
+
+    fn to_api_string(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl ApiField for Time {
+    fn from_api_str(s: &str) -> Result<Self, String> {
+        Time::parse(s, format_description!("[hour]:[minute]:[second]"))
+            .or_else(|_| Time::parse(s, format_description!("[hour]:[minute]")))
+            .map_err(|e| invalid("time", s, e))
+    }
+
+    fn to_api_string(&self) -> String {
+        format!("{:02}:{:02}:{:02}", self.hour(), self.minute(), self.second())
+    }
+}
+
+impl ApiField for PrimitiveDateTime {
+    fn from_api_str(s: &str) -> Result<Self, String> {
+        PrimitiveDateTime::parse(
+            s,
+            format_description!("[year]-[month]-[day]T[hour]:[minute]:[second].[subsecond]"),
+        )
+        .or_else(|_| {
+            PrimitiveDateTime::parse(
+                s,
+                format_description!("[year]-[month]-[day]T[hour]:[minute]:[second]"),
+            )
+        })
+        .map_err(|e| invalid("date-time", s, e))
+    }
+
+    fn to_api_string(&self) -> String {
+        format!(
+            "{}T{}.{:03}",
+            self.date(),
+            self.time().to_api_string(),
+            self.millisecond()
+        )
+    }
+}
+
+impl ApiField for OffsetDateTime {
+    fn from_api_str(s: &str) -> Result<Self, String> {
+        OffsetDateTime::parse(s, &Rfc3339).map_err(|e| invalid("timestamp", s, e))
+    }
+
+    fn to_api_string(&self) -> String {
+        self.format(&Rfc3339).unwrap_or_default()
+    }
+}
+
+/// Serde functions for `ApiField` values, used with `#[serde(with = "...")]`
+pub(crate) mod api_field {
+    use super::ApiField;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<T: ApiField, S: Serializer>(
+        value: &T,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&value.to_api_string())
+    }
+
+    pub fn deserialize<'de, T: ApiField, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<T, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        T::from_api_str(&s).map_err(serde::de::Error::custom)
+    }
+}
+
+/// Serde functions for optional `ApiField` values, used with `#[serde(with = "...")]`
+///
+/// Fields using these functions also need `#[serde(default)]` to accept a
+/// missing value.
+#[cfg_attr(not(feature = "full"), allow(dead_code))]
+pub(crate) mod option_api_field {
+    use super::ApiField;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<T: ApiField, S: Serializer>(
+        value: &Option<T>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match value {
+            Some(value) => serializer.serialize_some(&value.to_api_string()),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, T: ApiField, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<T>, D::Error> {
+        Option::<String>::deserialize(deserializer)?
+            .map(|s| T::from_api_str(&s).map_err(serde::de::Error::custom))
+            .transpose()
+    }
+}
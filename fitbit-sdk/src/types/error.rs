@@ -0,0 +1,167 @@
+//! Error Types
+//!
+//! This module contains the error type shared by the endpoints that do not
+//! belong to a single API domain, like `FitbitClient::request`, into which
+//! the error of every domain converts, and the error of building a client.
+//!
+use crate::types::auth::{AuthError, Scope};
+use reqwest::Method;
+use thiserror::Error;
+
+/// Error of a request to any Fitbit API endpoint
+///
+/// Unlike the error types of each domain, insufficient scope errors only
+/// name the missing scopes when the API message does.
+///
+/// The error type of every domain converts into `FitbitError` and back,
+/// so code calling several domains can handle their errors in one place.
+///
+/// # Examples
+///
+/// ```
+/// use fitbit_sdk::client::FitbitClient;
+/// use fitbit_sdk::transport::MockTransport;
+/// use fitbit_sdk::types::error::FitbitError;
+/// use fitbit_sdk::types::sleep::SleepClient;
+/// use fitbit_sdk::types::user::UserClient;
+/// use reqwest::Method;
+///
+/// async fn badges_and_sleep_goal(client: &FitbitClient) -> Result<(usize, i32), FitbitError> {
+///     // `UserError` and `SleepError` both convert with `?`
+///     let badges = client.get_badges("-").await?;
+///     let sleep_goal = client.get_sleep_goal("-").await?;
+///     Ok((badges.len(), sleep_goal.goal.min_duration))
+/// }
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), FitbitError> {
+/// let mock = MockTransport::new()
+///     .with_json(Method::GET, "/1/user/-/badges.json", r#"{"badges": []}"#)
+///     .with_json(
+///         Method::GET,
+///         "/1.2/user/-/sleep/goal.json",
+///         r#"{"goal": {"minDuration": 480, "updatedOn": "2025-01-10T08:14:15.111Z"}}"#,
+///     );
+/// let client = FitbitClient::builder()
+///     .with_access_token("test-token")
+///     .with_transport(mock)
+///     .build()?;
+///
+/// assert_eq!(badges_and_sleep_goal(&client).await?, (0, 480));
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Error)]
+pub enum FitbitError {
+    #[error("API request failed: {0}")]
+    RequestFailed(String),
+    #[error("API error: {0}")]
+    ApiError(String),
+    #[error("Insufficient scope, the request requires: {}", Scope::join(.0))]
+    InsufficientScope(Vec<Scope>),
+    #[error("No known Fitbit API endpoint matches {method} {path}")]
+    UnknownEndpoint { method: Method, path: String },
+    #[error("Failed to build the client: {0}")]
+    Build(#[from] ClientBuildError),
+}
+
+impl From<String> for FitbitError {
+    fn from(error: String) -> Self {
+        match Scope::insufficient_scope(&error, &[]) {
+            Some(scopes) => FitbitError::InsufficientScope(scopes),
+            None => FitbitError::ApiError(error),
+        }
+    }
+}
+
+impl From<AuthError> for FitbitError {
+    fn from(error: AuthError) -> Self {
+        match error {
+            AuthError::RequestFailed(error) => FitbitError::RequestFailed(error),
+            AuthError::ApiError(error) => FitbitError::ApiError(error),
+        }
+    }
+}
+
+/// Error of building a `FitbitClient`
+///
+/// Every error type of the SDK converts from it, so `build()?` works in
+/// functions returning the error type of the domain they call.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum ClientBuildError {
+    #[error(
+        "Access token must be provided either via builder or FITBIT_ACCESS_TOKEN environment variable"
+    )]
+    MissingAccessToken,
+    #[error("Invalid API base URL {url}: {reason}")]
+    InvalidBaseUrl { url: String, reason: String },
+    #[error("Invalid proxy URL {url}: {reason}")]
+    InvalidProxyUrl { url: String, reason: String },
+    #[error("Failed to initialize the HTTP client: {0}")]
+    HttpClient(String),
+    #[error("Invalid value {value:?} of environment variable {name}: {reason}")]
+    InvalidEnvVar {
+        name: String,
+        value: String,
+        reason: String,
+    },
+}
+
+/// Implements the conversions of a domain error type
+///
+/// The error type must wrap a `FitbitError`, either as a newtype or, if it
+/// has variants of its own, in a variant converting from it. It converts
+/// from `ClientBuildError` and to and from `FitbitError`. Optional arguments,
+/// in this order:
+///
+/// - `scopes [..]` converts from the message of a failed request, reading
+///   insufficient scope errors as missing the given scopes.
+/// - `from [..]` converts from the error types of other domains.
+/// - Match arms convert the variants of an enum into `FitbitError`; a
+///   newtype is unwrapped without them.
+macro_rules! domain_error {
+    ($error:ident, scopes [$($scope:expr),* $(,)?] $(, $($rest:tt)*)?) => {
+        impl From<String> for $error {
+            fn from(error: String) -> Self {
+                match $crate::types::auth::Scope::insufficient_scope(&error, &[$($scope),*]) {
+                    Some(scopes) => $crate::types::error::FitbitError::InsufficientScope(scopes),
+                    None => $crate::types::error::FitbitError::ApiError(error),
+                }
+                .into()
+            }
+        }
+
+        $crate::types::error::domain_error!($error $(, $($rest)*)?);
+    };
+    ($error:ident, from [$($source:ty),* $(,)?] $(, $($rest:tt)*)?) => {
+        $(
+            impl From<$source> for $error {
+                fn from(error: $source) -> Self {
+                    $crate::types::error::FitbitError::from(error).into()
+                }
+            }
+        )*
+
+        $crate::types::error::domain_error!($error $(, $($rest)*)?);
+    };
+    ($error:ident $(,)?) => {
+        $crate::types::error::domain_error!($error, $error(error) => error);
+    };
+    ($error:ident, $($variant:pat => $converted:expr),+ $(,)?) => {
+        impl From<$crate::types::error::ClientBuildError> for $error {
+            fn from(error: $crate::types::error::ClientBuildError) -> Self {
+                $crate::types::error::FitbitError::Build(error).into()
+            }
+        }
+
+        impl From<$error> for $crate::types::error::FitbitError {
+            fn from(error: $error) -> Self {
+                match error {
+                    $($variant => $converted,)+
+                }
+            }
+        }
+    };
+}
+
+pub(crate) use domain_error;
@@ -0,0 +1,24 @@
+//! Unmodeled Fields
+//!
+//! This module contains the serde function for the `extra` field of response
+//! types, which collects the fields a type does not model.
+//!
+//! With the `strict-serde` feature, a response carrying such fields fails to
+//! deserialize instead, naming the fields. Running the fixture tests with the
+//! feature detects when Fitbit adds fields the SDK does not model yet.
+
+use serde::{Deserialize, Deserializer};
+use serde_json::{Map, Value};
+
+/// Deserializes the fields a type does not model, used with
+/// `#[serde(flatten, deserialize_with = "...")]`
+pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Map<String, Value>, D::Error> {
+    let extra = Map::deserialize(deserializer)?;
+    if cfg!(feature = "strict-serde") && !extra.is_empty() {
+        let fields: Vec<&str> = extra.keys().map(String::as_str).collect();
+        return Err(serde::de::Error::custom(format!("unknown fields: {}", fields.join(", "))));
+    }
+    Ok(extra)
+}
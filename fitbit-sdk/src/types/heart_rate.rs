@@ -0,0 +1,320 @@
+//! Heart Rate API Types
+//!
+//! This module contains the types and functions for the Fitbit Heart Rate API.
+//!
+use crate::types::auth::Scope;
+use crate::types::datetime::ApiDate;
+use crate::types::error::{FitbitError, domain_error};
+use crate::types::intraday::{DetailLevel, IntradayDataPoint, IntradayDataset};
+use crate::types::user::UserId;
+use async_trait::async_trait;
+use futures_util::stream::BoxStream;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use thiserror::Error;
+use time::{Date, Duration};
+
+/// Error types for the Heart Rate API
+#[derive(Debug, Error)]
+#[error(transparent)]
+pub struct HeartRateError(#[from] pub FitbitError);
+
+domain_error!(HeartRateError, scopes [Scope::Heartrate]);
+
+#[async_trait]
+pub trait HeartRateClient {
+    async fn get_heart_rate_time_series<'a>(
+        &'a self,
+        user_id: impl Into<UserId> + Send,
+        date: &'a str,
+        period: &'a str,
+    ) -> Result<Vec<HeartRateTimeSeries>, HeartRateError>;
+
+    async fn get_heart_rate_time_series_range<'a>(
+        &'a self,
+        user_id: impl Into<UserId> + Send,
+        start_date: &'a str,
+        end_date: &'a str,
+    ) -> Result<Vec<HeartRateTimeSeries>, HeartRateError>;
+
+    async fn get_resting_heart_rate<'a>(
+        &'a self,
+        user_id: impl Into<UserId> + Send,
+        date: &'a str,
+        period: &'a str,
+    ) -> Result<Vec<RestingHeartRate>, HeartRateError>;
+
+    async fn get_custom_heart_rate_zones<'a>(
+        &'a self,
+        user_id: impl Into<UserId> + Send,
+        date: &'a str,
+    ) -> Result<Option<HeartRateZones>, HeartRateError>;
+
+    async fn get_heart_rate_intraday<'a>(
+        &'a self,
+        user_id: impl Into<UserId> + Send,
+        date: &'a str,
+        detail_level: DetailLevel,
+    ) -> Result<IntradayHeartRateSeries, HeartRateError>;
+
+    async fn get_heart_rate_intraday_window<'a>(
+        &'a self,
+        user_id: impl Into<UserId> + Send,
+        date: &'a str,
+        detail_level: DetailLevel,
+        start_time: &'a str,
+        end_time: &'a str,
+    ) -> Result<IntradayHeartRateSeries, HeartRateError>;
+
+    async fn get_heart_rate_time_in_zones<'a>(
+        &'a self,
+        user_id: impl Into<UserId> + Send,
+        start_date: Date,
+        end_date: Date,
+        detail_level: DetailLevel,
+        zones: &'a ZoneSelection,
+        max_gap: Duration,
+    ) -> Result<IntradayZoneTime, HeartRateError>;
+
+    fn stream_heart_rate_intraday<'a>(
+        &'a self,
+        user_id: impl Into<UserId> + Send,
+        date: &'a str,
+        detail_level: DetailLevel,
+    ) -> BoxStream<'a, Result<IntradayDataPoint<i32>, HeartRateError>>;
+}
+
+/// Heart rate data for a single day
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HeartRateTimeSeries {
+    /// Date of the data point
+    #[serde(rename = "dateTime", with = "crate::types::datetime::api_field")]
+    pub date_time: ApiDate,
+    /// Heart rate zones and resting heart rate for the day
+    pub value: HeartRateValue,
+    /// Fields of the response not modeled above
+    #[serde(flatten, deserialize_with = "crate::types::extra::deserialize")]
+    pub extra: Map<String, Value>,
+}
+
+impl HeartRateTimeSeries {
+    /// Resting heart rate for the day, if one was calculated
+    // The date is only `Copy` with the `typed-time` feature
+    #[allow(clippy::clone_on_copy)]
+    pub fn resting_heart_rate(&self) -> Option<RestingHeartRate> {
+        self.value.resting_heart_rate.map(|value| RestingHeartRate {
+            date: self.date_time.clone(),
+            value,
+            extra: self.extra.clone(),
+        })
+    }
+}
+
+/// Daily heart rate value
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HeartRateValue {
+    /// Time spent in the user's custom heart rate zones
+    #[serde(rename = "customHeartRateZones", default)]
+    pub custom_heart_rate_zones: Vec<HeartRateZone>,
+    /// Time spent in the default heart rate zones
+    #[serde(rename = "heartRateZones", default)]
+    pub heart_rate_zones: Vec<HeartRateZone>,
+    /// Resting heart rate in beats per minute
+    #[serde(rename = "restingHeartRate")]
+    pub resting_heart_rate: Option<i32>,
+    /// Fields of the response not modeled above
+    #[serde(flatten, deserialize_with = "crate::types::extra::deserialize")]
+    pub extra: Map<String, Value>,
+}
+
+impl HeartRateValue {
+    /// Boundaries of the user's custom heart rate zones, if the user set any
+    pub fn custom_zones(&self) -> Option<HeartRateZones> {
+        (!self.custom_heart_rate_zones.is_empty())
+            .then(|| HeartRateZones::from_zones(&self.custom_heart_rate_zones))
+    }
+
+    /// Boundaries of the default heart rate zones
+    pub fn default_zones(&self) -> HeartRateZones {
+        HeartRateZones::from_zones(&self.heart_rate_zones)
+    }
+}
+
+/// Heart rate zone and the time spent in it
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HeartRateZone {
+    /// Name of the zone (Out of Range, Fat Burn, Cardio, Peak)
+    pub name: String,
+    /// Lower bound of the zone in beats per minute
+    pub min: i32,
+    /// Upper bound of the zone in beats per minute
+    pub max: i32,
+    /// Minutes spent in the zone
+    pub minutes: Option<i32>,
+    /// Calories burned in the zone
+    #[serde(rename = "caloriesOut")]
+    pub calories_out: Option<f64>,
+    /// Fields of the response not modeled above
+    #[serde(flatten, deserialize_with = "crate::types::extra::deserialize")]
+    pub extra: Map<String, Value>,
+}
+
+/// Boundaries of a single heart rate zone
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HeartRateZoneBounds {
+    /// Name of the zone, e.g. Fat Burn, or Below, Custom Zone and Above for
+    /// custom zones
+    pub name: String,
+    /// Lower bound of the zone in beats per minute, inclusive
+    pub min: i32,
+    /// Upper bound of the zone in beats per minute, exclusive except for the
+    /// highest zone
+    pub max: i32,
+}
+
+impl HeartRateZoneBounds {
+    /// Returns true if the heart rate falls within the bounds
+    pub fn contains(&self, bpm: i32) -> bool {
+        self.min <= bpm && bpm < self.max
+    }
+}
+
+/// Boundaries of a set of heart rate zones, ordered from the lowest zone
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HeartRateZones {
+    /// Zones ordered by their lower bound
+    pub zones: Vec<HeartRateZoneBounds>,
+}
+
+impl HeartRateZones {
+    /// Collects the boundaries of the zones of a heart rate time series
+    pub fn from_zones(zones: &[HeartRateZone]) -> Self {
+        let mut zones: Vec<_> = zones
+            .iter()
+            .map(|zone| HeartRateZoneBounds {
+                name: zone.name.clone(),
+                min: zone.min,
+                max: zone.max,
+            })
+            .collect();
+        zones.sort_by_key(|zone| zone.min);
+        Self { zones }
+    }
+
+    /// Finds the zone a heart rate falls in
+    ///
+    /// The upper bound of a zone is the lower bound of the next one, so a
+    /// heart rate on a boundary belongs to the higher zone. The upper bound
+    /// of the highest zone is included. Returns `None` for heart rates
+    /// outside all zones.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fitbit_sdk::types::heart_rate::{HeartRateZoneBounds, HeartRateZones};
+    ///
+    /// let zone = |name: &str, min, max| HeartRateZoneBounds {
+    ///     name: name.to_string(),
+    ///     min,
+    ///     max,
+    /// };
+    /// let zones = HeartRateZones {
+    ///     zones: vec![
+    ///         zone("Below", 30, 120),
+    ///         zone("Custom Zone", 120, 150),
+    ///         zone("Above", 150, 220),
+    ///     ],
+    /// };
+    ///
+    /// assert_eq!(zones.zone_for(119).unwrap().name, "Below");
+    /// assert_eq!(zones.zone_for(120).unwrap().name, "Custom Zone");
+    /// assert_eq!(zones.zone_for(220).unwrap().name, "Above");
+    /// assert_eq!(zones.zone_for(25), None);
+    /// ```
+    pub fn zone_for(&self, bpm: i32) -> Option<&HeartRateZoneBounds> {
+        self.zones.iter().find(|zone| zone.contains(bpm)).or_else(|| {
+            self.zones.last().filter(|zone| zone.min <= bpm && bpm == zone.max)
+        })
+    }
+}
+
+/// Time and calories spent in a heart rate zone
+#[derive(Debug, Clone, PartialEq)]
+pub struct ZoneTime {
+    /// Name of the zone
+    pub name: String,
+    /// Minutes spent in the zone
+    pub minutes: f64,
+    /// Calories burned in the zone, only reported by the daily time series
+    pub calories_out: Option<f64>,
+}
+
+/// Zones the intraday heart rate samples are classified into
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ZoneSelection {
+    /// The default zones of each day (Out of Range, Fat Burn, Cardio, Peak)
+    Default,
+    /// The custom zones of each day, for users who set them
+    Custom,
+    /// The same zones for every day, e.g. from `get_custom_heart_rate_zones`
+    Fixed(HeartRateZones),
+}
+
+/// Time spent in each heart rate zone on a single day
+#[derive(Debug, Clone, PartialEq)]
+pub struct DailyZoneTime {
+    /// Date of the samples
+    pub date: Date,
+    /// Time in each zone, from the lowest zone
+    pub zones: Vec<ZoneTime>,
+    /// Minutes with a heart rate outside every zone
+    pub outside_minutes: f64,
+}
+
+/// Time spent in each heart rate zone over several days
+#[derive(Debug, Clone, PartialEq)]
+pub struct IntradayZoneTime {
+    /// Time in each zone per day, in date order
+    pub days: Vec<DailyZoneTime>,
+    /// Time in each zone over all days, in the order the zones first appear
+    pub totals: Vec<ZoneTime>,
+    /// Minutes with a heart rate outside every zone over all days
+    pub outside_minutes: f64,
+}
+
+/// Resting heart rate for a single day
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RestingHeartRate {
+    /// Date of the measurement
+    #[serde(with = "crate::types::datetime::api_field")]
+    pub date: ApiDate,
+    /// Resting heart rate in beats per minute
+    pub value: i32,
+    /// Fields of the daily heart rate data not modeled above
+    #[serde(flatten, deserialize_with = "crate::types::extra::deserialize")]
+    pub extra: Map<String, Value>,
+}
+
+/// Intraday heart rate for a single day
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IntradayHeartRateSeries {
+    /// Daily heart rate zones and resting heart rate
+    #[serde(rename = "activities-heart")]
+    pub summary: Vec<HeartRateTimeSeries>,
+    /// Heart rate in beats per minute at each timestamp
+    #[serde(rename = "activities-heart-intraday")]
+    pub intraday: IntradayDataset<i32>,
+    /// Fields of the response not modeled above
+    #[serde(flatten, deserialize_with = "crate::types::extra::deserialize")]
+    pub extra: Map<String, Value>,
+}
+
+/// Response wrapper for heart rate time series
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HeartRateTimeSeriesResponse {
+    #[serde(rename = "activities-heart")]
+    pub activities_heart: Vec<HeartRateTimeSeries>,
+    /// Fields of the response not modeled above
+    #[serde(flatten, deserialize_with = "crate::types::extra::deserialize")]
+    pub extra: Map<String, Value>,
+}
@@ -0,0 +1,127 @@
+//! Heart Rate Variability API Types
+//!
+//! This module contains the types and functions for the Fitbit Heart Rate Variability (HRV) API.
+//!
+use crate::types::auth::Scope;
+use crate::types::datetime::{ApiDate, ApiDateTime};
+use crate::types::error::{FitbitError, domain_error};
+use crate::types::user::UserId;
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use thiserror::Error;
+
+/// Error types for the HRV API
+#[derive(Debug, Error)]
+#[error(transparent)]
+pub struct HrvError(#[from] pub FitbitError);
+
+domain_error!(HrvError, scopes [Scope::Heartrate]);
+
+#[async_trait]
+pub trait HrvClient {
+    async fn get_hrv_summary<'a>(&'a self, user_id: impl Into<UserId> + Send, date: &'a str) -> Result<Vec<HrvSummary>, HrvError>;
+
+    async fn get_hrv_summary_range<'a>(
+        &'a self,
+        user_id: impl Into<UserId> + Send,
+        start_date: &'a str,
+        end_date: &'a str,
+    ) -> Result<Vec<HrvSummary>, HrvError>;
+
+    async fn get_hrv_intraday<'a>(&'a self, user_id: impl Into<UserId> + Send, date: &'a str) -> Result<Vec<HrvIntraday>, HrvError>;
+
+    async fn get_hrv_intraday_range<'a>(
+        &'a self,
+        user_id: impl Into<UserId> + Send,
+        start_date: &'a str,
+        end_date: &'a str,
+    ) -> Result<Vec<HrvIntraday>, HrvError>;
+}
+
+/// Daily HRV summary
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HrvSummary {
+    /// Date of the main sleep the HRV was measured during
+    #[serde(rename = "dateTime", with = "crate::types::datetime::api_field")]
+    pub date_time: ApiDate,
+    /// HRV values for the day
+    pub value: HrvSummaryValue,
+    /// Fields of the response not modeled above
+    #[serde(flatten, deserialize_with = "crate::types::extra::deserialize")]
+    pub extra: Map<String, Value>,
+}
+
+/// Daily HRV values
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HrvSummaryValue {
+    /// Root mean square of successive differences (ms) over the main sleep
+    #[serde(rename = "dailyRmssd")]
+    pub daily_rmssd: f64,
+    /// Root mean square of successive differences (ms) during deep sleep
+    #[serde(rename = "deepRmssd")]
+    pub deep_rmssd: f64,
+    /// Fields of the response not modeled above
+    #[serde(flatten, deserialize_with = "crate::types::extra::deserialize")]
+    pub extra: Map<String, Value>,
+}
+
+/// Intraday HRV for a single day
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HrvIntraday {
+    /// Date of the main sleep the HRV was measured during
+    #[serde(rename = "dateTime", with = "crate::types::datetime::api_field")]
+    pub date_time: ApiDate,
+    /// HRV measured over 5-minute intervals
+    pub minutes: Vec<HrvMinute>,
+    /// Fields of the response not modeled above
+    #[serde(flatten, deserialize_with = "crate::types::extra::deserialize")]
+    pub extra: Map<String, Value>,
+}
+
+/// HRV measured over a 5-minute interval
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HrvMinute {
+    /// Start of the interval
+    #[serde(with = "crate::types::datetime::api_field")]
+    pub minute: ApiDateTime,
+    /// HRV values for the interval
+    pub value: HrvMinuteValue,
+    /// Fields of the response not modeled above
+    #[serde(flatten, deserialize_with = "crate::types::extra::deserialize")]
+    pub extra: Map<String, Value>,
+}
+
+/// HRV values for a 5-minute interval
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HrvMinuteValue {
+    /// Root mean square of successive differences (ms)
+    pub rmssd: f64,
+    /// Percentage of data coverage in the interval (0.0 - 1.0)
+    pub coverage: f64,
+    /// Power in the high frequency band (0.15 - 0.4 Hz)
+    pub hf: f64,
+    /// Power in the low frequency band (0.04 - 0.15 Hz)
+    pub lf: f64,
+    /// Fields of the response not modeled above
+    #[serde(flatten, deserialize_with = "crate::types::extra::deserialize")]
+    pub extra: Map<String, Value>,
+}
+
+/// Response wrapper for HRV summaries
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HrvSummaryResponse {
+    pub hrv: Vec<HrvSummary>,
+    /// Fields of the response not modeled above
+    #[serde(flatten, deserialize_with = "crate::types::extra::deserialize")]
+    pub extra: Map<String, Value>,
+}
+
+/// Response wrapper for intraday HRV
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HrvIntradayResponse {
+    pub hrv: Vec<HrvIntraday>,
+    /// Fields of the response not modeled above
+    #[serde(flatten, deserialize_with = "crate::types::extra::deserialize")]
+    pub extra: Map<String, Value>,
+}
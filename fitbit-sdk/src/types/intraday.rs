@@ -0,0 +1,195 @@
+//! Intraday Types
+//!
+//! This module contains the types shared by the Fitbit intraday time series endpoints.
+//!
+use crate::types::datetime::ApiTime;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+/// Granularity of intraday data points
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DetailLevel {
+    /// One data point per second (heart rate only)
+    OneSecond,
+    /// One data point per minute
+    OneMinute,
+    /// One data point per five minutes
+    FiveMinutes,
+    /// One data point per fifteen minutes
+    FifteenMinutes,
+}
+
+impl DetailLevel {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DetailLevel::OneSecond => "1sec",
+            DetailLevel::OneMinute => "1min",
+            DetailLevel::FiveMinutes => "5min",
+            DetailLevel::FifteenMinutes => "15min",
+        }
+    }
+}
+
+/// Intraday data set returned alongside the daily summary
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IntradayDataset<T> {
+    /// Data points in chronological order
+    pub dataset: Vec<IntradayDataPoint<T>>,
+    /// Interval between data points, in units of `dataset_type`
+    #[serde(rename = "datasetInterval")]
+    pub dataset_interval: i32,
+    /// Unit of the interval (e.g. "second", "minute")
+    #[serde(rename = "datasetType")]
+    pub dataset_type: String,
+    /// Fields of the response not modeled above
+    #[serde(flatten, deserialize_with = "crate::types::extra::deserialize")]
+    pub extra: Map<String, Value>,
+}
+
+/// Single intraday data point
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IntradayDataPoint<T> {
+    /// Time of day in format HH:mm:ss
+    #[serde(with = "crate::types::datetime::api_field")]
+    pub time: ApiTime,
+    /// Value measured at this time
+    pub value: T,
+    /// Fields of the response not modeled above
+    #[serde(flatten, deserialize_with = "crate::types::extra::deserialize")]
+    pub extra: Map<String, Value>,
+}
+
+/// Builds the path suffix for an intraday request
+///
+/// Produces `1d/{detail-level}.json` or, when a time window is given,
+/// `1d/{detail-level}/time/{start-time}/{end-time}.json`.
+#[cfg_attr(not(feature = "full"), allow(dead_code))]
+pub(crate) fn intraday_path_suffix(
+    detail_level: DetailLevel,
+    time_window: Option<(&str, &str)>,
+) -> String {
+    match time_window {
+        Some((start_time, end_time)) => format!(
+            "1d/{}/time/{}/{}.json",
+            detail_level.as_str(),
+            start_time,
+            end_time
+        ),
+        None => format!("1d/{}.json", detail_level.as_str()),
+    }
+}
+
+/// Position of `DatasetParser` in the response body
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+enum DatasetState {
+    /// Looking for the `dataset` key
+    #[default]
+    Key,
+    /// After the `dataset` key, expecting a colon
+    Colon,
+    /// After the colon, expecting the array of data points
+    Array,
+    /// Inside the array of data points
+    Points,
+    /// After the array of data points
+    Done,
+}
+
+/// Incremental parser extracting the data points of an intraday dataset
+///
+/// The response body is fed chunk by chunk, and the JSON of each data point
+/// of the first `dataset` array is returned as soon as it is complete.
+/// Only the data point being read is kept in memory, so a full day of
+/// 1-second data points is parsed without buffering the body.
+#[derive(Debug, Default)]
+pub(crate) struct DatasetParser {
+    state: DatasetState,
+    /// Whether the current byte is inside a JSON string
+    in_string: bool,
+    /// Whether the previous byte was a backslash inside a string
+    escaped: bool,
+    /// String being read while looking for the `dataset` key
+    key: Vec<u8>,
+    /// Nesting depth inside the current data point
+    depth: usize,
+    /// JSON of the data point being read
+    point: Vec<u8>,
+}
+
+impl DatasetParser {
+    /// Feeds the next chunk of the body, returning the data points it completes
+    pub(crate) fn feed(&mut self, chunk: &[u8]) -> Result<Vec<Vec<u8>>, String> {
+        let mut points = Vec::new();
+        for &byte in chunk {
+            if self.in_string {
+                if self.state == DatasetState::Points {
+                    self.point.push(byte);
+                }
+                match byte {
+                    _ if self.escaped => self.escaped = false,
+                    b'\\' => self.escaped = true,
+                    b'"' => {
+                        self.in_string = false;
+                        if self.state == DatasetState::Key && self.key == b"dataset" {
+                            self.state = DatasetState::Colon;
+                        }
+                    }
+                    _ if self.state == DatasetState::Key => self.key.push(byte),
+                    _ => {}
+                }
+                continue;
+            }
+            if byte.is_ascii_whitespace() && self.depth == 0 {
+                continue;
+            }
+            match self.state {
+                DatasetState::Colon if byte == b':' => self.state = DatasetState::Array,
+                DatasetState::Array if byte == b'[' => self.state = DatasetState::Points,
+                DatasetState::Key | DatasetState::Colon | DatasetState::Array => {
+                    // `dataset` was a value or another field, keep looking
+                    self.state = DatasetState::Key;
+                    if byte == b'"' {
+                        self.in_string = true;
+                        self.key.clear();
+                    }
+                }
+                DatasetState::Points => {
+                    match byte {
+                        b',' if self.depth == 0 => continue,
+                        b']' if self.depth == 0 => {
+                            self.state = DatasetState::Done;
+                            continue;
+                        }
+                        b'{' | b'[' => self.depth += 1,
+                        b'}' | b']' if self.depth > 0 => self.depth -= 1,
+                        b'"' if self.depth > 0 => self.in_string = true,
+                        _ if self.depth == 0 => {
+                            return Err(format!(
+                                "Unexpected '{}' in the intraday dataset",
+                                byte.escape_ascii()
+                            ));
+                        }
+                        _ => {}
+                    }
+                    self.point.push(byte);
+                    if self.depth == 0 {
+                        points.push(std::mem::take(&mut self.point));
+                    }
+                }
+                DatasetState::Done => break,
+            }
+        }
+        Ok(points)
+    }
+
+    /// Checks that the body contained a complete dataset once it has ended
+    pub(crate) fn finish(&self) -> Result<(), String> {
+        match self.state {
+            DatasetState::Done => Ok(()),
+            DatasetState::Points => {
+                Err("Response body ended inside the intraday dataset".to_string())
+            }
+            _ => Err("Response body has no intraday dataset".to_string()),
+        }
+    }
+}
@@ -0,0 +1,156 @@
+//! Locale Types
+//!
+//! This module contains the types selecting the locale and unit system of
+//! Fitbit API responses.
+//!
+
+use std::str::FromStr;
+
+/// Locale of the food database and localized texts
+///
+/// Sent as the `Accept-Locale` header.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Locale {
+    /// Australia
+    EnAu,
+    /// France
+    FrFr,
+    /// Germany
+    DeDe,
+    /// Japan
+    JaJp,
+    /// New Zealand
+    EnNz,
+    /// Spain
+    EsEs,
+    /// United Kingdom
+    EnGb,
+    /// United States
+    EnUs,
+}
+
+impl Locale {
+    /// Name of the header carrying the locale
+    pub const HEADER: &'static str = "Accept-Locale";
+
+    /// Every locale supported by the Fitbit API
+    pub const ALL: [Locale; 8] = [
+        Locale::EnAu,
+        Locale::FrFr,
+        Locale::DeDe,
+        Locale::JaJp,
+        Locale::EnNz,
+        Locale::EsEs,
+        Locale::EnGb,
+        Locale::EnUs,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Locale::EnAu => "en_AU",
+            Locale::FrFr => "fr_FR",
+            Locale::DeDe => "de_DE",
+            Locale::JaJp => "ja_JP",
+            Locale::EnNz => "en_NZ",
+            Locale::EsEs => "es_ES",
+            Locale::EnGb => "en_GB",
+            Locale::EnUs => "en_US",
+        }
+    }
+}
+
+impl FromStr for Locale {
+    type Err = String;
+
+    /// Parses a locale such as `en_US`, also accepting `en-US`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fitbit_sdk::types::locale::Locale;
+    ///
+    /// assert_eq!("ja_JP".parse::<Locale>(), Ok(Locale::JaJp));
+    /// assert_eq!("en-GB".parse::<Locale>(), Ok(Locale::EnGb));
+    /// assert!("xx_XX".parse::<Locale>().is_err());
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let normalized = s.replace('-', "_");
+        Locale::ALL
+            .into_iter()
+            .find(|locale| locale.as_str().eq_ignore_ascii_case(&normalized))
+            .ok_or_else(|| format!("Unknown locale: {}", s))
+    }
+}
+
+/// Unit system of measurements in requests and responses
+///
+/// Sent as the `Accept-Language` header. Without it, the Fitbit API uses
+/// metric units.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UnitSystem {
+    /// Metric units (kilograms, kilometers, milliliters)
+    Metric,
+    /// United States units (pounds, miles, fluid ounces)
+    Us,
+    /// United Kingdom units (stones, miles, milliliters)
+    Uk,
+}
+
+impl UnitSystem {
+    /// Name of the header carrying the unit system
+    pub const HEADER: &'static str = "Accept-Language";
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            UnitSystem::Metric => "metric",
+            UnitSystem::Us => "en_US",
+            UnitSystem::Uk => "en_GB",
+        }
+    }
+
+    /// Returns the unit system of a response from its `Content-Language` header
+    ///
+    /// Responses without the header are in metric units.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fitbit_sdk::types::locale::UnitSystem;
+    ///
+    /// assert_eq!(UnitSystem::from_content_language(Some("en_US")), UnitSystem::Us);
+    /// assert_eq!(UnitSystem::from_content_language(Some("en_GB")), UnitSystem::Uk);
+    /// assert_eq!(UnitSystem::from_content_language(None), UnitSystem::Metric);
+    /// ```
+    pub fn from_content_language(content_language: Option<&str>) -> Self {
+        match content_language {
+            Some("en_US") => UnitSystem::Us,
+            Some("en_GB") => UnitSystem::Uk,
+            _ => UnitSystem::Metric,
+        }
+    }
+}
+
+impl FromStr for UnitSystem {
+    type Err = String;
+
+    /// Parses a unit system from its name (`metric`, `us` or `uk`) or from
+    /// the locale the API associates with it (`en_US` or `en_GB`)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fitbit_sdk::types::locale::UnitSystem;
+    ///
+    /// assert_eq!("metric".parse::<UnitSystem>(), Ok(UnitSystem::Metric));
+    /// assert_eq!("US".parse::<UnitSystem>(), Ok(UnitSystem::Us));
+    /// assert_eq!("en_GB".parse::<UnitSystem>(), Ok(UnitSystem::Uk));
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().replace('-', "_").as_str() {
+            "metric" => Ok(UnitSystem::Metric),
+            "us" | "en_us" => Ok(UnitSystem::Us),
+            "uk" | "en_gb" => Ok(UnitSystem::Uk),
+            _ => Err(format!("Unknown unit system: {}", s)),
+        }
+    }
+}
@@ -0,0 +1,123 @@
+//! Metrics Types
+//!
+//! This module contains the types for observing the requests `FitbitClient`
+//! sends, e.g. to export request counts, latencies and errors.
+//!
+use crate::client::ApiVersion;
+use reqwest::{Method, StatusCode};
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Outcome of a request sent to the Fitbit API
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RequestMetrics {
+    /// HTTP method of the request
+    pub method: Method,
+    /// API version the request was sent to
+    pub api_version: ApiVersion,
+    /// Path of the request after the API version, with user IDs, dates, times
+    /// and numeric IDs replaced by placeholders (e.g.
+    /// `/user/{user}/activities/date/{date}.json`)
+    ///
+    /// Unlike the path, the endpoint can be used as a metrics label: the
+    /// number of its values stays bounded and it holds no user IDs.
+    pub endpoint: String,
+    /// HTTP status of the response, or `None` if no response was received
+    pub status: Option<StatusCode>,
+    /// Time from sending the request until the response body was received
+    pub duration: Duration,
+}
+
+impl RequestMetrics {
+    /// Returns true if a response with a 2xx status was received
+    pub fn is_success(&self) -> bool {
+        self.status.is_some_and(|status| status.is_success())
+    }
+}
+
+/// Returns the endpoint of a request path, without its query string
+pub(crate) fn endpoint_template(path: &str) -> String {
+    let path = path.split_once('?').map_or(path, |(path, _)| path);
+    let mut endpoint: Vec<String> = Vec::new();
+    for segment in path.split('/') {
+        let (stem, extension) = match segment.rsplit_once('.') {
+            Some((stem, extension)) => (stem, Some(extension)),
+            None => (segment, None),
+        };
+        let placeholder = if endpoint.last().is_some_and(|previous| previous == "user") {
+            (stem != "-").then_some("{user}")
+        } else if is_date(stem) {
+            Some("{date}")
+        } else if is_time(stem) {
+            Some("{time}")
+        } else if !stem.is_empty() && stem.bytes().all(|b| b.is_ascii_digit()) {
+            Some("{id}")
+        } else {
+            None
+        };
+        endpoint.push(match (placeholder, extension) {
+            (Some(placeholder), Some(extension)) => format!("{}.{}", placeholder, extension),
+            (Some(placeholder), None) => placeholder.to_string(),
+            (None, _) => segment.to_string(),
+        });
+    }
+    endpoint.join("/")
+}
+
+/// Returns true if `segment` is a date in the `yyyy-MM-dd` format
+fn is_date(segment: &str) -> bool {
+    segment.len() == 10
+        && segment.char_indices().all(|(i, c)| match i {
+            4 | 7 => c == '-',
+            _ => c.is_ascii_digit(),
+        })
+}
+
+/// Returns true if `segment` is a time in the `HH:mm` format
+fn is_time(segment: &str) -> bool {
+    segment.len() == 5
+        && segment.char_indices().all(|(i, c)| match i {
+            2 => c == ':',
+            _ => c.is_ascii_digit(),
+        })
+}
+
+/// Observes every request `FitbitClient` sends
+///
+/// `record` is called once per HTTP request, after the response body has been
+/// received or the request has failed, including for the retry after a token
+/// refresh. Responses served from a cache are not recorded. Recording should
+/// be quick, as it delays returning the response.
+///
+/// # Examples
+///
+/// ```no_run
+/// use fitbit_sdk::client::FitbitClient;
+/// use fitbit_sdk::types::metrics::{MetricsRecorder, RequestMetrics};
+/// use fitbit_sdk::types::user::UserError;
+///
+/// struct LogRecorder;
+///
+/// impl MetricsRecorder for LogRecorder {
+///     fn record(&self, metrics: &RequestMetrics) {
+///         println!("{} {} {:?} in {:?}", metrics.method, metrics.endpoint, metrics.status, metrics.duration);
+///     }
+/// }
+///
+/// # fn example() -> Result<(), UserError> {
+/// let client = FitbitClient::builder()
+///     .with_metrics_recorder(LogRecorder)
+///     .build()?;
+/// # Ok(())
+/// # }
+/// ```
+pub trait MetricsRecorder: Send + Sync {
+    /// Records the outcome of a request
+    fn record(&self, metrics: &RequestMetrics);
+}
+
+impl<T: MetricsRecorder + ?Sized> MetricsRecorder for Arc<T> {
+    fn record(&self, metrics: &RequestMetrics) {
+        (**self).record(metrics)
+    }
+}
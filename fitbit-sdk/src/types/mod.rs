@@ -1,5 +1,49 @@
 pub mod user;
+#[cfg(feature = "activity")]
 pub mod activity;
+#[cfg(feature = "sleep")]
 pub mod sleep;
+#[cfg(feature = "body")]
 pub mod body;
+#[cfg(feature = "nutrition")]
 pub mod nutrition;
+#[cfg(feature = "heart-rate")]
+pub mod heart_rate;
+#[cfg(feature = "hrv")]
+pub mod hrv;
+#[cfg(feature = "breathing-rate")]
+pub mod breathing_rate;
+#[cfg(feature = "temperature")]
+pub mod temperature;
+#[cfg(feature = "cardio-fitness")]
+pub mod cardio_fitness;
+#[cfg(feature = "azm")]
+pub mod azm;
+#[cfg(feature = "subscriptions")]
+pub mod subscriptions;
+#[cfg(feature = "subscriptions")]
+pub mod webhook;
+pub mod error;
+pub mod auth;
+pub mod rate_limit;
+pub mod response;
+pub mod locale;
+pub mod profile;
+pub mod request;
+pub mod transport;
+pub mod cache;
+pub mod metrics;
+pub mod intraday;
+pub mod pagination;
+pub mod date;
+pub mod datetime;
+pub(crate) mod extra;
+#[cfg(feature = "chrono")]
+pub mod chrono;
+pub mod time_series;
+#[cfg(feature = "tcx")]
+pub mod tcx;
+#[cfg(feature = "sync")]
+pub mod sync;
+#[cfg(feature = "wellness")]
+pub mod wellness;
@@ -2,70 +2,114 @@
 //!
 //! This module contains the types and functions for the Fitbit Nutrition API.
 //!
+use crate::types::auth::Scope;
+use crate::types::date::FitbitDate;
+use crate::types::datetime::ApiDate;
+use crate::types::error::{FitbitError, domain_error};
+use crate::types::time_series::TimeSeriesPoint;
+use crate::types::user::UserId;
 use async_trait::async_trait;
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Serialize, Serializer};
+use serde_json::{Map, Value};
 use thiserror::Error;
 
 /// Error types for the Nutrition API
 #[derive(Debug, Error)]
-pub enum NutritionError {
-    #[error("API request failed: {0}")]
-    RequestFailed(String),
-    #[error("API error: {0}")]
-    ApiError(String),
-}
+#[error(transparent)]
+pub struct NutritionError(#[from] pub FitbitError);
 
-impl From<String> for NutritionError {
-    fn from(error: String) -> Self {
-        NutritionError::ApiError(error)
-    }
-}
+domain_error!(NutritionError, scopes [Scope::Nutrition]);
 
 #[async_trait]
 pub trait NutritionClient {
-    async fn get_water_logs<'a>(&'a self, user_id: &'a str, date: &'a str) -> Result<WaterLog, NutritionError>;
-    async fn get_food_logs<'a>(&'a self, user_id: &'a str, date: &'a str) -> Result<FoodLog, NutritionError>;
+    async fn get_water_logs<'a>(&'a self, user_id: impl Into<UserId> + Send, date: impl Into<FitbitDate> + Send) -> Result<WaterLog, NutritionError>;
+    async fn get_food_logs<'a>(&'a self, user_id: impl Into<UserId> + Send, date: impl Into<FitbitDate> + Send) -> Result<FoodLog, NutritionError>;
+    async fn log_water<'a>(&'a self, user_id: impl Into<UserId> + Send, params: &'a LogWaterParams) -> Result<WaterEntry, NutritionError>;
+    async fn delete_water_log<'a>(&'a self, user_id: impl Into<UserId> + Send, log_id: i64) -> Result<(), NutritionError>;
+    async fn get_water_goal<'a>(&'a self, user_id: impl Into<UserId> + Send) -> Result<WaterGoal, NutritionError>;
+    async fn update_water_goal<'a>(&'a self, user_id: impl Into<UserId> + Send, target: f64) -> Result<WaterGoal, NutritionError>;
+    async fn log_food<'a>(&'a self, user_id: impl Into<UserId> + Send, params: &'a LogFoodParams) -> Result<FoodEntry, NutritionError>;
+    async fn edit_food_log<'a>(
+        &'a self,
+        user_id: impl Into<UserId> + Send,
+        log_id: i64,
+        params: &'a EditFoodLogParams,
+    ) -> Result<FoodEntry, NutritionError>;
+    async fn delete_food_log<'a>(&'a self, user_id: impl Into<UserId> + Send, log_id: i64) -> Result<(), NutritionError>;
+    async fn get_nutrition_time_series<'a>(
+        &'a self,
+        user_id: impl Into<UserId> + Send,
+        resource: NutritionResource,
+        date: impl Into<FitbitDate> + Send,
+        period: &'a str,
+    ) -> Result<Vec<NutritionTimeSeries>, NutritionError>;
 }
 
 /// Water log information
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct WaterLog {
     /// Water consumption summary
     pub summary: WaterSummary,
     /// Individual water log entries
     pub water: Vec<WaterEntry>,
+    /// Fields of the response not modeled above
+    #[serde(flatten, deserialize_with = "crate::types::extra::deserialize")]
+    pub extra: Map<String, Value>,
 }
 
 /// Water consumption summary
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct WaterSummary {
     /// Total water consumed in milliliters
     pub water: f64,
+    /// Fields of the response not modeled above
+    #[serde(flatten, deserialize_with = "crate::types::extra::deserialize")]
+    pub extra: Map<String, Value>,
 }
 
 /// Individual water log entry
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct WaterEntry {
     /// Log ID
     #[serde(rename = "logId")]
     pub log_id: i64,
     /// Amount of water in milliliters
     pub amount: f64,
-    /// Time the water was logged
-    pub time: String,
+    /// Time the water was logged, which the API usually does not report
+    #[serde(default)]
+    pub time: Option<String>,
+    /// Fields of the response not modeled above
+    #[serde(flatten, deserialize_with = "crate::types::extra::deserialize")]
+    pub extra: Map<String, Value>,
 }
 
 /// Food log information
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct FoodLog {
     /// Food consumption summary
     pub summary: FoodSummary,
     /// Individual food log entries
     pub foods: Vec<FoodEntry>,
+    /// Daily food goals, if the user set any
+    #[serde(default)]
+    pub goals: Option<FoodGoals>,
+    /// Fields of the response not modeled above
+    #[serde(flatten, deserialize_with = "crate::types::extra::deserialize")]
+    pub extra: Map<String, Value>,
+}
+
+/// Daily food goals
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FoodGoals {
+    /// Target calories consumed per day
+    pub calories: Option<i32>,
+    /// Fields of the response not modeled above
+    #[serde(flatten, deserialize_with = "crate::types::extra::deserialize")]
+    pub extra: Map<String, Value>,
 }
 
 /// Food consumption summary
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct FoodSummary {
     /// Total calories consumed
     pub calories: i32,
@@ -81,10 +125,13 @@ pub struct FoodSummary {
     pub sodium: f64,
     /// Total water in milliliters
     pub water: f64,
+    /// Fields of the response not modeled above
+    #[serde(flatten, deserialize_with = "crate::types::extra::deserialize")]
+    pub extra: Map<String, Value>,
 }
 
 /// Individual food log entry
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct FoodEntry {
     /// Log ID
     #[serde(rename = "logId")]
@@ -95,10 +142,19 @@ pub struct FoodEntry {
     /// Nutritional values
     #[serde(rename = "nutritionalValues")]
     pub nutritional_values: NutritionalValues,
+    /// Date the food was logged for
+    #[serde(rename = "logDate", default, with = "crate::types::datetime::option_api_field")]
+    pub log_date: Option<ApiDate>,
+    /// Whether the food is one of the user's favorites
+    #[serde(rename = "isFavorite", default)]
+    pub is_favorite: Option<bool>,
+    /// Fields of the response not modeled above
+    #[serde(flatten, deserialize_with = "crate::types::extra::deserialize")]
+    pub extra: Map<String, Value>,
 }
 
 /// Logged food information
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct LoggedFood {
     /// Meal the food was logged to
     #[serde(rename = "mealTypeId")]
@@ -109,10 +165,38 @@ pub struct LoggedFood {
     pub amount: f64,
     /// Unit of measurement for the amount
     pub unit: Unit,
+    /// ID of the food in the food database
+    #[serde(rename = "foodId", default)]
+    pub food_id: Option<i64>,
+    /// Brand of the food, empty for generic foods
+    #[serde(default)]
+    pub brand: Option<String>,
+    /// Calories of the logged amount
+    #[serde(default)]
+    pub calories: Option<i32>,
+    /// Who can see the food (PUBLIC or PRIVATE)
+    #[serde(rename = "accessLevel", default)]
+    pub access_level: Option<String>,
+    /// Locale of the food database the food is from, e.g. en_US
+    #[serde(default)]
+    pub locale: Option<String>,
+    /// IDs of the units the food can be logged in
+    #[serde(default)]
+    pub units: Vec<i32>,
+    /// Fields of the response not modeled above
+    #[serde(flatten, deserialize_with = "crate::types::extra::deserialize")]
+    pub extra: Map<String, Value>,
+}
+
+impl LoggedFood {
+    /// Meal the food was logged to, if the ID is known
+    pub fn meal_type(&self) -> Option<MealType> {
+        MealType::from_id(self.meal_type_id)
+    }
 }
 
 /// Unit of measurement for food
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Unit {
     /// ID of the unit
     pub id: i32,
@@ -120,10 +204,13 @@ pub struct Unit {
     pub name: String,
     /// Plural name of the unit
     pub plural: String,
+    /// Fields of the response not modeled above
+    #[serde(flatten, deserialize_with = "crate::types::extra::deserialize")]
+    pub extra: Map<String, Value>,
 }
 
 /// Nutritional values for a food item
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct NutritionalValues {
     /// Calories
     pub calories: i32,
@@ -137,18 +224,311 @@ pub struct NutritionalValues {
     pub protein: f64,
     /// Sodium in milligrams
     pub sodium: f64,
+    /// Fields of the response not modeled above
+    #[serde(flatten, deserialize_with = "crate::types::extra::deserialize")]
+    pub extra: Map<String, Value>,
 }
 
 /// Response wrapper for water logs
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct WaterLogResponse {
     #[serde(flatten)]
     pub water_log: WaterLog,
 }
 
 /// Response wrapper for food logs
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct FoodLogResponse {
     #[serde(flatten)]
     pub food_log: FoodLog,
 }
+
+/// Unit of a water amount
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum WaterUnit {
+    #[serde(rename = "ml")]
+    Milliliter,
+    #[serde(rename = "fl oz")]
+    FluidOunce,
+    #[serde(rename = "cup")]
+    Cup,
+}
+
+/// Parameters for logging water intake
+#[derive(Debug, Serialize)]
+pub struct LogWaterParams {
+    /// Amount of water, in the user's unit unless `unit` is set
+    pub amount: f64,
+    /// Date of the intake in format YYYY-MM-DD
+    pub date: String,
+    /// Unit of the amount
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unit: Option<WaterUnit>,
+}
+
+impl LogWaterParams {
+    /// Create new LogWaterParams
+    pub fn new(amount: f64, date: impl Into<String>) -> Self {
+        Self {
+            amount,
+            date: date.into(),
+            unit: None,
+        }
+    }
+
+    /// Set the unit of the amount
+    pub fn with_unit(mut self, unit: WaterUnit) -> Self {
+        self.unit = Some(unit);
+        self
+    }
+}
+
+/// Response wrapper for logged water intake
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LogWaterResponse {
+    #[serde(rename = "waterLog")]
+    pub water_log: WaterEntry,
+    /// Fields of the response not modeled above
+    #[serde(flatten, deserialize_with = "crate::types::extra::deserialize")]
+    pub extra: Map<String, Value>,
+}
+
+/// User's daily water goal
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WaterGoal {
+    /// Daily water goal in the user's unit
+    pub goal: f64,
+    /// Date the goal took effect
+    #[serde(rename = "startDate", with = "crate::types::datetime::api_field")]
+    pub start_date: ApiDate,
+    /// Fields of the response not modeled above
+    #[serde(flatten, deserialize_with = "crate::types::extra::deserialize")]
+    pub extra: Map<String, Value>,
+}
+
+/// Parameters for updating the water goal
+#[derive(Debug, Serialize)]
+pub(crate) struct UpdateWaterGoalParams {
+    pub(crate) target: f64,
+}
+
+/// Response wrapper for the water goal
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WaterGoalResponse {
+    pub goal: WaterGoal,
+    /// Fields of the response not modeled above
+    #[serde(flatten, deserialize_with = "crate::types::extra::deserialize")]
+    pub extra: Map<String, Value>,
+}
+
+/// Meal a food log entry belongs to
+///
+/// # Examples
+///
+/// ```
+/// use fitbit_sdk::types::nutrition::MealType;
+///
+/// assert_eq!(MealType::Lunch.id(), 3);
+/// assert_eq!(MealType::from_id(7), Some(MealType::Anytime));
+/// assert_eq!(MealType::from_id(6), None);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MealType {
+    Breakfast,
+    MorningSnack,
+    Lunch,
+    AfternoonSnack,
+    Dinner,
+    Anytime,
+}
+
+impl MealType {
+    /// Returns the meal type ID used by the Fitbit API
+    pub fn id(&self) -> i32 {
+        match self {
+            MealType::Breakfast => 1,
+            MealType::MorningSnack => 2,
+            MealType::Lunch => 3,
+            MealType::AfternoonSnack => 4,
+            MealType::Dinner => 5,
+            MealType::Anytime => 7,
+        }
+    }
+
+    /// Returns the meal type for a Fitbit API meal type ID
+    pub fn from_id(id: i32) -> Option<Self> {
+        match id {
+            1 => Some(MealType::Breakfast),
+            2 => Some(MealType::MorningSnack),
+            3 => Some(MealType::Lunch),
+            4 => Some(MealType::AfternoonSnack),
+            5 => Some(MealType::Dinner),
+            7 => Some(MealType::Anytime),
+            _ => None,
+        }
+    }
+}
+
+impl Serialize for MealType {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_i32(self.id())
+    }
+}
+
+/// Parameters for logging a food
+///
+/// The amount is measured in the unit identified by `unit_id`, one of the
+/// units from the Fitbit food units list (e.g. 147 for grams, 304 for servings).
+#[derive(Debug, Serialize)]
+pub struct LogFoodParams {
+    /// ID of the food from the food database
+    #[serde(rename = "foodId", skip_serializing_if = "Option::is_none")]
+    pub food_id: Option<i64>,
+    /// Name of a custom food
+    #[serde(rename = "foodName", skip_serializing_if = "Option::is_none")]
+    pub food_name: Option<String>,
+    /// Calories of a custom food
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub calories: Option<i32>,
+    /// Brand name of a custom food
+    #[serde(rename = "brandName", skip_serializing_if = "Option::is_none")]
+    pub brand_name: Option<String>,
+    /// Meal the food is logged to
+    #[serde(rename = "mealTypeId")]
+    pub meal_type: MealType,
+    /// ID of the unit of the amount
+    #[serde(rename = "unitId")]
+    pub unit_id: i32,
+    /// Amount of the food in the unit
+    pub amount: f64,
+    /// Date of the entry in format YYYY-MM-DD
+    pub date: String,
+    /// Whether to add the food to the user's favorites
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub favorite: Option<bool>,
+}
+
+impl LogFoodParams {
+    /// Create parameters for logging a food from the food database
+    pub fn for_food(
+        food_id: i64,
+        meal_type: MealType,
+        unit_id: i32,
+        amount: f64,
+        date: impl Into<String>,
+    ) -> Self {
+        Self {
+            food_id: Some(food_id),
+            food_name: None,
+            calories: None,
+            brand_name: None,
+            meal_type,
+            unit_id,
+            amount,
+            date: date.into(),
+            favorite: None,
+        }
+    }
+
+    /// Create parameters for logging a custom food
+    pub fn custom(
+        food_name: impl Into<String>,
+        calories: i32,
+        meal_type: MealType,
+        unit_id: i32,
+        amount: f64,
+        date: impl Into<String>,
+    ) -> Self {
+        Self {
+            food_id: None,
+            food_name: Some(food_name.into()),
+            calories: Some(calories),
+            brand_name: None,
+            meal_type,
+            unit_id,
+            amount,
+            date: date.into(),
+            favorite: None,
+        }
+    }
+
+    /// Set the brand name of a custom food
+    pub fn with_brand_name(mut self, brand_name: impl Into<String>) -> Self {
+        self.brand_name = Some(brand_name.into());
+        self
+    }
+
+    /// Set whether to add the food to the user's favorites
+    pub fn with_favorite(mut self, favorite: bool) -> Self {
+        self.favorite = Some(favorite);
+        self
+    }
+}
+
+/// Parameters for editing a food log entry
+#[derive(Debug, Serialize)]
+pub struct EditFoodLogParams {
+    /// Meal the food is logged to
+    #[serde(rename = "mealTypeId")]
+    pub meal_type: MealType,
+    /// ID of the unit of the amount
+    #[serde(rename = "unitId")]
+    pub unit_id: i32,
+    /// Amount of the food in the unit
+    pub amount: f64,
+    /// Calories, only for entries of custom foods
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub calories: Option<i32>,
+}
+
+impl EditFoodLogParams {
+    /// Create new EditFoodLogParams
+    pub fn new(meal_type: MealType, unit_id: i32, amount: f64) -> Self {
+        Self {
+            meal_type,
+            unit_id,
+            amount,
+            calories: None,
+        }
+    }
+
+    /// Set the calories of an entry for a custom food
+    pub fn with_calories(mut self, calories: i32) -> Self {
+        self.calories = Some(calories);
+        self
+    }
+}
+
+/// Response wrapper for a created or edited food log entry
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LogFoodResponse {
+    #[serde(rename = "foodLog")]
+    pub food_log: FoodEntry,
+    /// Fields of the response not modeled above
+    #[serde(flatten, deserialize_with = "crate::types::extra::deserialize")]
+    pub extra: Map<String, Value>,
+}
+
+/// Nutrition resource types for time series
+#[derive(Debug, Clone, Copy)]
+pub enum NutritionResource {
+    CaloriesIn,
+    Water,
+}
+
+impl NutritionResource {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            NutritionResource::CaloriesIn => "caloriesIn",
+            NutritionResource::Water => "water",
+        }
+    }
+
+    /// Returns the key holding the time series in the API response
+    pub fn response_key(&self) -> String {
+        format!("foods-log-{}", self.as_str())
+    }
+}
+
+/// Nutrition time series data point (calories, or water in the user's unit)
+pub type NutritionTimeSeries = TimeSeriesPoint<f64>;
@@ -0,0 +1,209 @@
+//! Pagination Types
+//!
+//! This module contains the types shared by the Fitbit list endpoints.
+//!
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+/// Sort order of a list endpoint
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SortOrder {
+    /// Oldest entries first, used with `afterDate`
+    Asc,
+    /// Newest entries first, used with `beforeDate`
+    Desc,
+}
+
+impl SortOrder {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SortOrder::Asc => "asc",
+            SortOrder::Desc => "desc",
+        }
+    }
+}
+
+/// Pagination information returned by a list endpoint
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Pagination {
+    /// Entries are after this date, when listing forward
+    #[serde(rename = "afterDate")]
+    pub after_date: Option<String>,
+    /// Entries are before this date, when listing backward
+    #[serde(rename = "beforeDate")]
+    pub before_date: Option<String>,
+    /// Maximum number of entries in a page
+    pub limit: u32,
+    /// URL of the next page, or an empty string on the last page
+    #[serde(default)]
+    pub next: String,
+    /// Offset of the page
+    pub offset: u32,
+    /// URL of the previous page, or an empty string on the first page
+    #[serde(default)]
+    pub previous: String,
+    /// Sort order of the entries
+    pub sort: SortOrder,
+    /// Fields of the response not modeled above
+    #[serde(flatten, deserialize_with = "crate::types::extra::deserialize")]
+    pub extra: Map<String, Value>,
+}
+
+impl Pagination {
+    /// Number of pages `FitbitClient::paginate` fetches by default before giving up
+    pub const DEFAULT_MAX_PAGES: usize = 1000;
+
+    /// Query parameters of the next page, or `None` on the last page
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fitbit_sdk::types::pagination::Pagination;
+    ///
+    /// let pagination: Pagination = serde_json::from_str(r#"{
+    ///     "afterDate": "2025-01-01",
+    ///     "limit": 10,
+    ///     "next": "https://api.fitbit.com/1.2/user/-/sleep/list.json?offset=10&limit=10&sort=asc&afterDate=2025-01-01",
+    ///     "offset": 0,
+    ///     "previous": "",
+    ///     "sort": "asc"
+    /// }"#).unwrap();
+    ///
+    /// let next = pagination.next_params().unwrap();
+    /// assert!(next.contains(&("offset".to_string(), "10".to_string())));
+    /// assert!(next.contains(&("afterDate".to_string(), "2025-01-01".to_string())));
+    /// ```
+    pub fn next_params(&self) -> Option<Vec<(String, String)>> {
+        let url = reqwest::Url::parse(&self.next).ok()?;
+        Some(url.query_pairs().into_owned().collect())
+    }
+}
+
+/// Query parameters of a list endpoint
+///
+/// Entries are listed either before a date (newest first) or after a date
+/// (oldest first). The list endpoints reject any other combination, so the
+/// parameters are checked with `validate` before each request.
+///
+/// # Examples
+///
+/// ```
+/// use fitbit_sdk::types::pagination::{ListParams, SortOrder};
+///
+/// let params = ListParams::before("2025-01-16").with_limit(10);
+/// assert_eq!(params.sort, SortOrder::Desc);
+/// assert!(params.validate().is_ok());
+///
+/// assert!(ListParams::after("2025-01-01").with_limit(101).validate().is_err());
+///
+/// let mut both = ListParams::after("2025-01-01");
+/// both.before_date = Some("2025-01-16".to_string());
+/// assert!(both.validate().is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ListParams {
+    /// List entries before this date, in format YYYY-MM-DD
+    #[serde(rename = "beforeDate", skip_serializing_if = "Option::is_none")]
+    pub before_date: Option<String>,
+    /// List entries after this date, in format YYYY-MM-DD
+    #[serde(rename = "afterDate", skip_serializing_if = "Option::is_none")]
+    pub after_date: Option<String>,
+    /// Sort order of the entries, descending before a date and ascending after a date
+    pub sort: SortOrder,
+    /// Maximum number of entries per page, from 1 to `MAX_LIMIT`
+    pub limit: u32,
+    /// Offset of the first entry
+    pub offset: u32,
+    /// Maximum number of pages to fetch when following the `next` links
+    #[serde(skip)]
+    pub max_pages: usize,
+}
+
+impl ListParams {
+    /// Maximum number of entries per page
+    pub const MAX_LIMIT: u32 = 100;
+
+    /// List entries before a date, newest first
+    pub fn before(date: impl Into<String>) -> Self {
+        Self {
+            before_date: Some(date.into()),
+            after_date: None,
+            sort: SortOrder::Desc,
+            limit: Self::MAX_LIMIT,
+            offset: 0,
+            max_pages: Pagination::DEFAULT_MAX_PAGES,
+        }
+    }
+
+    /// List entries after a date, oldest first
+    pub fn after(date: impl Into<String>) -> Self {
+        Self {
+            before_date: None,
+            after_date: Some(date.into()),
+            sort: SortOrder::Asc,
+            limit: Self::MAX_LIMIT,
+            offset: 0,
+            max_pages: Pagination::DEFAULT_MAX_PAGES,
+        }
+    }
+
+    /// Set the maximum number of entries per page
+    pub fn with_limit(mut self, limit: u32) -> Self {
+        self.limit = limit;
+        self
+    }
+
+    /// Set the offset of the first entry
+    pub fn with_offset(mut self, offset: u32) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// Set the maximum number of pages to fetch when following the `next` links
+    pub fn with_max_pages(mut self, max_pages: usize) -> Self {
+        self.max_pages = max_pages;
+        self
+    }
+
+    /// Checks the parameters against the constraints of the list endpoints
+    ///
+    /// Returns an error describing the first violated constraint if:
+    /// - Neither or both of `before_date` and `after_date` are set
+    /// - `sort` is not descending with `before_date` or ascending with `after_date`
+    /// - `limit` is 0 or greater than `MAX_LIMIT`
+    pub fn validate(&self) -> Result<(), String> {
+        let (expected_sort, direction) = match (&self.before_date, &self.after_date) {
+            (Some(_), None) => (SortOrder::Desc, "before"),
+            (None, Some(_)) => (SortOrder::Asc, "after"),
+            _ => return Err("Exactly one of beforeDate and afterDate must be set".to_string()),
+        };
+        if self.sort != expected_sort {
+            return Err(format!(
+                "Entries {} a date must be sorted {}",
+                direction,
+                expected_sort.as_str()
+            ));
+        }
+        if self.limit == 0 || self.limit > Self::MAX_LIMIT {
+            return Err(format!(
+                "Limit must be between 1 and {}, got {}",
+                Self::MAX_LIMIT,
+                self.limit
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Page of a list endpoint
+///
+/// Implemented by the responses of the list endpoints, so that
+/// `FitbitClient::paginate` can follow their `next` links.
+pub trait Paginated<T> {
+    /// Pagination information of the page
+    fn pagination(&self) -> &Pagination;
+
+    /// Consumes the page, returning its entries
+    fn into_items(self) -> Vec<T>;
+}
@@ -0,0 +1,157 @@
+//! Client Profile Types
+//!
+//! This module contains the types selecting the API a `FitbitClient` talks
+//! to, and the catalogue of known endpoint paths checked when testing against
+//! a mock server.
+//!
+
+/// API a `FitbitClient` sends its requests to
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum ClientProfile {
+    /// The Fitbit API at `FitbitClient::DEFAULT_API_BASE_URL`
+    #[default]
+    Production,
+    /// A mock server at the given base URL, without the API version
+    ///
+    /// Request paths are checked against the known endpoints of the Fitbit
+    /// API before being sent, so a typo in the path of a
+    /// `FitbitClient::request` call fails with
+    /// `FitbitError::UnknownEndpoint` instead of a 404 of the mock server.
+    Mock(String),
+}
+
+impl ClientProfile {
+    /// Returns true if request paths are checked against `KNOWN_ENDPOINTS`
+    pub fn validates_paths(&self) -> bool {
+        matches!(self, ClientProfile::Mock(_))
+    }
+}
+
+/// Paths of the Fitbit API endpoints, after the API version
+///
+/// A `*` matches the non-empty variable part of a segment, e.g. a user ID,
+/// a date or a log ID.
+pub const KNOWN_ENDPOINTS: &[&str] = &[
+    // User
+    "/user/*/profile.json",
+    "/user/*/badges.json",
+    "/user/*/friends.json",
+    "/user/*/friends/invitations.json",
+    "/user/*/friends/invitations/*.json",
+    "/user/*/leaderboard/friends.json",
+    // Devices
+    "/user/*/devices.json",
+    "/user/*/devices/tracker/*/alarms.json",
+    "/user/*/devices/tracker/*/alarms/*.json",
+    // Activity
+    "/activities.json",
+    "/activities/*.json",
+    "/user/*/activities.json",
+    "/user/*/activities/*.json",
+    "/user/*/activities/*.tcx",
+    "/user/*/activities/date/*.json",
+    "/user/*/activities/goals/*.json",
+    "/user/*/activities/favorite/*.json",
+    "/user/*/activities/*/date/*/*.json",
+    "/user/*/activities/*/date/*/*/*.json",
+    "/user/*/activities/*/date/*/*/*/time/*/*.json",
+    "/user/*/activities/tracker/*/date/*/*.json",
+    // Body
+    "/user/*/body/log/*.json",
+    "/user/*/body/log/*/*.json",
+    "/user/*/body/log/*/date/*.json",
+    "/user/*/body/log/*/date/*/*.json",
+    "/user/*/body/*/date/*/*.json",
+    // Nutrition
+    "/foods/*.json",
+    "/foods/search.json",
+    "/user/*/foods.json",
+    "/user/*/foods/*.json",
+    "/user/*/foods/log.json",
+    "/user/*/foods/log/*.json",
+    "/user/*/foods/log/date/*.json",
+    "/user/*/foods/log/favorite/*.json",
+    "/user/*/foods/log/water/*.json",
+    "/user/*/foods/log/water/date/*.json",
+    "/user/*/foods/log/*/date/*/*.json",
+    "/user/*/meals.json",
+    "/user/*/meals/*.json",
+    // Sleep
+    "/user/*/sleep.json",
+    "/user/*/sleep/*.json",
+    "/user/*/sleep/date/*.json",
+    "/user/*/sleep/date/*/*.json",
+    // Heart rate variability, breathing rate, SpO2 and cardio fitness score
+    "/user/*/hrv/date/*.json",
+    "/user/*/hrv/date/*/*.json",
+    "/user/*/hrv/date/*/all.json",
+    "/user/*/hrv/date/*/*/all.json",
+    "/user/*/br/date/*.json",
+    "/user/*/br/date/*/*.json",
+    "/user/*/br/date/*/all.json",
+    "/user/*/br/date/*/*/all.json",
+    "/user/*/spo2/date/*.json",
+    "/user/*/spo2/date/*/*.json",
+    "/user/*/spo2/date/*/all.json",
+    "/user/*/spo2/date/*/*/all.json",
+    "/user/*/cardioscore/date/*.json",
+    "/user/*/cardioscore/date/*/*.json",
+    // Temperature
+    "/user/*/temp/*/date/*.json",
+    "/user/*/temp/*/date/*/*.json",
+    // Electrocardiogram and irregular rhythm notifications
+    "/user/*/ecg/list.json",
+    "/user/*/irn/alerts/list.json",
+    "/user/*/irn/profile.json",
+    // Subscriptions
+    "/user/*/apiSubscriptions.json",
+    "/user/*/apiSubscriptions/*.json",
+    "/user/*/*/apiSubscriptions.json",
+    "/user/*/*/apiSubscriptions/*.json",
+];
+
+/// Returns true if `path` matches one of `KNOWN_ENDPOINTS`
+///
+/// The query string, if any, is ignored.
+///
+/// # Examples
+///
+/// ```
+/// use fitbit_sdk::types::profile::is_known_endpoint;
+///
+/// assert!(is_known_endpoint("/user/-/activities/steps/date/today/7d.json"));
+/// assert!(is_known_endpoint("/user/-/sleep/list.json?afterDate=2025-01-01"));
+/// assert!(!is_known_endpoint("/user/-/profle.json"));
+/// assert!(!is_known_endpoint("/user/-/activities/date/2025-01-15"));
+/// ```
+pub fn is_known_endpoint(path: &str) -> bool {
+    let path = path.split_once('?').map_or(path, |(path, _)| path);
+    KNOWN_ENDPOINTS
+        .iter()
+        .any(|pattern| matches_pattern(pattern, path))
+}
+
+/// Returns true if every segment of `path` matches the segment of `pattern`
+fn matches_pattern(pattern: &str, path: &str) -> bool {
+    let (mut pattern, mut path) = (pattern.split('/'), path.split('/'));
+    loop {
+        match (pattern.next(), path.next()) {
+            (Some(expected), Some(segment)) if matches_segment(expected, segment) => {}
+            (None, None) => return true,
+            _ => return false,
+        }
+    }
+}
+
+/// Returns true if `segment` matches `expected`, whose `*` matches any
+/// non-empty text
+fn matches_segment(expected: &str, segment: &str) -> bool {
+    match expected.split_once('*') {
+        Some((prefix, suffix)) => {
+            segment.len() > prefix.len() + suffix.len()
+                && segment.starts_with(prefix)
+                && segment.ends_with(suffix)
+        }
+        None => expected == segment,
+    }
+}
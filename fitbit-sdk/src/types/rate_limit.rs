@@ -0,0 +1,177 @@
+//! Rate Limit Types
+//!
+//! This module contains the types describing the Fitbit API rate limit.
+//!
+use reqwest::header::HeaderMap;
+use time::{Duration, OffsetDateTime};
+
+/// Rate limit status reported by the Fitbit API
+///
+/// Fitbit includes the `Fitbit-Rate-Limit-Limit`, `Fitbit-Rate-Limit-Remaining`
+/// and `Fitbit-Rate-Limit-Reset` headers on every response. The client keeps the
+/// most recent values so applications can throttle themselves before hitting 429.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimitStatus {
+    /// Quota of requests for the current rate limit window
+    pub limit: u32,
+    /// Number of requests remaining before the limit is hit
+    pub remaining: u32,
+    /// Time at which the rate limit window resets
+    pub reset_at: OffsetDateTime,
+}
+
+impl RateLimitStatus {
+    pub const LIMIT_HEADER: &str = "Fitbit-Rate-Limit-Limit";
+    pub const REMAINING_HEADER: &str = "Fitbit-Rate-Limit-Remaining";
+    pub const RESET_HEADER: &str = "Fitbit-Rate-Limit-Reset";
+    pub const RETRY_AFTER_HEADER: &str = "Retry-After";
+
+    /// Parse the rate limit headers of a response
+    ///
+    /// Returns `None` if any of the headers is missing or malformed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fitbit_sdk::types::rate_limit::RateLimitStatus;
+    /// use reqwest::header::HeaderMap;
+    ///
+    /// let mut headers = HeaderMap::new();
+    /// headers.insert("Fitbit-Rate-Limit-Limit", "150".parse().unwrap());
+    /// headers.insert("Fitbit-Rate-Limit-Remaining", "149".parse().unwrap());
+    /// headers.insert("Fitbit-Rate-Limit-Reset", "1800".parse().unwrap());
+    ///
+    /// let status = RateLimitStatus::from_headers(&headers).unwrap();
+    /// assert_eq!(status.limit, 150);
+    /// assert_eq!(status.remaining, 149);
+    /// assert!(!status.is_exhausted());
+    /// ```
+    pub fn from_headers(headers: &HeaderMap) -> Option<Self> {
+        let header = |name: &str| -> Option<u64> {
+            headers.get(name)?.to_str().ok()?.trim().parse().ok()
+        };
+
+        let limit = header(Self::LIMIT_HEADER)?;
+        let remaining = header(Self::REMAINING_HEADER)?;
+        let reset = header(Self::RESET_HEADER)?;
+
+        Some(Self {
+            limit: u32::try_from(limit).ok()?,
+            remaining: u32::try_from(remaining).ok()?,
+            reset_at: OffsetDateTime::now_utc() + Duration::seconds(i64::try_from(reset).ok()?),
+        })
+    }
+
+    /// Time to wait before retrying a request answered with `429 Too Many Requests`
+    ///
+    /// Read from the `Retry-After` header, in seconds, falling back to the
+    /// `Fitbit-Rate-Limit-Reset` header. Returns `None` if neither is present
+    /// and valid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fitbit_sdk::types::rate_limit::RateLimitStatus;
+    /// use reqwest::header::HeaderMap;
+    /// use std::time::Duration;
+    ///
+    /// let mut headers = HeaderMap::new();
+    /// headers.insert("Fitbit-Rate-Limit-Reset", "1800".parse().unwrap());
+    /// assert_eq!(RateLimitStatus::retry_after(&headers), Some(Duration::from_secs(1800)));
+    ///
+    /// headers.insert("Retry-After", "60".parse().unwrap());
+    /// assert_eq!(RateLimitStatus::retry_after(&headers), Some(Duration::from_secs(60)));
+    /// ```
+    pub fn retry_after(headers: &HeaderMap) -> Option<std::time::Duration> {
+        let header = |name: &str| -> Option<u64> {
+            headers.get(name)?.to_str().ok()?.trim().parse().ok()
+        };
+
+        header(Self::RETRY_AFTER_HEADER)
+            .or_else(|| header(Self::RESET_HEADER))
+            .map(std::time::Duration::from_secs)
+    }
+
+    /// Time left until the rate limit window resets
+    pub fn reset_in(&self) -> std::time::Duration {
+        (self.reset_at - OffsetDateTime::now_utc())
+            .try_into()
+            .unwrap_or_default()
+    }
+
+    /// Returns true if no requests remain in the current window
+    pub fn is_exhausted(&self) -> bool {
+        self.remaining == 0
+    }
+}
+
+/// What a client-side rate limiter does with a request when the limit is reached
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RateLimitMode {
+    /// Hold the request until the limit allows it; requests are sent in order
+    #[default]
+    Wait,
+    /// Fail the request without sending it
+    Reject,
+}
+
+/// Client-side rate limit
+///
+/// Allows `requests` requests per `period`, refilled continuously, so a
+/// client never sends more requests than the Fitbit API accepts. The default
+/// matches the Fitbit limit of 150 requests per hour per user.
+///
+/// # Examples
+///
+/// ```
+/// use fitbit_sdk::types::rate_limit::{RateLimit, RateLimitMode};
+/// use std::time::Duration;
+///
+/// let limit = RateLimit::default();
+/// assert_eq!(limit.requests, 150);
+/// assert_eq!(limit.period, Duration::from_secs(3600));
+/// assert_eq!(limit.mode, RateLimitMode::Wait);
+///
+/// let limit = RateLimit::per_hour(100).with_mode(RateLimitMode::Reject);
+/// assert_eq!(limit.requests, 100);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimit {
+    /// Number of requests allowed per period
+    pub requests: u32,
+    /// Period over which requests are counted
+    pub period: std::time::Duration,
+    /// What to do with requests over the limit
+    pub mode: RateLimitMode,
+}
+
+impl RateLimit {
+    /// Requests per hour allowed by the Fitbit API for each user
+    pub const FITBIT_REQUESTS_PER_HOUR: u32 = 150;
+
+    /// Allow `requests` requests per `period`
+    pub fn new(requests: u32, period: std::time::Duration) -> Self {
+        Self {
+            requests,
+            period,
+            mode: RateLimitMode::default(),
+        }
+    }
+
+    /// Allow `requests` requests per hour
+    pub fn per_hour(requests: u32) -> Self {
+        Self::new(requests, std::time::Duration::from_secs(3600))
+    }
+
+    /// Set what to do with requests over the limit
+    pub fn with_mode(mut self, mode: RateLimitMode) -> Self {
+        self.mode = mode;
+        self
+    }
+}
+
+impl Default for RateLimit {
+    fn default() -> Self {
+        Self::per_hour(Self::FITBIT_REQUESTS_PER_HOUR)
+    }
+}
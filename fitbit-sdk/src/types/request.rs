@@ -0,0 +1,130 @@
+//! Request Types
+//!
+//! This module contains the options that adjust how requests are sent, and
+//! the encodings of request bodies.
+//!
+use crate::client::ApiVersion;
+use crate::types::locale::{Locale, UnitSystem};
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+
+/// Header selecting the subscriber endpoint when an application has several
+pub(crate) const SUBSCRIBER_ID_HEADER: &str = "X-Fitbit-Subscriber-Id";
+
+/// Options overriding the client configuration for a set of requests
+///
+/// Pass the options to `FitbitClient::with_options` to get a client that
+/// applies them to every request it sends. Headers set here take precedence
+/// over the headers configured on the builder.
+///
+/// # Examples
+///
+/// ```no_run
+/// use fitbit_sdk::client::{ApiVersion, FitbitClient};
+/// use fitbit_sdk::types::locale::UnitSystem;
+/// use fitbit_sdk::types::request::RequestOptions;
+/// use fitbit_sdk::types::user::{UserClient, UserError};
+/// use tokio;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), UserError> {
+///     let client = FitbitClient::new::<UserError>()?;
+///
+///     let us_client = client.with_options::<UserError>(
+///         RequestOptions::new().with_unit_system(UnitSystem::Us),
+///     )?;
+///     let profile = us_client.get_profile("-").await?;
+///     println!("Weight: {:?} {:?}", profile.weight, profile.weight_unit);
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct RequestOptions {
+    /// Locale sent as the `Accept-Locale` header
+    pub locale: Option<Locale>,
+    /// Unit system sent as the `Accept-Language` header
+    pub unit_system: Option<UnitSystem>,
+    /// Subscriber endpoint sent as the `X-Fitbit-Subscriber-Id` header
+    pub subscriber_id: Option<String>,
+    /// API version used instead of the version of each endpoint
+    pub api_version: Option<ApiVersion>,
+    /// Additional headers as name and value pairs
+    pub headers: Vec<(String, String)>,
+}
+
+impl RequestOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_locale(mut self, locale: Locale) -> Self {
+        self.locale = Some(locale);
+        self
+    }
+
+    pub fn with_unit_system(mut self, unit_system: UnitSystem) -> Self {
+        self.unit_system = Some(unit_system);
+        self
+    }
+
+    pub fn with_subscriber_id(mut self, subscriber_id: impl Into<String>) -> Self {
+        self.subscriber_id = Some(subscriber_id.into());
+        self
+    }
+
+    pub fn with_api_version(mut self, api_version: ApiVersion) -> Self {
+        self.api_version = Some(api_version);
+        self
+    }
+
+    pub fn with_header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.push((name.into(), value.into()));
+        self
+    }
+
+    /// Converts the options into request headers
+    ///
+    /// Returns an error message if a header name or value is not valid.
+    pub(crate) fn to_headers(&self) -> Result<HeaderMap, String> {
+        let mut headers = HeaderMap::new();
+        if let Some(locale) = self.locale {
+            headers.insert(Locale::HEADER, HeaderValue::from_static(locale.as_str()));
+        }
+        if let Some(unit_system) = self.unit_system {
+            headers.insert(UnitSystem::HEADER, HeaderValue::from_static(unit_system.as_str()));
+        }
+        if let Some(subscriber_id) = &self.subscriber_id {
+            let value = HeaderValue::from_str(subscriber_id)
+                .map_err(|e| format!("Invalid subscriber ID: {}", e))?;
+            headers.insert(SUBSCRIBER_ID_HEADER, value);
+        }
+        for (name, value) in &self.headers {
+            let name = HeaderName::from_bytes(name.as_bytes())
+                .map_err(|e| format!("Invalid header name '{}': {}", name, e))?;
+            let value = HeaderValue::from_str(value)
+                .map_err(|e| format!("Invalid value for header '{}': {}", name, e))?;
+            headers.insert(name, value);
+        }
+        Ok(headers)
+    }
+}
+
+/// Body of a request and its encoding
+///
+/// The Fitbit write endpoints mostly expect `application/x-www-form-urlencoded`
+/// bodies; a few, like the newer APIs, expect JSON.
+#[derive(Debug)]
+pub enum Body<'a, B: ?Sized> {
+    /// Serialized as JSON with `Content-Type: application/json`
+    Json(&'a B),
+    /// Serialized with `Content-Type: application/x-www-form-urlencoded`
+    Form(&'a B),
+}
+
+impl<B: ?Sized> Clone for Body<'_, B> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<B: ?Sized> Copy for Body<'_, B> {}
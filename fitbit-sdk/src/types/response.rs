@@ -0,0 +1,128 @@
+//! Response Types
+//!
+//! This module contains the types describing a Fitbit API response as received.
+//!
+use crate::types::locale::UnitSystem;
+use crate::types::rate_limit::RateLimitStatus;
+use reqwest::StatusCode;
+use reqwest::header::HeaderMap;
+
+/// Typed response together with the raw JSON, status and headers it was parsed from
+///
+/// The raw JSON gives access to fields the typed structs do not model yet.
+///
+/// # Examples
+///
+/// ```no_run
+/// use fitbit_sdk::client::{ApiVersion, FitbitClient};
+/// use fitbit_sdk::types::user::{UserError, UserProfileResponse};
+/// use tokio;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), UserError> {
+///     let client = FitbitClient::new::<UserError>()?;
+///
+///     let response = client
+///         .get_raw::<UserProfileResponse, UserError>(ApiVersion::V1, "/user/-/profile.json")
+///         .await?;
+///     println!("{} ({})", response.value.user.display_name, response.status);
+///     println!("Member since {}", response.raw["user"]["memberSince"]);
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Response<T> {
+    /// Deserialized response body
+    pub value: T,
+    /// Response body as JSON (`null` for an empty body)
+    pub raw: serde_json::Value,
+    /// HTTP status of the response
+    pub status: StatusCode,
+    /// Headers of the response
+    pub headers: HeaderMap,
+}
+
+impl<T> Response<T> {
+    /// Returns the deserialized response body, discarding the rest
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+
+    /// Maps the deserialized response body, keeping the raw JSON, status and headers
+    pub fn map<U>(self, f: impl FnOnce(T) -> U) -> Response<U> {
+        Response {
+            value: f(self.value),
+            raw: self.raw,
+            status: self.status,
+            headers: self.headers,
+        }
+    }
+
+    /// Returns the rate limit status reported by the response, if any
+    pub fn rate_limit(&self) -> Option<RateLimitStatus> {
+        RateLimitStatus::from_headers(&self.headers)
+    }
+
+    /// Returns the metadata of the response
+    pub fn meta(&self) -> ResponseMeta {
+        ResponseMeta::from_headers(self.status, &self.headers)
+    }
+}
+
+/// Metadata of a Fitbit API response, useful to debug unexpected values
+///
+/// The language of a response determines the units of its measurements, so
+/// `content_language` tells which unit system a weight or distance is in.
+///
+/// # Examples
+///
+/// ```
+/// use fitbit_sdk::types::response::ResponseMeta;
+/// use reqwest::StatusCode;
+/// use reqwest::header::HeaderMap;
+///
+/// let mut headers = HeaderMap::new();
+/// headers.insert("Content-Language", "en_GB".parse().unwrap());
+/// headers.insert("X-Request-Id", "8e2f1c".parse().unwrap());
+///
+/// let meta = ResponseMeta::from_headers(StatusCode::OK, &headers);
+/// assert_eq!(meta.content_language.as_deref(), Some("en_GB"));
+/// assert_eq!(meta.request_id.as_deref(), Some("8e2f1c"));
+/// assert_eq!(meta.rate_limit, None);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResponseMeta {
+    /// HTTP status of the response
+    pub status: StatusCode,
+    /// Language, and so unit system, of the response (`Content-Language`)
+    pub content_language: Option<String>,
+    /// Identifier of the request, to quote when reporting an issue to Fitbit
+    pub request_id: Option<String>,
+    /// Rate limit status reported by the response
+    pub rate_limit: Option<RateLimitStatus>,
+}
+
+impl ResponseMeta {
+    pub const CONTENT_LANGUAGE_HEADER: &str = "Content-Language";
+    pub const REQUEST_ID_HEADER: &str = "X-Request-Id";
+
+    /// Reads the metadata from the status and headers of a response
+    pub fn from_headers(status: StatusCode, headers: &HeaderMap) -> Self {
+        let header = |name: &str| -> Option<String> {
+            Some(headers.get(name)?.to_str().ok()?.trim().to_string())
+        };
+
+        Self {
+            status,
+            content_language: header(Self::CONTENT_LANGUAGE_HEADER),
+            request_id: header(Self::REQUEST_ID_HEADER),
+            rate_limit: RateLimitStatus::from_headers(headers),
+        }
+    }
+
+    /// Returns the unit system the measurements of the response are in
+    pub fn unit_system(&self) -> UnitSystem {
+        UnitSystem::from_content_language(self.content_language.as_deref())
+    }
+}
@@ -2,42 +2,69 @@
 //!
 //! This module contains the types and functions for the Fitbit Sleep API.
 //!
+use crate::types::auth::Scope;
+use crate::types::date::FitbitDate;
+use crate::types::datetime::{ApiDate, ApiDateTime, ApiTime, ApiTimestamp};
+use crate::types::error::{FitbitError, domain_error};
+use crate::types::pagination::{ListParams, Paginated, Pagination};
+use crate::types::user::UserId;
 use async_trait::async_trait;
-use serde::{Deserialize, Serialize};
+use futures_util::stream::BoxStream;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::{Map, Value};
+use std::fmt;
+use std::str::FromStr;
 use thiserror::Error;
 
 /// Error types for the Sleep API
 #[derive(Debug, Error)]
-pub enum SleepError {
-    #[error("API request failed: {0}")]
-    RequestFailed(String),
-    #[error("API error: {0}")]
-    ApiError(String),
-}
+#[error(transparent)]
+pub struct SleepError(#[from] pub FitbitError);
 
-impl From<String> for SleepError {
-    fn from(error: String) -> Self {
-        SleepError::ApiError(error)
-    }
-}
+domain_error!(SleepError, scopes [Scope::Sleep]);
 
 #[async_trait]
 pub trait SleepClient {
-    async fn get_sleep_logs<'a>(&'a self, user_id: &'a str, date: &'a str) -> Result<SleepLog, SleepError>;
-    async fn get_sleep_goal<'a>(&'a self, user_id: &'a str) -> Result<SleepGoal, SleepError>;
+    async fn get_sleep_logs<'a>(&'a self, user_id: impl Into<UserId> + Send, date: impl Into<FitbitDate> + Send) -> Result<SleepLog, SleepError>;
+    async fn get_sleep_goal<'a>(&'a self, user_id: impl Into<UserId> + Send) -> Result<SleepGoalResponse, SleepError>;
+    async fn update_sleep_goal<'a>(
+        &'a self,
+        user_id: impl Into<UserId> + Send,
+        min_duration: i32,
+    ) -> Result<SleepGoalResponse, SleepError>;
+    async fn log_sleep<'a>(&'a self, user_id: impl Into<UserId> + Send, params: &'a LogSleepParams) -> Result<SleepEntry, SleepError>;
+    async fn delete_sleep_log<'a>(&'a self, user_id: impl Into<UserId> + Send, log_id: i64) -> Result<(), SleepError>;
+    async fn get_sleep_log_list<'a>(
+        &'a self,
+        user_id: impl Into<UserId> + Send,
+        params: &'a SleepListParams,
+    ) -> Result<SleepLogList, SleepError>;
+    async fn get_all_sleep_log_list<'a>(
+        &'a self,
+        user_id: impl Into<UserId> + Send,
+        params: &'a SleepListParams,
+    ) -> Result<Vec<SleepEntry>, SleepError>;
+    fn stream_sleep_log_list<'a>(
+        &'a self,
+        user_id: impl Into<UserId> + Send,
+        params: &'a SleepListParams,
+    ) -> BoxStream<'a, Result<SleepEntry, SleepError>>;
 }
 
 /// Sleep log information
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct SleepLog {
     /// Sleep summary
     pub summary: SleepSummary,
     /// List of sleep entries
     pub sleep: Vec<SleepEntry>,
+    /// Fields of the response not modeled above
+    #[serde(flatten, deserialize_with = "crate::types::extra::deserialize")]
+    pub extra: Map<String, Value>,
 }
 
 /// Sleep summary for a day
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct SleepSummary {
     /// Total number of sleep records
     #[serde(rename = "totalSleepRecords")]
@@ -48,20 +75,50 @@ pub struct SleepSummary {
     /// Total minutes asleep
     #[serde(rename = "totalMinutesAsleep")]
     pub total_minutes_asleep: i32,
+    /// Minutes in each sleep stage, summed over the stage logs of the day
+    ///
+    /// Missing when the day only has classic logs.
+    pub stages: Option<SleepStagesSummary>,
+    /// Fields of the response not modeled above
+    #[serde(flatten, deserialize_with = "crate::types::extra::deserialize")]
+    pub extra: Map<String, Value>,
+}
+
+/// Minutes spent in each sleep stage over a day
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SleepStagesSummary {
+    /// Minutes of deep sleep
+    pub deep: i32,
+    /// Minutes of light sleep
+    pub light: i32,
+    /// Minutes of REM sleep
+    pub rem: i32,
+    /// Minutes awake
+    pub wake: i32,
+    /// Fields of the response not modeled above
+    #[serde(flatten, deserialize_with = "crate::types::extra::deserialize")]
+    pub extra: Map<String, Value>,
+}
+
+impl SleepStagesSummary {
+    /// Returns the minutes asleep, in any stage
+    pub fn asleep_minutes(&self) -> i32 {
+        self.deep + self.light + self.rem
+    }
 }
 
 /// Individual sleep entry
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct SleepEntry {
     /// Log ID for the sleep entry
     #[serde(rename = "logId")]
     pub log_id: i64,
     /// Start time of sleep
-    #[serde(rename = "startTime")]
-    pub start_time: String,
+    #[serde(rename = "startTime", with = "crate::types::datetime::api_field")]
+    pub start_time: ApiDateTime,
     /// End time of sleep
-    #[serde(rename = "endTime")]
-    pub end_time: String,
+    #[serde(rename = "endTime", with = "crate::types::datetime::api_field")]
+    pub end_time: ApiDateTime,
     /// Duration in milliseconds
     pub duration: i64,
     /// Minutes in bed before falling asleep
@@ -83,19 +140,52 @@ pub struct SleepEntry {
     pub is_main_sleep: bool,
     /// Sleep levels data
     pub levels: Option<SleepLevels>,
+    /// Date the sleep is counted towards, usually the day it ended
+    #[serde(rename = "dateOfSleep", default, with = "crate::types::datetime::option_api_field")]
+    pub date_of_sleep: Option<ApiDate>,
+    /// Minutes awake during the sleep
+    #[serde(rename = "minutesAwake", default)]
+    pub minutes_awake: Option<i32>,
+    /// Minutes in bed after waking up
+    #[serde(rename = "minutesAfterWakeup", default)]
+    pub minutes_after_wakeup: Option<i32>,
+    /// How the sleep was logged (auto_detected or manual)
+    #[serde(rename = "logType", default)]
+    pub log_type: Option<String>,
+    /// Quality of the sleep stages data: 0 when stages were computed, other
+    /// codes when the log fell back to classic levels
+    #[serde(rename = "infoCode", default)]
+    pub info_code: Option<i32>,
+    /// Fields of the response not modeled above
+    #[serde(flatten, deserialize_with = "crate::types::extra::deserialize")]
+    pub extra: Map<String, Value>,
 }
 
 /// Sleep levels data
-#[derive(Debug, Deserialize)]
+///
+/// Stage logs (`type` "stages") use the `deep`, `light`, `rem` and `wake`
+/// levels; classic logs, recorded without heart rate or for naps shorter
+/// than three hours, use `asleep`, `restless` and `awake`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct SleepLevels {
     /// Summary of time spent in each sleep level
     pub summary: SleepLevelsSummary,
     /// Detailed data of sleep stages throughout the night
     pub data: Vec<SleepLevelData>,
+    /// Wake periods of three minutes or less, overlapping `data`
+    ///
+    /// Only sent for stage logs. Fitbit counts these brief wakes in the
+    /// `wake` summary but leaves them out of `data`, so they do not split
+    /// the surrounding stages.
+    #[serde(rename = "shortData", default, skip_serializing_if = "Option::is_none")]
+    pub short_data: Option<Vec<SleepLevelData>>,
+    /// Fields of the response not modeled above
+    #[serde(flatten, deserialize_with = "crate::types::extra::deserialize")]
+    pub extra: Map<String, Value>,
 }
 
 /// Summary of time spent in each sleep level
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct SleepLevelsSummary {
     /// Time spent in REM sleep
     pub rem: Option<SleepLevelSummary>,
@@ -105,44 +195,279 @@ pub struct SleepLevelsSummary {
     pub light: Option<SleepLevelSummary>,
     /// Time spent awake
     pub wake: Option<SleepLevelSummary>,
+    /// Time spent asleep, in classic logs
+    pub asleep: Option<SleepLevelSummary>,
+    /// Time spent restless, in classic logs
+    pub restless: Option<SleepLevelSummary>,
+    /// Time spent awake, in classic logs
+    pub awake: Option<SleepLevelSummary>,
+    /// Fields of the response not modeled above
+    #[serde(flatten, deserialize_with = "crate::types::extra::deserialize")]
+    pub extra: Map<String, Value>,
 }
 
 /// Individual sleep level summary
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct SleepLevelSummary {
     /// Number of minutes in this sleep level
     pub minutes: i32,
     /// Number of times this sleep level occurred
     pub count: i32,
+    /// Average minutes in this sleep level over the last 30 days, in stage logs
+    #[serde(rename = "thirtyDayAvgMinutes", default)]
+    pub thirty_day_avg_minutes: Option<i32>,
+    /// Fields of the response not modeled above
+    #[serde(flatten, deserialize_with = "crate::types::extra::deserialize")]
+    pub extra: Map<String, Value>,
 }
 
 /// Individual sleep level data point
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct SleepLevelData {
     /// Date-time for this data point
-    pub datetime: String,
-    /// Sleep level (wake, rem, light, deep)
-    pub level: String,
+    #[serde(rename = "dateTime", with = "crate::types::datetime::api_field")]
+    pub datetime: ApiDateTime,
+    /// Sleep level
+    pub level: SleepLevel,
     /// Number of seconds in this level
     pub seconds: i32,
+    /// Fields of the response not modeled above
+    #[serde(flatten, deserialize_with = "crate::types::extra::deserialize")]
+    pub extra: Map<String, Value>,
+}
+
+/// Level of a sleep data point
+///
+/// Covers the levels of both stage logs and classic logs.
+///
+/// # Examples
+///
+/// ```
+/// use fitbit_sdk::types::sleep::SleepLevel;
+///
+/// assert_eq!("rem".parse::<SleepLevel>(), Ok(SleepLevel::Rem));
+/// assert_eq!("restless".parse::<SleepLevel>(), Ok(SleepLevel::Restless));
+/// assert!(SleepLevel::Awake.is_awake());
+/// assert!(SleepLevel::Wake.is_awake());
+/// assert!(!SleepLevel::Restless.is_awake());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum SleepLevel {
+    /// Deep sleep, in stage logs
+    Deep,
+    /// Light sleep, in stage logs
+    Light,
+    /// REM sleep, in stage logs
+    Rem,
+    /// Awake, in stage logs
+    Wake,
+    /// Asleep, in classic logs
+    Asleep,
+    /// Restless, in classic logs
+    Restless,
+    /// Awake, in classic logs
+    Awake,
+    /// Level not known to this SDK
+    Other(String),
+}
+
+impl SleepLevel {
+    pub fn as_str(&self) -> &str {
+        match self {
+            SleepLevel::Deep => "deep",
+            SleepLevel::Light => "light",
+            SleepLevel::Rem => "rem",
+            SleepLevel::Wake => "wake",
+            SleepLevel::Asleep => "asleep",
+            SleepLevel::Restless => "restless",
+            SleepLevel::Awake => "awake",
+            SleepLevel::Other(other) => other,
+        }
+    }
+
+    /// Returns true if the level means the user was awake, in either vocabulary
+    pub fn is_awake(&self) -> bool {
+        matches!(self, SleepLevel::Wake | SleepLevel::Awake)
+    }
+}
+
+impl FromStr for SleepLevel {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "deep" => SleepLevel::Deep,
+            "light" => SleepLevel::Light,
+            "rem" => SleepLevel::Rem,
+            "wake" => SleepLevel::Wake,
+            "asleep" => SleepLevel::Asleep,
+            "restless" => SleepLevel::Restless,
+            "awake" => SleepLevel::Awake,
+            other => SleepLevel::Other(other.to_string()),
+        })
+    }
+}
+
+impl fmt::Display for SleepLevel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Serialize for SleepLevel {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for SleepLevel {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        value.parse().map_err(serde::de::Error::custom)
+    }
 }
 
 /// User's sleep goal
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct SleepGoal {
-    /// Sleep goal in minutes
-    pub goal: i32,
+    /// Target sleep duration in minutes
+    #[serde(rename = "minDuration")]
+    pub min_duration: i32,
+    /// Time the goal was last updated
+    #[serde(rename = "updatedOn", with = "crate::types::datetime::api_field")]
+    pub updated_on: ApiTimestamp,
+    /// Target bedtime in format HH:mm, if the user set a sleep schedule
+    #[serde(default, with = "crate::types::datetime::option_api_field")]
+    pub bedtime: Option<ApiTime>,
+    /// Target wake-up time in format HH:mm, if the user set a sleep schedule
+    #[serde(
+        rename = "wakeupTime",
+        default,
+        with = "crate::types::datetime::option_api_field"
+    )]
+    pub wakeup_time: Option<ApiTime>,
+    /// Fields of the response not modeled above
+    #[serde(flatten, deserialize_with = "crate::types::extra::deserialize")]
+    pub extra: Map<String, Value>,
+}
+
+/// Sleep goal after an update
+#[deprecated(note = "renamed to `SleepGoal`")]
+pub type UpdatedSleepGoal = SleepGoal;
+
+/// Result of a sleep goal update
+#[deprecated(note = "the sleep goal endpoints share `SleepGoalResponse`")]
+pub type SleepGoalUpdate = SleepGoalResponse;
+
+/// Sleep consistency information
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SleepConsistency {
+    /// Sleep consistency flow the user is in
+    #[serde(rename = "flowId")]
+    pub flow_id: i32,
+    /// Percentage of time spent awake or restless
+    #[serde(rename = "awakeRestlessPercentage")]
+    pub awake_restless_percentage: Option<f64>,
+    /// Recommended sleep goal in minutes
+    #[serde(rename = "recommendedSleepGoal")]
+    pub recommended_sleep_goal: Option<i32>,
+    /// Typical sleep duration in minutes
+    #[serde(rename = "typicalDuration")]
+    pub typical_duration: Option<i32>,
+    /// Typical wake-up time in format HH:mm
+    #[serde(
+        rename = "typicalWakeupTime",
+        default,
+        with = "crate::types::datetime::option_api_field"
+    )]
+    pub typical_wakeup_time: Option<ApiTime>,
+    /// Fields of the response not modeled above
+    #[serde(flatten, deserialize_with = "crate::types::extra::deserialize")]
+    pub extra: Map<String, Value>,
+}
+
+/// Parameters for updating the sleep goal
+#[derive(Debug, Serialize)]
+pub(crate) struct UpdateSleepGoalParams {
+    #[serde(rename = "minDuration")]
+    pub(crate) min_duration: i32,
 }
 
 /// Response wrapper for sleep logs
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct SleepLogResponse {
     #[serde(flatten)]
     pub sleep_log: SleepLog,
 }
 
-/// Response wrapper for sleep goal
-#[derive(Debug, Deserialize)]
+/// Sleep goal with the sleep consistency information behind it
+///
+/// Returned both when getting and when updating the goal.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct SleepGoalResponse {
+    /// Sleep consistency information used to recommend a goal, missing until
+    /// enough sleep has been logged
+    pub consistency: Option<SleepConsistency>,
+    /// The sleep goal
     pub goal: SleepGoal,
+    /// Fields of the response not modeled above
+    #[serde(flatten, deserialize_with = "crate::types::extra::deserialize")]
+    pub extra: Map<String, Value>,
+}
+
+/// Parameters for logging a sleep entry
+#[derive(Debug, Serialize)]
+pub struct LogSleepParams {
+    /// Start time of the sleep in format HH:mm
+    #[serde(rename = "startTime")]
+    pub start_time: String,
+    /// Duration of the sleep in milliseconds
+    pub duration: i64,
+    /// Date the sleep started in format YYYY-MM-DD
+    pub date: String,
+}
+
+impl LogSleepParams {
+    /// Create new LogSleepParams
+    pub fn new(start_time: impl Into<String>, duration_millis: i64, date: impl Into<String>) -> Self {
+        Self {
+            start_time: start_time.into(),
+            duration: duration_millis,
+            date: date.into(),
+        }
+    }
+}
+
+/// Response wrapper for a logged sleep entry
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LogSleepResponse {
+    pub sleep: SleepEntry,
+    /// Fields of the response not modeled above
+    #[serde(flatten, deserialize_with = "crate::types::extra::deserialize")]
+    pub extra: Map<String, Value>,
+}
+
+/// Parameters for listing sleep log entries
+pub type SleepListParams = ListParams;
+
+/// Page of sleep log entries
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SleepLogList {
+    /// Sleep log entries in the page
+    pub sleep: Vec<SleepEntry>,
+    /// Pagination information
+    pub pagination: Pagination,
+    /// Fields of the response not modeled above
+    #[serde(flatten, deserialize_with = "crate::types::extra::deserialize")]
+    pub extra: Map<String, Value>,
+}
+
+impl Paginated<SleepEntry> for SleepLogList {
+    fn pagination(&self) -> &Pagination {
+        &self.pagination
+    }
+
+    fn into_items(self) -> Vec<SleepEntry> {
+        self.sleep
+    }
 }
@@ -0,0 +1,113 @@
+//! Subscriptions API Types
+//!
+//! This module contains the types and functions for the Fitbit Subscriptions API.
+//!
+use crate::types::error::{FitbitError, domain_error};
+use crate::types::user::UserId;
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use thiserror::Error;
+
+/// Error types for the Subscriptions API
+#[derive(Debug, Error)]
+#[error(transparent)]
+pub struct SubscriptionError(#[from] pub FitbitError);
+
+domain_error!(SubscriptionError, scopes []);
+
+#[async_trait]
+pub trait SubscriptionClient {
+    async fn create_subscription<'a>(
+        &'a self,
+        user_id: impl Into<UserId> + Send,
+        collection: Option<CollectionType>,
+        subscription_id: &'a str,
+        subscriber_id: Option<&'a str>,
+    ) -> Result<Subscription, SubscriptionError>;
+
+    async fn list_subscriptions<'a>(
+        &'a self,
+        user_id: impl Into<UserId> + Send,
+        collection: Option<CollectionType>,
+    ) -> Result<Vec<Subscription>, SubscriptionError>;
+
+    async fn delete_subscription<'a>(
+        &'a self,
+        user_id: impl Into<UserId> + Send,
+        collection: Option<CollectionType>,
+        subscription_id: &'a str,
+        subscriber_id: Option<&'a str>,
+    ) -> Result<(), SubscriptionError>;
+}
+
+/// Collection of data a subscription or update notification is about
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum CollectionType {
+    Activities,
+    Body,
+    Foods,
+    Sleep,
+    UserRevokedAccess,
+    /// The user deleted their account (only sent in notifications)
+    DeleteUser,
+    /// A collection this version of the SDK does not know about
+    #[serde(untagged)]
+    Other(String),
+}
+
+impl CollectionType {
+    pub fn as_str(&self) -> &str {
+        match self {
+            CollectionType::Activities => "activities",
+            CollectionType::Body => "body",
+            CollectionType::Foods => "foods",
+            CollectionType::Sleep => "sleep",
+            CollectionType::UserRevokedAccess => "userRevokedAccess",
+            CollectionType::DeleteUser => "deleteUser",
+            CollectionType::Other(collection) => collection,
+        }
+    }
+}
+
+/// Subscription to update notifications for a user
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Subscription {
+    /// Collection the subscription is for, or "user" for all collections
+    #[serde(rename = "collectionType")]
+    pub collection_type: String,
+    /// Encoded ID of the user the subscription is for
+    #[serde(rename = "ownerId")]
+    pub owner_id: String,
+    /// Type of the owner (always "user")
+    #[serde(rename = "ownerType")]
+    pub owner_type: String,
+    /// ID of the subscriber endpoint configured for the application
+    #[serde(rename = "subscriberId")]
+    pub subscriber_id: String,
+    /// ID chosen by the application when the subscription was created
+    #[serde(rename = "subscriptionId")]
+    pub subscription_id: String,
+    /// Fields of the response not modeled above
+    #[serde(flatten, deserialize_with = "crate::types::extra::deserialize")]
+    pub extra: Map<String, Value>,
+}
+
+/// Response wrapper for subscription lists
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SubscriptionListResponse {
+    #[serde(rename = "apiSubscriptions")]
+    pub api_subscriptions: Vec<Subscription>,
+    /// Fields of the response not modeled above
+    #[serde(flatten, deserialize_with = "crate::types::extra::deserialize")]
+    pub extra: Map<String, Value>,
+}
+
+/// Builds the path of a user's subscriptions, optionally narrowed to a collection
+pub(crate) fn subscriptions_path(user_id: &UserId, collection: Option<CollectionType>) -> String {
+    match collection {
+        Some(collection) => format!("/user/{}/{}/apiSubscriptions", user_id, collection.as_str()),
+        None => format!("/user/{}/apiSubscriptions", user_id),
+    }
+}
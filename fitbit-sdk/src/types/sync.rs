@@ -0,0 +1,245 @@
+//! Sync Types
+//!
+//! This module contains the types for keeping a local mirror of a user's
+//! Fitbit data up to date.
+//!
+use crate::types::activity::{ActivityError, ActivityTimeSeries, Resource};
+use crate::types::body::{BodyError, BodyFat, BodyWeight};
+use crate::types::error::{FitbitError, domain_error};
+use crate::types::heart_rate::{HeartRateError, HeartRateTimeSeries};
+use crate::types::sleep::{SleepEntry, SleepError};
+use crate::types::user::UserId;
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize, Serializer};
+use thiserror::Error;
+use time::Date;
+
+time::serde::format_description!(sync_date, Date, "[year]-[month]-[day]");
+
+/// Error types for syncing data
+#[derive(Debug, Error)]
+pub enum SyncError {
+    #[error(transparent)]
+    Fitbit(#[from] FitbitError),
+    #[error("Sink failed: {0}")]
+    SinkFailed(String),
+}
+
+impl From<String> for SyncError {
+    fn from(error: String) -> Self {
+        SyncError::Fitbit(FitbitError::ApiError(error))
+    }
+}
+
+domain_error!(
+    SyncError,
+    from [ActivityError, SleepError, BodyError, HeartRateError],
+    SyncError::Fitbit(error) => error,
+    SyncError::SinkFailed(error) => FitbitError::RequestFailed(format!("Sink failed: {}", error)),
+);
+
+/// Trait for syncing data into a local store
+#[async_trait]
+pub trait SyncClient {
+    async fn sync<'a, S>(
+        &'a self,
+        user_id: impl Into<UserId> + Send,
+        params: &'a SyncParams,
+        sink: &'a mut S,
+    ) -> Result<SyncSummary, SyncError>
+    where
+        S: SyncSink + ?Sized;
+}
+
+/// Receives the records pulled by `SyncClient::sync`
+///
+/// Records are written in the order they are pulled, one data type at a
+/// time. `finish` is called once every record has been written, before the
+/// new checkpoint is returned; a sink that buffers records should make them
+/// durable there.
+///
+/// # Examples
+///
+/// ```
+/// use async_trait::async_trait;
+/// use fitbit_sdk::types::sync::{SyncError, SyncRecord, SyncSink};
+///
+/// /// Keeps the records in memory
+/// #[derive(Default)]
+/// struct MemorySink(Vec<SyncRecord>);
+///
+/// #[async_trait]
+/// impl SyncSink for MemorySink {
+///     async fn write(&mut self, record: SyncRecord) -> Result<(), SyncError> {
+///         self.0.push(record);
+///         Ok(())
+///     }
+/// }
+/// ```
+#[async_trait]
+pub trait SyncSink: Send {
+    /// Writes a single record
+    async fn write(&mut self, record: SyncRecord) -> Result<(), SyncError>;
+
+    /// Called once all records of a sync have been written
+    async fn finish(&mut self) -> Result<(), SyncError> {
+        Ok(())
+    }
+}
+
+/// Data types that can be synced
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SyncData {
+    /// Daily activity time series of `SyncParams::activity_resources`
+    Activity,
+    /// Sleep log entries
+    Sleep,
+    /// Weight and body fat logs
+    Body,
+    /// Daily heart rate zones and resting heart rate
+    HeartRate,
+}
+
+impl SyncData {
+    /// Every data type that can be synced
+    pub const ALL: [SyncData; 4] = [
+        SyncData::Activity,
+        SyncData::Sleep,
+        SyncData::Body,
+        SyncData::HeartRate,
+    ];
+}
+
+/// Record pulled by `SyncClient::sync`
+///
+/// Records serialize with a `type` tag naming the variant, so a stream of
+/// records can be stored in a single file.
+// Records are handled one at a time, so the size of the sleep entries is not
+// worth boxing them
+#[allow(clippy::large_enum_variant)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum SyncRecord {
+    /// Value of an activity resource for a day
+    Activity {
+        /// The activity resource
+        #[serde(serialize_with = "serialize_resource")]
+        resource: Resource,
+        /// The value for the day
+        point: ActivityTimeSeries,
+    },
+    /// Sleep log entry
+    Sleep(SleepEntry),
+    /// Weight log entry
+    Weight(BodyWeight),
+    /// Body fat log entry
+    BodyFat(BodyFat),
+    /// Heart rate zones and resting heart rate for a day
+    HeartRate(HeartRateTimeSeries),
+}
+
+/// Serializes an activity resource as its name in the API
+fn serialize_resource<S: Serializer>(
+    resource: &Resource,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(resource.as_str())
+}
+
+/// Position of a local mirror in the user's data
+///
+/// Store the checkpoint returned by `SyncClient::sync` and pass it to the
+/// next sync, which pulls the days after it.
+///
+/// # Examples
+///
+/// ```
+/// use fitbit_sdk::types::sync::SyncCheckpoint;
+/// use time::macros::date;
+///
+/// let checkpoint = SyncCheckpoint::through(date!(2025 - 01 - 15));
+/// let json = serde_json::to_string(&checkpoint).unwrap();
+/// assert_eq!(json, r#"{"synced_through":"2025-01-15"}"#);
+/// assert_eq!(serde_json::from_str::<SyncCheckpoint>(&json).unwrap(), checkpoint);
+///
+/// assert_eq!(SyncCheckpoint::default().synced_through, None);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct SyncCheckpoint {
+    /// Last day whose data has been synced, or `None` before the first sync
+    #[serde(with = "sync_date::option")]
+    pub synced_through: Option<Date>,
+}
+
+impl SyncCheckpoint {
+    /// Checkpoint after syncing every day up to and including `date`
+    pub fn through(date: Date) -> Self {
+        Self {
+            synced_through: Some(date),
+        }
+    }
+}
+
+/// Parameters for syncing data
+///
+/// Only complete days are synced: by default the sync ends yesterday, in the
+/// local time zone, so that today's data is pulled once the day is over.
+#[derive(Debug, Clone)]
+pub struct SyncParams {
+    /// Position of the local mirror
+    pub checkpoint: SyncCheckpoint,
+    /// First day to sync when the checkpoint is empty
+    pub start_date: Date,
+    /// Last day to sync, or `None` for yesterday
+    pub end_date: Option<Date>,
+    /// Data types to sync
+    pub data: Vec<SyncData>,
+    /// Activity resources to sync when syncing `SyncData::Activity`
+    pub activity_resources: Vec<Resource>,
+}
+
+impl SyncParams {
+    /// Sync every data type after `checkpoint`, or from `start_date` on the first sync
+    pub fn new(checkpoint: SyncCheckpoint, start_date: Date) -> Self {
+        Self {
+            checkpoint,
+            start_date,
+            end_date: None,
+            data: SyncData::ALL.to_vec(),
+            activity_resources: vec![
+                Resource::Steps,
+                Resource::Distance,
+                Resource::Calories,
+                Resource::Floors,
+            ],
+        }
+    }
+
+    /// Set the last day to sync
+    pub fn with_end_date(mut self, end_date: Date) -> Self {
+        self.end_date = Some(end_date);
+        self
+    }
+
+    /// Set the data types to sync
+    pub fn with_data(mut self, data: impl IntoIterator<Item = SyncData>) -> Self {
+        self.data = data.into_iter().collect();
+        self
+    }
+
+    /// Set the activity resources to sync
+    pub fn with_activity_resources(mut self, resources: impl IntoIterator<Item = Resource>) -> Self {
+        self.activity_resources = resources.into_iter().collect();
+        self
+    }
+}
+
+/// Outcome of a sync
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SyncSummary {
+    /// Checkpoint to pass to the next sync
+    pub checkpoint: SyncCheckpoint,
+    /// Number of records written to the sink
+    pub records: usize,
+}
@@ -0,0 +1,153 @@
+//! TCX Types
+//!
+//! This module contains the types for parsing Training Center XML (TCX) exports
+//! of logged activities. Requires the `tcx` feature.
+//!
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+/// Parsed TCX document
+///
+/// # Examples
+///
+/// ```
+/// use fitbit_sdk::types::tcx::Tcx;
+///
+/// let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+/// <TrainingCenterDatabase xmlns="http://www.garmin.com/xmlschemas/TrainingCenterDatabase/v2">
+///   <Activities>
+///     <Activity Sport="Running">
+///       <Id>2025-01-15T07:30:00.000+09:00</Id>
+///       <Lap StartTime="2025-01-15T07:30:00.000+09:00">
+///         <TotalTimeSeconds>1800.0</TotalTimeSeconds>
+///         <DistanceMeters>5000.0</DistanceMeters>
+///         <Calories>320</Calories>
+///         <Track>
+///           <Trackpoint>
+///             <Time>2025-01-15T07:30:00.000+09:00</Time>
+///             <Position>
+///               <LatitudeDegrees>35.6812</LatitudeDegrees>
+///               <LongitudeDegrees>139.7671</LongitudeDegrees>
+///             </Position>
+///             <AltitudeMeters>40.2</AltitudeMeters>
+///             <DistanceMeters>0.0</DistanceMeters>
+///             <HeartRateBpm><Value>98</Value></HeartRateBpm>
+///           </Trackpoint>
+///         </Track>
+///       </Lap>
+///     </Activity>
+///   </Activities>
+/// </TrainingCenterDatabase>"#;
+///
+/// let tcx: Tcx = xml.parse().unwrap();
+/// let activity = &tcx.activities.activity[0];
+/// assert_eq!(activity.sport, "Running");
+/// assert_eq!(activity.laps[0].distance_meters, Some(5000.0));
+///
+/// let point = &activity.laps[0].track.as_ref().unwrap().trackpoints[0];
+/// assert_eq!(point.position.as_ref().unwrap().latitude_degrees, 35.6812);
+/// assert_eq!(point.heart_rate_bpm.as_ref().unwrap().value, 98);
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Tcx {
+    /// Activities in the document
+    #[serde(rename = "Activities")]
+    pub activities: TcxActivities,
+}
+
+impl FromStr for Tcx {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        quick_xml::de::from_str(s).map_err(|e| format!("TCX parsing error: {}", e))
+    }
+}
+
+/// List of activities in a TCX document
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TcxActivities {
+    /// Activities in the document
+    #[serde(rename = "Activity", default)]
+    pub activity: Vec<TcxActivity>,
+}
+
+/// Single activity
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TcxActivity {
+    /// Sport of the activity (e.g. "Running", "Biking", "Other")
+    #[serde(rename = "@Sport")]
+    pub sport: String,
+    /// Start time of the activity, used as its identifier
+    #[serde(rename = "Id")]
+    pub id: String,
+    /// Laps of the activity
+    #[serde(rename = "Lap", default)]
+    pub laps: Vec<TcxLap>,
+}
+
+/// Single lap of an activity
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TcxLap {
+    /// Start time of the lap
+    #[serde(rename = "@StartTime")]
+    pub start_time: String,
+    /// Duration of the lap in seconds
+    #[serde(rename = "TotalTimeSeconds")]
+    pub total_time_seconds: f64,
+    /// Distance covered in the lap in meters
+    #[serde(rename = "DistanceMeters")]
+    pub distance_meters: Option<f64>,
+    /// Calories burned in the lap
+    #[serde(rename = "Calories")]
+    pub calories: Option<i32>,
+    /// Recorded track of the lap
+    #[serde(rename = "Track")]
+    pub track: Option<TcxTrack>,
+}
+
+/// Recorded track
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TcxTrack {
+    /// Track points in chronological order
+    #[serde(rename = "Trackpoint", default)]
+    pub trackpoints: Vec<TcxTrackpoint>,
+}
+
+/// Single track point
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TcxTrackpoint {
+    /// Time of the track point
+    #[serde(rename = "Time")]
+    pub time: String,
+    /// GPS position, absent when no fix was available
+    #[serde(rename = "Position")]
+    pub position: Option<TcxPosition>,
+    /// Altitude in meters
+    #[serde(rename = "AltitudeMeters")]
+    pub altitude_meters: Option<f64>,
+    /// Distance covered since the start of the activity in meters
+    #[serde(rename = "DistanceMeters")]
+    pub distance_meters: Option<f64>,
+    /// Heart rate at the track point
+    #[serde(rename = "HeartRateBpm")]
+    pub heart_rate_bpm: Option<TcxHeartRate>,
+}
+
+/// GPS position
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TcxPosition {
+    /// Latitude in degrees
+    #[serde(rename = "LatitudeDegrees")]
+    pub latitude_degrees: f64,
+    /// Longitude in degrees
+    #[serde(rename = "LongitudeDegrees")]
+    pub longitude_degrees: f64,
+}
+
+/// Heart rate measurement
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TcxHeartRate {
+    /// Heart rate in beats per minute
+    #[serde(rename = "Value")]
+    pub value: i32,
+}
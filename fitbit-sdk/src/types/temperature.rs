@@ -0,0 +1,108 @@
+//! Temperature API Types
+//!
+//! This module contains the types and functions for the Fitbit Temperature API.
+//!
+use crate::types::auth::Scope;
+use crate::types::datetime::{ApiDate, ApiDateTime};
+use crate::types::error::{FitbitError, domain_error};
+use crate::types::user::UserId;
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use thiserror::Error;
+
+/// Error types for the Temperature API
+#[derive(Debug, Error)]
+#[error(transparent)]
+pub struct TemperatureError(#[from] pub FitbitError);
+
+domain_error!(TemperatureError, scopes [Scope::Temperature]);
+
+#[async_trait]
+pub trait TemperatureClient {
+    async fn get_skin_temperature<'a>(
+        &'a self,
+        user_id: impl Into<UserId> + Send,
+        date: &'a str,
+    ) -> Result<Vec<SkinTemperature>, TemperatureError>;
+
+    async fn get_skin_temperature_range<'a>(
+        &'a self,
+        user_id: impl Into<UserId> + Send,
+        start_date: &'a str,
+        end_date: &'a str,
+    ) -> Result<Vec<SkinTemperature>, TemperatureError>;
+
+    async fn get_core_temperature<'a>(
+        &'a self,
+        user_id: impl Into<UserId> + Send,
+        date: &'a str,
+    ) -> Result<Vec<CoreTemperature>, TemperatureError>;
+
+    async fn get_core_temperature_range<'a>(
+        &'a self,
+        user_id: impl Into<UserId> + Send,
+        start_date: &'a str,
+        end_date: &'a str,
+    ) -> Result<Vec<CoreTemperature>, TemperatureError>;
+}
+
+/// Skin temperature measured during the main sleep of a single day
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SkinTemperature {
+    /// Date of the main sleep the temperature was measured during
+    #[serde(rename = "dateTime", with = "crate::types::datetime::api_field")]
+    pub date_time: ApiDate,
+    /// Skin temperature values for the night
+    pub value: SkinTemperatureValue,
+    /// Type of sensor that recorded the temperature (e.g. "dedicated_temp_sensor")
+    #[serde(rename = "logType")]
+    pub log_type: String,
+    /// Fields of the response not modeled above
+    #[serde(flatten, deserialize_with = "crate::types::extra::deserialize")]
+    pub extra: Map<String, Value>,
+}
+
+/// Nightly skin temperature values
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SkinTemperatureValue {
+    /// Variation from the user's baseline skin temperature in degrees
+    #[serde(rename = "nightlyRelative")]
+    pub nightly_relative: f64,
+    /// Fields of the response not modeled above
+    #[serde(flatten, deserialize_with = "crate::types::extra::deserialize")]
+    pub extra: Map<String, Value>,
+}
+
+/// Core temperature logged by the user
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CoreTemperature {
+    /// Date and time of the measurement
+    #[serde(rename = "dateTime", with = "crate::types::datetime::api_field")]
+    pub date_time: ApiDateTime,
+    /// Core temperature in the user's temperature unit
+    pub value: f64,
+    /// Fields of the response not modeled above
+    #[serde(flatten, deserialize_with = "crate::types::extra::deserialize")]
+    pub extra: Map<String, Value>,
+}
+
+/// Response wrapper for skin temperature
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SkinTemperatureResponse {
+    #[serde(rename = "tempSkin")]
+    pub temp_skin: Vec<SkinTemperature>,
+    /// Fields of the response not modeled above
+    #[serde(flatten, deserialize_with = "crate::types::extra::deserialize")]
+    pub extra: Map<String, Value>,
+}
+
+/// Response wrapper for core temperature
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CoreTemperatureResponse {
+    #[serde(rename = "tempCore")]
+    pub temp_core: Vec<CoreTemperature>,
+    /// Fields of the response not modeled above
+    #[serde(flatten, deserialize_with = "crate::types::extra::deserialize")]
+    pub extra: Map<String, Value>,
+}
@@ -0,0 +1,105 @@
+//! Time Series Types
+//!
+//! This module contains the types shared by the Fitbit daily time series endpoints.
+//!
+use crate::types::datetime::{ApiDate, ApiField};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// Single data point of a daily time series
+///
+/// The Fitbit API returns time series values as strings. The value is parsed
+/// into `T` during deserialization, and the string as received is kept in
+/// `raw_value` for values that lose precision or formatting when parsed.
+///
+/// # Examples
+///
+/// ```
+/// use fitbit_sdk::types::datetime::ApiField;
+/// use fitbit_sdk::types::time_series::TimeSeriesPoint;
+///
+/// let point: TimeSeriesPoint<i64> =
+///     serde_json::from_str(r#"{"dateTime": "2025-01-15", "value": "10234"}"#).unwrap();
+/// assert_eq!(point.date_time.to_api_string(), "2025-01-15");
+/// assert_eq!(point.value, 10234);
+/// assert_eq!(point.raw_value, "10234");
+///
+/// // Numbers are accepted as well as strings
+/// let point: TimeSeriesPoint<f64> =
+///     serde_json::from_str(r#"{"dateTime": "2025-01-15", "value": 6.52}"#).unwrap();
+/// assert_eq!(point.value, 6.52);
+///
+/// let json = serde_json::to_string(&point).unwrap();
+/// assert_eq!(json, r#"{"dateTime":"2025-01-15","value":"6.52"}"#);
+/// assert_eq!(serde_json::from_str::<TimeSeriesPoint<f64>>(&json).unwrap(), point);
+///
+/// assert!(serde_json::from_str::<TimeSeriesPoint<i64>>(r#"{"dateTime": "2025-01-15", "value": "n/a"}"#).is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct TimeSeriesPoint<T> {
+    /// Date for the data point
+    pub date_time: ApiDate,
+    /// Parsed value for the data point
+    pub value: T,
+    /// Value for the data point as returned by the API
+    pub raw_value: String,
+}
+
+impl<T> Serialize for TimeSeriesPoint<T> {
+    /// Serializes the point with its raw value, as sent by the Fitbit API
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        RawPointRef {
+            date_time: &self.date_time.to_api_string(),
+            value: &self.raw_value,
+        }
+        .serialize(serializer)
+    }
+}
+
+/// Value of a time series data point as it appears on the wire
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum RawValue {
+    String(String),
+    Number(serde_json::Number),
+}
+
+#[derive(Serialize)]
+struct RawPointRef<'a> {
+    #[serde(rename = "dateTime")]
+    date_time: &'a str,
+    value: &'a str,
+}
+
+#[derive(Deserialize)]
+#[cfg_attr(feature = "strict-serde", serde(deny_unknown_fields))]
+struct RawPoint {
+    #[serde(rename = "dateTime")]
+    date_time: String,
+    value: RawValue,
+}
+
+impl<'de, T> Deserialize<'de> for TimeSeriesPoint<T>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let point = RawPoint::deserialize(deserializer)?;
+        let raw_value = match point.value {
+            RawValue::String(value) => value,
+            RawValue::Number(value) => value.to_string(),
+        };
+        let value = raw_value.parse().map_err(|e| {
+            serde::de::Error::custom(format!("Invalid time series value '{}': {}", raw_value, e))
+        })?;
+        let date_time =
+            ApiDate::from_api_str(&point.date_time).map_err(serde::de::Error::custom)?;
+        Ok(TimeSeriesPoint {
+            date_time,
+            value,
+            raw_value,
+        })
+    }
+}
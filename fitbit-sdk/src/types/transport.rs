@@ -0,0 +1,176 @@
+//! Transport Types
+//!
+//! This module contains the types abstracting how `FitbitClient` sends HTTP requests.
+//!
+use async_trait::async_trait;
+use bytes::Bytes;
+use futures_util::stream::{self, Stream, TryStreamExt};
+use reqwest::StatusCode;
+use reqwest::header::HeaderMap;
+use std::borrow::Cow;
+use std::fmt;
+use std::pin::Pin;
+use std::sync::Arc;
+use thiserror::Error;
+
+/// Error types for sending a request over a transport
+#[derive(Debug, Error)]
+pub enum TransportError {
+    #[error("API request failed: {0}")]
+    RequestFailed(String),
+    #[error("API error: {0}")]
+    ApiError(String),
+}
+
+impl From<String> for TransportError {
+    fn from(error: String) -> Self {
+        TransportError::ApiError(error)
+    }
+}
+
+/// HTTP response as received by a transport
+#[derive(Debug, Clone)]
+pub struct TransportResponse {
+    /// HTTP status of the response
+    pub status: StatusCode,
+    /// Headers of the response
+    pub headers: HeaderMap,
+    /// Body of the response, as received
+    pub body: Bytes,
+}
+
+impl TransportResponse {
+    /// Returns the body as text, replacing invalid UTF-8 sequences
+    pub fn text(&self) -> Cow<'_, str> {
+        String::from_utf8_lossy(&self.body)
+    }
+
+    /// Returns the body to parse as JSON
+    ///
+    /// Deletions reply `204 No Content` and other endpoints may return an
+    /// empty body; it is parsed as `null` so that callers can deserialize it into `()`.
+    pub(crate) fn json_body(&self) -> Cow<'_, str> {
+        if self.is_empty() {
+            Cow::Borrowed("null")
+        } else {
+            self.text()
+        }
+    }
+
+    /// Whether the response has no body, e.g. `204 No Content`
+    pub(crate) fn is_empty(&self) -> bool {
+        self.status == StatusCode::NO_CONTENT || self.body.trim_ascii().is_empty()
+    }
+}
+
+/// Body of a response received as a stream of chunks
+pub type BodyStream = Pin<Box<dyn Stream<Item = Result<Bytes, TransportError>> + Send>>;
+
+/// HTTP response whose body is received as a stream of chunks
+///
+/// Returned by `Transport::send_streaming`, for responses too large to
+/// buffer, such as a full day of 1-second intraday heart rate.
+pub struct StreamingResponse {
+    /// HTTP status of the response
+    pub status: StatusCode,
+    /// Headers of the response
+    pub headers: HeaderMap,
+    /// Chunks of the body, in the order they are received
+    pub body: BodyStream,
+}
+
+impl StreamingResponse {
+    /// Receives the rest of the body, e.g. to read an error response
+    pub async fn collect(self) -> Result<TransportResponse, TransportError> {
+        let chunks: Vec<Bytes> = self.body.try_collect().await?;
+        Ok(TransportResponse {
+            status: self.status,
+            headers: self.headers,
+            body: chunks.concat().into(),
+        })
+    }
+}
+
+impl From<TransportResponse> for StreamingResponse {
+    fn from(response: TransportResponse) -> Self {
+        Self {
+            status: response.status,
+            headers: response.headers,
+            body: Box::pin(stream::once(async move { Ok(response.body) })),
+        }
+    }
+}
+
+impl fmt::Debug for StreamingResponse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("StreamingResponse")
+            .field("status", &self.status)
+            .field("headers", &self.headers)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Sends the HTTP requests built by `FitbitClient`
+///
+/// The client builds every request (URL, query, headers and body) and hands
+/// it to the transport, which returns the response as received. The default
+/// transport sends requests with reqwest; `MockTransport` serves canned
+/// responses instead, for testing code built on the client without a network.
+///
+/// # Examples
+///
+/// ```no_run
+/// use async_trait::async_trait;
+/// use fitbit_sdk::client::FitbitClient;
+/// use fitbit_sdk::transport::ReqwestTransport;
+/// use fitbit_sdk::types::transport::{Transport, TransportError, TransportResponse};
+/// use fitbit_sdk::types::user::UserError;
+///
+/// struct LoggingTransport(ReqwestTransport);
+///
+/// #[async_trait]
+/// impl Transport for LoggingTransport {
+///     async fn send(&self, request: reqwest::Request) -> Result<TransportResponse, TransportError> {
+///         println!("{} {}", request.method(), request.url());
+///         self.0.send(request).await
+///     }
+/// }
+///
+/// # fn example() -> Result<(), UserError> {
+/// let client = FitbitClient::builder()
+///     .with_transport(LoggingTransport(ReqwestTransport::default()))
+///     .build()?;
+/// # Ok(())
+/// # }
+/// ```
+#[async_trait]
+pub trait Transport: Send + Sync {
+    /// Sends `request` and returns the response, whatever its status
+    async fn send(&self, request: reqwest::Request) -> Result<TransportResponse, TransportError>;
+
+    /// Sends `request` and returns the response with its body as a stream of chunks
+    ///
+    /// The default implementation receives the whole body with `send` and
+    /// returns it as a single chunk; transports able to stream the body
+    /// override it.
+    async fn send_streaming(
+        &self,
+        request: reqwest::Request,
+    ) -> Result<StreamingResponse, TransportError> {
+        self.send(request).await.map(StreamingResponse::from)
+    }
+}
+
+#[async_trait]
+impl<T: Transport + ?Sized> Transport for Arc<T> {
+    async fn send(&self, request: reqwest::Request) -> Result<TransportResponse, TransportError> {
+        (**self).send(request).await
+    }
+
+    async fn send_streaming(
+        &self,
+        request: reqwest::Request,
+    ) -> Result<StreamingResponse, TransportError> {
+        (**self).send_streaming(request).await
+    }
+}
@@ -2,59 +2,137 @@
 //!
 //! This module contains the types and functions for the Fitbit User API.
 //!
+use crate::types::auth::Scope;
+use crate::types::datetime::ApiDate;
+use crate::types::error::{FitbitError, domain_error};
+#[cfg(feature = "user")]
 use async_trait::async_trait;
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::{Map, Value};
+use std::str::FromStr;
 use thiserror::Error;
-use time::Date;
 
 /// Error types for the User API
 #[derive(Debug, Error)]
-pub enum UserError {
-    #[error("API request failed: {0}")]
-    RequestFailed(String),
-    #[error("API error: {0}")]
-    ApiError(String),
-}
+#[error(transparent)]
+pub struct UserError(#[from] pub FitbitError);
 
-impl From<String> for UserError {
-    fn from(error: String) -> Self {
-        UserError::ApiError(error)
-    }
-}
+domain_error!(UserError, scopes [Scope::Profile]);
 
+#[cfg(feature = "user")]
 #[async_trait]
 pub trait UserClient {
-    async fn get_profile<'a>(&'a self, user_id: &'a str) -> Result<UserProfile, UserError>;
+    async fn get_profile<'a>(&'a self, user_id: impl Into<UserId> + Send) -> Result<UserProfile, UserError>;
     async fn update_profile<'a>(&'a self, params: &'a UpdateProfileParams) -> Result<UserProfile, UserError>;
+    async fn get_badges<'a>(&'a self, user_id: impl Into<UserId> + Send) -> Result<Vec<Badge>, UserError>;
+}
+
+/// ID of the user a request is made for
+///
+/// Endpoints accept any `impl Into<UserId>`, so "-" and encoded user IDs can be
+/// passed as strings. Characters that are not alphanumeric are percent-encoded
+/// when the ID is written into a request path.
+///
+/// # Examples
+///
+/// ```
+/// use fitbit_sdk::types::user::UserId;
+///
+/// assert_eq!(UserId::from("-"), UserId::Current);
+/// assert_eq!(UserId::Current.to_string(), "-");
+/// assert_eq!(UserId::from("228TQ4").to_string(), "228TQ4");
+/// assert_eq!(UserId::from("../profile").to_string(), "%2E%2E%2Fprofile");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum UserId {
+    /// The user the access token belongs to
+    #[default]
+    Current,
+    /// The encoded ID of a specific user
+    Encoded(String),
+}
+
+impl From<&str> for UserId {
+    fn from(user_id: &str) -> Self {
+        match user_id {
+            "-" => UserId::Current,
+            _ => UserId::Encoded(user_id.to_string()),
+        }
+    }
+}
+
+impl From<String> for UserId {
+    fn from(user_id: String) -> Self {
+        match user_id.as_str() {
+            "-" => UserId::Current,
+            _ => UserId::Encoded(user_id),
+        }
+    }
+}
+
+impl From<&UserId> for UserId {
+    fn from(user_id: &UserId) -> Self {
+        user_id.clone()
+    }
+}
+
+impl std::fmt::Display for UserId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            UserId::Current => f.write_str("-"),
+            UserId::Encoded(user_id) => {
+                for byte in user_id.bytes() {
+                    if byte.is_ascii_alphanumeric() {
+                        write!(f, "{}", byte as char)?;
+                    } else {
+                        write!(f, "%{:02X}", byte)?;
+                    }
+                }
+                Ok(())
+            }
+        }
+    }
 }
 
 /// User profile information
-#[derive(Debug, Deserialize)]
+///
+/// Personal fields are only returned when the access token grants the
+/// `profile` scope and the user's privacy settings share them, e.g. the
+/// profile of a friend may omit everything but the display name and avatars.
+/// These fields are `None` when they are not returned.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct UserProfile {
     /// First and last name of the user
-    #[serde(rename = "fullName")]
-    pub full_name: String,
+    #[serde(rename = "fullName", default)]
+    pub full_name: Option<String>,
     /// Display name for the user within the Fitbit UI
     #[serde(rename = "displayName")]
     pub display_name: String,
     /// User's date of birth
-    #[serde(rename = "dateOfBirth")]
-    pub date_of_birth: String,
+    #[serde(
+        rename = "dateOfBirth",
+        default,
+        with = "crate::types::datetime::option_api_field"
+    )]
+    pub date_of_birth: Option<ApiDate>,
     /// Gender assigned to the user on the Fitbit website profile
-    pub gender: Gender,
+    #[serde(default)]
+    pub gender: Option<Gender>,
     /// Length units for the user (METRIC or US)
     #[serde(rename = "heightUnit")]
     pub height_unit: HeightUnit,
     /// Weight units for the user (METRIC or US)
     #[serde(rename = "weightUnit")]
     pub weight_unit: WeightUnit,
-    /// Height for the user in the format X'Y" or decimal
-    pub height: String,
+    /// Height of the user in their height unit
+    #[serde(default)]
+    pub height: Option<f64>,
     /// The Weight of the user in their default unit
+    #[serde(default)]
     pub weight: Option<f64>,
     /// The average number of daily steps the user takes
-    #[serde(rename = "averageDailySteps")]
-    pub average_daily_steps: i32,
+    #[serde(rename = "averageDailySteps", default)]
+    pub average_daily_steps: Option<i32>,
     /// The user's avatar image URL
     pub avatar: String,
     /// The user's avatar image URL (medium)
@@ -63,10 +141,175 @@ pub struct UserProfile {
     /// The user's avatar image URL (big)
     #[serde(rename = "avatar640")]
     pub avatar640: String,
+    /// Encoded ID of the user
+    #[serde(rename = "encodedId", default)]
+    pub encoded_id: Option<String>,
+    /// First name of the user
+    #[serde(rename = "firstName", default)]
+    pub first_name: Option<String>,
+    /// Last name of the user
+    #[serde(rename = "lastName", default)]
+    pub last_name: Option<String>,
+    /// Which name is shown as display name (e.g. "name", "username")
+    #[serde(rename = "displayNameSetting", default)]
+    pub display_name_setting: Option<String>,
+    /// Text the user wrote about themselves
+    #[serde(rename = "aboutMe", default)]
+    pub about_me: Option<String>,
+    /// Age of the user in years
+    #[serde(default)]
+    pub age: Option<i32>,
+    /// Country of the user as a two-letter code
+    #[serde(default)]
+    pub country: Option<String>,
+    /// State of the user, for users in the US
+    #[serde(default)]
+    pub state: Option<String>,
+    /// City of the user
+    #[serde(default)]
+    pub city: Option<String>,
+    /// Date the user joined Fitbit
+    #[serde(
+        rename = "memberSince",
+        default,
+        with = "crate::types::datetime::option_api_field"
+    )]
+    pub member_since: Option<ApiDate>,
+    /// Time zone of the user (e.g. "Europe/London")
+    #[serde(default)]
+    pub timezone: Option<String>,
+    /// Offset of the user's time zone from UTC, in milliseconds
+    #[serde(rename = "offsetFromUTCMillis", default)]
+    pub offset_from_utc_millis: Option<i64>,
+    /// Locale of the user (e.g. "en_GB")
+    #[serde(default)]
+    pub locale: Option<String>,
+    /// Locale of the Fitbit UI language
+    #[serde(rename = "languageLocale", default)]
+    pub language_locale: Option<String>,
+    /// Locale of the food database
+    #[serde(rename = "foodsLocale", default)]
+    pub foods_locale: Option<String>,
+    /// Distance units for the user (METRIC or US)
+    #[serde(rename = "distanceUnit", default)]
+    pub distance_unit: Option<String>,
+    /// Glucose units for the user (METRIC or US)
+    #[serde(rename = "glucoseUnit", default)]
+    pub glucose_unit: Option<String>,
+    /// Swimming units for the user (METRIC or US)
+    #[serde(rename = "swimUnit", default)]
+    pub swim_unit: Option<String>,
+    /// Temperature units for the user (METRIC or US)
+    #[serde(rename = "temperatureUnit", default)]
+    pub temperature_unit: Option<String>,
+    /// Water units for the user (METRIC or US)
+    #[serde(rename = "waterUnit", default)]
+    pub water_unit: Option<String>,
+    /// Name of the water unit (e.g. "ml", "fl oz")
+    #[serde(rename = "waterUnitName", default)]
+    pub water_unit_name: Option<String>,
+    /// Whether the stride lengths are calculated automatically
+    #[serde(rename = "autoStrideEnabled", default)]
+    pub auto_stride_enabled: Option<bool>,
+    /// Walking stride length of the user in their height unit
+    #[serde(rename = "strideLengthWalking", default)]
+    pub stride_length_walking: Option<f64>,
+    /// How the walking stride length is set (e.g. "auto", "manual")
+    #[serde(rename = "strideLengthWalkingType", default)]
+    pub stride_length_walking_type: Option<String>,
+    /// Running stride length of the user in their height unit
+    #[serde(rename = "strideLengthRunning", default)]
+    pub stride_length_running: Option<f64>,
+    /// How the running stride length is set (e.g. "auto", "manual")
+    #[serde(rename = "strideLengthRunningType", default)]
+    pub stride_length_running_type: Option<String>,
+    /// Sensitivity of the sleep tracking
+    #[serde(rename = "sleepTracking", default)]
+    pub sleep_tracking: Option<SleepTracking>,
+    /// Format the clock of the user's devices is shown in
+    #[serde(rename = "clockTimeDisplayFormat", default)]
+    pub clock_time_display_format: Option<ClockTimeFormat>,
+    /// First day of the user's week
+    #[serde(rename = "startDayOfWeek", default)]
+    pub start_day_of_week: Option<StartDayOfWeek>,
+    /// Whether the user is a Fitbit ambassador
+    #[serde(default)]
+    pub ambassador: Option<bool>,
+    /// Whether the user takes part in the challenges beta
+    #[serde(rename = "challengesBeta", default)]
+    pub challenges_beta: Option<bool>,
+    /// Whether the user belongs to a corporate program
+    #[serde(default)]
+    pub corporate: Option<bool>,
+    /// Whether the user administers a corporate program
+    #[serde(rename = "corporateAdmin", default)]
+    pub corporate_admin: Option<bool>,
+    /// Whether the account is a child account
+    #[serde(rename = "isChild", default)]
+    pub is_child: Option<bool>,
+    /// Whether the user is a coach
+    #[serde(rename = "isCoach", default)]
+    pub is_coach: Option<bool>,
+    /// Whether the user can submit bug reports
+    #[serde(rename = "isBugReportEnabled", default)]
+    pub is_bug_report_enabled: Option<bool>,
+    /// Whether the user has to accept updated legal terms
+    #[serde(rename = "legalTermsAcceptRequired", default)]
+    pub legal_terms_accept_required: Option<bool>,
+    /// Whether multi-factor authentication is enabled
+    #[serde(rename = "mfaEnabled", default)]
+    pub mfa_enabled: Option<bool>,
+    /// Whether the user is a registered SDK developer
+    #[serde(rename = "sdkDeveloper", default)]
+    pub sdk_developer: Option<bool>,
+    /// Features enabled for the user
+    #[serde(default)]
+    pub features: Option<ProfileFeatures>,
+    /// Badges shown on the user's profile
+    #[serde(rename = "topBadges", default)]
+    pub top_badges: Vec<Badge>,
+    /// Fields of the profile not modeled above
+    #[serde(flatten, deserialize_with = "crate::types::extra::deserialize")]
+    pub extra: Map<String, Value>,
+}
+
+/// Features enabled for a user
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ProfileFeatures {
+    /// Whether the user can set exercise goals
+    #[serde(rename = "exerciseGoal", default)]
+    pub exercise_goal: bool,
+    /// Fields of the response not modeled above
+    #[serde(flatten, deserialize_with = "crate::types::extra::deserialize")]
+    pub extra: Map<String, Value>,
+}
+
+/// Sensitivity of the sleep tracking
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SleepTracking {
+    Normal,
+    Sensitive,
+}
+
+/// Format of the clock on the user's devices
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ClockTimeFormat {
+    #[serde(rename = "12hour")]
+    TwelveHour,
+    #[serde(rename = "24hour")]
+    TwentyFourHour,
+}
+
+/// First day of the week
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum StartDayOfWeek {
+    Sunday,
+    Monday,
 }
 
 /// Gender enumeration
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "UPPERCASE")]
 pub enum Gender {
     Male,
@@ -75,7 +318,7 @@ pub enum Gender {
 }
 
 /// Height unit enumeration
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "UPPERCASE")]
 pub enum HeightUnit {
     Metric,
@@ -83,7 +326,7 @@ pub enum HeightUnit {
 }
 
 /// Weight unit enumeration
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "UPPERCASE")]
 pub enum WeightUnit {
     Metric,
@@ -91,22 +334,27 @@ pub enum WeightUnit {
 }
 
 /// Response wrapper for user profile
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct UserProfileResponse {
     pub user: UserProfile,
+    /// Fields of the response not modeled above
+    #[serde(flatten, deserialize_with = "crate::types::extra::deserialize")]
+    pub extra: Map<String, Value>,
 }
 
 /// Parameters for updating user profile
+///
+/// Only the fields that are set are sent, as form parameters.
 #[derive(Debug, Serialize, Default)]
 pub struct UpdateProfileParams {
     /// First and last name of the user
-    #[serde(rename = "fullName", skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "fullname", skip_serializing_if = "Option::is_none")]
     pub full_name: Option<String>,
     /// Display name for the user within the Fitbit UI
     #[serde(rename = "displayName", skip_serializing_if = "Option::is_none")]
     pub display_name: Option<String>,
-    /// User's date of birth
-    #[serde(rename = "dateOfBirth", skip_serializing_if = "Option::is_none")]
+    /// User's date of birth in format YYYY-MM-DD
+    #[serde(rename = "birthday", skip_serializing_if = "Option::is_none")]
     pub date_of_birth: Option<String>,
     /// Gender assigned to the user on the Fitbit website profile
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -117,9 +365,54 @@ pub struct UpdateProfileParams {
     /// Weight units for the user (METRIC or US)
     #[serde(rename = "weightUnit", skip_serializing_if = "Option::is_none")]
     pub weight_unit: Option<WeightUnit>,
-    /// Height for the user in the format X'Y" or decimal
+    /// Water units for the user (METRIC or US)
+    #[serde(rename = "waterUnit", skip_serializing_if = "Option::is_none")]
+    pub water_unit: Option<String>,
+    /// Glucose units for the user (METRIC or US)
+    #[serde(rename = "glucoseUnit", skip_serializing_if = "Option::is_none")]
+    pub glucose_unit: Option<String>,
+    /// Height of the user in their height unit
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub height: Option<f64>,
+    /// Text the user wrote about themselves
+    #[serde(rename = "aboutMe", skip_serializing_if = "Option::is_none")]
+    pub about_me: Option<String>,
+    /// Country of the user as a two-letter code
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub country: Option<String>,
+    /// State of the user, for users in the US
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state: Option<String>,
+    /// City of the user
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub city: Option<String>,
+    /// Walking stride length of the user in their height unit
+    #[serde(rename = "strideLengthWalking", skip_serializing_if = "Option::is_none")]
+    pub stride_length_walking: Option<f64>,
+    /// Running stride length of the user in their height unit
+    #[serde(rename = "strideLengthRunning", skip_serializing_if = "Option::is_none")]
+    pub stride_length_running: Option<f64>,
+    /// Time zone of the user (e.g. "Europe/London")
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timezone: Option<String>,
+    /// Locale of the food database (e.g. "en_GB")
+    #[serde(rename = "foodsLocale", skip_serializing_if = "Option::is_none")]
+    pub foods_locale: Option<String>,
+    /// Locale of the user (e.g. "en_GB")
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub height: Option<String>,
+    pub locale: Option<String>,
+    /// Language of the user's locale (e.g. "en")
+    #[serde(rename = "localeLang", skip_serializing_if = "Option::is_none")]
+    pub locale_lang: Option<String>,
+    /// Country of the user's locale (e.g. "GB")
+    #[serde(rename = "localeCountry", skip_serializing_if = "Option::is_none")]
+    pub locale_country: Option<String>,
+    /// First day of the user's week
+    #[serde(rename = "startDayOfWeek", skip_serializing_if = "Option::is_none")]
+    pub start_day_of_week: Option<StartDayOfWeek>,
+    /// Format the clock of the user's devices is shown in
+    #[serde(rename = "clockTimeDisplayFormat", skip_serializing_if = "Option::is_none")]
+    pub clock_time_display_format: Option<ClockTimeFormat>,
 }
 
 impl UpdateProfileParams {
@@ -164,9 +457,311 @@ impl UpdateProfileParams {
         self
     }
 
-    /// Set the height
-    pub fn with_height(mut self, height: impl Into<String>) -> Self {
-        self.height = Some(height.into());
+    /// Set the water unit
+    pub fn with_water_unit(mut self, water_unit: impl Into<String>) -> Self {
+        self.water_unit = Some(water_unit.into());
         self
     }
+
+    /// Set the glucose unit
+    pub fn with_glucose_unit(mut self, glucose_unit: impl Into<String>) -> Self {
+        self.glucose_unit = Some(glucose_unit.into());
+        self
+    }
+
+    /// Set the height, in the user's height unit
+    pub fn with_height(mut self, height: f64) -> Self {
+        self.height = Some(height);
+        self
+    }
+
+    /// Set the text about the user
+    pub fn with_about_me(mut self, about_me: impl Into<String>) -> Self {
+        self.about_me = Some(about_me.into());
+        self
+    }
+
+    /// Set the country, as a two-letter code
+    pub fn with_country(mut self, country: impl Into<String>) -> Self {
+        self.country = Some(country.into());
+        self
+    }
+
+    /// Set the state, for users in the US
+    pub fn with_state(mut self, state: impl Into<String>) -> Self {
+        self.state = Some(state.into());
+        self
+    }
+
+    /// Set the city
+    pub fn with_city(mut self, city: impl Into<String>) -> Self {
+        self.city = Some(city.into());
+        self
+    }
+
+    /// Set the walking stride length, in the user's height unit
+    pub fn with_stride_length_walking(mut self, stride_length: f64) -> Self {
+        self.stride_length_walking = Some(stride_length);
+        self
+    }
+
+    /// Set the running stride length, in the user's height unit
+    pub fn with_stride_length_running(mut self, stride_length: f64) -> Self {
+        self.stride_length_running = Some(stride_length);
+        self
+    }
+
+    /// Set the time zone
+    pub fn with_timezone(mut self, timezone: impl Into<String>) -> Self {
+        self.timezone = Some(timezone.into());
+        self
+    }
+
+    /// Set the locale of the food database
+    pub fn with_foods_locale(mut self, foods_locale: impl Into<String>) -> Self {
+        self.foods_locale = Some(foods_locale.into());
+        self
+    }
+
+    /// Set the locale, e.g. "en_GB", and its language and country parts
+    pub fn with_locale(mut self, locale: impl Into<String>) -> Self {
+        let locale = locale.into();
+        if let Some((lang, country)) = locale.split_once('_') {
+            self.locale_lang = Some(lang.to_string());
+            self.locale_country = Some(country.to_string());
+        }
+        self.locale = Some(locale);
+        self
+    }
+
+    /// Set the first day of the week
+    pub fn with_start_day_of_week(mut self, start_day_of_week: StartDayOfWeek) -> Self {
+        self.start_day_of_week = Some(start_day_of_week);
+        self
+    }
+
+    /// Set the clock format of the user's devices
+    pub fn with_clock_time_display_format(mut self, format: ClockTimeFormat) -> Self {
+        self.clock_time_display_format = Some(format);
+        self
+    }
+}
+
+/// Badge earned by the user
+///
+/// # Examples
+///
+/// ```
+/// use fitbit_sdk::types::datetime::ApiField;
+/// use fitbit_sdk::types::user::{Badge, BadgeCategory, BadgeColor};
+///
+/// let json = r#"{
+///     "badgeGradientEndColor": "00D3D6",
+///     "badgeGradientStartColor": "007D3C",
+///     "badgeType": "DAILY_STEPS",
+///     "category": "Daily Steps",
+///     "dateTime": "2025-01-15",
+///     "description": "10,000 steps in a day",
+///     "earnedMessage": "Congrats on earning your first Sneakers badge!",
+///     "encodedId": "228TQ4",
+///     "image100px": "https://static0.fitbit.com/images/badges_new/100px/badge_daily_steps10k.png",
+///     "name": "Sneakers (10,000 steps in a day)",
+///     "shareText": "I took 10,000 steps and earned the Sneakers badge! #Fitbit",
+///     "shortName": "Sneakers",
+///     "timesAchieved": 42,
+///     "value": 10000
+/// }"#;
+///
+/// let badge: Badge = serde_json::from_str(json).unwrap();
+/// assert_eq!(badge.category, BadgeCategory::DailySteps);
+/// assert_eq!(badge.gradient_start_color, BadgeColor { red: 0x00, green: 0x7D, blue: 0x3C });
+/// assert_eq!(badge.gradient_end_color.to_string(), "#00D3D6");
+/// assert_eq!(badge.date_time.to_api_string(), "2025-01-15");
+///
+/// let json = serde_json::to_string(&badge).unwrap();
+/// assert_eq!(serde_json::from_str::<Badge>(&json).unwrap(), badge);
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Badge {
+    /// Type of the badge (e.g. "DAILY_STEPS", "LIFETIME_DISTANCE")
+    #[serde(rename = "badgeType")]
+    pub badge_type: String,
+    /// Category of the badge
+    pub category: BadgeCategory,
+    /// Date the badge was last earned
+    #[serde(rename = "dateTime", with = "crate::types::datetime::api_field")]
+    pub date_time: ApiDate,
+    /// Name of the badge
+    pub name: String,
+    /// Short name of the badge
+    #[serde(rename = "shortName")]
+    pub short_name: String,
+    /// Description of the achievement
+    pub description: String,
+    /// Message shown when the badge was earned
+    #[serde(rename = "earnedMessage")]
+    pub earned_message: Option<String>,
+    /// Text for sharing the badge
+    #[serde(rename = "shareText")]
+    pub share_text: Option<String>,
+    /// Encoded ID of the badge
+    #[serde(rename = "encodedId")]
+    pub encoded_id: String,
+    /// Number of times the badge was earned
+    #[serde(rename = "timesAchieved")]
+    pub times_achieved: i32,
+    /// Threshold value of the badge (e.g. steps or floors)
+    pub value: Option<f64>,
+    /// Unit of the value, if any
+    pub unit: Option<String>,
+    /// Start color of the badge gradient
+    #[serde(rename = "badgeGradientStartColor")]
+    pub gradient_start_color: BadgeColor,
+    /// End color of the badge gradient
+    #[serde(rename = "badgeGradientEndColor")]
+    pub gradient_end_color: BadgeColor,
+    /// Short description of the achievement (e.g. "25,000 steps")
+    #[serde(rename = "shortDescription")]
+    pub short_description: Option<String>,
+    /// Description of the achievement shown in the mobile app
+    #[serde(rename = "mobileDescription")]
+    pub mobile_description: Option<String>,
+    /// Promotional description of the achievement
+    #[serde(rename = "marketingDescription")]
+    pub marketing_description: Option<String>,
+    /// Badge image URL (50px)
+    #[serde(rename = "image50px")]
+    pub image_50px: Option<String>,
+    /// Badge image URL (75px)
+    #[serde(rename = "image75px")]
+    pub image_75px: Option<String>,
+    /// Badge image URL (100px)
+    #[serde(rename = "image100px")]
+    pub image_100px: Option<String>,
+    /// Badge image URL (125px)
+    #[serde(rename = "image125px")]
+    pub image_125px: Option<String>,
+    /// Badge image URL (300px)
+    #[serde(rename = "image300px")]
+    pub image_300px: Option<String>,
+    /// Image URL for sharing the badge (640px)
+    #[serde(rename = "shareImage640px")]
+    pub share_image_640px: Option<String>,
+    /// Cheers friends gave for the badge
+    #[serde(default)]
+    pub cheers: Vec<Value>,
+    /// Fields of the response not modeled above
+    #[serde(flatten, deserialize_with = "crate::types::extra::deserialize")]
+    pub extra: Map<String, Value>,
+}
+
+/// Category of a badge
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BadgeCategory {
+    DailySteps,
+    DailyClimb,
+    LifetimeDistance,
+    LifetimeClimb,
+    WeightGoal,
+    /// Category not known to this SDK
+    Other(String),
+}
+
+impl FromStr for BadgeCategory {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "Daily Steps" => BadgeCategory::DailySteps,
+            "Daily Climb" => BadgeCategory::DailyClimb,
+            "Lifetime Distance" => BadgeCategory::LifetimeDistance,
+            "Lifetime Climb" => BadgeCategory::LifetimeClimb,
+            "Weight Goal" => BadgeCategory::WeightGoal,
+            other => BadgeCategory::Other(other.to_string()),
+        })
+    }
+}
+
+impl BadgeCategory {
+    pub fn as_str(&self) -> &str {
+        match self {
+            BadgeCategory::DailySteps => "Daily Steps",
+            BadgeCategory::DailyClimb => "Daily Climb",
+            BadgeCategory::LifetimeDistance => "Lifetime Distance",
+            BadgeCategory::LifetimeClimb => "Lifetime Climb",
+            BadgeCategory::WeightGoal => "Weight Goal",
+            BadgeCategory::Other(other) => other,
+        }
+    }
+}
+
+impl Serialize for BadgeCategory {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for BadgeCategory {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        value.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+/// Color of a badge gradient
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BadgeColor {
+    pub red: u8,
+    pub green: u8,
+    pub blue: u8,
+}
+
+impl FromStr for BadgeColor {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let hex = s.trim_start_matches('#');
+        let channel = |i: usize| {
+            hex.get(i..i + 2)
+                .and_then(|c| u8::from_str_radix(c, 16).ok())
+                .ok_or_else(|| format!("Invalid badge color: {}", s))
+        };
+        if hex.len() != 6 {
+            return Err(format!("Invalid badge color: {}", s));
+        }
+        Ok(BadgeColor {
+            red: channel(0)?,
+            green: channel(2)?,
+            blue: channel(4)?,
+        })
+    }
+}
+
+impl std::fmt::Display for BadgeColor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "#{:02X}{:02X}{:02X}", self.red, self.green, self.blue)
+    }
+}
+
+impl Serialize for BadgeColor {
+    /// Serializes the color without the leading `#`, as sent by the Fitbit API
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&format_args!("{:02X}{:02X}{:02X}", self.red, self.green, self.blue))
+    }
+}
+
+impl<'de> Deserialize<'de> for BadgeColor {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        value.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+/// Response wrapper for badges
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BadgesResponse {
+    pub badges: Vec<Badge>,
+    /// Fields of the response not modeled above
+    #[serde(flatten, deserialize_with = "crate::types::extra::deserialize")]
+    pub extra: Map<String, Value>,
 }
@@ -0,0 +1,41 @@
+//! Webhook Types
+//!
+//! This module contains the types for consuming Fitbit subscription notifications.
+//!
+use crate::types::datetime::ApiDate;
+use crate::types::subscriptions::CollectionType;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use thiserror::Error;
+
+/// Error types for webhook handling
+#[derive(Debug, Error)]
+pub enum WebhookError {
+    #[error("Missing or invalid X-Fitbit-Signature")]
+    InvalidSignature,
+    #[error("Invalid notification payload: {0}")]
+    InvalidPayload(String),
+}
+
+/// Notification that a user's data in a collection has changed
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UpdateNotification {
+    /// Collection that changed
+    #[serde(rename = "collectionType")]
+    pub collection_type: CollectionType,
+    /// Date of the changed data, absent for account notifications
+    #[serde(default, with = "crate::types::datetime::option_api_field")]
+    pub date: Option<ApiDate>,
+    /// Encoded ID of the user whose data changed
+    #[serde(rename = "ownerId")]
+    pub owner_id: String,
+    /// Type of the owner (always "user")
+    #[serde(rename = "ownerType")]
+    pub owner_type: String,
+    /// ID the subscription was created with
+    #[serde(rename = "subscriptionId")]
+    pub subscription_id: String,
+    /// Fields of the response not modeled above
+    #[serde(flatten, deserialize_with = "crate::types::extra::deserialize")]
+    pub extra: Map<String, Value>,
+}
@@ -0,0 +1,78 @@
+//! Wellness Types
+//!
+//! This module contains the types for reports combining the daily health
+//! metrics of several API domains.
+//!
+use crate::types::breathing_rate::BreathingRateError;
+use crate::types::cardio_fitness::{CardioFitnessError, Vo2Max};
+use crate::types::error::{FitbitError, domain_error};
+use crate::types::heart_rate::HeartRateError;
+use crate::types::hrv::{HrvError, HrvSummaryValue};
+use crate::types::temperature::TemperatureError;
+use crate::types::user::UserId;
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use time::Date;
+
+/// Error types for wellness reports
+#[derive(Debug, Error)]
+#[error(transparent)]
+pub struct WellnessError(#[from] pub FitbitError);
+
+impl From<String> for WellnessError {
+    fn from(error: String) -> Self {
+        WellnessError(FitbitError::ApiError(error))
+    }
+}
+
+domain_error!(
+    WellnessError,
+    from [HrvError, HeartRateError, BreathingRateError, TemperatureError, CardioFitnessError],
+);
+
+/// Trait for reports combining several health metrics
+#[async_trait]
+pub trait WellnessClient {
+    async fn get_recovery_report<'a>(
+        &'a self,
+        user_id: impl Into<UserId> + Send,
+        start_date: Date,
+        end_date: Date,
+    ) -> Result<Vec<RecoveryDay>, WellnessError>;
+}
+
+/// Recovery metrics of a single day
+///
+/// Each metric is `None` when Fitbit did not calculate it for the day, e.g.
+/// when the tracker was not worn during sleep.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecoveryDay {
+    /// Date of the metrics
+    #[serde(with = "crate::types::datetime::api_field")]
+    pub date: Date,
+    /// Heart rate variability during the main sleep
+    pub hrv: Option<HrvSummaryValue>,
+    /// Resting heart rate in beats per minute
+    pub resting_heart_rate: Option<i32>,
+    /// Average breathing rate during sleep, in breaths per minute
+    pub breathing_rate: Option<f64>,
+    /// Skin temperature variation from the user's baseline, in degrees
+    pub skin_temperature: Option<f64>,
+    /// Estimated VO2 Max in mL/kg/min
+    pub vo2_max: Option<Vo2Max>,
+}
+
+impl RecoveryDay {
+    /// Creates a day without any metrics
+    pub fn new(date: Date) -> Self {
+        Self {
+            date,
+            hrv: None,
+            resting_heart_rate: None,
+            breathing_rate: None,
+            skin_temperature: None,
+            vo2_max: None,
+        }
+    }
+}
@@ -1,13 +1,16 @@
 //! User API
 //!
 //! This module contains the implementations for the Fitbit User API endpoints.
-//! It provides functionality for getting and updating user profile information.
+//! It provides functionality for getting and updating user profile information and getting badges.
 
 use crate::client::FitbitClient;
 use crate::types::user::{
-    UpdateProfileParams, UserClient, UserError, UserProfile, UserProfileResponse,
+    Badge, BadgesResponse, UpdateProfileParams, UserClient, UserError, UserId, UserProfile,
+    UserProfileResponse,
 };
+use crate::types::request::Body;
 use async_trait::async_trait;
+use reqwest::Method;
 
 #[async_trait]
 impl UserClient for FitbitClient {
@@ -49,15 +52,17 @@ impl UserClient for FitbitClient {
     ///     Ok(())
     /// }
     /// ```
-    async fn get_profile<'a>(&'a self, user_id: &'a str) -> Result<UserProfile, UserError> {
+    async fn get_profile<'a>(&'a self, user_id: impl Into<UserId> + Send) -> Result<UserProfile, UserError> {
+        let user_id: UserId = user_id.into();
         let path = format!("/user/{}/profile.json", user_id);
-        let response: UserProfileResponse = self.get(&path, Option::<&()>::None).await?;
+        let response: UserProfileResponse = self.get::<_, (), UserError>(&path, None).await?;
         Ok(response.user)
     }
 
     /// Updates the user's profile information
     ///
-    /// Updates the profile information for the authenticated user.
+    /// Updates the profile information for the authenticated user. Only the
+    /// fields set in `params` are changed.
     ///
     /// # Arguments
     ///
@@ -101,7 +106,54 @@ impl UserClient for FitbitClient {
         params: &'a UpdateProfileParams,
     ) -> Result<UserProfile, UserError> {
         let path = "/user/-/profile.json";
-        let response: UserProfileResponse = self.post(path, Some(params)).await?;
+        let response: UserProfileResponse = self
+            .send_request::<_, (), _, UserError>(Method::POST, path, None, Some(Body::Form(params)))
+            .await?;
         Ok(response.user)
     }
+
+    /// Gets the user's badges
+    ///
+    /// Retrieves the badges the user has earned. Badges of other users are only
+    /// returned if their privacy settings allow it.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The user ID to get badges for, or "-" for current user
+    ///
+    /// # Returns
+    ///
+    /// Returns the user's badges on success.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `UserError` if:
+    /// - The request fails to send
+    /// - The API returns an error response
+    /// - The response cannot be parsed
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use fitbit_sdk::client::FitbitClient;
+    /// use fitbit_sdk::types::user::{UserClient, UserError};
+    /// use tokio;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), UserError> {
+    ///     let client = FitbitClient::new::<UserError>()?;
+    ///
+    ///     for badge in client.get_badges("-").await? {
+    ///         println!("{} (earned {})", badge.short_name, badge.date_time);
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    async fn get_badges<'a>(&'a self, user_id: impl Into<UserId> + Send) -> Result<Vec<Badge>, UserError> {
+        let user_id: UserId = user_id.into();
+        let path = format!("/user/{}/badges.json", user_id);
+        let response: BadgesResponse = self.get::<_, (), UserError>(&path, None).await?;
+        Ok(response.badges)
+    }
 }
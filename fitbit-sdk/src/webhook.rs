@@ -0,0 +1,125 @@
+//! Webhook helpers
+//!
+//! This module contains helpers for servers receiving Fitbit subscription notifications.
+//! It provides functionality for verifying the subscriber endpoint, checking the
+//! `X-Fitbit-Signature` header, and parsing notification payloads.
+
+use crate::types::webhook::{UpdateNotification, WebhookError};
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use hmac::{Hmac, Mac};
+use reqwest::StatusCode;
+use sha1::Sha1;
+
+/// Verifier for requests sent by Fitbit to a subscriber endpoint
+///
+/// # Examples
+///
+/// ```
+/// use fitbit_sdk::types::datetime::ApiField;
+/// use fitbit_sdk::types::subscriptions::CollectionType;
+/// use fitbit_sdk::webhook::WebhookVerifier;
+/// use reqwest::StatusCode;
+///
+/// let verifier = WebhookVerifier::new("client-secret", "verification-code");
+///
+/// // GET /webhook?verify=...
+/// assert_eq!(verifier.verify_subscriber("verification-code"), StatusCode::NO_CONTENT);
+/// assert_eq!(verifier.verify_subscriber("something-else"), StatusCode::NOT_FOUND);
+///
+/// // POST /webhook
+/// let body = br#"[{"collectionType":"sleep","date":"2025-01-01","ownerId":"ABC123","ownerType":"user","subscriptionId":"1"}]"#;
+/// let notifications = verifier
+///     .verify_notifications(body, Some("tdcWlwSa+UerV8kZ+NdgzjgSozU="))
+///     .unwrap();
+/// assert_eq!(notifications[0].collection_type, CollectionType::Sleep);
+/// assert_eq!(
+///     notifications[0].date.as_ref().map(ApiField::to_api_string).as_deref(),
+///     Some("2025-01-01")
+/// );
+///
+/// assert!(verifier.verify_notifications(body, Some("forged")).is_err());
+/// assert!(verifier.verify_notifications(body, None).is_err());
+/// ```
+#[derive(Debug, Clone)]
+pub struct WebhookVerifier {
+    client_secret: String,
+    verification_code: String,
+}
+
+impl WebhookVerifier {
+    /// Name of the header carrying the notification signature
+    pub const SIGNATURE_HEADER: &'static str = "X-Fitbit-Signature";
+
+    /// Creates a verifier from the application's client secret and the
+    /// verification code shown when the subscriber was configured
+    pub fn new(client_secret: impl Into<String>, verification_code: impl Into<String>) -> Self {
+        Self {
+            client_secret: client_secret.into(),
+            verification_code: verification_code.into(),
+        }
+    }
+
+    /// Answers a subscriber verification request
+    ///
+    /// Fitbit verifies a subscriber by sending `GET ?verify=<code>` twice, once
+    /// with the correct code and once with an incorrect one. Returns the status
+    /// the endpoint must respond with: `204 No Content` for the correct code and
+    /// `404 Not Found` otherwise.
+    pub fn verify_subscriber(&self, verify: &str) -> StatusCode {
+        if verify == self.verification_code {
+            StatusCode::NO_CONTENT
+        } else {
+            StatusCode::NOT_FOUND
+        }
+    }
+
+    /// Returns true if `signature` is the HMAC-SHA1 signature of `body`
+    ///
+    /// The signature is the base64-encoded HMAC-SHA1 of the raw request body,
+    /// keyed with the client secret followed by `&`. The comparison runs in
+    /// constant time.
+    pub fn verify_signature(&self, body: &[u8], signature: &str) -> bool {
+        let Ok(signature) = STANDARD.decode(signature.trim()) else {
+            return false;
+        };
+        let key = format!("{}&", self.client_secret);
+        let Ok(mut mac) = Hmac::<Sha1>::new_from_slice(key.as_bytes()) else {
+            return false;
+        };
+        mac.update(body);
+        mac.verify_slice(&signature).is_ok()
+    }
+
+    /// Verifies the signature of a notification request and parses its body
+    ///
+    /// # Arguments
+    ///
+    /// * `body` - The raw request body
+    /// * `signature` - The value of the `X-Fitbit-Signature` header, if present
+    ///
+    /// # Errors
+    ///
+    /// Returns a `WebhookError` if:
+    /// - The signature is missing or does not match the body
+    /// - The body is not a list of update notifications
+    pub fn verify_notifications(
+        &self,
+        body: &[u8],
+        signature: Option<&str>,
+    ) -> Result<Vec<UpdateNotification>, WebhookError> {
+        match signature {
+            Some(signature) if self.verify_signature(body, signature) => parse_notifications(body),
+            _ => Err(WebhookError::InvalidSignature),
+        }
+    }
+}
+
+/// Parses the body of a notification request without verifying its signature
+///
+/// # Errors
+///
+/// Returns `WebhookError::InvalidPayload` if the body is not a list of update notifications.
+pub fn parse_notifications(body: &[u8]) -> Result<Vec<UpdateNotification>, WebhookError> {
+    serde_json::from_slice(body).map_err(|e| WebhookError::InvalidPayload(e.to_string()))
+}
@@ -0,0 +1,143 @@
+//! Wellness Reports
+//!
+//! This module contains the implementation of `WellnessClient`, which combines
+//! the daily metrics of the HRV, heart rate, breathing rate, temperature and
+//! cardio fitness endpoints into a single report per day.
+
+use crate::client::FitbitClient;
+use crate::types::breathing_rate::BreathingRateClient;
+use crate::types::cardio_fitness::CardioFitnessClient;
+use crate::types::date::date_windows;
+use crate::types::datetime::ApiField;
+use crate::types::heart_rate::HeartRateClient;
+use crate::types::hrv::HrvClient;
+use crate::types::temperature::TemperatureClient;
+use crate::types::user::UserId;
+use crate::types::wellness::{RecoveryDay, WellnessClient, WellnessError};
+use async_trait::async_trait;
+use futures_util::future::try_join5;
+use futures_util::TryFutureExt;
+use std::collections::BTreeMap;
+use time::Date;
+
+/// Maximum number of days of an HRV, breathing rate, skin temperature or
+/// cardio fitness score range
+const RECOVERY_RANGE_DAYS: i64 = 30;
+
+#[async_trait]
+impl WellnessClient for FitbitClient {
+    /// Gets the recovery metrics of each day in a date range
+    ///
+    /// Requests the HRV, resting heart rate, breathing rate, skin temperature
+    /// and cardio fitness score of the range concurrently and merges them by
+    /// day, the usual inputs of a readiness dashboard. Ranges longer than 30
+    /// days are split into several requests per metric.
+    ///
+    /// # Arguments
+    ///
+    /// * `user_id` - The user ID to get the report for, or "-" for current user
+    /// * `start_date` - The first day of the report
+    /// * `end_date` - The last day of the report
+    ///
+    /// # Returns
+    ///
+    /// Returns a `RecoveryDay` for every day from `start_date` to `end_date`
+    /// in chronological order, with the metrics Fitbit did not calculate set
+    /// to `None`. Returns no days if `start_date` is after `end_date`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `WellnessError` if:
+    /// - Any request fails to send
+    /// - The API returns an error response, e.g. because the token lacks one
+    ///   of the `heartrate`, `respiratory_rate`, `temperature` and
+    ///   `cardio_fitness` scopes
+    /// - A response cannot be parsed
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use fitbit_sdk::client::FitbitClient;
+    /// use fitbit_sdk::types::wellness::{WellnessClient, WellnessError};
+    /// use time::macros::date;
+    /// use tokio;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), WellnessError> {
+    ///     let client = FitbitClient::new::<WellnessError>()?;
+    ///
+    ///     let report = client
+    ///         .get_recovery_report("-", date!(2025 - 01 - 01), date!(2025 - 01 - 14))
+    ///         .await?;
+    ///     for day in report {
+    ///         println!(
+    ///             "{}: HRV {:?}, resting HR {:?}",
+    ///             day.date,
+    ///             day.hrv.map(|hrv| hrv.daily_rmssd),
+    ///             day.resting_heart_rate
+    ///         );
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    async fn get_recovery_report<'a>(
+        &'a self,
+        user_id: impl Into<UserId> + Send,
+        start_date: Date,
+        end_date: Date,
+    ) -> Result<Vec<RecoveryDay>, WellnessError> {
+        let user_id: UserId = user_id.into();
+        let mut days = BTreeMap::new();
+        let mut date = Some(start_date);
+        while let Some(day) = date.filter(|day| *day <= end_date) {
+            days.insert(day, RecoveryDay::new(day));
+            date = day.next_day();
+        }
+
+        for (from, to) in date_windows(start_date, end_date, RECOVERY_RANGE_DAYS) {
+            let (from, to) = (from.to_string(), to.to_string());
+            let (hrv, heart_rate, breathing_rate, skin_temperature, cardio_fitness) = try_join5(
+                self.get_hrv_summary_range(&user_id, &from, &to)
+                    .map_err(WellnessError::from),
+                self.get_heart_rate_time_series_range(&user_id, &from, &to)
+                    .map_err(WellnessError::from),
+                self.get_breathing_rate_summary_range(&user_id, &from, &to)
+                    .map_err(WellnessError::from),
+                self.get_skin_temperature_range(&user_id, &from, &to)
+                    .map_err(WellnessError::from),
+                self.get_cardio_fitness_score_range(&user_id, &from, &to)
+                    .map_err(WellnessError::from),
+            )
+            .await?;
+
+            for summary in hrv {
+                if let Some(day) = days.get_mut(&summary.date_time.convert::<Date>()?) {
+                    day.hrv = Some(summary.value);
+                }
+            }
+            for series in heart_rate {
+                if let Some(day) = days.get_mut(&series.date_time.convert::<Date>()?) {
+                    day.resting_heart_rate = series.value.resting_heart_rate;
+                }
+            }
+            for summary in breathing_rate {
+                if let Some(day) = days.get_mut(&summary.date_time.convert::<Date>()?) {
+                    day.breathing_rate = Some(summary.value.breathing_rate);
+                }
+            }
+            for temperature in skin_temperature {
+                if let Some(day) = days.get_mut(&temperature.date_time.convert::<Date>()?) {
+                    day.skin_temperature = Some(temperature.value.nightly_relative);
+                }
+            }
+            for score in cardio_fitness {
+                if let Some(day) = days.get_mut(&score.date_time.convert::<Date>()?) {
+                    day.vo2_max = Some(score.value.vo2_max);
+                }
+            }
+        }
+
+        Ok(days.into_values().collect())
+    }
+}